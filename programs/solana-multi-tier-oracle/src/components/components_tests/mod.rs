@@ -0,0 +1,6 @@
+#[cfg(test)]
+pub mod price_source;
+#[cfg(test)]
+pub mod raydium_clmm_tests;
+#[cfg(test)]
+pub mod update_price_compute;
@@ -0,0 +1,231 @@
+//! Dispatch from `SourceType` to the observer modules.
+//!
+//! The Raydium path is identified by the observer's own account validation
+//! error surfacing unchanged; unsupported sources must fail before any
+//! account is read. Confidence normalization must put equal real-world
+//! uncertainty on equal footing across source types, and the spread mapping for
+//! order-book sources must land on the same 10,000-is-best scale. External
+//! readings dated past the tolerated clock skew are refused; DEX readings,
+//! stamped by the cluster clock, are not checked.
+
+use crate::components::components_tests::raydium_clmm_tests::helpers::{
+    assert_raydium_error, observation_pda,
+};
+use crate::components::price_source::{
+    check_sample_timestamp, confidence_from_spread_bps, fetch_price, normalize_confidence,
+    Observer, SourceAccounts, SourceParams,
+};
+use crate::components::raydium_clmm_observer::fetch_raydium_price::{
+    ConfidenceMethod, RaydiumParams, ValidationPolicy,
+};
+use crate::error::{RaydiumObserverError, StateError};
+use crate::state::price_feed::SourceType;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use crate::utils::constants::MAX_CLOCK_SKEW;
+use anchor_lang::prelude::*;
+
+fn raydium_params() -> SourceParams {
+    SourceParams::RaydiumClmm(RaydiumParams {
+        window_seconds: 3_600,
+        min_seconds: 60,
+        min_liquidity: 0,
+        max_tick_deviation: 500,
+        alpha_basis_points: 1_000,
+        validation_policy: ValidationPolicy::Both,
+        impact_trade_size: 0,
+        confidence_method: ConfidenceMethod::Variance,
+        strict_coverage: false,
+        strict_min_elapsed: true,
+        allow_spot_fallback: false,
+        min_observations: 0,
+        timestamp: 1_700_000_000,
+    })
+}
+
+/// Runs `fetch_price` against two empty accounts.
+///
+/// Empty data is rejected by every observer's size check, so the error tells
+/// which observer (if any) handled the call. The observation sits at the
+/// pool's PDA so the Raydium path gets past address binding to that check.
+fn fetch_with_empty_accounts(source_type: SourceType) -> Result<()> {
+    let program_id = Pubkey::new_unique();
+    let pool_key = Pubkey::new_unique();
+    let observation_key = observation_pda(&pool_key, &program_id);
+    let (mut pool_lamports, mut observation_lamports) = (0u64, 0u64);
+    let (mut pool_data, mut observation_data) = (Vec::<u8>::new(), Vec::<u8>::new());
+
+    let pool = AccountInfo::new(
+        &pool_key,
+        false,
+        false,
+        &mut pool_lamports,
+        &mut pool_data,
+        &program_id,
+        false,
+        0,
+    );
+    let observation = AccountInfo::new(
+        &observation_key,
+        false,
+        false,
+        &mut observation_lamports,
+        &mut observation_data,
+        &program_id,
+        false,
+        0,
+    );
+
+    fetch_price(
+        source_type,
+        SourceAccounts {
+            price_account: &pool,
+            history_account: &observation,
+            program_id: &program_id,
+        },
+        raydium_params(),
+    )
+    .map(|_| ())
+}
+
+#[test]
+fn dex_sources_route_to_raydium() {
+    assert_eq!(
+        Observer::for_source(SourceType::DEX),
+        Some(Observer::RaydiumClmm)
+    );
+}
+
+#[test]
+fn sources_without_an_observer_have_no_route() {
+    for source_type in [SourceType::CEX, SourceType::Oracle, SourceType::Aggregator] {
+        assert_eq!(Observer::for_source(source_type), None);
+    }
+}
+
+#[test]
+fn dex_fetch_reaches_the_raydium_observer() {
+    assert_raydium_error(
+        fetch_with_empty_accounts(SourceType::DEX),
+        RaydiumObserverError::TooSmall,
+    );
+}
+
+#[test]
+fn unsupported_source_fails_before_reading_accounts() {
+    for source_type in [SourceType::CEX, SourceType::Oracle, SourceType::Aggregator] {
+        assert_state_error(
+            fetch_with_empty_accounts(source_type),
+            StateError::UnsupportedSourceType,
+        );
+    }
+}
+
+/// DEX score for a one-sigma move of `sigma_bps`, as the Raydium observer
+/// derives it from tick variance (one tick is about one basis point).
+fn dex_score_for_sigma(sigma_bps: u32) -> u32 {
+    10_000u32.saturating_sub((sigma_bps * sigma_bps / 100).min(10_000))
+}
+
+#[test]
+fn equal_uncertainty_normalizes_equally_across_sources() {
+    for sigma_bps in [0, 10, 50, 100, 250, 999, 1_000] {
+        let dex = normalize_confidence(SourceType::DEX, dex_score_for_sigma(sigma_bps));
+        let oracle = normalize_confidence(SourceType::Oracle, sigma_bps);
+        let cex = normalize_confidence(SourceType::CEX, sigma_bps);
+
+        assert_eq!(dex, oracle, "sigma {sigma_bps} bps");
+        assert_eq!(dex, cex, "sigma {sigma_bps} bps");
+    }
+}
+
+#[test]
+fn larger_deviation_means_lower_confidence() {
+    let tight = normalize_confidence(SourceType::Oracle, 20);
+    let wide = normalize_confidence(SourceType::Oracle, 200);
+
+    assert!(tight > wide);
+    assert_eq!(normalize_confidence(SourceType::Oracle, 0), 10_000);
+}
+
+#[test]
+fn normalized_confidence_stays_within_basis_points() {
+    for source_type in [
+        SourceType::DEX,
+        SourceType::CEX,
+        SourceType::Oracle,
+        SourceType::Aggregator,
+    ] {
+        for raw in [0, 1, 9_999, 10_000, 10_001, u32::MAX] {
+            assert!(normalize_confidence(source_type, raw) <= 10_000);
+        }
+    }
+
+    // Scores already on the common scale are clamped, not rescaled
+    assert_eq!(normalize_confidence(SourceType::DEX, 7_500), 7_500);
+    assert_eq!(normalize_confidence(SourceType::Aggregator, 20_000), 10_000);
+    // A deviation of 10% or more leaves no confidence at all
+    assert_eq!(normalize_confidence(SourceType::Oracle, u32::MAX), 0);
+}
+
+#[test]
+fn spread_confidence_shares_the_dex_scale() {
+    // A one-bps book is about as certain as a pool with a one-bps sigma
+    let tight = confidence_from_spread_bps(1);
+    assert!(tight >= 9_990);
+    assert!(tight.abs_diff(dex_score_for_sigma(1)) <= 10);
+
+    // A book wider than most pools will ever get sits at the bottom
+    assert!(confidence_from_spread_bps(9_900) <= 100);
+    assert!(dex_score_for_sigma(995) <= 100);
+}
+
+#[test]
+fn spread_confidence_is_monotonic_and_clamped() {
+    assert_eq!(confidence_from_spread_bps(0), 10_000);
+    assert_eq!(confidence_from_spread_bps(10_000), 0);
+    assert_eq!(confidence_from_spread_bps(u32::MAX), 0);
+
+    let mut previous = confidence_from_spread_bps(0);
+    for spread_bps in [1, 5, 25, 100, 1_000, 5_000, 9_999] {
+        let confidence = confidence_from_spread_bps(spread_bps);
+        assert!(confidence < previous, "spread {spread_bps} bps");
+        previous = confidence;
+    }
+}
+
+const NOW: i64 = 1_700_000_000;
+const SKEW: u32 = 5;
+
+#[test]
+fn external_reading_within_skew_is_accepted() {
+    for source_type in [SourceType::CEX, SourceType::Oracle, SourceType::Aggregator] {
+        assert!(check_sample_timestamp(source_type, NOW, NOW, SKEW).is_ok());
+        assert!(check_sample_timestamp(source_type, NOW + SKEW as i64, NOW, SKEW).is_ok());
+    }
+}
+
+#[test]
+fn external_reading_past_skew_is_rejected() {
+    assert_state_error(
+        check_sample_timestamp(SourceType::Oracle, NOW + SKEW as i64 + 1, NOW, SKEW),
+        StateError::FutureTimestamp,
+    );
+    assert_state_error(
+        check_sample_timestamp(SourceType::CEX, NOW + 3_600, NOW, SKEW),
+        StateError::FutureTimestamp,
+    );
+}
+
+#[test]
+fn dex_reading_is_exempt_from_the_skew_check() {
+    assert!(check_sample_timestamp(SourceType::DEX, NOW + 3_600, NOW, 0).is_ok());
+}
+
+#[test]
+fn skew_tolerance_is_capped() {
+    assert!(check_sample_timestamp(SourceType::Oracle, NOW, NOW, MAX_CLOCK_SKEW).is_ok());
+    assert_state_error(
+        check_sample_timestamp(SourceType::Oracle, NOW, NOW, MAX_CLOCK_SKEW + 1),
+        StateError::InvalidClockSkew,
+    );
+}
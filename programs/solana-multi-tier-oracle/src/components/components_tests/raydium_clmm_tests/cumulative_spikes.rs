@@ -0,0 +1,153 @@
+//! Isolated `tick_cumulative` jumps inside the observation ring.
+//!
+//! Tampering with one observation's cumulative bends the two intervals it
+//! bounds in opposite directions. Both must drop out of the T2EMA and variance
+//! passes and surface as spike risk instead, while a genuine move that holds
+//! its new level is kept.
+
+use super::helpers::{with_linked_ring, with_observation_reader};
+use crate::components::raydium_clmm_observer::fetch_raydium_price::{
+    observe_raydium_price, ConfidenceMethod, RaydiumParams, ValidationPolicy,
+};
+use crate::components::raydium_clmm_observer::raydium_constants::CUMULATIVE_SPIKE_RISK_BPS;
+use crate::components::raydium_clmm_observer::twap::{
+    confidence_from_variance, count_cumulative_spikes, ewma_confidence_from_observations,
+    t2ema_tick,
+};
+
+const FIRST_TIMESTAMP: u32 = 1_700_000_000;
+const CADENCE: u32 = 15;
+const COUNT: usize = 20;
+const RING_TICK: i64 = 40;
+const SPIKED_INDEX: usize = 10;
+/// Extra cumulative injected at `SPIKED_INDEX`: a 5,000-tick jump over one interval.
+const INJECTED: i64 = 5_000 * CADENCE as i64;
+
+/// Observations whose successive intervals average the given ticks.
+fn observations_for_ticks(ticks: &[i64]) -> Vec<(u32, i64)> {
+    let mut cumulative = 0i64;
+    let mut observations = vec![(FIRST_TIMESTAMP, cumulative)];
+    for (i, tick) in ticks.iter().enumerate() {
+        cumulative += tick * CADENCE as i64;
+        observations.push((FIRST_TIMESTAMP + (i as u32 + 1) * CADENCE, cumulative));
+    }
+    observations
+}
+
+fn steady_ring() -> Vec<(u32, i64)> {
+    observations_for_ticks(&[RING_TICK; COUNT - 1])
+}
+
+/// Steady ring with one observation's cumulative pushed up by `INJECTED`.
+fn spiked_ring() -> Vec<(u32, i64)> {
+    let mut observations = steady_ring();
+    observations[SPIKED_INDEX].1 += INJECTED;
+    observations
+}
+
+/// `(spikes, t2ema, variance confidence, ewma confidence)` over the whole ring.
+fn estimates(observations: &[(u32, i64)]) -> (u32, i64, u32, u32) {
+    let mut result = None;
+    with_observation_reader(observations, |reader| {
+        let index_now = observations.len() - 1;
+        result = Some((
+            count_cumulative_spikes(reader, 0, index_now),
+            t2ema_tick(reader, 0, index_now, 2_000).unwrap(),
+            confidence_from_variance(reader, 0, index_now, 1).unwrap(),
+            ewma_confidence_from_observations(reader, 0, index_now, 2_000, 1).unwrap(),
+        ));
+    });
+    result.unwrap()
+}
+
+fn params() -> RaydiumParams {
+    RaydiumParams {
+        window_seconds: 120,
+        min_seconds: 0,
+        min_liquidity: 1,
+        max_tick_deviation: 100,
+        alpha_basis_points: 2_000,
+        validation_policy: ValidationPolicy::Both,
+        impact_trade_size: 0,
+        confidence_method: ConfidenceMethod::Variance,
+        strict_coverage: false,
+        strict_min_elapsed: true,
+        allow_spot_fallback: false,
+        min_observations: 0,
+        timestamp: (FIRST_TIMESTAMP + (COUNT as u32 - 1) * CADENCE) as i64,
+    }
+}
+
+#[test]
+fn normal_ring_excludes_nothing() {
+    let (spikes, t2ema, variance, ewma) = estimates(&steady_ring());
+
+    assert_eq!(spikes, 0);
+    assert_eq!(t2ema, RING_TICK);
+    assert_eq!(variance, 10_000);
+    assert_eq!(ewma, 10_000);
+}
+
+#[test]
+fn injected_spike_is_excluded_from_estimators() {
+    let (spikes, t2ema, variance, ewma) = estimates(&spiked_ring());
+
+    // The tampered observation bounds two intervals, one pulled up and one down
+    assert_eq!(spikes, 2);
+    // With both dropped the estimators see the same steady ring as before
+    assert_eq!(t2ema, RING_TICK);
+    assert_eq!(variance, 10_000);
+    assert_eq!(ewma, 10_000);
+}
+
+#[test]
+fn sustained_move_is_not_a_spike() {
+    let mut ticks = [RING_TICK; COUNT - 1];
+    for tick in &mut ticks[SPIKED_INDEX..] {
+        *tick = RING_TICK + 5_000;
+    }
+    let (spikes, _, variance, _) = estimates(&observations_for_ticks(&ticks));
+
+    assert_eq!(spikes, 0);
+    assert!(variance < 10_000, "a real move still counts as variance");
+}
+
+#[test]
+fn spike_at_ring_edge_is_kept() {
+    // The newest interval has no successor to compare against
+    let mut observations = steady_ring();
+    observations.last_mut().unwrap().1 += INJECTED;
+
+    let (spikes, _, _, _) = estimates(&observations);
+    assert_eq!(spikes, 0);
+}
+
+#[test]
+fn injected_spike_raises_manipulation_risk() {
+    let mut clean = None;
+    with_linked_ring(
+        &steady_ring(),
+        RING_TICK as i32,
+        |pool, observation, program_id| {
+            clean = Some(observe_raydium_price(pool, observation, program_id, params()).unwrap());
+        },
+    );
+    let (clean_price, clean_factors) = clean.unwrap();
+
+    // Spike inside the 120 second window: the newest eight intervals
+    let mut observations = steady_ring();
+    observations[COUNT - 4].1 += INJECTED;
+    with_linked_ring(
+        &observations,
+        RING_TICK as i32,
+        |pool, observation, program_id| {
+            let (price, factors) =
+                observe_raydium_price(pool, observation, program_id, params()).unwrap();
+
+            assert_eq!(clean_factors.spike_risk, 0);
+            assert_eq!(factors.spike_risk, 2 * CUMULATIVE_SPIKE_RISK_BPS);
+            assert!(price.manipulation_score > clean_price.manipulation_score);
+            assert_eq!(price.price, clean_price.price);
+        },
+    );
+}
@@ -0,0 +1,64 @@
+//! Tick-cumulative wrap handling in the TWAP tick.
+//!
+//! A single wrap of the `i64` cumulative is absorbed by wrapping subtraction.
+//! A delta reduced by a further multiple of 2^64 flips the average's sign, which
+//! the endpoint ticks expose.
+
+use super::helpers::assert_raydium_error;
+use crate::components::raydium_clmm_observer::twap::twap_tick_from_cumulatives;
+use crate::error::RaydiumObserverError;
+
+const ELAPSED: u32 = 60;
+const TICK: i64 = 1_000;
+
+#[test]
+fn single_wrap_yields_the_true_average() {
+    // The cumulative crosses i64::MAX halfway through the window
+    let then = i64::MAX - TICK * (ELAPSED as i64 / 2) + 1;
+    let now = then.wrapping_add(TICK * ELAPSED as i64);
+    assert!(now < then, "fixture must wrap");
+
+    let tick = twap_tick_from_cumulatives(then, now, ELAPSED, Some((TICK, TICK))).unwrap();
+    assert_eq!(tick, TICK);
+
+    // Same for a falling cumulative wrapping below i64::MIN
+    let then = i64::MIN + TICK * (ELAPSED as i64 / 2) - 1;
+    let now = then.wrapping_sub(TICK * ELAPSED as i64);
+    let tick = twap_tick_from_cumulatives(then, now, ELAPSED, Some((-TICK, -TICK))).unwrap();
+    assert_eq!(tick, -TICK);
+}
+
+#[test]
+fn double_wrap_flipping_sign_is_rejected() {
+    // A true delta of 2^64 + 60_000 comes back as 60_000 - 2^64, i.e. wrapped once
+    // too many; contrived here as a negative delta between positive endpoints
+    let then = 0;
+    let now = -(TICK * ELAPSED as i64);
+
+    assert_raydium_error(
+        twap_tick_from_cumulatives(then, now, ELAPSED, Some((TICK, TICK + 200))),
+        RaydiumObserverError::TickOutOfBounds,
+    );
+    assert_raydium_error(
+        twap_tick_from_cumulatives(then, -now, ELAPSED, Some((-TICK, -TICK))),
+        RaydiumObserverError::TickOutOfBounds,
+    );
+}
+
+#[test]
+fn endpoints_without_a_shared_direction_do_not_constrain_the_sign() {
+    let then = 0;
+    let now = -(TICK * ELAPSED as i64);
+
+    // A path crossing zero, or one starting at it, can average to either sign
+    for endpoints in [(TICK, -TICK), (0, TICK), (-TICK, 0)] {
+        assert_eq!(
+            twap_tick_from_cumulatives(then, now, ELAPSED, Some(endpoints)).unwrap(),
+            -TICK
+        );
+    }
+    assert_eq!(
+        twap_tick_from_cumulatives(then, now, ELAPSED, None).unwrap(),
+        -TICK
+    );
+}
@@ -0,0 +1,42 @@
+//! Conversion of observer output into stored `PriceData`.
+
+use crate::state::state_tests::oracle_state_tests::helpers::decimal_price_with_decimals;
+
+const PUBLISHED_AT: i64 = 1_700_000_123;
+
+#[test]
+fn normal_price_converts_field_for_field() {
+    let decimal_price = decimal_price_with_decimals(9, 6);
+
+    let data = decimal_price.to_price_data(PUBLISHED_AT, -6);
+
+    assert_eq!(data.price, 1i128 << 64);
+    assert_eq!(data.conf, 9_500);
+    assert_eq!(data.timestamp, PUBLISHED_AT);
+    assert_eq!(data.expo, -6);
+    assert_eq!(data._padding, [0; 12]);
+}
+
+#[test]
+fn price_saturates_at_i128_max() {
+    let mut decimal_price = decimal_price_with_decimals(6, 6);
+
+    decimal_price.price = i128::MAX as u128;
+    assert_eq!(
+        decimal_price.to_price_data(PUBLISHED_AT, 0).price,
+        i128::MAX
+    );
+
+    // One past the signed range must not wrap into a negative price
+    decimal_price.price = i128::MAX as u128 + 1;
+    assert_eq!(
+        decimal_price.to_price_data(PUBLISHED_AT, 0).price,
+        i128::MAX
+    );
+
+    decimal_price.price = u128::MAX;
+    assert_eq!(
+        decimal_price.to_price_data(PUBLISHED_AT, 0).price,
+        i128::MAX
+    );
+}
@@ -0,0 +1,115 @@
+//! Recency-weighted confidence.
+//!
+//! The same set of interval ticks is replayed with the volatile interval at the
+//! end and at the start of the window: only the EWMA method should care where
+//! in the window the volatility happened.
+
+use super::helpers::{assert_raydium_error, observation_account_data};
+use crate::components::raydium_clmm_observer::raydium_accounts::ObservationReader;
+use crate::components::raydium_clmm_observer::twap::{
+    confidence_from_variance, ewma_confidence_from_observations,
+};
+use crate::error::RaydiumObserverError;
+use anchor_lang::prelude::*;
+
+const FIRST_TIMESTAMP: u32 = 1_700_000_000;
+const CADENCE: u32 = 15;
+const ALPHA_BPS: u16 = 3_000;
+const CALM_TICK: i64 = 100;
+const SPIKE_TICK: i64 = 1_100;
+const INTERVALS: usize = 20;
+
+/// Observations whose successive intervals average the given ticks.
+fn observations_for_ticks(ticks: &[i64]) -> Vec<(u32, i64)> {
+    let mut cumulative = 0i64;
+    let mut observations = vec![(FIRST_TIMESTAMP, cumulative)];
+    for (i, tick) in ticks.iter().enumerate() {
+        cumulative += tick * CADENCE as i64;
+        observations.push((FIRST_TIMESTAMP + (i as u32 + 1) * CADENCE, cumulative));
+    }
+    observations
+}
+
+fn ticks_with_spike_at(position: usize) -> Vec<i64> {
+    let mut ticks = vec![CALM_TICK; INTERVALS];
+    ticks[position] = SPIKE_TICK;
+    ticks
+}
+
+/// Returns `(equal_weight, ewma)` confidence over the whole ring.
+fn confidences(ticks: &[i64]) -> (u32, u32) {
+    let key = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut lamports = 1u64;
+    let mut data = observation_account_data(&observations_for_ticks(ticks));
+    let account_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    let reader = ObservationReader::new_ptr(&account_info).unwrap();
+    let index_now = ticks.len();
+
+    (
+        confidence_from_variance(&reader, 0, index_now, 1).unwrap(),
+        ewma_confidence_from_observations(&reader, 0, index_now, ALPHA_BPS, 1).unwrap(),
+    )
+}
+
+#[test]
+fn recent_spike_lowers_ewma_more_than_equal_weight() {
+    let (equal_weight, ewma) = confidences(&ticks_with_spike_at(INTERVALS - 1));
+    assert!(
+        ewma < equal_weight,
+        "ewma {ewma} should be below equal-weight {equal_weight}"
+    );
+}
+
+#[test]
+fn old_spike_is_forgotten_by_ewma_only() {
+    let (recent_equal, recent_ewma) = confidences(&ticks_with_spike_at(INTERVALS - 1));
+    let (old_equal, old_ewma) = confidences(&ticks_with_spike_at(1));
+
+    // Equal weighting cannot tell when the spike happened
+    assert_eq!(recent_equal, old_equal);
+    assert!(old_ewma > recent_ewma);
+    assert!(old_ewma > old_equal);
+}
+
+#[test]
+fn calm_window_keeps_full_confidence() {
+    let (equal_weight, ewma) = confidences(&[CALM_TICK; INTERVALS]);
+    assert_eq!(equal_weight, 10_000);
+    assert_eq!(ewma, 10_000);
+}
+
+#[test]
+fn ewma_rejects_out_of_range_alpha() {
+    let key = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut lamports = 1u64;
+    let mut data = observation_account_data(&observations_for_ticks(&[CALM_TICK; 4]));
+    let account_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    let reader = ObservationReader::new_ptr(&account_info).unwrap();
+
+    for alpha in [0, 10_001] {
+        assert_raydium_error(
+            ewma_confidence_from_observations(&reader, 0, 4, alpha, 1),
+            RaydiumObserverError::InvalidWindow,
+        );
+    }
+}
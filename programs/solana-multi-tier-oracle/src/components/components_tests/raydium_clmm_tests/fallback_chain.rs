@@ -0,0 +1,193 @@
+//! `ValidationPolicy::Fallback` degrading across progressively thinner rings.
+//!
+//! The same pool is observed through a warm ring, a single-observation ring
+//! where T2EMA has nothing to smooth, and rings with no recent history at all.
+//! Each read must succeed and be tagged with the estimator that priced it,
+//! while failures that are not about missing data still reject the price.
+//! The spot rung is only open to oracles that opted in, and a keeper's own
+//! thresholds can never open it.
+
+use super::helpers::{assert_raydium_error, with_linked_ring, with_observation_reader};
+use crate::components::raydium_clmm_observer::fetch_raydium_price::{
+    observe_raydium_price, ConfidenceMethod, DecimalPrice, PriceMethod, RaydiumParams,
+    ValidationPolicy,
+};
+use crate::components::raydium_clmm_observer::raydium_constants::{
+    MAX_OBSERVATION_STALENESS, SPOT_FALLBACK_CONFIDENCE_BPS,
+};
+use crate::components::raydium_clmm_observer::twap::t2ema_tick;
+use crate::error::RaydiumObserverError;
+use crate::instructions::update_price::SkipReason;
+
+const FIRST_TIMESTAMP: u32 = 1_700_000_000;
+const CADENCE: u32 = 15;
+const RING_TICK: i64 = 40;
+
+/// `count` observations at a steady `RING_TICK`, one every `CADENCE` seconds.
+fn ring(count: u32) -> Vec<(u32, i64)> {
+    (0..count)
+        .map(|i| {
+            let elapsed = i * CADENCE;
+            (FIRST_TIMESTAMP + elapsed, RING_TICK * elapsed as i64)
+        })
+        .collect()
+}
+
+/// Relaxed parameters read `age` seconds after the newest observation of a
+/// `count`-slot ring, on an oracle that opted into the spot fallback.
+fn params(count: u32, policy: ValidationPolicy, min_observations: u16, age: u32) -> RaydiumParams {
+    RaydiumParams {
+        window_seconds: 120,
+        min_seconds: 0,
+        min_liquidity: 1,
+        max_tick_deviation: 100,
+        alpha_basis_points: 2_000,
+        validation_policy: policy,
+        impact_trade_size: 0,
+        confidence_method: ConfidenceMethod::Variance,
+        strict_coverage: false,
+        strict_min_elapsed: false,
+        allow_spot_fallback: true,
+        min_observations,
+        timestamp: (FIRST_TIMESTAMP + count.saturating_sub(1) * CADENCE + age) as i64,
+    }
+}
+
+/// Observes a ring of `count` observations linked to a pool at `tick_current`.
+fn observe(
+    count: u32,
+    tick_current: i32,
+    policy: ValidationPolicy,
+    min_observations: u16,
+) -> anchor_lang::Result<DecimalPrice> {
+    observe_with(
+        count,
+        tick_current,
+        params(count, policy, min_observations, 0),
+    )
+}
+
+fn observe_with(
+    count: u32,
+    tick_current: i32,
+    params: RaydiumParams,
+) -> anchor_lang::Result<DecimalPrice> {
+    let mut result = None;
+    with_linked_ring(
+        &ring(count),
+        tick_current,
+        |pool, observation, program_id| {
+            result = Some(
+                observe_raydium_price(pool, observation, program_id, params)
+                    .map(|(price, _)| price),
+            );
+        },
+    );
+    result.unwrap()
+}
+
+#[test]
+fn warm_ring_is_cross_validated() {
+    let fallback = observe(20, RING_TICK as i32, ValidationPolicy::Fallback, 0).unwrap();
+    let both = observe(20, RING_TICK as i32, ValidationPolicy::Both, 0).unwrap();
+
+    assert_eq!(fallback.method, PriceMethod::CrossValidated);
+    assert_eq!(fallback.price, both.price);
+    assert_eq!(fallback.confidence, both.confidence);
+}
+
+#[test]
+fn single_observation_cannot_warm_t2ema() {
+    with_observation_reader(&ring(1), |reader| {
+        assert_raydium_error(
+            t2ema_tick(reader, 0, 0, 2_000),
+            RaydiumObserverError::T2emaNotWarm,
+        );
+    });
+
+    assert_raydium_error(
+        observe(1, RING_TICK as i32, ValidationPolicy::Both, 0).map(|_| ()),
+        RaydiumObserverError::T2emaNotWarm,
+    );
+}
+
+#[test]
+fn cold_t2ema_falls_back_to_twap() {
+    let fallback = observe(1, RING_TICK as i32, ValidationPolicy::Fallback, 0).unwrap();
+    let twap_only = observe(1, RING_TICK as i32, ValidationPolicy::TwapOnly, 0).unwrap();
+
+    assert_eq!(fallback.method, PriceMethod::Twap);
+    assert_eq!(fallback.price, twap_only.price);
+    // Carries the single-method penalty, like an explicit TWAP-only read
+    assert_eq!(fallback.confidence, twap_only.confidence);
+    assert!(
+        fallback.confidence
+            < observe(20, RING_TICK as i32, ValidationPolicy::Both, 0)
+                .unwrap()
+                .confidence
+    );
+}
+
+#[test]
+fn keeper_observation_floor_does_not_reach_spot() {
+    // The ring is live; only the caller's floor rejects it
+    assert_raydium_error(
+        observe(4, RING_TICK as i32, ValidationPolicy::Fallback, 10).map(|_| ()),
+        RaydiumObserverError::InsufficientObservations,
+    );
+}
+
+#[test]
+fn stale_ring_falls_back_to_spot() {
+    let stale = params(
+        4,
+        ValidationPolicy::Fallback,
+        0,
+        MAX_OBSERVATION_STALENESS as u32 + 1,
+    );
+
+    let spot = observe_with(4, RING_TICK as i32, stale).unwrap();
+    assert_eq!(spot.method, PriceMethod::Spot);
+    assert_eq!(spot.confidence, SPOT_FALLBACK_CONFIDENCE_BPS);
+    assert_eq!(spot.twap_deviation_bps, 0);
+    assert!(spot.price > 0);
+}
+
+#[test]
+fn spot_requires_the_oracle_opt_in() {
+    let mut closed = params(0, ValidationPolicy::Fallback, 0, 0);
+    closed.allow_spot_fallback = false;
+
+    assert!(observe_with(0, RING_TICK as i32, closed).is_err());
+}
+
+#[test]
+fn empty_ring_falls_back_to_spot() {
+    let spot = observe(0, RING_TICK as i32, ValidationPolicy::Fallback, 0).unwrap();
+    let warm = observe(20, RING_TICK as i32, ValidationPolicy::Fallback, 0).unwrap();
+
+    assert_eq!(spot.method, PriceMethod::Spot);
+    assert_eq!(spot.confidence, SPOT_FALLBACK_CONFIDENCE_BPS);
+    // The live tick matches the ring's steady tick, so the prices agree
+    assert_eq!(spot.price, warm.price);
+    assert!(spot.manipulation_score > warm.manipulation_score);
+}
+
+#[test]
+fn desynced_ring_is_not_a_data_shortage() {
+    // The ring averages tick 40 while the pool sits 500 ticks away
+    assert_raydium_error(
+        observe(20, RING_TICK as i32 + 500, ValidationPolicy::Fallback, 0).map(|_| ()),
+        RaydiumObserverError::StaleObservationRing,
+    );
+}
+
+#[test]
+fn cold_t2ema_is_a_soft_skip() {
+    let error = RaydiumObserverError::T2emaNotWarm.into();
+
+    assert_eq!(
+        SkipReason::from_error(&error),
+        Some(SkipReason::ObservationsNotReady)
+    );
+}
@@ -0,0 +1,170 @@
+use crate::components::raydium_clmm_observer::raydium_accounts::{
+    Observation, ObservationReader, ObservationState, PoolStatePartial,
+};
+use crate::components::raydium_clmm_observer::raydium_constants::OBSERVATION_SEED;
+use crate::error::RaydiumObserverError;
+use anchor_lang::prelude::*;
+use core::mem::{offset_of, size_of};
+
+/// Q64.64 representation of a sqrt price of exactly 1.0 (price 1:1).
+pub(crate) const SQRT_PRICE_ONE_X64: u128 = 1u128 << 64;
+
+/// Serializes the fields `PoolReader` consumes into a Raydium-shaped account buffer.
+///
+/// The eight-byte discriminator and the skipped prefix are zero-filled; only the
+/// trailing fields are populated at their packed offsets, exactly where the
+/// reader's unaligned loads will look for them.
+pub(crate) fn pool_account_data(liquidity: u128, sqrt_price_x64: u128) -> Vec<u8> {
+    let mut data = vec![0u8; 8 + size_of::<PoolStatePartial>()];
+
+    let liquidity_at = 8 + offset_of!(PoolStatePartial, liquidity);
+    data[liquidity_at..liquidity_at + 16].copy_from_slice(&liquidity.to_le_bytes());
+
+    let sqrt_at = 8 + offset_of!(PoolStatePartial, sqrt_price_x64);
+    data[sqrt_at..sqrt_at + 16].copy_from_slice(&sqrt_price_x64.to_le_bytes());
+
+    data
+}
+
+/// Overwrites the pool's tick spacing in a buffer built by `pool_account_data`.
+pub(crate) fn set_tick_spacing(data: &mut [u8], tick_spacing: u16) {
+    let spacing_at = 8 + offset_of!(PoolStatePartial, tick_spacing);
+    data[spacing_at..spacing_at + 2].copy_from_slice(&tick_spacing.to_le_bytes());
+}
+
+/// Overwrites the pool's token decimals in a buffer built by `pool_account_data`.
+pub(crate) fn set_mint_decimals(data: &mut [u8], decimal_0: u8, decimal_1: u8) {
+    data[8 + offset_of!(PoolStatePartial, mint_decimals_0)] = decimal_0;
+    data[8 + offset_of!(PoolStatePartial, mint_decimals_1)] = decimal_1;
+}
+
+/// Points the pool at `observation_key` and sets its live tick, as a linked pool would be.
+pub(crate) fn link_pool_observation(data: &mut [u8], observation_key: &Pubkey, tick_current: i32) {
+    let key_at = 8 + offset_of!(PoolStatePartial, observation_key);
+    data[key_at..key_at + 32].copy_from_slice(observation_key.as_ref());
+
+    let tick_at = 8 + offset_of!(PoolStatePartial, tick_current);
+    data[tick_at..tick_at + 4].copy_from_slice(&tick_current.to_le_bytes());
+}
+
+/// Serializes an initialized observation buffer holding `(block_timestamp, tick_cumulative)`
+/// pairs in slots `0..observations.len()`, with `observation_index` on the last one,
+/// matching Raydium's convention that the index names the most recent write.
+pub(crate) fn observation_account_data(observations: &[(u32, i64)]) -> Vec<u8> {
+    let mut data = vec![0u8; 8 + size_of::<ObservationState>()];
+
+    data[8 + offset_of!(ObservationState, initialized)] = 1;
+    let latest = observations.len().saturating_sub(1) as u16;
+    let index_at = 8 + offset_of!(ObservationState, observation_index);
+    data[index_at..index_at + 2].copy_from_slice(&latest.to_le_bytes());
+
+    let observations_at = 8 + offset_of!(ObservationState, observations);
+    for (slot, &(block_timestamp, tick_cumulative)) in observations.iter().enumerate() {
+        let at = observations_at + slot * size_of::<Observation>();
+        let timestamp_at = at + offset_of!(Observation, block_timestamp);
+        data[timestamp_at..timestamp_at + 4].copy_from_slice(&block_timestamp.to_le_bytes());
+        let cumulative_at = at + offset_of!(Observation, tick_cumulative);
+        data[cumulative_at..cumulative_at + 8].copy_from_slice(&tick_cumulative.to_le_bytes());
+    }
+
+    data
+}
+
+/// Runs `check` over a reader for a standalone ring holding `observations`.
+pub(crate) fn with_observation_reader(
+    observations: &[(u32, i64)],
+    check: impl FnOnce(&ObservationReader),
+) {
+    let key = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut lamports = 1u64;
+    let mut data = observation_account_data(observations);
+    let account_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    check(&ObservationReader::new_ptr(&account_info).unwrap());
+}
+
+/// Raydium's observation address for `pool_key` under `program_id`.
+pub(crate) fn observation_pda(pool_key: &Pubkey, program_id: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[OBSERVATION_SEED, pool_key.as_ref()], program_id).0
+}
+
+/// Runs `check` over a deep pool at price 1.0, linked to a ring holding `observations`.
+///
+/// The pool's live tick is set to `tick_current`, and both accounts are owned
+/// by the `program_id` passed to `check`, so the observer's ownership and link
+/// checks pass and only the ring contents decide the outcome.
+pub(crate) fn with_linked_ring(
+    observations: &[(u32, i64)],
+    tick_current: i32,
+    check: impl for<'info> FnOnce(&AccountInfo<'info>, &AccountInfo<'info>, &Pubkey),
+) {
+    with_linked_ring_decimals(observations, tick_current, (0, 0), check);
+}
+
+/// `with_linked_ring` over a pool whose mints carry `(decimal_0, decimal_1)` decimals.
+pub(crate) fn with_linked_ring_decimals(
+    observations: &[(u32, i64)],
+    tick_current: i32,
+    (decimal_0, decimal_1): (u8, u8),
+    check: impl for<'info> FnOnce(&AccountInfo<'info>, &AccountInfo<'info>, &Pubkey),
+) {
+    let program_id = Pubkey::new_unique();
+    let pool_key = Pubkey::new_unique();
+    let observation_key = observation_pda(&pool_key, &program_id);
+    let mut pool_lamports = 1u64;
+    let mut observation_lamports = 1u64;
+
+    let mut pool_data = pool_account_data(1_000_000, SQRT_PRICE_ONE_X64);
+    link_pool_observation(&mut pool_data, &observation_key, tick_current);
+    set_mint_decimals(&mut pool_data, decimal_0, decimal_1);
+    let mut observation_data = observation_account_data(observations);
+
+    let pool = AccountInfo::new(
+        &pool_key,
+        false,
+        false,
+        &mut pool_lamports,
+        &mut pool_data,
+        &program_id,
+        false,
+        0,
+    );
+    let observation = AccountInfo::new(
+        &observation_key,
+        false,
+        false,
+        &mut observation_lamports,
+        &mut observation_data,
+        &program_id,
+        false,
+        0,
+    );
+
+    check(&pool, &observation, &program_id);
+}
+
+/// Asserts that `result` failed with the given Raydium observer error code.
+pub(crate) fn assert_raydium_error<T: core::fmt::Debug>(
+    result: Result<T>,
+    expected: RaydiumObserverError,
+) {
+    let err = result.expect_err("expected error result");
+    let expected_error: Error = expected.into();
+
+    match (err, expected_error) {
+        (Error::AnchorError(actual), Error::AnchorError(expected)) => assert_eq!(
+            actual.error_code_number, expected.error_code_number,
+            "unexpected error variant"
+        ),
+        _ => panic!("expected anchor error code"),
+    }
+}
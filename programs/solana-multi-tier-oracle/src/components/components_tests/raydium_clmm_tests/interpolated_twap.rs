@@ -0,0 +1,124 @@
+//! Time-weighted ticks over explicit sub-windows of an index pair.
+//!
+//! The ring holds five observations a minute apart with ticks 100, 200, 300 and
+//! 400 over its four intervals, so every sub-window's average can be worked out
+//! by hand.
+
+use super::helpers::{assert_raydium_error, with_observation_reader};
+use crate::components::raydium_clmm_observer::twap::{
+    twap_tick_from_cumulatives, twap_tick_interpolated,
+};
+use crate::error::RaydiumObserverError;
+
+const FIRST_TIMESTAMP: u32 = 1_700_000_000;
+const CADENCE: u32 = 60;
+const TICKS: [i64; 4] = [100, 200, 300, 400];
+
+fn stepped_observations() -> Vec<(u32, i64)> {
+    let mut cumulative = 0;
+    let mut observations = vec![(FIRST_TIMESTAMP, 0)];
+    for (interval, tick) in TICKS.iter().enumerate() {
+        cumulative += tick * CADENCE as i64;
+        observations.push((
+            FIRST_TIMESTAMP + (interval as u32 + 1) * CADENCE,
+            cumulative,
+        ));
+    }
+    observations
+}
+
+fn at(seconds: u32) -> i64 {
+    (FIRST_TIMESTAMP + seconds) as i64
+}
+
+#[test]
+fn aligned_boundaries_match_the_cumulative_twap() {
+    with_observation_reader(&stepped_observations(), |reader| {
+        for (then, now) in [(0, 4), (1, 3), (2, 4), (0, 1)] {
+            let observation_then = reader.get_observation(then);
+            let observation_now = reader.get_observation(now);
+            let expected = twap_tick_from_cumulatives(
+                observation_then.tick_cumulative(),
+                observation_now.tick_cumulative(),
+                observation_now.block_timestamp() - observation_then.block_timestamp(),
+                None,
+            )
+            .unwrap();
+
+            let interpolated = twap_tick_interpolated(
+                reader,
+                0,
+                4,
+                observation_then.block_timestamp() as i64,
+                observation_now.block_timestamp() as i64,
+            )
+            .unwrap();
+
+            assert_eq!(interpolated, expected, "window {then}..{now}");
+        }
+    });
+}
+
+#[test]
+fn boundaries_inside_intervals_weight_each_part_by_its_time() {
+    with_observation_reader(&stepped_observations(), |reader| {
+        // 30 s at 100, 60 s at 200, 30 s at 300
+        assert_eq!(
+            twap_tick_interpolated(reader, 0, 4, at(30), at(150)).unwrap(),
+            200
+        );
+        // 45 s at 200 and 15 s at 300
+        assert_eq!(
+            twap_tick_interpolated(reader, 0, 4, at(75), at(135)).unwrap(),
+            225
+        );
+        // Entirely within one interval
+        assert_eq!(
+            twap_tick_interpolated(reader, 0, 4, at(190), at(200)).unwrap(),
+            400
+        );
+    });
+}
+
+#[test]
+fn walk_starts_at_index_then() {
+    with_observation_reader(&stepped_observations(), |reader| {
+        assert_eq!(
+            twap_tick_interpolated(reader, 2, 4, at(150), at(210)).unwrap(),
+            350
+        );
+        // The first two intervals are never visited from index 2
+        assert_raydium_error(
+            twap_tick_interpolated(reader, 2, 4, at(30), at(210)),
+            RaydiumObserverError::InsufficientTime,
+        );
+    });
+}
+
+#[test]
+fn targets_beyond_the_observations_are_rejected() {
+    with_observation_reader(&stepped_observations(), |reader| {
+        assert_raydium_error(
+            twap_tick_interpolated(reader, 0, 4, at(60), at(241)),
+            RaydiumObserverError::InsufficientTime,
+        );
+        assert_raydium_error(
+            twap_tick_interpolated(reader, 0, 4, at(0) - 1, at(60)),
+            RaydiumObserverError::InsufficientTime,
+        );
+    });
+}
+
+#[test]
+fn empty_or_inverted_windows_are_rejected() {
+    with_observation_reader(&stepped_observations(), |reader| {
+        assert_raydium_error(
+            twap_tick_interpolated(reader, 0, 4, at(60), at(60)),
+            RaydiumObserverError::InvalidWindow,
+        );
+        assert_raydium_error(
+            twap_tick_interpolated(reader, 0, 4, at(120), at(60)),
+            RaydiumObserverError::InvalidWindow,
+        );
+    });
+}
@@ -0,0 +1,123 @@
+//! Read-only manipulation assessment against the update path.
+//!
+//! The view and `update_price` must agree on the observed score for the same
+//! accounts and time, so both pipelines run here over one linked pool and ring.
+
+use super::helpers::with_linked_ring;
+use crate::components::price_source::{fetch_price, SourceAccounts, SourceParams};
+use crate::components::raydium_clmm_observer::fetch_raydium_price::{
+    observe_raydium_price, ConfidenceMethod, RaydiumParams, ValidationPolicy,
+};
+use crate::components::raydium_clmm_observer::raydium_accounts::PoolReader;
+use crate::components::raydium_clmm_observer::twap::assess_manipulation_risk;
+use crate::state::price_feed::SourceType;
+use anchor_lang::prelude::*;
+
+const FIRST_TIMESTAMP: u32 = 1_700_000_000;
+const CADENCE: u32 = 15;
+const RING_TICK: i64 = 40;
+
+/// Twenty observations at a steady `RING_TICK`, one every `CADENCE` seconds.
+fn steady_ring() -> Vec<(u32, i64)> {
+    (0..20)
+        .map(|i| {
+            let elapsed = i as u32 * CADENCE;
+            (FIRST_TIMESTAMP + elapsed, RING_TICK * elapsed as i64)
+        })
+        .collect()
+}
+
+fn params(min_liquidity: u128) -> RaydiumParams {
+    RaydiumParams {
+        window_seconds: 120,
+        min_seconds: 0,
+        min_liquidity,
+        max_tick_deviation: 100,
+        alpha_basis_points: 2_000,
+        validation_policy: ValidationPolicy::Both,
+        impact_trade_size: 1_000,
+        confidence_method: ConfidenceMethod::Variance,
+        strict_coverage: false,
+        strict_min_elapsed: true,
+        allow_spot_fallback: false,
+        min_observations: 0,
+        timestamp: FIRST_TIMESTAMP as i64 + 19 * CADENCE as i64,
+    }
+}
+
+fn pool_impact_bps(pool: &AccountInfo) -> u32 {
+    PoolReader::new_ptr(pool)
+        .unwrap()
+        .price_impact_bps(1_000)
+        .unwrap()
+}
+
+/// Runs `check` over a pool linked to a steady observation ring.
+fn with_linked_accounts(
+    check: impl for<'info> FnOnce(&AccountInfo<'info>, &AccountInfo<'info>, &Pubkey),
+) {
+    with_linked_ring(&steady_ring(), RING_TICK as i32, check);
+}
+
+#[test]
+fn view_score_matches_update_fetch() {
+    with_linked_accounts(|pool, observation, program_id| {
+        let (observed, factors) =
+            observe_raydium_price(pool, observation, program_id, params(1)).unwrap();
+
+        // The same read `update_price` performs through the source facade
+        let fetched = fetch_price(
+            SourceType::DEX,
+            SourceAccounts {
+                price_account: pool,
+                history_account: observation,
+                program_id,
+            },
+            SourceParams::RaydiumClmm(params(1)),
+        )
+        .unwrap();
+
+        assert_eq!(observed.manipulation_score, fetched.manipulation_score);
+        assert_eq!(factors.composite(), fetched.manipulation_score);
+        assert_eq!(observed.timestamp, fetched.timestamp);
+    });
+}
+
+#[test]
+fn factors_recompose_into_the_composite_score() {
+    with_linked_accounts(|pool, observation, program_id| {
+        let (observed, factors) =
+            observe_raydium_price(pool, observation, program_id, params(1)).unwrap();
+
+        // A steady ring in sync with the live tick carries no deviation risk
+        assert_eq!(factors.deviation_risk, 0);
+        assert_eq!(factors.liquidity_risk, 500);
+        assert_eq!(
+            observed.manipulation_score,
+            assess_manipulation_risk(
+                10_000 - factors.variance_risk,
+                0,
+                120,
+                1_000_000,
+                1,
+                pool_impact_bps(pool),
+                0,
+            )
+        );
+    });
+}
+
+#[test]
+fn thin_pool_raises_only_the_liquidity_factor() {
+    with_linked_accounts(|pool, observation, program_id| {
+        let (_, deep) = observe_raydium_price(pool, observation, program_id, params(1)).unwrap();
+        let (_, thin) =
+            observe_raydium_price(pool, observation, program_id, params(u128::MAX)).unwrap();
+
+        assert_eq!(thin.liquidity_risk, 4_000);
+        assert_eq!(thin.variance_risk, deep.variance_risk);
+        assert_eq!(thin.deviation_risk, deep.deviation_risk);
+        assert_eq!(thin.staleness_risk, deep.staleness_risk);
+        assert_eq!(thin.impact_risk, deep.impact_risk);
+    });
+}
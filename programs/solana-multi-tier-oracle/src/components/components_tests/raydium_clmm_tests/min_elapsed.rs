@@ -0,0 +1,113 @@
+//! Minimum elapsed span behind a pool price.
+//!
+//! Strict mode refuses any span shorter than the caller's minimum or one
+//! observation interval, whichever is longer. Relaxed mode, meant for testing
+//! and bootstrap, prices the span but discounts confidence by the shortfall.
+
+use super::helpers::{assert_raydium_error, with_linked_ring};
+use crate::components::raydium_clmm_observer::fetch_raydium_price::{
+    fetch_raydium_price_from_observations, ConfidenceMethod, DecimalPrice, RaydiumParams,
+    ValidationPolicy,
+};
+use crate::components::raydium_clmm_observer::raydium_constants::OBSERVATION_UPDATE_DURATION;
+use crate::components::raydium_clmm_observer::twap::{min_elapsed_seconds, min_elapsed_shortfall};
+use crate::error::RaydiumObserverError;
+use anchor_lang::prelude::*;
+
+const FIRST_TIMESTAMP: u32 = 1_700_000_000;
+const CADENCE: u32 = 15;
+const COUNT: u32 = 20;
+const RING_TICK: i64 = 40;
+// Two observation intervals: long enough for the protocol floor, short of `MIN_SECONDS`
+const WINDOW: u32 = 2 * CADENCE;
+const MIN_SECONDS: u32 = 4 * CADENCE;
+
+fn ring() -> Vec<(u32, i64)> {
+    (0..COUNT)
+        .map(|i| {
+            let elapsed = i * CADENCE;
+            (FIRST_TIMESTAMP + elapsed, RING_TICK * elapsed as i64)
+        })
+        .collect()
+}
+
+fn params(min_seconds: u32, strict_min_elapsed: bool) -> RaydiumParams {
+    RaydiumParams {
+        window_seconds: WINDOW,
+        min_seconds,
+        min_liquidity: 1,
+        max_tick_deviation: 100,
+        alpha_basis_points: 2_000,
+        validation_policy: ValidationPolicy::Both,
+        impact_trade_size: 0,
+        confidence_method: ConfidenceMethod::Variance,
+        strict_coverage: false,
+        strict_min_elapsed,
+        allow_spot_fallback: false,
+        min_observations: 0,
+        timestamp: (FIRST_TIMESTAMP + (COUNT - 1) * CADENCE) as i64,
+    }
+}
+
+fn observe(params: RaydiumParams) -> Result<DecimalPrice> {
+    let mut observed = None;
+    with_linked_ring(
+        &ring(),
+        RING_TICK as i32,
+        |pool, observation, program_id| {
+            observed = Some(fetch_raydium_price_from_observations(
+                pool,
+                observation,
+                program_id,
+                params,
+            ));
+        },
+    );
+    observed.unwrap()
+}
+
+#[test]
+fn minimum_never_drops_below_one_observation_interval() {
+    assert_eq!(min_elapsed_seconds(0), OBSERVATION_UPDATE_DURATION);
+    assert_eq!(min_elapsed_seconds(MIN_SECONDS), MIN_SECONDS);
+}
+
+#[test]
+fn shortfall_is_measured_against_the_minimum() {
+    assert_eq!(
+        min_elapsed_shortfall(MIN_SECONDS, MIN_SECONDS, true).unwrap(),
+        0
+    );
+    assert_eq!(
+        min_elapsed_shortfall(WINDOW, MIN_SECONDS, false).unwrap(),
+        MIN_SECONDS - WINDOW
+    );
+    assert_raydium_error(
+        min_elapsed_shortfall(OBSERVATION_UPDATE_DURATION - 1, 0, true),
+        RaydiumObserverError::InsufficientTime,
+    );
+}
+
+#[test]
+fn span_meeting_the_minimum_is_accepted_in_strict_mode() {
+    assert!(observe(params(WINDOW, true)).is_ok());
+}
+
+#[test]
+fn too_short_span_is_rejected_in_strict_mode() {
+    assert_raydium_error(
+        observe(params(MIN_SECONDS, true)).map(|_| ()),
+        RaydiumObserverError::InsufficientTime,
+    );
+}
+
+#[test]
+fn relaxed_mode_prices_a_short_span_at_reduced_confidence() {
+    let full = observe(params(WINDOW, true)).unwrap();
+    let relaxed = observe(params(MIN_SECONDS, false)).unwrap();
+
+    // The window spans half the minimum, so half the confidence survives
+    assert_eq!(relaxed.price, full.price);
+    assert!(relaxed.confidence < full.confidence);
+    assert_eq!(relaxed.confidence, full.confidence / 2);
+}
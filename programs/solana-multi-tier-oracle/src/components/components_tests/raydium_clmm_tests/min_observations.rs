@@ -0,0 +1,130 @@
+//! Per-source minimum ring depth before a DEX feed is priced.
+//!
+//! A young pool can bracket a short window with only a few samples. The
+//! observer must refuse such a cold ring when the feed asks for more history,
+//! and accept the same pool once the ring has warmed up.
+
+use super::helpers::{assert_raydium_error, with_linked_ring};
+use crate::components::raydium_clmm_observer::fetch_raydium_price::{
+    observe_raydium_price, ConfidenceMethod, RaydiumParams, ValidationPolicy,
+};
+use crate::error::RaydiumObserverError;
+use crate::instructions::update_price::SkipReason;
+
+const FIRST_TIMESTAMP: u32 = 1_700_000_000;
+const CADENCE: u32 = 15;
+const RING_TICK: i64 = 40;
+const MIN_OBSERVATIONS: u16 = 10;
+
+/// `count` observations at a steady `RING_TICK`, one every `CADENCE` seconds.
+fn ring(count: u32) -> Vec<(u32, i64)> {
+    (0..count)
+        .map(|i| {
+            let elapsed = i * CADENCE;
+            (FIRST_TIMESTAMP + elapsed, RING_TICK * elapsed as i64)
+        })
+        .collect()
+}
+
+/// Parameters read at the newest observation of a `count`-slot ring.
+fn params(count: u32, min_observations: u16) -> RaydiumParams {
+    RaydiumParams {
+        window_seconds: 120,
+        min_seconds: 0,
+        min_liquidity: 1,
+        max_tick_deviation: 100,
+        alpha_basis_points: 2_000,
+        validation_policy: ValidationPolicy::Both,
+        impact_trade_size: 0,
+        confidence_method: ConfidenceMethod::Variance,
+        strict_coverage: false,
+        strict_min_elapsed: true,
+        allow_spot_fallback: false,
+        min_observations,
+        timestamp: (FIRST_TIMESTAMP + (count - 1) * CADENCE) as i64,
+    }
+}
+
+#[test]
+fn cold_ring_is_rejected() {
+    let count = 4;
+    with_linked_ring(
+        &ring(count),
+        RING_TICK as i32,
+        |pool, observation, program_id| {
+            // Without a floor the short ring still yields a price
+            observe_raydium_price(pool, observation, program_id, params(count, 0))
+                .expect("cold ring accepted when no minimum is configured");
+
+            assert_raydium_error(
+                observe_raydium_price(
+                    pool,
+                    observation,
+                    program_id,
+                    params(count, MIN_OBSERVATIONS),
+                )
+                .map(|_| ()),
+                RaydiumObserverError::InsufficientObservations,
+            );
+        },
+    );
+}
+
+#[test]
+fn warmed_ring_is_accepted() {
+    let count = 20;
+    with_linked_ring(
+        &ring(count),
+        RING_TICK as i32,
+        |pool, observation, program_id| {
+            let (price, _) = observe_raydium_price(
+                pool,
+                observation,
+                program_id,
+                params(count, MIN_OBSERVATIONS),
+            )
+            .expect("ring holds more than the minimum");
+
+            assert!(price.price > 0);
+        },
+    );
+}
+
+#[test]
+fn minimum_is_inclusive() {
+    let count = MIN_OBSERVATIONS as u32;
+    with_linked_ring(
+        &ring(count),
+        RING_TICK as i32,
+        |pool, observation, program_id| {
+            observe_raydium_price(
+                pool,
+                observation,
+                program_id,
+                params(count, MIN_OBSERVATIONS),
+            )
+            .expect("exactly the minimum is enough");
+
+            assert_raydium_error(
+                observe_raydium_price(
+                    pool,
+                    observation,
+                    program_id,
+                    params(count, MIN_OBSERVATIONS + 1),
+                )
+                .map(|_| ()),
+                RaydiumObserverError::InsufficientObservations,
+            );
+        },
+    );
+}
+
+#[test]
+fn cold_ring_is_a_soft_skip() {
+    let error = RaydiumObserverError::InsufficientObservations.into();
+
+    assert_eq!(
+        SkipReason::from_error(&error),
+        Some(SkipReason::ObservationsNotReady)
+    );
+}
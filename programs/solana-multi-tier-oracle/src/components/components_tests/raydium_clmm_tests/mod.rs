@@ -0,0 +1,53 @@
+//! Test harness for the Raydium CLMM observer.
+//!
+//! Raydium accounts are read zero-copy out of raw account bytes, so these tests
+//! build byte-exact fakes instead of deserializing structs:
+//! - `helpers`: synthetic pool and ring account buffers, readers and shared assertions.
+//! - `validation_policy`: TWAP/T2EMA cross-validation and single-method relaxation.
+//! - `cumulative_spikes`: exclusion of isolated cumulative jumps from the window estimators.
+//! - `cumulative_wrap`: single-wrap recovery and sign-flip rejection of multi-wrap deltas.
+//! - `decimal_price`: conversion of observer output into stored `PriceData`.
+//! - `fallback_chain`: TWAP and spot-tick fallbacks for rings too thin to cross-validate.
+//! - `ewma_confidence`: recency-weighted confidence against the equal-weight variance.
+//! - `interpolated_twap`: exact sub-window TWAP with boundaries interpolated inside intervals.
+//! - `manipulation_view`: read-only risk assessment agreeing with the update path.
+//! - `min_elapsed`: strict rejection or discounted pricing of spans under the minimum.
+//! - `min_observations`: per-source floor on ring depth before a cold pool is priced.
+//! - `observation_binding`: rejection of observation accounts that do not belong to the pool.
+//! - `observed_price_scaling`: observer prices quoted in token1 base units per whole token0.
+//! - `observation_ring`: filled-length tracking for partially written rings.
+//! - `signed_deviation`: direction-preserving TWAP-versus-spot deviation in basis points.
+//! - `ring_sync`: rejection of observation rings lagging the live pool tick.
+//! - `price_impact`: trade-size impact estimation and its effect on risk scoring.
+//! - `sqrt_price_bounds`: rejection of pool sqrt prices outside Raydium's range.
+//! - `tick_spacing_confidence`: confidence reduction for coarse tick grids.
+//! - `soft_skip`: recoverable versus fatal read failures under soft-skip mode.
+//! - `ui_price_rounding`: floor, nearest and ceil resolution of decimal-scaled UI prices.
+//! - `window_coverage`: shortfall reporting for rings younger than the window.
+//! - `window_interpolation`: boundary cumulative interpolation for sparse rings.
+//! - `zero_copy_alignment`: alignment contract of the generic zero-copy pointer reader.
+
+pub mod cumulative_spikes;
+pub mod cumulative_wrap;
+pub mod decimal_price;
+pub mod ewma_confidence;
+pub mod fallback_chain;
+pub mod helpers;
+pub mod interpolated_twap;
+pub mod manipulation_view;
+pub mod min_elapsed;
+pub mod min_observations;
+pub mod observation_binding;
+pub mod observation_ring;
+pub mod observed_price_scaling;
+pub mod price_impact;
+pub mod ring_sync;
+pub mod signed_deviation;
+pub mod soft_skip;
+pub mod sqrt_price_bounds;
+pub mod tick_spacing_confidence;
+pub mod ui_price_rounding;
+pub mod validation_policy;
+pub mod window_coverage;
+pub mod window_interpolation;
+pub mod zero_copy_alignment;
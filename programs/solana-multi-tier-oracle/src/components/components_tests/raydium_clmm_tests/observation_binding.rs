@@ -0,0 +1,129 @@
+//! Binding of the observation account to the pool it is read with.
+//!
+//! `update_price` looks the feed up by pool address only, so the observation
+//! the keeper passes alongside must be proven to belong to that pool: it has
+//! to sit at Raydium's `["observation", pool]` PDA and be the account the pool
+//! itself records. A foreign ring paired with a legitimate pool is rejected.
+
+use super::helpers::{
+    assert_raydium_error, link_pool_observation, observation_account_data, observation_pda,
+    pool_account_data, SQRT_PRICE_ONE_X64,
+};
+use crate::components::raydium_clmm_observer::raydium_accounts::verify_observation_pda_and_read_pool;
+use crate::error::RaydiumObserverError;
+use crate::instructions::update_price::SkipReason;
+use anchor_lang::prelude::*;
+
+const RING: [(u32, i64); 2] = [(1_700_000_000, 0), (1_700_000_015, 600)];
+
+/// Verifies a pool at `pool_key` recording `linked_key` as its observation,
+/// read together with the observation account at `observation_key`.
+fn verify_pair(
+    pool_key: Pubkey,
+    observation_key: Pubkey,
+    linked_key: Pubkey,
+    program_id: Pubkey,
+) -> Result<()> {
+    let mut pool_lamports = 1u64;
+    let mut observation_lamports = 1u64;
+    let mut pool_data = pool_account_data(1_000_000, SQRT_PRICE_ONE_X64);
+    link_pool_observation(&mut pool_data, &linked_key, 40);
+    let mut observation_data = observation_account_data(&RING);
+
+    let pool = AccountInfo::new(
+        &pool_key,
+        false,
+        false,
+        &mut pool_lamports,
+        &mut pool_data,
+        &program_id,
+        false,
+        0,
+    );
+    let observation = AccountInfo::new(
+        &observation_key,
+        false,
+        false,
+        &mut observation_lamports,
+        &mut observation_data,
+        &program_id,
+        false,
+        0,
+    );
+
+    verify_observation_pda_and_read_pool(&pool, &observation, &program_id).map(|_| ())
+}
+
+#[test]
+fn pool_with_its_own_observation_is_accepted() {
+    let program_id = Pubkey::new_unique();
+    let pool_key = Pubkey::new_unique();
+    let observation_key = observation_pda(&pool_key, &program_id);
+
+    verify_pair(pool_key, observation_key, observation_key, program_id)
+        .expect("observation is the pool's PDA and the pool links it");
+}
+
+#[test]
+fn observation_off_the_pda_is_rejected() {
+    let program_id = Pubkey::new_unique();
+    let pool_key = Pubkey::new_unique();
+    let foreign = Pubkey::new_unique();
+
+    // Even a pool that links the foreign account cannot vouch for it
+    assert_raydium_error(
+        verify_pair(pool_key, foreign, foreign, program_id),
+        RaydiumObserverError::BadPda,
+    );
+}
+
+#[test]
+fn another_pools_observation_is_rejected() {
+    let program_id = Pubkey::new_unique();
+    let pool_key = Pubkey::new_unique();
+    let other_pool_observation = observation_pda(&Pubkey::new_unique(), &program_id);
+
+    assert_raydium_error(
+        verify_pair(
+            pool_key,
+            other_pool_observation,
+            observation_pda(&pool_key, &program_id),
+            program_id,
+        ),
+        RaydiumObserverError::BadPda,
+    );
+}
+
+#[test]
+fn observation_derived_under_another_program_is_rejected() {
+    let program_id = Pubkey::new_unique();
+    let pool_key = Pubkey::new_unique();
+    let other_network = observation_pda(&pool_key, &Pubkey::new_unique());
+
+    assert_raydium_error(
+        verify_pair(pool_key, other_network, other_network, program_id),
+        RaydiumObserverError::BadPda,
+    );
+}
+
+#[test]
+fn pool_linking_a_different_observation_is_rejected() {
+    let program_id = Pubkey::new_unique();
+    let pool_key = Pubkey::new_unique();
+    let observation_key = observation_pda(&pool_key, &program_id);
+
+    assert_raydium_error(
+        verify_pair(pool_key, observation_key, Pubkey::new_unique(), program_id),
+        RaydiumObserverError::PoolMismatch,
+    );
+}
+
+#[test]
+fn binding_failures_are_never_soft_skipped() {
+    for error in [
+        RaydiumObserverError::BadPda,
+        RaydiumObserverError::PoolMismatch,
+    ] {
+        assert_eq!(SkipReason::from_error(&error.into()), None);
+    }
+}
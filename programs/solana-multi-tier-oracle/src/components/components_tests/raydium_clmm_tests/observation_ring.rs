@@ -0,0 +1,81 @@
+//! Partially filled observation rings.
+//!
+//! A freshly created pool has written only the first few ring slots. Readers
+//! must report that real length and keep every traversal inside it.
+
+use super::helpers::with_observation_reader;
+use crate::components::raydium_clmm_observer::raydium_constants::OBSERVATION_NUM;
+use crate::components::raydium_clmm_observer::twap::{find_observation_for_window, t2ema_tick};
+
+const FIRST_TIMESTAMP: u32 = 1_700_000_000;
+const CADENCE: u32 = 15;
+
+/// `count` observations at a steady tick of 100, one every `CADENCE` seconds.
+fn steady_observations(count: usize) -> Vec<(u32, i64)> {
+    (0..count)
+        .map(|i| {
+            let elapsed = i as u32 * CADENCE;
+            (FIRST_TIMESTAMP + elapsed, 100 * elapsed as i64)
+        })
+        .collect()
+}
+
+#[test]
+fn ten_percent_filled_ring_reports_its_length() {
+    let filled = OBSERVATION_NUM / 10;
+    with_observation_reader(&steady_observations(filled), |reader| {
+        assert_eq!(reader.current_index(), filled - 1);
+        assert_eq!(reader.filled_count(), filled);
+    });
+}
+
+#[test]
+fn full_and_empty_rings_are_bounded() {
+    with_observation_reader(&steady_observations(OBSERVATION_NUM), |reader| {
+        assert_eq!(reader.filled_count(), OBSERVATION_NUM);
+    });
+    with_observation_reader(&[], |reader| {
+        assert_eq!(reader.filled_count(), 0);
+    });
+}
+
+#[test]
+fn window_search_stops_at_oldest_filled_observation() {
+    let observations = steady_observations(OBSERVATION_NUM / 10);
+    let (latest, _) = *observations.last().unwrap();
+
+    with_observation_reader(&observations, |reader| {
+        // Ask for far more history than the ring holds
+        let (index_then, index_now, elapsed) =
+            find_observation_for_window(reader, latest as i64, 3_600).unwrap();
+
+        assert_eq!(index_then, 0);
+        assert_eq!(index_now, observations.len() - 1);
+        assert_eq!(elapsed, latest - FIRST_TIMESTAMP);
+    });
+}
+
+#[test]
+fn t2ema_over_partial_ring_uses_only_filled_entries() {
+    let observations = steady_observations(OBSERVATION_NUM / 10);
+
+    with_observation_reader(&observations, |reader| {
+        let tick = t2ema_tick(reader, 0, observations.len() - 1, 500).unwrap();
+        assert_eq!(tick, 100);
+    });
+}
+
+#[test]
+fn window_search_stops_at_requested_window_start() {
+    let observations = steady_observations(OBSERVATION_NUM / 10);
+    let (latest, _) = *observations.last().unwrap();
+
+    with_observation_reader(&observations, |reader| {
+        let window = 4 * CADENCE;
+        let (index_then, _, elapsed) =
+            find_observation_for_window(reader, latest as i64, window).unwrap();
+
+        assert_eq!(index_then, observations.len() - 1 - 4);
+        assert_eq!(elapsed, window);
+    });
+}
@@ -0,0 +1,80 @@
+//! Decimal scaling of the price the observer reports.
+//!
+//! The observer quotes one whole token0 in token1 base units, so the price
+//! together with `implied_expo` must reproduce the human token ratio, and a
+//! sub-unit price must keep token1's precision rather than flooring to zero.
+
+use super::helpers::with_linked_ring_decimals;
+use crate::components::raydium_clmm_observer::fetch_raydium_price::{
+    fetch_raydium_price_from_observations, ConfidenceMethod, DecimalPrice, RaydiumParams,
+    ValidationPolicy,
+};
+
+const FIRST_TIMESTAMP: u32 = 1_700_000_000;
+const CADENCE: u32 = 15;
+const COUNT: u32 = 20;
+
+fn params() -> RaydiumParams {
+    RaydiumParams {
+        window_seconds: 120,
+        min_seconds: 0,
+        min_liquidity: 1,
+        max_tick_deviation: 100,
+        alpha_basis_points: 2_000,
+        validation_policy: ValidationPolicy::Both,
+        impact_trade_size: 0,
+        confidence_method: ConfidenceMethod::Variance,
+        strict_coverage: false,
+        strict_min_elapsed: true,
+        allow_spot_fallback: false,
+        min_observations: 0,
+        timestamp: (FIRST_TIMESTAMP + (COUNT - 1) * CADENCE) as i64,
+    }
+}
+
+/// Observer output for a pool held at `tick` with the given mint decimals.
+fn observe_at(tick: i32, decimals: (u8, u8)) -> DecimalPrice {
+    let ring: Vec<(u32, i64)> = (0..COUNT)
+        .map(|i| {
+            let elapsed = i * CADENCE;
+            (FIRST_TIMESTAMP + elapsed, tick as i64 * elapsed as i64)
+        })
+        .collect();
+
+    let mut observed = None;
+    with_linked_ring_decimals(&ring, tick, decimals, |pool, observation, program_id| {
+        observed = Some(
+            fetch_raydium_price_from_observations(pool, observation, program_id, params()).unwrap(),
+        );
+    });
+    observed.unwrap()
+}
+
+#[test]
+fn unit_ratio_scales_by_base_token_decimals() {
+    // One micro-USDC per lamport is 1000 USDC per SOL, at expo -6
+    let sol_usdc = observe_at(0, (9, 6));
+    assert_eq!(sol_usdc.price, 1_000_000_000);
+    assert_eq!(sol_usdc.implied_expo(), -6);
+
+    // The inverse pair quotes 0.001 SOL per USDC, at expo -9
+    let usdc_sol = observe_at(0, (6, 9));
+    assert_eq!(usdc_sol.price, 1_000_000);
+    assert_eq!(usdc_sol.implied_expo(), -9);
+}
+
+#[test]
+fn known_tick_matches_the_scaled_token_ratio() {
+    // 1.0001^-18971 * 10^9 = 150_017_226.02..., i.e. 150.017226 USDC per SOL
+    let observed = observe_at(-18_971, (9, 6));
+
+    assert!(observed.price.abs_diff(150_017_226) <= 1);
+    assert_eq!(observed.implied_expo(), -6);
+}
+
+#[test]
+fn observed_price_is_no_longer_the_sqrt_ratio() {
+    // At tick 0 the sqrt ratio is 2^64, which the old path reported verbatim
+    assert_ne!(observe_at(0, (6, 6)).price, 1u128 << 64);
+    assert_eq!(observe_at(0, (6, 6)).price, 1_000_000);
+}
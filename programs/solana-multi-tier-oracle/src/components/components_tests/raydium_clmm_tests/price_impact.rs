@@ -0,0 +1,129 @@
+//! Price impact estimation for manipulation sizing.
+//!
+//! Within one tick range a CLMM swap of `Δy` moves `√P` by `Δy / L`, so the
+//! expected impact for small trades is close to `2·Δy / (L·√P)`. The fixtures
+//! use round numbers where that closed form is easy to check by hand.
+
+use super::helpers::{assert_raydium_error, pool_account_data, SQRT_PRICE_ONE_X64};
+use crate::components::raydium_clmm_observer::raydium_accounts::PoolReader;
+use crate::components::raydium_clmm_observer::sqrt_price_to_tick::estimate_price_impact_bps;
+use crate::components::raydium_clmm_observer::twap::assess_manipulation_risk;
+use crate::error::RaydiumObserverError;
+use anchor_lang::prelude::*;
+
+#[test]
+fn zero_trade_has_no_impact() {
+    let impact = estimate_price_impact_bps(1_000_000, SQRT_PRICE_ONE_X64, 0).unwrap();
+    assert_eq!(impact, 0);
+}
+
+#[test]
+fn small_trade_at_unit_price_matches_closed_form() {
+    // Δ√P/√P = 1_000 / 1_000_000 = 0.1%, so impact = 2·0.001 + 0.001² ≈ 20.01 bps.
+    let impact = estimate_price_impact_bps(1_000_000, SQRT_PRICE_ONE_X64, 1_000).unwrap();
+    assert!(
+        (19..=21).contains(&impact),
+        "impact {impact} outside 19..=21"
+    );
+}
+
+#[test]
+fn impact_is_relative_to_current_sqrt_price() {
+    // Doubling √P halves the relative move of the same Δ√P; doubling the trade restores it.
+    let sqrt_price_x64 = SQRT_PRICE_ONE_X64 << 1;
+    let impact = estimate_price_impact_bps(1_000_000, sqrt_price_x64, 2_000).unwrap();
+    assert!(
+        (19..=21).contains(&impact),
+        "impact {impact} outside 19..=21"
+    );
+}
+
+#[test]
+fn impact_grows_with_trade_size_and_shrinks_with_liquidity() {
+    let small = estimate_price_impact_bps(10_000_000, SQRT_PRICE_ONE_X64, 10_000).unwrap();
+    let large = estimate_price_impact_bps(10_000_000, SQRT_PRICE_ONE_X64, 100_000).unwrap();
+    let deep = estimate_price_impact_bps(100_000_000, SQRT_PRICE_ONE_X64, 100_000).unwrap();
+
+    assert!(large > small, "larger trade must move price further");
+    assert!(
+        deep < large,
+        "deeper liquidity must absorb the same trade better"
+    );
+    // Roughly linear for small moves: 10x trade ≈ 10x impact.
+    assert!(
+        (190..=215).contains(&large),
+        "impact {large} outside 190..=215"
+    );
+}
+
+#[test]
+fn trade_doubling_sqrt_price_saturates() {
+    let impact = estimate_price_impact_bps(1_000_000, SQRT_PRICE_ONE_X64, 1_000_000).unwrap();
+    assert_eq!(impact, 10_000);
+
+    let impact = estimate_price_impact_bps(1, SQRT_PRICE_ONE_X64, u128::MAX).unwrap();
+    assert_eq!(impact, 10_000);
+}
+
+#[test]
+fn empty_pool_is_rejected() {
+    assert_raydium_error(
+        estimate_price_impact_bps(0, SQRT_PRICE_ONE_X64, 1),
+        RaydiumObserverError::MathError,
+    );
+    assert_raydium_error(
+        estimate_price_impact_bps(1_000_000, 0, 1),
+        RaydiumObserverError::MathError,
+    );
+}
+
+#[test]
+fn zero_trade_size_skips_the_depth_check() {
+    assert_eq!(
+        estimate_price_impact_bps(0, SQRT_PRICE_ONE_X64, 0).unwrap(),
+        0
+    );
+    assert_eq!(estimate_price_impact_bps(1_000_000, 0, 0).unwrap(), 0);
+}
+
+#[test]
+fn pool_reader_estimates_impact_from_account_bytes() {
+    let key = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut lamports = 0u64;
+    let mut data = pool_account_data(1_000_000, SQRT_PRICE_ONE_X64);
+    let account_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    let pool = PoolReader::new_ptr(&account_info).unwrap();
+    assert_eq!(pool.liquidity(), 1_000_000);
+    assert_eq!(pool.sqrt_price_x64(), SQRT_PRICE_ONE_X64);
+
+    let impact = pool.price_impact_bps(1_000).unwrap();
+    assert!(
+        (19..=21).contains(&impact),
+        "impact {impact} outside 19..=21"
+    );
+}
+
+#[test]
+fn price_impact_raises_manipulation_risk() {
+    // Fully confident, fresh-window, deep pool: only the impact factor varies.
+    let baseline = assess_manipulation_risk(10_000, 0, 60, 1_000, 1, 0, 0);
+    let impacted = assess_manipulation_risk(10_000, 0, 60, 1_000, 1, 200, 0);
+
+    assert_eq!(baseline, 1_000);
+    assert_eq!(impacted, baseline + 1_000);
+    assert_eq!(
+        assess_manipulation_risk(10_000, 0, 60, 1_000, 1, 10_000, 0),
+        10_000
+    );
+}
@@ -0,0 +1,65 @@
+//! Observation ring consistency with the live pool tick.
+//!
+//! A ring whose newest timestamp is fresh can still record ticks from before a
+//! large move; the newest interval's tick is compared with `tick_current`.
+
+use super::helpers::{assert_raydium_error, with_observation_reader};
+use crate::components::raydium_clmm_observer::raydium_accounts::ObservationReader;
+use crate::components::raydium_clmm_observer::twap::{check_ring_in_sync, latest_observation_tick};
+use crate::error::RaydiumObserverError;
+use crate::instructions::update_price::SkipReason;
+use anchor_lang::prelude::*;
+
+const FIRST_TIMESTAMP: u32 = 1_700_000_000;
+const CADENCE: u32 = 15;
+const RING_TICK: i64 = 2_000;
+const MAX_TICK_DEVIATION: i32 = 100;
+
+/// `count` observations recorded at a steady `RING_TICK`.
+fn steady_ring(count: usize) -> Vec<(u32, i64)> {
+    (0..count)
+        .map(|i| {
+            let elapsed = i as u32 * CADENCE;
+            (FIRST_TIMESTAMP + elapsed, RING_TICK * elapsed as i64)
+        })
+        .collect()
+}
+
+#[test]
+fn in_sync_ring_is_accepted() {
+    with_observation_reader(&steady_ring(8), |reader| {
+        assert_eq!(latest_observation_tick(reader), Some(RING_TICK));
+
+        let live_tick = (RING_TICK + MAX_TICK_DEVIATION as i64) as i32;
+        assert!(check_ring_in_sync(reader, live_tick, MAX_TICK_DEVIATION).is_ok());
+    });
+}
+
+#[test]
+fn ring_lagging_live_tick_is_rejected() {
+    with_observation_reader(&steady_ring(8), |reader| {
+        // The pool has rallied 5,000 ticks since the ring last recorded anything useful
+        let live_tick = (RING_TICK + 5_000) as i32;
+        assert_raydium_error(
+            check_ring_in_sync(reader, live_tick, MAX_TICK_DEVIATION),
+            RaydiumObserverError::StaleObservationRing,
+        );
+    });
+}
+
+#[test]
+fn single_observation_ring_is_left_to_other_checks() {
+    with_observation_reader(&steady_ring(1), |reader| {
+        assert_eq!(latest_observation_tick(reader), None);
+        assert!(check_ring_in_sync(reader, 0, MAX_TICK_DEVIATION).is_ok());
+    });
+}
+
+#[test]
+fn desynced_ring_soft_skips_as_stale() {
+    let err = Error::from(RaydiumObserverError::StaleObservationRing);
+    assert_eq!(
+        SkipReason::from_error(&err),
+        Some(SkipReason::StaleObservations)
+    );
+}
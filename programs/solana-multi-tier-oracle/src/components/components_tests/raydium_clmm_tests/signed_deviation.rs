@@ -0,0 +1,133 @@
+//! Signed TWAP-versus-spot deviation.
+//!
+//! The absolute tick gap gates `max_tick_deviation`; the signed basis-point
+//! value travels with the price so consumers can tell which side of the
+//! window average the pool currently sits on.
+
+use super::helpers::{assert_raydium_error, with_linked_ring};
+use crate::components::raydium_clmm_observer::fetch_raydium_price::{
+    cross_validate_ticks, observe_raydium_price, ConfidenceMethod, RaydiumParams, ValidationPolicy,
+};
+use crate::components::raydium_clmm_observer::raydium_constants::{MAX_TICK, MIN_TICK};
+use crate::components::raydium_clmm_observer::twap::signed_deviation_bps;
+use crate::error::RaydiumObserverError;
+
+const FIRST_TIMESTAMP: u32 = 1_700_000_000;
+const CADENCE: u32 = 15;
+const COUNT: u32 = 20;
+const RING_TICK: i64 = 40;
+
+/// Steady ring averaging `RING_TICK` over every interval.
+fn ring() -> Vec<(u32, i64)> {
+    (0..COUNT)
+        .map(|i| {
+            let elapsed = i * CADENCE;
+            (FIRST_TIMESTAMP + elapsed, RING_TICK * elapsed as i64)
+        })
+        .collect()
+}
+
+fn params(max_tick_deviation: i32) -> RaydiumParams {
+    RaydiumParams {
+        window_seconds: 120,
+        min_seconds: 0,
+        min_liquidity: 1,
+        max_tick_deviation,
+        alpha_basis_points: 2_000,
+        validation_policy: ValidationPolicy::Both,
+        impact_trade_size: 0,
+        confidence_method: ConfidenceMethod::Variance,
+        strict_coverage: false,
+        strict_min_elapsed: true,
+        allow_spot_fallback: false,
+        min_observations: 0,
+        timestamp: (FIRST_TIMESTAMP + (COUNT - 1) * CADENCE) as i64,
+    }
+}
+
+#[test]
+fn equal_ticks_have_no_deviation() {
+    assert_eq!(signed_deviation_bps(0, 0), 0);
+    assert_eq!(signed_deviation_bps(-12_345, -12_345), 0);
+}
+
+#[test]
+fn window_above_spot_is_positive() {
+    // 1.0001^100 - 1 = 1.0050%
+    assert_eq!(signed_deviation_bps(100, 0), 100);
+    assert_eq!(signed_deviation_bps(1_000, 0), 1_051);
+}
+
+#[test]
+fn window_below_spot_is_negative() {
+    // 1.0001^-100 - 1 = -0.9950%, truncated toward zero
+    assert_eq!(signed_deviation_bps(0, 100), -99);
+    assert_eq!(signed_deviation_bps(0, 1_000), -951);
+}
+
+#[test]
+fn deviation_depends_only_on_tick_gap() {
+    assert_eq!(
+        signed_deviation_bps(-4_900, -5_000),
+        signed_deviation_bps(100, 0)
+    );
+    assert_eq!(
+        signed_deviation_bps(250_000, 250_100),
+        signed_deviation_bps(0, 100)
+    );
+}
+
+#[test]
+fn extreme_gaps_saturate_without_overflow() {
+    let above = signed_deviation_bps(MAX_TICK as i64, MIN_TICK);
+    let below = signed_deviation_bps(MIN_TICK as i64, MAX_TICK);
+
+    assert_eq!(above, i32::MAX);
+    // The price can fall by at most 100%
+    assert!((-10_000..=-9_999).contains(&below), "got {below}");
+    assert_eq!(signed_deviation_bps(i64::MAX, 0), above);
+}
+
+#[test]
+fn observed_price_carries_signed_deviation() {
+    for (tick_current, expected_sign) in [(RING_TICK as i32 - 20, 1), (RING_TICK as i32 + 20, -1)] {
+        with_linked_ring(&ring(), tick_current, |pool, observation, program_id| {
+            let (price, _) = observe_raydium_price(pool, observation, program_id, params(100))
+                .expect("deviation within threshold");
+
+            assert_eq!(price.twap_deviation_bps.signum(), expected_sign);
+            assert_eq!(
+                price.twap_deviation_bps,
+                signed_deviation_bps(RING_TICK, tick_current)
+            );
+        });
+    }
+}
+
+#[test]
+fn threshold_still_uses_absolute_gap() {
+    // Spot 20 ticks below and 20 ticks above the window trip the same limit
+    for current_tick in [RING_TICK as i32 - 20, RING_TICK as i32 + 20] {
+        assert_raydium_error(
+            cross_validate_ticks(
+                ValidationPolicy::TwapOnly,
+                RING_TICK,
+                None,
+                current_tick,
+                10,
+                10_000,
+            )
+            .map(|_| ()),
+            RaydiumObserverError::ExcessiveCurrentDeviation,
+        );
+        assert!(cross_validate_ticks(
+            ValidationPolicy::TwapOnly,
+            RING_TICK,
+            None,
+            current_tick,
+            20,
+            10_000,
+        )
+        .is_ok());
+    }
+}
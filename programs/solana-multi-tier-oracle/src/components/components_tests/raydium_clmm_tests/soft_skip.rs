@@ -0,0 +1,100 @@
+//! Soft-skip classification of read-phase failures.
+//!
+//! Recoverable market conditions must downgrade to a reported skip, while any
+//! error that indicts the supplied accounts must keep reverting even with the
+//! mode enabled.
+
+use super::helpers::{assert_raydium_error, pool_account_data, SQRT_PRICE_ONE_X64};
+use crate::components::raydium_clmm_observer::fetch_raydium_price::{
+    cross_validate_ticks, ValidationPolicy,
+};
+use crate::components::raydium_clmm_observer::raydium_accounts::read_pool;
+use crate::error::{RaydiumObserverError, StateError};
+use crate::instructions::update_price::{soft_skip, ReadOutcome, SkipReason};
+use anchor_lang::prelude::*;
+
+/// TWAP and T2EMA each sit 60 ticks from the pool but 120 from each other.
+fn divergent_read() -> Result<()> {
+    cross_validate_ticks(ValidationPolicy::Both, 1_060, Some(940), 1_000, 100, 9_000).map(|_| ())
+}
+
+#[test]
+fn excessive_deviation_soft_skips_with_reason() {
+    match soft_skip(divergent_read(), true).unwrap() {
+        ReadOutcome::Skipped(reason) => {
+            assert_eq!(reason, SkipReason::ExcessiveDeviation);
+            assert_eq!(reason.code(), 1);
+        }
+        ReadOutcome::Ready(_) => panic!("divergent read should be skipped"),
+    }
+}
+
+#[test]
+fn excessive_deviation_reverts_when_mode_disabled() {
+    assert_raydium_error(
+        soft_skip(divergent_read(), false),
+        RaydiumObserverError::ExcessiveMethodDivergence,
+    );
+}
+
+#[test]
+fn bad_owner_still_reverts() {
+    let result: Result<()> = Err(RaydiumObserverError::InvalidOwner.into());
+    assert_raydium_error(soft_skip(result, true), RaydiumObserverError::InvalidOwner);
+}
+
+#[test]
+fn malformed_pool_account_still_reverts() {
+    let key = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut lamports = 1u64;
+    let mut data = pool_account_data(1_000_000, SQRT_PRICE_ONE_X64);
+    data.truncate(data.len() - 1);
+    let account_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+
+    assert_raydium_error(
+        soft_skip(read_pool(&account_info, &owner).map(|_| ()), true),
+        RaydiumObserverError::TooSmall,
+    );
+}
+
+#[test]
+fn reason_codes_are_pinned() {
+    let expected = [
+        (RaydiumObserverError::ExcessiveCurrentDeviation, 1),
+        (RaydiumObserverError::ExcessiveMethodDivergence, 1),
+        (RaydiumObserverError::InsufficientTime, 2),
+        (RaydiumObserverError::InvalidIndex, 3),
+        (RaydiumObserverError::TickOutOfBounds, 4),
+        (RaydiumObserverError::InvalidPrice, 5),
+    ];
+    for (variant, code) in expected {
+        let reason = SkipReason::from_error(&variant.into()).expect("recoverable");
+        assert_eq!(reason.code(), code);
+    }
+}
+
+#[test]
+fn state_error_sharing_a_code_number_is_not_recoverable() {
+    // Both error enums start at the same offset, so code numbers alone collide
+    let collision: Error = StateError::AuthorityNotAdminMember.into();
+    let deviation: Error = RaydiumObserverError::ExcessiveCurrentDeviation.into();
+    match (&collision, &deviation) {
+        (Error::AnchorError(a), Error::AnchorError(b)) => {
+            assert_eq!(a.error_code_number, b.error_code_number)
+        }
+        _ => panic!("expected anchor errors"),
+    }
+
+    assert_eq!(SkipReason::from_error(&collision), None);
+    assert!(soft_skip(Err::<(), _>(collision), true).is_err());
+}
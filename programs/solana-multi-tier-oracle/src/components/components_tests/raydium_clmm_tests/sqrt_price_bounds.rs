@@ -0,0 +1,64 @@
+//! Range validation of the pool's raw sqrt price.
+//!
+//! The field is read straight out of account bytes, so an out-of-range value
+//! means a corrupted or spoofed pool and must stop the read before any price
+//! math runs on it.
+
+use super::helpers::{assert_raydium_error, pool_account_data, SQRT_PRICE_ONE_X64};
+use crate::components::raydium_clmm_observer::raydium_accounts::PoolReader;
+use crate::components::raydium_clmm_observer::raydium_constants::{
+    MAX_SQRT_PRICE_X64, MIN_SQRT_PRICE_X64,
+};
+use crate::error::RaydiumObserverError;
+use anchor_lang::prelude::*;
+
+fn with_pool_reader(sqrt_price_x64: u128, check: impl FnOnce(&PoolReader)) {
+    let key = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut lamports = 1u64;
+    let mut data = pool_account_data(1_000_000, sqrt_price_x64);
+    let account_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    check(&PoolReader::new_ptr(&account_info).unwrap());
+}
+
+#[test]
+fn in_range_sqrt_price_is_accepted() {
+    for sqrt_price_x64 in [MIN_SQRT_PRICE_X64, SQRT_PRICE_ONE_X64, MAX_SQRT_PRICE_X64] {
+        with_pool_reader(sqrt_price_x64, |pool| {
+            assert_eq!(pool.checked_sqrt_price_x64().unwrap(), sqrt_price_x64);
+        });
+    }
+}
+
+#[test]
+fn out_of_range_sqrt_price_is_rejected() {
+    for sqrt_price_x64 in [0, MIN_SQRT_PRICE_X64 - 1, MAX_SQRT_PRICE_X64 + 1, u128::MAX] {
+        with_pool_reader(sqrt_price_x64, |pool| {
+            // The raw accessor still reports what the bytes hold
+            assert_eq!(pool.sqrt_price_x64(), sqrt_price_x64);
+            assert_raydium_error(
+                pool.checked_sqrt_price_x64(),
+                RaydiumObserverError::MathError,
+            );
+        });
+    }
+}
+
+#[test]
+fn fetch_path_impact_estimate_rejects_out_of_range_sqrt_price() {
+    with_pool_reader(MAX_SQRT_PRICE_X64 + 1, |pool| {
+        assert_raydium_error(
+            pool.price_impact_bps(1_000),
+            RaydiumObserverError::MathError,
+        );
+    });
+}
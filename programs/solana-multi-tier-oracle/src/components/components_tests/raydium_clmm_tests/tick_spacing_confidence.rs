@@ -0,0 +1,82 @@
+//! Tick-spacing-aware confidence.
+//!
+//! Two pools with identical observation histories differ only in how finely they
+//! can express price; the coarser grid must never report more confidence.
+
+use super::helpers::{
+    observation_account_data, pool_account_data, set_tick_spacing, SQRT_PRICE_ONE_X64,
+};
+use crate::components::raydium_clmm_observer::raydium_accounts::{ObservationReader, PoolReader};
+use crate::components::raydium_clmm_observer::raydium_constants::MAX_TICK_SPACING_PENALTY_BPS;
+use crate::components::raydium_clmm_observer::twap::{
+    confidence_from_variance, spacing_adjusted_confidence,
+};
+use anchor_lang::prelude::*;
+
+/// Steady pool hovering around tick 100 with a 15-second observation cadence.
+const OBSERVATIONS: [(u32, i64); 4] = [(1_000, 0), (1_015, 1_500), (1_030, 3_150), (1_045, 4_650)];
+
+/// Reads the pool's tick spacing and scores the shared observation history with it.
+fn confidence_for_spacing(tick_spacing: u16) -> u32 {
+    let owner = Pubkey::new_unique();
+
+    let pool_key = Pubkey::new_unique();
+    let mut pool_lamports = 1u64;
+    let mut pool_data = pool_account_data(1_000_000, SQRT_PRICE_ONE_X64);
+    set_tick_spacing(&mut pool_data, tick_spacing);
+    let pool_info = AccountInfo::new(
+        &pool_key,
+        false,
+        false,
+        &mut pool_lamports,
+        &mut pool_data,
+        &owner,
+        false,
+        0,
+    );
+
+    let observation_key = Pubkey::new_unique();
+    let mut observation_lamports = 1u64;
+    let mut observation_data = observation_account_data(&OBSERVATIONS);
+    let observation_info = AccountInfo::new(
+        &observation_key,
+        false,
+        false,
+        &mut observation_lamports,
+        &mut observation_data,
+        &owner,
+        false,
+        0,
+    );
+
+    let pool = PoolReader::new_ptr(&pool_info).unwrap();
+    let observation = ObservationReader::new_ptr(&observation_info).unwrap();
+    confidence_from_variance(&observation, 0, OBSERVATIONS.len() - 1, pool.tick_spacing()).unwrap()
+}
+
+#[test]
+fn coarser_tick_spacing_reports_lower_confidence() {
+    let fine = confidence_for_spacing(1);
+    let coarse = confidence_for_spacing(60);
+
+    assert!(fine > 0, "fixture should produce a usable variance score");
+    assert!(coarse < fine, "coarse {coarse} should be below fine {fine}");
+}
+
+#[test]
+fn finest_grid_is_not_penalized() {
+    assert_eq!(spacing_adjusted_confidence(8_000, 0), 8_000);
+    assert_eq!(spacing_adjusted_confidence(8_000, 1), 8_000);
+}
+
+#[test]
+fn penalty_is_monotonic_and_bounded() {
+    let mut previous = spacing_adjusted_confidence(10_000, 1);
+    for spacing in [10u16, 60, 200, 1_000, u16::MAX] {
+        let adjusted = spacing_adjusted_confidence(10_000, spacing);
+        assert!(adjusted <= previous, "spacing {spacing} raised confidence");
+        previous = adjusted;
+    }
+
+    assert_eq!(previous, 10_000 - MAX_TICK_SPACING_PENALTY_BPS);
+}
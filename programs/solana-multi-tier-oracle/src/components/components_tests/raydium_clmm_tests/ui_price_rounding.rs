@@ -0,0 +1,74 @@
+//! Rounding of decimal-scaled UI prices.
+//!
+//! Sqrt prices are built from small binary fractions so the squared price is
+//! exact in Q64.64: `3/2` squares to `2.25`, `5/4` to `1.5625`. Every expected
+//! value below is then plain decimal arithmetic on those prices.
+
+use super::helpers::{assert_raydium_error, SQRT_PRICE_ONE_X64};
+use crate::components::raydium_clmm_observer::sqrt_price_to_tick::{
+    ui_price_from_sqrt_q64, RoundingMode,
+};
+use crate::error::RaydiumObserverError;
+
+/// √2.25 = 1.5
+const SQRT_PRICE_2_25: u128 = 3 * (SQRT_PRICE_ONE_X64 >> 1);
+/// √1.5625 = 1.25
+const SQRT_PRICE_1_5625: u128 = 5 * (SQRT_PRICE_ONE_X64 >> 2);
+/// √25 = 5
+const SQRT_PRICE_25: u128 = 5 * SQRT_PRICE_ONE_X64;
+
+fn all_modes(sqrt_price_x64: u128, decimal_0: u8, decimal_1: u8) -> [u128; 3] {
+    [
+        RoundingMode::Floor,
+        RoundingMode::Nearest,
+        RoundingMode::Ceil,
+    ]
+    .map(|mode| ui_price_from_sqrt_q64(sqrt_price_x64, decimal_0, decimal_1, mode).unwrap())
+}
+
+#[test]
+fn nearest_is_the_default_mode() {
+    assert_eq!(RoundingMode::default(), RoundingMode::Nearest);
+}
+
+#[test]
+fn no_scale_rounds_the_q64_fraction() {
+    assert_eq!(all_modes(SQRT_PRICE_2_25, 6, 6), [2, 2, 3]);
+    assert_eq!(all_modes(SQRT_PRICE_1_5625, 6, 6), [1, 2, 2]);
+}
+
+#[test]
+fn scale_up_rounds_after_applying_decimals() {
+    // 2.25 · 10 = 22.5; 1.5625 · 100 = 156.25
+    assert_eq!(all_modes(SQRT_PRICE_2_25, 7, 6), [22, 23, 23]);
+    assert_eq!(all_modes(SQRT_PRICE_1_5625, 8, 6), [156, 156, 157]);
+}
+
+#[test]
+fn scale_down_rounds_after_applying_decimals() {
+    // 25 / 10 = 2.5; 25 / 100 = 0.25; 2.25 / 10 = 0.225
+    assert_eq!(all_modes(SQRT_PRICE_25, 6, 7), [2, 3, 3]);
+    assert_eq!(all_modes(SQRT_PRICE_25, 6, 8), [0, 0, 1]);
+    assert_eq!(all_modes(SQRT_PRICE_2_25, 6, 7), [0, 0, 1]);
+}
+
+#[test]
+fn exact_results_agree_across_modes() {
+    // 25 · 10 = 250 and 25 / 1 = 25 leave no fraction to resolve
+    assert_eq!(all_modes(SQRT_PRICE_25, 7, 6), [250; 3]);
+    assert_eq!(all_modes(SQRT_PRICE_25, 6, 6), [25; 3]);
+}
+
+#[test]
+fn unsupported_decimal_difference_is_rejected() {
+    for mode in [
+        RoundingMode::Floor,
+        RoundingMode::Nearest,
+        RoundingMode::Ceil,
+    ] {
+        assert_raydium_error(
+            ui_price_from_sqrt_q64(SQRT_PRICE_25, 19, 0, mode).map(|_| ()),
+            RaydiumObserverError::MathError,
+        );
+    }
+}
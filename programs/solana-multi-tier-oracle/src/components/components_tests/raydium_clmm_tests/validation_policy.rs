@@ -0,0 +1,142 @@
+//! Cross-validation policy selection.
+//!
+//! The fixtures use a TWAP/T2EMA pair that disagree by more than the allowed
+//! deviation while each stays close to the live pool tick: exactly the shape
+//! of data a thin pool produces when T2EMA has not warmed up.
+
+use super::helpers::assert_raydium_error;
+use crate::components::raydium_clmm_observer::fetch_raydium_price::{
+    cross_validate_ticks, ValidationPolicy,
+};
+use crate::components::raydium_clmm_observer::raydium_constants::SINGLE_METHOD_CONFIDENCE_BPS;
+use crate::error::RaydiumObserverError;
+
+const MAX_DEVIATION: i32 = 100;
+const CURRENT_TICK: i32 = 1_000;
+const TWAP_TICK: i64 = 1_060;
+const T2EMA_TICK: i64 = 940;
+const BASE_CONFIDENCE: u32 = 9_000;
+
+#[test]
+fn default_policy_is_both() {
+    assert_eq!(ValidationPolicy::default(), ValidationPolicy::Both);
+    assert!(ValidationPolicy::Both.uses_t2ema());
+    assert!(ValidationPolicy::T2emaOnly.uses_t2ema());
+    assert!(ValidationPolicy::Fallback.uses_t2ema());
+    assert!(!ValidationPolicy::TwapOnly.uses_t2ema());
+}
+
+#[test]
+fn both_rejects_divergent_pair() {
+    // Each estimate is within 60 ticks of the pool, but they are 120 apart.
+    assert_raydium_error(
+        cross_validate_ticks(
+            ValidationPolicy::Both,
+            TWAP_TICK,
+            Some(T2EMA_TICK),
+            CURRENT_TICK,
+            MAX_DEVIATION,
+            BASE_CONFIDENCE,
+        ),
+        RaydiumObserverError::ExcessiveMethodDivergence,
+    );
+}
+
+#[test]
+fn both_reports_a_desynced_pool_before_method_divergence() {
+    // T2EMA is 500 ticks off the pool and 620 off the TWAP: both checks would
+    // fail, and the pool check runs first
+    assert_raydium_error(
+        cross_validate_ticks(
+            ValidationPolicy::Both,
+            TWAP_TICK,
+            Some(CURRENT_TICK as i64 + 500 + 60 + 60),
+            CURRENT_TICK,
+            MAX_DEVIATION,
+            BASE_CONFIDENCE,
+        ),
+        RaydiumObserverError::ExcessiveCurrentDeviation,
+    );
+}
+
+#[test]
+fn both_accepts_agreeing_pair_at_full_confidence() {
+    let validated = cross_validate_ticks(
+        ValidationPolicy::Both,
+        1_020,
+        Some(T2EMA_TICK + 40),
+        CURRENT_TICK,
+        MAX_DEVIATION,
+        BASE_CONFIDENCE,
+    )
+    .unwrap();
+
+    assert_eq!(validated.tick, T2EMA_TICK + 40);
+    assert_eq!(validated.deviation_vs_current, 20);
+    assert_eq!(validated.confidence, BASE_CONFIDENCE);
+}
+
+#[test]
+fn twap_only_accepts_divergent_pair_with_reduced_confidence() {
+    let validated = cross_validate_ticks(
+        ValidationPolicy::TwapOnly,
+        TWAP_TICK,
+        Some(T2EMA_TICK),
+        CURRENT_TICK,
+        MAX_DEVIATION,
+        BASE_CONFIDENCE,
+    )
+    .unwrap();
+
+    assert_eq!(validated.tick, TWAP_TICK);
+    assert_eq!(validated.deviation_vs_current, 60);
+    assert!(validated.confidence < BASE_CONFIDENCE);
+    assert_eq!(
+        validated.confidence,
+        BASE_CONFIDENCE * SINGLE_METHOD_CONFIDENCE_BPS / 10_000
+    );
+}
+
+#[test]
+fn twap_only_does_not_need_t2ema() {
+    let validated = cross_validate_ticks(
+        ValidationPolicy::TwapOnly,
+        TWAP_TICK,
+        None,
+        CURRENT_TICK,
+        MAX_DEVIATION,
+        BASE_CONFIDENCE,
+    )
+    .unwrap();
+    assert_eq!(validated.tick, TWAP_TICK);
+}
+
+#[test]
+fn single_method_still_bounds_deviation_from_pool() {
+    assert_raydium_error(
+        cross_validate_ticks(
+            ValidationPolicy::T2emaOnly,
+            TWAP_TICK,
+            Some(CURRENT_TICK as i64 + 500),
+            CURRENT_TICK,
+            MAX_DEVIATION,
+            BASE_CONFIDENCE,
+        ),
+        RaydiumObserverError::ExcessiveCurrentDeviation,
+    );
+}
+
+#[test]
+fn both_requires_t2ema_estimate() {
+    assert_raydium_error(
+        cross_validate_ticks(
+            ValidationPolicy::Both,
+            TWAP_TICK,
+            None,
+            CURRENT_TICK,
+            MAX_DEVIATION,
+            BASE_CONFIDENCE,
+        ),
+        RaydiumObserverError::MathError,
+    );
+}
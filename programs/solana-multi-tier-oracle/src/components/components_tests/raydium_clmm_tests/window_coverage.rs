@@ -0,0 +1,73 @@
+//! Coverage of the requested TWAP window by the observation ring.
+//!
+//! A young ring cannot span a long window; the shortfall is reported instead of
+//! silently shrinking the window, and confidence shrinks with it.
+
+use super::helpers::with_observation_reader;
+use crate::components::raydium_clmm_observer::raydium_accounts::ObservationReader;
+use crate::components::raydium_clmm_observer::raydium_constants::MIN_WINDOW_COVERAGE_BPS;
+use crate::components::raydium_clmm_observer::twap::{
+    coverage_adjusted_confidence, find_window_bracket, window_coverage_bps,
+};
+use anchor_lang::prelude::*;
+
+const FIRST_TIMESTAMP: u32 = 1_700_000_000;
+const CADENCE: u32 = 15;
+const WINDOW: u32 = 120;
+
+/// `count` observations at a steady tick of 100, one every `CADENCE` seconds.
+fn steady_observations(count: usize) -> Vec<(u32, i64)> {
+    (0..count)
+        .map(|i| {
+            let elapsed = i as u32 * CADENCE;
+            (FIRST_TIMESTAMP + elapsed, 100 * elapsed as i64)
+        })
+        .collect()
+}
+
+fn newest_timestamp(observations: &[(u32, i64)]) -> i64 {
+    observations.last().unwrap().0 as i64
+}
+
+#[test]
+fn ring_covering_window_reports_no_shortfall() {
+    let observations = steady_observations(20);
+    with_observation_reader(&observations, |reader| {
+        let bracket = find_window_bracket(reader, newest_timestamp(&observations), WINDOW).unwrap();
+
+        assert_eq!(bracket.coverage_shortfall_seconds, 0);
+        assert_eq!(coverage_adjusted_confidence(9_000, WINDOW, 0), 9_000);
+    });
+}
+
+#[test]
+fn short_ring_reports_gap_and_lowers_confidence() {
+    // Three observations span 30 s of the 120 s window
+    let observations = steady_observations(3);
+    with_observation_reader(&observations, |reader| {
+        let bracket = find_window_bracket(reader, newest_timestamp(&observations), WINDOW).unwrap();
+        let shortfall = bracket.coverage_shortfall_seconds;
+
+        assert_eq!(shortfall, WINDOW - 2 * CADENCE);
+        assert_eq!(
+            coverage_adjusted_confidence(8_000, WINDOW, shortfall),
+            2_000
+        );
+        assert!(window_coverage_bps(WINDOW, shortfall) < MIN_WINDOW_COVERAGE_BPS);
+    });
+}
+
+#[test]
+fn staleness_counts_against_coverage() {
+    // The window runs to the current time, not to the newest observation
+    let observations = steady_observations(20);
+    with_observation_reader(&observations, |reader| {
+        let now = newest_timestamp(&observations) + 60;
+        let bracket = find_window_bracket(reader, now, WINDOW).unwrap();
+        assert_eq!(bracket.coverage_shortfall_seconds, 0);
+
+        let long_window = 19 * CADENCE + 60 + 30;
+        let bracket = find_window_bracket(reader, now, long_window).unwrap();
+        assert_eq!(bracket.coverage_shortfall_seconds, 30);
+    });
+}
@@ -0,0 +1,104 @@
+//! Boundary interpolation for sparse observation rings.
+//!
+//! Observations here are a minute apart, so a 90 second window starts halfway
+//! through the oldest interval. Snapping to either neighbouring observation
+//! stretches or shrinks the window; interpolating the cumulative at the exact
+//! start should land between the two.
+
+use super::helpers::with_observation_reader;
+use crate::components::raydium_clmm_observer::raydium_accounts::ObservationReader;
+use crate::components::raydium_clmm_observer::twap::{
+    find_observation_for_window, find_window_bracket, twap_tick_from_cumulatives,
+};
+use anchor_lang::prelude::*;
+
+const FIRST_TIMESTAMP: u32 = 1_700_000_000;
+const SPARSE_CADENCE: u32 = 60;
+const EARLY_TICK: i64 = 100;
+const LATE_TICK: i64 = 300;
+const WINDOW: u32 = 90;
+
+/// Three observations: one interval at `EARLY_TICK`, then one at `LATE_TICK`.
+fn sparse_observations() -> Vec<(u32, i64)> {
+    let middle = EARLY_TICK * SPARSE_CADENCE as i64;
+    vec![
+        (FIRST_TIMESTAMP, 0),
+        (FIRST_TIMESTAMP + SPARSE_CADENCE, middle),
+        (
+            FIRST_TIMESTAMP + 2 * SPARSE_CADENCE,
+            middle + LATE_TICK * SPARSE_CADENCE as i64,
+        ),
+    ]
+}
+
+/// TWAP tick over `[then, now]` using the stored cumulatives as-is.
+fn snapped_tick(reader: &ObservationReader, index_then: usize, index_now: usize) -> i64 {
+    let then = reader.get_observation(index_then);
+    let now = reader.get_observation(index_now);
+    let elapsed = now.block_timestamp() - then.block_timestamp();
+    twap_tick_from_cumulatives(then.tick_cumulative(), now.tick_cumulative(), elapsed, None)
+        .unwrap()
+}
+
+#[test]
+fn bracket_reports_fractional_position_of_window_start() {
+    with_observation_reader(&sparse_observations(), |reader| {
+        let now = (FIRST_TIMESTAMP + 2 * SPARSE_CADENCE) as i64;
+        let bracket = find_window_bracket(reader, now, WINDOW).unwrap();
+
+        assert_eq!(bracket.index_before, 0);
+        assert_eq!(bracket.index_after, 1);
+        assert_eq!(bracket.index_now, 2);
+        assert_eq!(bracket.offset_seconds, 30);
+        assert_eq!(bracket.span_seconds, SPARSE_CADENCE);
+        assert!(bracket.needs_interpolation());
+    });
+}
+
+#[test]
+fn interpolated_tick_lies_between_bracketing_ticks() {
+    with_observation_reader(&sparse_observations(), |reader| {
+        let now = (FIRST_TIMESTAMP + 2 * SPARSE_CADENCE) as i64;
+        let bracket = find_window_bracket(reader, now, WINDOW).unwrap();
+
+        let boundary = bracket.boundary_tick_cumulative(reader);
+        assert_eq!(boundary, EARLY_TICK * 30);
+
+        let cumulative_now = reader.get_observation(bracket.index_now).tick_cumulative();
+        let interpolated =
+            twap_tick_from_cumulatives(boundary, cumulative_now, WINDOW, None).unwrap();
+
+        let from_before = snapped_tick(reader, bracket.index_before, bracket.index_now);
+        let from_after = snapped_tick(reader, bracket.index_after, bracket.index_now);
+
+        // 30 s at 100 plus 60 s at 300 over 90 s
+        assert_eq!(interpolated, 233);
+        assert!(from_before < interpolated && interpolated < from_after);
+    });
+}
+
+#[test]
+fn wrapper_keeps_two_index_result() {
+    with_observation_reader(&sparse_observations(), |reader| {
+        let now = (FIRST_TIMESTAMP + 2 * SPARSE_CADENCE) as i64;
+        assert_eq!(
+            find_observation_for_window(reader, now, WINDOW).unwrap(),
+            (0, 2, 2 * SPARSE_CADENCE)
+        );
+    });
+}
+
+#[test]
+fn aligned_window_start_needs_no_interpolation() {
+    with_observation_reader(&sparse_observations(), |reader| {
+        let now = (FIRST_TIMESTAMP + 2 * SPARSE_CADENCE) as i64;
+        let bracket = find_window_bracket(reader, now, SPARSE_CADENCE).unwrap();
+
+        assert_eq!(bracket.index_before, 1);
+        assert!(!bracket.needs_interpolation());
+        assert_eq!(
+            bracket.boundary_tick_cumulative(reader),
+            reader.get_observation(1).tick_cumulative()
+        );
+    });
+}
@@ -0,0 +1,59 @@
+//! Alignment contract of `read_zc_ptr`.
+//!
+//! Packed Raydium layouts must be readable from any byte offset; a non-packed
+//! type read from a misaligned buffer must trip the debug assertion.
+
+use crate::components::raydium_clmm_observer::raydium_accounts::{read_zc_ptr, Observation};
+use anchor_lang::prelude::*;
+use core::ptr;
+
+/// Backing storage aligned well beyond any type under test, so the data slice
+/// can be placed at a chosen misalignment by offsetting into it.
+#[repr(C, align(16))]
+struct AlignedBuffer([u8; 64]);
+
+/// Calls `f` with an account whose data starts `offset` bytes past a 16-byte boundary.
+fn with_account_at_offset<R>(offset: usize, f: impl FnOnce(&AccountInfo) -> R) -> R {
+    let key = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut lamports = 0u64;
+    let mut buffer = AlignedBuffer([0; 64]);
+    let data = &mut buffer.0[offset..];
+    // Observation layout: block_timestamp (u32) then tick_cumulative (i64)
+    data[8..12].copy_from_slice(&1_700_000_000u32.to_le_bytes());
+    data[12..20].copy_from_slice(&(-42i64).to_le_bytes());
+
+    let account = AccountInfo::new(&key, false, false, &mut lamports, data, &owner, false, 0);
+    f(&account)
+}
+
+#[test]
+fn packed_layout_reads_from_any_offset() {
+    for offset in 0..8 {
+        with_account_at_offset(offset, |account| {
+            let observation = read_zc_ptr::<Observation>(account).unwrap();
+            let timestamp =
+                unsafe { ptr::read_unaligned(ptr::addr_of!((*observation).block_timestamp)) };
+            let tick_cumulative =
+                unsafe { ptr::read_unaligned(ptr::addr_of!((*observation).tick_cumulative)) };
+            assert_eq!(timestamp, 1_700_000_000);
+            assert_eq!(tick_cumulative, -42);
+        });
+    }
+}
+
+#[test]
+fn aligned_buffer_passes_for_non_packed_type() {
+    with_account_at_offset(0, |account| {
+        read_zc_ptr::<u64>(account).unwrap();
+    });
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "misaligned")]
+fn misaligned_buffer_trips_debug_assertion_for_non_packed_type() {
+    with_account_at_offset(1, |account| {
+        let _ = read_zc_ptr::<u64>(account);
+    });
+}
@@ -0,0 +1,352 @@
+//! Compute-unit ceilings for the `update_price` hot path.
+//!
+//! Each update runs the Raydium observer, a TWAP over as many as 384 historical
+//! points and the feed safeguards, so features added to any of them quietly eat
+//! into the transaction budget. These tests replay a representative update in
+//! `solana_program_test` and fail once its consumption crosses a ceiling:
+//! - `cold_first_update`: empty history, so the observer price seeds the TWAP.
+//! - `warm_full_history_update`: all three chunks full and inside the window.
+//!
+//! Native builtins are not metered, so the tests need the SBF build. They are
+//! ignored by default and run with `cargo test-sbf -- --ignored`, which fails
+//! outright if the program binary is missing rather than passing vacuously.
+
+use crate::components::components_tests::raydium_clmm_tests::helpers::{
+    link_pool_observation, observation_account_data, pool_account_data, SQRT_PRICE_ONE_X64,
+};
+use crate::components::raydium_clmm_observer::fetch_raydium_price::ConfidenceMethod;
+use crate::components::raydium_clmm_observer::raydium_constants::{
+    OBSERVATION_NUM, OBSERVATION_UPDATE_DURATION, RAYDIUM_CLMM_PROGRAM_ID_MAINNET,
+};
+use crate::instructions::update_price::{UpdatePriceConfig, WeightingScheme};
+use crate::state::governance_state::GovernanceState;
+use crate::state::historical_chunk::{HistoricalChunk, PricePoint};
+use crate::state::oracle_state::{OracleState, PriceData, UpdaterPolicy};
+use crate::state::price_feed::FeedFlags;
+use crate::state::state_tests::governance_state_tests::helpers::governance_fixture;
+use crate::state::state_tests::historical_chunk_tests::helpers::{
+    empty_chunk, minimal_oracle_state,
+};
+use crate::state::state_tests::price_feed_tests::core_unit_tests_and_utils::sample_price_feed;
+use crate::utils::constants::{
+    BUFFER_SIZE, GOVERNANCE_SEED, HISTORICAL_CHUNK_SEED, MAX_MANIPULATION_THRESHOLD,
+    MAX_TWAP_WINDOW, MIN_HISTORICAL_CHUNKS, MIN_HISTORICAL_INTERVAL, ORACLE_STATE_SEED,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use solana_account::Account;
+use solana_program_test::{find_file, ProgramTest, ProgramTestContext};
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+const PROGRAM_NAME: &str = "solana_multi_tier_oracle";
+
+/// Ceiling for the first update. It stays inside the default 200k per-instruction
+/// budget so a keeper seeding a new oracle needs no compute budget request.
+///
+/// Provisional: both ceilings are budget bounds, not measured figures. Replace
+/// them with the consumption a `cargo test-sbf -- --ignored` run reports plus
+/// headroom, so the tests catch creep well before the budget is hit.
+const COLD_UPDATE_CU_CEILING: u64 = 200_000;
+
+/// Ceiling for a steady-state update over a full 96-hour history, matching the
+/// bound the TypeScript performance suite holds against a live validator.
+const WARM_UPDATE_CU_CEILING: u64 = 300_000;
+
+/// Transaction limit the harness runs under, so a regression is measured and
+/// reported against its ceiling instead of aborting on the default budget.
+const HARNESS_CU_LIMIT: u64 = 1_400_000;
+
+const NOW: i64 = 1_700_000_000;
+const ASSET_SEED: [u8; 32] = [7; 32];
+const POOL_TICK: i32 = 40;
+const POOL_LIQUIDITY: u128 = 1_000_000_000_000;
+
+/// Keys of one oracle's program-derived accounts.
+struct OracleKeys {
+    oracle: Pubkey,
+    governance: Pubkey,
+    chunks: [Pubkey; MIN_HISTORICAL_CHUNKS],
+    pool: Pubkey,
+    observation: Pubkey,
+}
+
+impl OracleKeys {
+    fn derive() -> Self {
+        let (oracle, _) =
+            Pubkey::find_program_address(&[ORACLE_STATE_SEED, &ASSET_SEED], &crate::ID);
+        let (governance, _) =
+            Pubkey::find_program_address(&[GOVERNANCE_SEED, oracle.as_ref()], &crate::ID);
+        let chunks = core::array::from_fn(|index| {
+            Pubkey::find_program_address(
+                &[HISTORICAL_CHUNK_SEED, oracle.as_ref(), &[index as u8]],
+                &crate::ID,
+            )
+            .0
+        });
+
+        Self {
+            oracle,
+            governance,
+            chunks,
+            pool: Pubkey::new_unique(),
+            observation: Pubkey::new_unique(),
+        }
+    }
+}
+
+/// Account holding `bytes` behind `discriminator`, owned by `owner`.
+fn account_with(discriminator: &[u8], bytes: &[u8], owner: Pubkey) -> Account {
+    let data = [discriminator, bytes].concat();
+    Account {
+        lamports: Rent::default().minimum_balance(data.len()),
+        data,
+        owner,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Program-owned account holding a zero-copy `state` as Anchor lays it out.
+fn zero_copy_account<T: bytemuck::Pod + Discriminator>(state: &T) -> Account {
+    account_with(T::DISCRIMINATOR, bytemuck::bytes_of(state), crate::ID)
+}
+
+/// Oracle over one registered Raydium feed.
+///
+/// The updater is allowlisted by `start` once the harness payer is known.
+///
+/// Thresholds are relaxed so every safeguard runs to completion; a test that
+/// stops at the first rejection would measure less than a real update does.
+fn oracle_state(keys: &OracleKeys, twap_window: u32, published: Option<PriceData>) -> OracleState {
+    let mut state = minimal_oracle_state();
+    state.asset_seed = ASSET_SEED;
+    state.twap_window = twap_window;
+    state.manipulation_threshold = MAX_MANIPULATION_THRESHOLD;
+    state.historical_chunks[..MIN_HISTORICAL_CHUNKS].copy_from_slice(&keys.chunks);
+    state.current_chunk_index = (MIN_HISTORICAL_CHUNKS - 1) as u16;
+
+    let mut feed = sample_price_feed();
+    feed.source_address = keys.pool;
+    feed.last_expo = 0;
+    feed.manipulation_score = 0;
+    feed.flags.set(FeedFlags::ACTIVE);
+    state.price_feeds[0] = feed;
+    state.active_feed_count = 1;
+
+    if let Some(price) = published {
+        state.current_price = price;
+        state.last_update = price.timestamp;
+        state.expo_initialized = 1;
+    }
+
+    state
+}
+
+/// Chunk `index` of the ring, holding the points of `history` that fall into it.
+fn history_chunk(keys: &OracleKeys, index: usize, history: &[PricePoint]) -> HistoricalChunk {
+    let mut chunk = empty_chunk();
+    chunk.chunk_id = index as u16;
+    chunk.oracle_state = keys.oracle;
+    for point in history.iter().skip(index * BUFFER_SIZE).take(BUFFER_SIZE) {
+        chunk.push_checked(*point).unwrap();
+    }
+    chunk
+}
+
+/// A snapshot every `MIN_HISTORICAL_INTERVAL`, the newest one interval before `NOW`.
+fn full_history() -> Vec<PricePoint> {
+    let len = BUFFER_SIZE * MIN_HISTORICAL_CHUNKS;
+    (0..len)
+        .map(|i| PricePoint {
+            price: (SQRT_PRICE_ONE_X64 as i128) + (i as i128 % 7) * 1_000,
+            volume: 0,
+            conf: 9_000,
+            timestamp: NOW - (len - i) as i64 * MIN_HISTORICAL_INTERVAL,
+        })
+        .collect()
+}
+
+/// Builds the program and every account `update_price` reads.
+async fn start(
+    keys: &OracleKeys,
+    oracle: &OracleState,
+    history: &[PricePoint],
+) -> ProgramTestContext {
+    let mut program_test = ProgramTest::new(PROGRAM_NAME, crate::ID, None);
+    program_test.prefer_bpf(true);
+    program_test.set_compute_max_units(HARNESS_CU_LIMIT);
+
+    program_test.add_account(keys.oracle, zero_copy_account(oracle));
+    let governance: GovernanceState = governance_fixture(1);
+    program_test.add_account(keys.governance, zero_copy_account(&governance));
+    for (index, key) in keys.chunks.iter().enumerate() {
+        program_test.add_account(
+            *key,
+            zero_copy_account(&history_chunk(keys, index, history)),
+        );
+    }
+
+    // A full ring at the pool's cadence, its newest write landing at `NOW`
+    let observations: Vec<(u32, i64)> = (0..OBSERVATION_NUM as u32)
+        .map(|i| {
+            let age = (OBSERVATION_NUM as u32 - 1 - i) * OBSERVATION_UPDATE_DURATION;
+            let timestamp = NOW as u32 - age;
+            (timestamp, POOL_TICK as i64 * timestamp as i64)
+        })
+        .collect();
+    let mut pool_data = pool_account_data(POOL_LIQUIDITY, SQRT_PRICE_ONE_X64);
+    link_pool_observation(&mut pool_data, &keys.observation, POOL_TICK);
+    program_test.add_account(
+        keys.pool,
+        account_with(&[], &pool_data, RAYDIUM_CLMM_PROGRAM_ID_MAINNET),
+    );
+    program_test.add_account(
+        keys.observation,
+        account_with(
+            &[],
+            &observation_account_data(&observations),
+            RAYDIUM_CLMM_PROGRAM_ID_MAINNET,
+        ),
+    );
+
+    let mut context = program_test.start_with_context().await;
+
+    // The payer signs each update, so list it as the oracle's keeper
+    let mut oracle = *oracle;
+    oracle
+        .set_updaters(UpdaterPolicy::Allowlist, &[context.payer.pubkey()])
+        .unwrap();
+    context.set_account(&keys.oracle, &zero_copy_account(&oracle).into());
+
+    let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp = NOW;
+    context.set_sysvar(&clock);
+    context
+}
+
+fn update_config() -> UpdatePriceConfig {
+    UpdatePriceConfig {
+        window_seconds: MIN_HISTORICAL_INTERVAL as u32,
+        min_seconds: 0,
+        min_liquidity: 1,
+        max_tick_deviation: 100,
+        alpha_basis_points: 2_000,
+        impact_trade_size: 1_000_000,
+        confidence_method: ConfidenceMethod::Variance,
+        weighting_scheme: WeightingScheme::ConfidenceWeighted,
+        feed_ewma_alpha_bps: 2_000,
+        soft_skip: false,
+        strict_coverage: false,
+        max_clock_skew: 0,
+        history_deviation_bps: 0,
+        require_window_alignment: true,
+        force: false,
+        max_saturation_events: 0,
+        dry_run: false,
+        expected_min: None,
+        expected_max: None,
+        window_short: 0,
+        window_long: 0,
+        asset_seed: ASSET_SEED,
+        use_mainnet: true,
+    }
+}
+
+/// Compute units one `update_price` consumes, asserting the update succeeded.
+async fn measure_update(context: &mut ProgramTestContext, keys: &OracleKeys) -> u64 {
+    let authority = context.payer.pubkey();
+    let instruction = Instruction {
+        program_id: crate::ID,
+        accounts: crate::accounts::UpdatePrice {
+            oracle_state: keys.oracle,
+            governance_state: keys.governance,
+            historical_chunk_0: keys.chunks[0],
+            historical_chunk_1: keys.chunks[1],
+            historical_chunk_2: keys.chunks[2],
+            raydium_pool: keys.pool,
+            raydium_observation: keys.observation,
+            authority,
+        }
+        .to_account_metas(None),
+        data: crate::instruction::UpdatePrice {
+            config: update_config(),
+        }
+        .data(),
+    };
+
+    let blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[instruction],
+        Some(&authority),
+        &[&context.payer],
+        blockhash,
+    );
+    let simulation = context
+        .banks_client
+        .simulate_transaction(transaction)
+        .await
+        .unwrap();
+    let details = simulation
+        .simulation_details
+        .expect("simulation reports its details");
+
+    assert!(
+        matches!(simulation.result, Some(Ok(()))),
+        "update failed: {:?}\n{}",
+        simulation.result,
+        details.logs.join("\n")
+    );
+    details.units_consumed
+}
+
+/// Fails the test unless the SBF build this harness measures is available.
+fn require_sbf_program() {
+    assert!(
+        find_file(&format!("{PROGRAM_NAME}.so")).is_some(),
+        "{PROGRAM_NAME}.so not found; run under `cargo test-sbf -- --ignored`"
+    );
+}
+
+#[solana_program_test::tokio::test(crate = "solana_program_test::tokio")]
+#[ignore = "requires cargo test-sbf"]
+async fn cold_first_update() {
+    require_sbf_program();
+
+    let keys = OracleKeys::derive();
+    let oracle = oracle_state(&keys, MIN_HISTORICAL_INTERVAL as u32, None);
+    let mut context = start(&keys, &oracle, &[]).await;
+
+    let consumed = measure_update(&mut context, &keys).await;
+    assert!(
+        consumed <= COLD_UPDATE_CU_CEILING,
+        "cold update consumed {consumed} CU, ceiling is {COLD_UPDATE_CU_CEILING}"
+    );
+}
+
+#[solana_program_test::tokio::test(crate = "solana_program_test::tokio")]
+#[ignore = "requires cargo test-sbf"]
+async fn warm_full_history_update() {
+    require_sbf_program();
+
+    let keys = OracleKeys::derive();
+    let history = full_history();
+    let newest = history.last().unwrap();
+    let published = PriceData {
+        price: newest.price,
+        conf: newest.conf,
+        timestamp: newest.timestamp,
+        expo: 0,
+        _padding: [0; 12],
+    };
+    // The widest window, so the TWAP walks every stored point
+    let oracle = oracle_state(&keys, MAX_TWAP_WINDOW, Some(published));
+    let mut context = start(&keys, &oracle, &history).await;
+
+    let consumed = measure_update(&mut context, &keys).await;
+    assert!(
+        consumed <= WARM_UPDATE_CU_CEILING,
+        "warm update consumed {consumed} CU, ceiling is {WARM_UPDATE_CU_CEILING}"
+    );
+}
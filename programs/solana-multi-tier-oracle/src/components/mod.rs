@@ -1,3 +1,8 @@
+pub mod price_source;
 pub mod raydium_clmm_observer;
 
+pub use price_source::*;
 pub use raydium_clmm_observer::*;
+
+#[cfg(test)]
+pub mod components_tests;
@@ -0,0 +1,177 @@
+use crate::components::raydium_clmm_observer::fetch_raydium_price::{
+    fetch_raydium_price_from_observations, DecimalPrice, RaydiumParams,
+};
+use crate::error::{RaydiumObserverError, StateError};
+use crate::state::price_feed::SourceType;
+use crate::utils::constants::MAX_CLOCK_SKEW;
+/// Source-agnostic entry point into the price observers.
+///
+/// # Dispatch Strategy
+///
+/// The update path asks for "a price from this kind of source" and stays
+/// ignorant of which observer module answers. Each observer keeps its own
+/// account layout and parameter set; this module only maps a `SourceType` to
+/// the observer that serves it and forwards the matching accounts and params.
+///
+/// # Uniform Post-Processing
+///
+/// Checks that must hold for every source, whatever produced the reading, are
+/// applied here once after dispatch instead of being repeated in each caller.
+///
+/// # Confidence Convention
+///
+/// Every source reports confidence in basis points where 10,000 is best and 0
+/// means the reading carries no usable certainty. Observers derive it from
+/// whatever their market exposes (tick variance for DEX pools, quoted spread
+/// for order books) but must land on this scale, through the shared mappings
+/// below, so aggregation can weigh readings without knowing where they came
+/// from.
+use anchor_lang::prelude::*;
+
+/// Maps a source's native confidence onto the common 0-10,000 basis-point scale.
+///
+/// # Native Scales
+///
+/// - `DEX`: the Raydium observer's score, already on the common scale. It is
+///   `10_000 - variance / 100`, with variance measured in ticks², and one tick is
+///   about one basis point of price, so a one-sigma move of `s` bps scores
+///   `10_000 - s² / 100`.
+/// - `CEX`: one-sigma dispersion of recent trade prices, in basis points of price.
+/// - `Oracle`: the published one-sigma interval (e.g. Pyth `conf / price`), in
+///   basis points of price.
+/// - `Aggregator`: the aggregator's own quality score, already on the common scale.
+///
+/// Standard deviations are passed through the same `10_000 - s² / 100` curve
+/// the DEX score uses, so the same real-world uncertainty yields the same
+/// normalized confidence whichever source reported it. Scores already on the
+/// common scale are only clamped to 10,000.
+pub fn normalize_confidence(source_type: SourceType, raw_conf: u32) -> u16 {
+    match source_type {
+        SourceType::DEX | SourceType::Aggregator => raw_conf.min(10_000) as u16,
+        SourceType::CEX | SourceType::Oracle => {
+            let variance = (raw_conf as u64).saturating_mul(raw_conf as u64);
+            10_000u64.saturating_sub((variance / 100).min(10_000)) as u16
+        }
+    }
+}
+
+/// Canonical confidence of an order-book quote: `10_000 - min(spread_bps, 10_000)`.
+///
+/// # Why Linear
+///
+/// A quoted bid/ask spread already bounds where the next trade can print, so
+/// it is an interval in basis points of price rather than a dispersion to be
+/// squared; one basis point of spread costs one basis point of confidence. A
+/// spread of 100% or wider leaves nothing, the same floor the DEX variance
+/// score reaches for its widest markets.
+///
+/// Any observer that prices from a book (an OpenBook market, a CEX top of
+/// book) should use this instead of inventing its own curve, so a tight book
+/// and a calm pool both score near 10,000.
+pub fn confidence_from_spread_bps(spread_bps: u32) -> u32 {
+    10_000 - spread_bps.min(10_000)
+}
+
+/// Rejects an external reading dated more than `max_clock_skew` seconds past `now`.
+///
+/// # Why Only External Sources
+///
+/// A DEX observation is stamped by the cluster clock when the pool is written,
+/// so it can never run ahead of the validator reading it. CEX attestations and
+/// external oracles carry their publisher's clock instead; a reading dated in
+/// the future would sort after every honest sample and take an outsized share
+/// of any time-weighted average. A few seconds of skew between independent
+/// clocks is normal, so the keeper may tolerate up to `MAX_CLOCK_SKEW`.
+///
+/// # Errors
+///
+/// - `InvalidClockSkew`: `max_clock_skew` exceeds `MAX_CLOCK_SKEW`
+/// - `FutureTimestamp`: an external reading is dated beyond `now + max_clock_skew`
+pub fn check_sample_timestamp(
+    source_type: SourceType,
+    sample_timestamp: i64,
+    now: i64,
+    max_clock_skew: u32,
+) -> Result<()> {
+    require!(
+        max_clock_skew <= MAX_CLOCK_SKEW,
+        StateError::InvalidClockSkew
+    );
+
+    if source_type != SourceType::DEX {
+        require!(
+            sample_timestamp <= now.saturating_add(max_clock_skew as i64),
+            StateError::FutureTimestamp
+        );
+    }
+
+    Ok(())
+}
+
+/// Observer module responsible for a class of price sources.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Observer {
+    /// Raydium concentrated-liquidity pools read through their observation ring.
+    RaydiumClmm,
+}
+
+impl Observer {
+    /// Returns the observer serving `source_type`, or `None` when no on-chain
+    /// observer exists for it yet.
+    ///
+    /// Raydium CLMM is currently the only DEX integration, so every DEX feed is
+    /// routed to it; a second DEX observer will need the feed to say which one.
+    pub fn for_source(source_type: SourceType) -> Option<Self> {
+        match source_type {
+            SourceType::DEX => Some(Self::RaydiumClmm),
+            SourceType::CEX | SourceType::Oracle | SourceType::Aggregator => None,
+        }
+    }
+}
+
+/// Accounts an observer reads from.
+///
+/// `price_account` holds the live market state (the pool for Raydium) and
+/// `history_account` the time series the observer averages over (the
+/// observation ring). `program_id` is the program expected to own both.
+pub struct SourceAccounts<'a, 'info> {
+    pub price_account: &'a AccountInfo<'info>,
+    pub history_account: &'a AccountInfo<'info>,
+    pub program_id: &'a Pubkey,
+}
+
+/// Observer-specific tuning, one variant per `Observer`.
+pub enum SourceParams {
+    RaydiumClmm(RaydiumParams),
+}
+
+/// Fetches a price from whichever observer serves `source_type`.
+///
+/// Fails with `UnsupportedSourceType` before touching any account when the
+/// source type has no observer. Observer errors propagate unchanged so callers
+/// can still classify them (for example as soft skips). A reading that is not
+/// strictly positive is rejected with `InvalidPrice` regardless of source, and
+/// the confidence is normalized with `normalize_confidence` so readings from
+/// different source types can be aggregated side by side.
+pub fn fetch_price(
+    source_type: SourceType,
+    accounts: SourceAccounts,
+    params: SourceParams,
+) -> Result<DecimalPrice> {
+    let observer = Observer::for_source(source_type).ok_or(StateError::UnsupportedSourceType)?;
+
+    let mut price = match (observer, params) {
+        (Observer::RaydiumClmm, SourceParams::RaydiumClmm(params)) => {
+            fetch_raydium_price_from_observations(
+                accounts.price_account,
+                accounts.history_account,
+                accounts.program_id,
+                params,
+            )?
+        }
+    };
+
+    require!(price.price > 0, RaydiumObserverError::InvalidPrice);
+    price.confidence = normalize_confidence(source_type, price.confidence) as u32;
+    Ok(price)
+}
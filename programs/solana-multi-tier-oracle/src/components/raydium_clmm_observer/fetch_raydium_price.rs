@@ -1,9 +1,12 @@
 use crate::components::raydium_clmm_observer::raydium_accounts::{
-    read_observation, verify_observation_pda_and_read_pool,
+    read_observation, verify_observation_pda_and_read_pool, ObservationReader, PoolReader,
+};
+use crate::components::raydium_clmm_observer::raydium_constants::{
+    MAX_OBSERVATION_STALENESS, MIN_WINDOW_COVERAGE_BPS, OBSERVATION_NUM,
+    OBSERVATION_UPDATE_DURATION, SINGLE_METHOD_CONFIDENCE_BPS, SPOT_FALLBACK_CONFIDENCE_BPS,
 };
-use crate::components::raydium_clmm_observer::raydium_constants::OBSERVATION_UPDATE_DURATION;
 use crate::components::raydium_clmm_observer::sqrt_price_to_tick::{
-    get_sqrt_ratio_at_tick, ui_price_from_sqrt_q64,
+    get_sqrt_ratio_at_tick, ui_price_from_sqrt_q64, RoundingMode,
 };
 /// Comprehensive Raydium price fetching with multi-layer validation and manipulation detection.
 ///
@@ -21,10 +24,14 @@ use crate::components::raydium_clmm_observer::sqrt_price_to_tick::{
 /// manipulation attempts. This dual-method approach significantly increases the difficulty
 /// of successful oracle attacks.
 use crate::components::raydium_clmm_observer::twap::{
-    assess_manipulation_risk, confidence_from_variance, find_observation_for_window, t2ema_tick,
-    twap_tick_from_cumulatives,
+    check_ring_in_sync, confidence_from_variance, count_cumulative_spikes,
+    coverage_adjusted_confidence, ewma_confidence_from_observations, find_window_bracket,
+    interval_tick_ending_at, manipulation_risk_factors, min_elapsed_seconds, min_elapsed_shortfall,
+    signed_deviation_bps, t2ema_tick, twap_tick_from_cumulatives, window_coverage_bps,
+    ManipulationRiskFactors,
 };
 use crate::error::RaydiumObserverError;
+use crate::state::oracle_state::PriceData;
 use anchor_lang::prelude::*;
 
 /// Comprehensive price result with embedded metadata for downstream risk assessment.
@@ -41,8 +48,8 @@ use anchor_lang::prelude::*;
 /// implement their own risk thresholds rather than trusting oracle-level filtering.
 /// This transparency enables more sophisticated risk management strategies.
 pub struct DecimalPrice {
-    /// Human-readable price scaled for token decimal differences.
-    /// Represents token1/token0 ratio adjusted for display precision.
+    /// Price of one whole token0 quoted in token1 base units.
+    /// Multiplying by `10^implied_expo()` yields the human-readable token1/token0 price.
     pub price: u128,
 
     /// Statistical confidence metric (0-10,000 basis points) based on price variance analysis.
@@ -65,6 +72,14 @@ pub struct DecimalPrice {
     /// Higher values indicate greater likelihood of price manipulation or anomalies.
     pub manipulation_score: u32,
 
+    /// Window TWAP relative to the live pool price, in signed basis points.
+    /// Positive means the window averaged above spot; see `signed_deviation_bps`.
+    pub twap_deviation_bps: i32,
+
+    /// Estimator the published price came from. Anything other than
+    /// `CrossValidated` means less evidence stands behind the price.
+    pub method: PriceMethod,
+
     /// Decimal places for token0 in the pool, used for price scaling.
     pub decimal_0: u8,
 
@@ -72,6 +87,225 @@ pub struct DecimalPrice {
     pub decimal_1: u8,
 }
 
+impl DecimalPrice {
+    /// Base-10 exponent implied by the pool's token decimals.
+    ///
+    /// `price` counts token1 base units per whole token0, so one unit of the
+    /// mantissa is worth `10^-decimal_1` of token1. Two pools over the same pair
+    /// always agree on this value; a change between updates means the price is
+    /// being read from a differently-denominated pool.
+    #[inline(always)]
+    pub fn implied_expo(&self) -> i32 {
+        -(self.decimal_1 as i32)
+    }
+
+    /// Converts the observer output into the oracle's stored price format.
+    ///
+    /// Observers report unsigned prices, while `PriceData` is signed to allow
+    /// spread instruments. Values beyond `i128::MAX` saturate rather than wrap
+    /// into a negative price. Confidence widens losslessly to `u64`; `timestamp`
+    /// and `expo` come from the caller because the publish time and canonical
+    /// exponent are oracle-level decisions, not properties of one observation.
+    pub fn to_price_data(&self, timestamp: i64, expo: i32) -> PriceData {
+        PriceData {
+            price: core::cmp::min(self.price, i128::MAX as u128) as i128,
+            conf: self.confidence as u64,
+            timestamp,
+            expo,
+            _padding: [0; 12],
+        }
+    }
+}
+
+/// Selects which price estimators must pass deviation checks before a price is accepted.
+///
+/// # Relaxation Trade-off
+///
+/// `Both` requires TWAP and T2EMA to agree with each other and with the live pool, which
+/// is the strongest guarantee but fails on sparse observation histories where T2EMA has
+/// too few samples to warm up. The single-method policies let operators of thin pools keep
+/// publishing, at the cost of a confidence score scaled by `SINGLE_METHOD_CONFIDENCE_BPS`
+/// so consumers can see that less evidence stands behind the price.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum ValidationPolicy {
+    /// Accept the TWAP tick after checking it against the current pool tick.
+    TwapOnly,
+
+    /// Accept the T2EMA tick after checking it against the current pool tick.
+    T2emaOnly,
+
+    /// Require TWAP and T2EMA to agree; the T2EMA tick is published.
+    #[default]
+    Both,
+
+    /// Start as `Both`, then degrade as the ring thins: to `TwapOnly` when T2EMA
+    /// cannot warm up, and, where the oracle has opted in, to the live pool tick
+    /// when the ring holds no recent history at all. Tagged by `PriceMethod`.
+    Fallback,
+}
+
+impl ValidationPolicy {
+    /// Returns true when the policy needs a T2EMA estimate to be computed at all.
+    ///
+    /// Skipping the T2EMA pass under `TwapOnly` is the point of the policy: on sparse
+    /// data the pass itself is what fails, not just its agreement with TWAP.
+    #[inline(always)]
+    pub fn uses_t2ema(self) -> bool {
+        !matches!(self, Self::TwapOnly)
+    }
+
+    /// Byte stored in `OracleState::validation_policy`.
+    ///
+    /// The stored code is pinned separately from the variant order so that a
+    /// zeroed field, on an account created before the oracle stored a policy,
+    /// reads as the strict `Both` rather than the first variant.
+    #[inline(always)]
+    pub const fn as_stored_u8(self) -> u8 {
+        match self {
+            Self::Both => 0,
+            Self::TwapOnly => 1,
+            Self::T2emaOnly => 2,
+            Self::Fallback => 3,
+        }
+    }
+
+    /// Decodes a stored byte, falling back to `Both`.
+    ///
+    /// An unknown byte means corrupted or future-versioned data; reading it as
+    /// the strictest policy fails closed instead of relaxing validation.
+    #[inline(always)]
+    pub const fn from_stored_u8(value: u8) -> Self {
+        match value {
+            1 => Self::TwapOnly,
+            2 => Self::T2emaOnly,
+            3 => Self::Fallback,
+            _ => Self::Both,
+        }
+    }
+}
+
+/// Estimator behind a `DecimalPrice`, from most to least evidence.
+///
+/// Under the fixed policies this mirrors the policy chosen; under
+/// `ValidationPolicy::Fallback` it records which rung of the chain priced the pool.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriceMethod {
+    /// T2EMA tick, cross-validated against the TWAP.
+    CrossValidated,
+
+    /// TWAP tick alone, at the single-method confidence penalty.
+    Twap,
+
+    /// T2EMA tick alone, at the single-method confidence penalty.
+    T2ema,
+
+    /// The pool's live tick, at `SPOT_FALLBACK_CONFIDENCE_BPS`.
+    Spot,
+}
+
+/// Selects how observation variance is turned into a confidence score.
+///
+/// `Variance` weighs every interval in the window equally. `Ewma` reuses the
+/// T2EMA smoothing factor to weight recent intervals more, reacting faster to a
+/// fresh volatility spike at the cost of forgetting older turbulence sooner.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum ConfidenceMethod {
+    /// Equal-weight sample variance via `confidence_from_variance`.
+    #[default]
+    Variance,
+
+    /// Exponentially weighted variance via `ewma_confidence_from_observations`.
+    Ewma,
+}
+
+/// Outcome of cross-validation: the tick to publish and the evidence behind it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValidatedTick {
+    /// Tick selected for price conversion.
+    pub tick: i64,
+
+    /// Absolute deviation of the selected tick from the current pool tick.
+    /// Feeds the manipulation risk model as the deviation factor.
+    pub deviation_vs_current: i32,
+
+    /// Confidence after applying any single-method penalty.
+    pub confidence: u32,
+
+    /// Estimator the selected tick came from.
+    pub method: PriceMethod,
+}
+
+/// Apply the configured validation policy to the computed price estimates.
+///
+/// # Checks Performed
+///
+/// Every policy bounds the published tick's distance from the live pool tick by
+/// `max_tick_deviation`. `Both` additionally bounds TWAP against T2EMA, which is what
+/// catches manipulation sustained long enough to drag one estimator but not the other.
+///
+/// # Errors
+///
+/// - `ExcessiveCurrentDeviation`: the published tick is further than
+///   `max_tick_deviation` from the live pool tick, so the ring is desynced
+///   from the pool or the pool has just been moved
+/// - `ExcessiveMethodDivergence`: under `Both`, TWAP and T2EMA are further than
+///   `max_tick_deviation` apart, so the estimators disagree about the window
+/// - `MathError`: the policy requires a T2EMA estimate that was not supplied
+///
+/// `Fallback` is validated as `Both`, its first rung; degrading to later rungs is
+/// the caller's decision, since it depends on why the estimates are missing.
+pub fn cross_validate_ticks(
+    policy: ValidationPolicy,
+    twap_tick: i64,
+    t2ema_tick: Option<i64>,
+    current_tick: i32,
+    max_tick_deviation: i32,
+    base_confidence: u32,
+) -> Result<ValidatedTick> {
+    let deviation_from = |tick: i64, reference: i64| -> i32 {
+        i32::try_from(tick.abs_diff(reference)).unwrap_or(i32::MAX)
+    };
+
+    let (tick, method) = match policy {
+        ValidationPolicy::TwapOnly => (twap_tick, PriceMethod::Twap),
+        ValidationPolicy::T2emaOnly => (
+            t2ema_tick.ok_or(RaydiumObserverError::MathError)?,
+            PriceMethod::T2ema,
+        ),
+        ValidationPolicy::Both | ValidationPolicy::Fallback => (
+            t2ema_tick.ok_or(RaydiumObserverError::MathError)?,
+            PriceMethod::CrossValidated,
+        ),
+    };
+
+    // Check the published estimate against the current pool state
+    // Large deviations could indicate stale data or manipulation attempts
+    let deviation_vs_current = deviation_from(tick, current_tick as i64);
+    require!(
+        deviation_vs_current <= max_tick_deviation,
+        RaydiumObserverError::ExcessiveCurrentDeviation
+    );
+
+    let confidence = if method == PriceMethod::CrossValidated {
+        // Cross-validate TWAP vs T2EMA consistency
+        // Significant divergence between methods suggests potential manipulation or data quality issues
+        require!(
+            deviation_from(twap_tick, tick) <= max_tick_deviation,
+            RaydiumObserverError::ExcessiveMethodDivergence
+        );
+        base_confidence
+    } else {
+        ((base_confidence as u64 * SINGLE_METHOD_CONFIDENCE_BPS as u64) / 10_000) as u32
+    };
+
+    Ok(ValidatedTick {
+        tick,
+        deviation_vs_current,
+        confidence,
+        method,
+    })
+}
+
 /// Configuration parameters controlling price calculation behavior and risk thresholds.
 ///
 /// # Parameterization Strategy
@@ -105,6 +339,41 @@ pub struct RaydiumParams {
     /// Controls responsiveness vs stability trade-off in trend analysis.
     pub alpha_basis_points: u16,
 
+    /// Which price estimators must agree before the price is accepted.
+    /// Defaults to `Both`; single-method policies carry a confidence penalty.
+    pub validation_policy: ValidationPolicy,
+
+    /// Reference trade size in token1 base units used to estimate price impact.
+    /// Zero disables the impact factor for feeds without a meaningful sizing budget.
+    pub impact_trade_size: u128,
+
+    /// Variance method behind the base confidence score.
+    /// `Ewma` weights intervals by `alpha_basis_points`, like the T2EMA pass.
+    pub confidence_method: ConfidenceMethod,
+
+    /// Reject windows the ring covers less than `MIN_WINDOW_COVERAGE_BPS` of.
+    /// When false, a coverage shortfall only scales confidence down proportionally.
+    pub strict_coverage: bool,
+
+    /// Reject windows spanning less than `max(min_seconds, OBSERVATION_UPDATE_DURATION)`.
+    /// Only testing and cold-pool bootstrap should clear this; a relaxed short span
+    /// is still priced, at confidence scaled by the share of the minimum it covers.
+    /// The instructions derive it from the oracle's `RELAXED_MIN_ELAPSED` flag,
+    /// never from caller input.
+    pub strict_min_elapsed: bool,
+
+    /// Whether `ValidationPolicy::Fallback` may publish the live pool tick.
+    /// Derived from the oracle's `SPOT_FALLBACK_ENABLED` flag, never from
+    /// caller input; without it the chain stops at `TwapOnly`.
+    pub allow_spot_fallback: bool,
+
+    /// Fewest initialized observations the ring must hold before any estimate is made.
+    /// A freshly created pool can bracket a short window with a handful of
+    /// samples; this keeps such cold rings from producing accepted prices.
+    /// The instructions take it from the oracle's `observation_floor`, never
+    /// from caller input.
+    pub min_observations: u16,
+
     /// Current timestamp for staleness and time window calculations.
     /// Should represent actual current time for accurate freshness assessment.
     pub timestamp: i64,
@@ -137,6 +406,39 @@ pub fn fetch_raydium_price_from_observations(
     program_id: &Pubkey,
     params: RaydiumParams,
 ) -> Result<DecimalPrice> {
+    observe_raydium_price(
+        pool_account_info,
+        observation_account_info,
+        program_id,
+        params,
+    )
+    .map(|(price, _)| price)
+}
+
+/// `fetch_raydium_price_from_observations` plus the factors behind its manipulation score.
+///
+/// Runs the identical pipeline, so a read-only assessment reports exactly the
+/// score an update would observe for the same accounts, parameters and time.
+///
+/// # Fallback Chain
+///
+/// Under `ValidationPolicy::Fallback` the pipeline is first run as `Both`. If
+/// T2EMA cannot warm up it is rerun as `TwapOnly`. Each rung is only reached
+/// when the one above failed for lack of data: a desynced ring or divergent
+/// estimators say something about the pool, not the ring, and still reject the
+/// price.
+///
+/// The live pool tick is the last rung, and it is reached only when the oracle
+/// has opted in (`allow_spot_fallback`) and `ring_has_no_recent_history` holds.
+/// That test reads the ring alone, so no caller-supplied threshold such as
+/// `min_observations` or `strict_coverage` can push a pool onto its spot price:
+/// a floor the keeper sets above the ring's depth rejects the read instead.
+pub fn observe_raydium_price(
+    pool_account_info: &AccountInfo,
+    observation_account_info: &AccountInfo,
+    program_id: &Pubkey,
+    params: RaydiumParams,
+) -> Result<(DecimalPrice, ManipulationRiskFactors)> {
     // Phase 1: Account Authentication and Relationship Validation
     // Cryptographically verify that pool and observation accounts are legitimate
     // and properly linked to prevent spoofing attacks and data contamination
@@ -146,16 +448,115 @@ pub fn fetch_raydium_price_from_observations(
         program_id,
     )?;
     let observation = read_observation(observation_account_info, program_id)?;
+    let source = pool_account_info.key;
+
+    if params.validation_policy != ValidationPolicy::Fallback {
+        return observe_ring(
+            &pool,
+            &observation,
+            source,
+            &params,
+            params.validation_policy,
+        );
+    }
+
+    let cross_validated =
+        observe_ring(&pool, &observation, source, &params, ValidationPolicy::Both);
+    let averaged = match cross_validated {
+        Err(error) if is_observer_error(&error, &[RaydiumObserverError::T2emaNotWarm]) => {
+            observe_ring(
+                &pool,
+                &observation,
+                source,
+                &params,
+                ValidationPolicy::TwapOnly,
+            )
+        }
+        result => result,
+    };
+
+    match averaged {
+        Err(_)
+            if params.allow_spot_fallback
+                && ring_has_no_recent_history(&observation, params.timestamp) =>
+        {
+            observe_spot(&pool, source, &params)
+        }
+        result => result,
+    }
+}
+
+/// Whether the ring has nothing a window could be averaged from, whatever the
+/// window.
+///
+/// True when the newest observation was never written or is older than
+/// `MAX_OBSERVATION_STALENESS`. Both are facts about the ring: no parameter
+/// of the read can make such a ring price, while the live pool tick is
+/// current by construction.
+fn ring_has_no_recent_history(observation: &ObservationReader, timestamp: i64) -> bool {
+    let newest = observation
+        .get_observation(observation.current_index())
+        .block_timestamp() as i64;
+    newest == 0 || timestamp.saturating_sub(newest) > MAX_OBSERVATION_STALENESS
+}
+
+/// Returns true when `error` is one of the observer errors in `variants`.
+fn is_observer_error(error: &Error, variants: &[RaydiumObserverError]) -> bool {
+    let Error::AnchorError(actual) = error else {
+        return false;
+    };
+    variants.iter().any(|&variant| match Error::from(variant) {
+        Error::AnchorError(expected) => {
+            expected.error_code_number == actual.error_code_number
+                && expected.error_name == actual.error_name
+        }
+        _ => false,
+    })
+}
+
+/// Prices the pool from its observation ring under a fixed `policy`.
+///
+/// Phases 2-8 of `observe_raydium_price`; `policy` overrides the one in
+/// `params` so the fallback chain can rerun the pipeline one rung down.
+fn observe_ring(
+    pool: &PoolReader,
+    observation: &ObservationReader,
+    source: &Pubkey,
+    params: &RaydiumParams,
+    policy: ValidationPolicy,
+) -> Result<(DecimalPrice, ManipulationRiskFactors)> {
+    // A cold ring yields estimates from too few samples to trust, however well
+    // they happen to bracket the window
+    require!(
+        observation.filled_count() >= params.min_observations as usize,
+        RaydiumObserverError::InsufficientObservations
+    );
 
     // Phase 2: Time Window Selection and Data Freshness Validation
     // Find optimal observation pair for TWAP calculation while ensuring data freshness
     // The time window selection balances accuracy (longer windows) with responsiveness
-    let (index_then, index_now, seconds_elapsed) =
-        find_observation_for_window(&observation, params.timestamp, params.window_seconds)?;
+    let bracket = find_window_bracket(observation, params.timestamp, params.window_seconds)?;
+    let (index_then, index_now, seconds_elapsed) = bracket.endpoints(observation);
+
+    // A young ring silently shortens the window; strict mode refuses a large gap
+    require!(
+        !params.strict_coverage
+            || window_coverage_bps(params.window_seconds, bracket.coverage_shortfall_seconds)
+                >= MIN_WINDOW_COVERAGE_BPS,
+        RaydiumObserverError::InsufficientCoverage
+    );
+
+    // A fresh timestamp is not enough: the ring's newest tick must also agree with
+    // the live pool, otherwise every estimator below inherits the lag
+    check_ring_in_sync(observation, pool.tick_current(), params.max_tick_deviation)?;
 
     // Enforce minimum time requirements to prevent manipulation through micro-timeframes
     // Uses the stricter of user-defined minimum or protocol-defined update duration
-    //require!(seconds_elapsed >= core::cmp::max(params.min_seconds, OBSERVATION_UPDATE_DURATION), RaydiumObserverError::InsufficientTime);
+    let elapsed_shortfall = min_elapsed_shortfall(
+        seconds_elapsed,
+        params.min_seconds,
+        params.strict_min_elapsed,
+    )?;
 
     // Phase 3: Historical Data Extraction
     // Extract the specific observations that bracket our desired time window
@@ -167,10 +568,20 @@ pub fn fetch_raydium_price_from_observations(
     // Calculate price using two independent methods for cross-validation
 
     // TWAP: Traditional time-weighted average price resistant to short-term manipulation
+    // Ticks in force at the window's start and end, for multi-wrap detection;
+    // the start is the first recorded interval inside the window
+    let endpoint_ticks = if index_then != index_now {
+        interval_tick_ending_at(observation, (index_then + 1) % OBSERVATION_NUM)
+            .map(|tick_then| (tick_then, pool.tick_current() as i64))
+    } else {
+        None
+    };
+
     let mut twap_tick = twap_tick_from_cumulatives(
         observation_then.tick_cumulative(),
         observation_now.tick_cumulative(),
         seconds_elapsed,
+        endpoint_ticks,
     )?;
 
     // Fallback logic: if TWAP calculation returned 0 (no meaningful time difference),
@@ -181,69 +592,144 @@ pub fn fetch_raydium_price_from_observations(
     }
 
     // T2EMA: Advanced exponential moving average with lag compensation for trend analysis
-    let t2ema_tick = t2ema_tick(
-        &observation,
-        index_then,
-        index_now,
-        params.alpha_basis_points,
-    )?;
-
-    // Phase 5: Cross-Method Validation and Deviation Analysis
-    // Verify consistency between different calculation methods to detect manipulation
-
-    let current_tick = pool.tick_current();
-
-    // Check T2EMA deviation from current pool state
-    // Large deviations could indicate stale data or manipulation attempts
-    let dev64 = t2ema_tick.abs_diff(current_tick as i64);
-    let dev_vs_current = i32::try_from(dev64).unwrap_or(i32::MAX);
-
-    require!(
-        dev_vs_current <= params.max_tick_deviation,
-        RaydiumObserverError::ExcessiveDeviation
+    // Skipped entirely when the policy does not use it, since on sparse data the T2EMA
+    // pass itself is what fails
+    let t2ema_tick = if policy.uses_t2ema() {
+        Some(t2ema_tick(
+            observation,
+            index_then,
+            index_now,
+            params.alpha_basis_points,
+        )?)
+    } else {
+        None
+    };
+
+    // Statistical confidence based on price variance over the observation window,
+    // scaled down for pools whose tick spacing limits price resolution
+    let method_confidence = match params.confidence_method {
+        ConfidenceMethod::Variance => {
+            confidence_from_variance(observation, index_then, index_now, pool.tick_spacing())?
+        }
+        ConfidenceMethod::Ewma => ewma_confidence_from_observations(
+            observation,
+            index_then,
+            index_now,
+            params.alpha_basis_points,
+            pool.tick_spacing(),
+        )?,
+    };
+
+    // Evidence shrinks with the share of the requested window the ring covers,
+    // and again with the share of the minimum span a relaxed read falls short of
+    let base_confidence = coverage_adjusted_confidence(
+        coverage_adjusted_confidence(
+            method_confidence,
+            params.window_seconds,
+            bracket.coverage_shortfall_seconds,
+        ),
+        min_elapsed_seconds(params.min_seconds),
+        elapsed_shortfall,
     );
 
-    // Cross-validate TWAP vs T2EMA consistency
-    // Significant divergence between methods suggests potential manipulation or data quality issues
-    let dev_twap_vs_t2ema64 = twap_tick.abs_diff(t2ema_tick);
-    let dev_twap_vs_t2ema = i32::try_from(dev_twap_vs_t2ema64).unwrap_or(i32::MAX);
-
-    require!(
-        dev_twap_vs_t2ema <= params.max_tick_deviation,
-        RaydiumObserverError::ExcessiveDeviation
-    );
+    // Phase 5: Cross-Method Validation and Deviation Analysis
+    // Verify consistency between the estimators selected by the validation policy
+    let validated = cross_validate_ticks(
+        policy,
+        twap_tick,
+        t2ema_tick,
+        pool.tick_current(),
+        params.max_tick_deviation,
+        base_confidence,
+    )?;
 
     // Phase 6: Price Conversion and Human-Readable Formatting
-    // Convert validated tick to actual price ratio with proper decimal scaling
-    let sqrt_price_x64 = get_sqrt_ratio_at_tick(t2ema_tick as i32)?;
+    // Convert validated tick to actual price ratio with proper decimal scaling.
+    // Only token0's decimals are applied: the ratio stays in token1 base units, so a
+    // sub-unit price keeps token1's full precision instead of flooring to a whole
+    // UI unit, and `implied_expo` carries the remaining 10^-decimal_1
+    let sqrt_price_x64 = get_sqrt_ratio_at_tick(validated.tick as i32)?;
     let (decimal_0, decimal_1) = pool.decimals();
-    // let ui_price = ui_price_from_sqrt_q64(sqrt_price_x64, decimal_0, decimal_1)?;
+    let price = ui_price_from_sqrt_q64(sqrt_price_x64, decimal_0, 0, RoundingMode::Nearest)?;
 
     // Phase 7: Confidence and Risk Assessment
     // Generate metadata for downstream risk management decisions
 
-    // Statistical confidence based on price variance over the observation window
-    let base_confidence = confidence_from_variance(&observation, index_then, index_now)?;
+    // Estimate how far the reference trade could push the live pool price
+    let price_impact = pool.price_impact_bps(params.impact_trade_size)?;
+
+    // Intervals the estimators above dropped as cumulative spikes
+    let cumulative_spikes = count_cumulative_spikes(observation, index_then, index_now);
 
     // Comprehensive manipulation risk assessment incorporating multiple risk factors
-    let risk_score = assess_manipulation_risk(
-        base_confidence,
-        dev_vs_current,
+    let risk_factors = manipulation_risk_factors(
+        validated.confidence,
+        validated.deviation_vs_current,
         seconds_elapsed,
         pool.liquidity(),
         params.min_liquidity,
+        price_impact,
+        cumulative_spikes,
     );
 
     // Phase 8: Result Assembly
     // Package validated price with comprehensive metadata for informed downstream usage
-    Ok(DecimalPrice {
-        price: sqrt_price_x64,
-        confidence: base_confidence,
-        timestamp: observation_now.block_timestamp() as i64,
-        source: *pool_account_info.key,
-        liquidity_depth: pool.liquidity(),
-        manipulation_score: risk_score,
-        decimal_0,
-        decimal_1,
-    })
+    Ok((
+        DecimalPrice {
+            price,
+            confidence: validated.confidence,
+            timestamp: observation_now.block_timestamp() as i64,
+            source: *source,
+            liquidity_depth: pool.liquidity(),
+            manipulation_score: risk_factors.composite(),
+            twap_deviation_bps: signed_deviation_bps(twap_tick, pool.tick_current()),
+            method: validated.method,
+            decimal_0,
+            decimal_1,
+        },
+        risk_factors,
+    ))
+}
+
+/// Prices the pool from its live tick alone: the last rung of the fallback chain.
+///
+/// No window is averaged, so the TWAP deviation is zero and the manipulation
+/// model sees zero seconds elapsed and no cumulative spikes. Confidence is
+/// `SPOT_FALLBACK_CONFIDENCE_BPS`, and the price is stamped at `params.timestamp`
+/// because it is the pool's state as of this read, not of any observation.
+fn observe_spot(
+    pool: &PoolReader,
+    source: &Pubkey,
+    params: &RaydiumParams,
+) -> Result<(DecimalPrice, ManipulationRiskFactors)> {
+    let sqrt_price_x64 = get_sqrt_ratio_at_tick(pool.tick_current())?;
+    let (decimal_0, decimal_1) = pool.decimals();
+    let price = ui_price_from_sqrt_q64(sqrt_price_x64, decimal_0, 0, RoundingMode::Nearest)?;
+
+    let price_impact = pool.price_impact_bps(params.impact_trade_size)?;
+    let risk_factors = manipulation_risk_factors(
+        SPOT_FALLBACK_CONFIDENCE_BPS,
+        0,
+        0,
+        pool.liquidity(),
+        params.min_liquidity,
+        price_impact,
+        0,
+    );
+
+    Ok((
+        DecimalPrice {
+            price,
+            confidence: SPOT_FALLBACK_CONFIDENCE_BPS,
+            timestamp: params.timestamp,
+            source: *source,
+            liquidity_depth: pool.liquidity(),
+            manipulation_score: risk_factors.composite(),
+            twap_deviation_bps: 0,
+            method: PriceMethod::Spot,
+            decimal_0,
+            decimal_1,
+        },
+        risk_factors,
+    ))
 }
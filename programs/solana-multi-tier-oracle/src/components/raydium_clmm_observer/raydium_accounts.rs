@@ -1,6 +1,8 @@
 use crate::components::raydium_clmm_observer::raydium_constants::{
-    OBSERVATION_NUM, OBSERVATION_SEED, RAYDIUM_CLMM_PROGRAM_ID_DEVNET,
+    MAX_SQRT_PRICE_X64, MIN_SQRT_PRICE_X64, OBSERVATION_NUM, OBSERVATION_SEED,
+    RAYDIUM_CLMM_PROGRAM_ID_DEVNET,
 };
+use crate::components::raydium_clmm_observer::sqrt_price_to_tick::estimate_price_impact_bps;
 use crate::error::RaydiumObserverError;
 /// Zero-copy account structures for Raydium CLMM integration with strict compatibility requirements.
 ///
@@ -26,7 +28,7 @@ use crate::error::RaydiumObserverError;
 /// - Reader wrappers provide safe access patterns over unsafe raw pointer operations
 /// - Validation functions ensure account ownership and initialization before dereferencing
 use anchor_lang::prelude::*;
-use core::mem::size_of;
+use core::mem::{align_of, size_of};
 use core::ptr;
 
 /// Size of invariant prefix fields in PoolState that precede the fields we need.
@@ -111,6 +113,12 @@ pub struct ObservationState {
     pub padding: [u64; 4],
 }
 
+/// Minimum byte length of a Raydium pool account readable by `PoolReader`.
+///
+/// Anchor discriminator plus the packed prefix we map; anything shorter cannot
+/// be a pool this observer understands, regardless of who owns it.
+pub const MIN_POOL_ACCOUNT_LEN: usize = 8 + size_of::<PoolStatePartial>();
+
 /// Partial view of Raydium's PoolState containing only fields needed for price observation.
 ///
 /// # Partial Struct Strategy
@@ -240,6 +248,10 @@ pub struct ObservationReader<'a> {
     /// Cached observation index to avoid repeated unsafe pointer reads.
     /// Updated only during construction since index changes require account updates.
     cached_index: u16,
+
+    /// Number of initialized observations reachable walking back from `cached_index`.
+    /// Computed once at construction; bounds every ring traversal.
+    cached_filled: u16,
 }
 
 impl<'a> ObservationReader<'a> {
@@ -270,12 +282,14 @@ impl<'a> ObservationReader<'a> {
         // Skip 8-byte Anchor discriminator to access actual account data
         let ptr = unsafe { data.as_ptr().add(8) as *const ObservationState };
 
-        let reader = Self {
+        let mut reader = Self {
             _data_ref: data,
             data: ptr,
             // Cache index immediately to avoid repeated unsafe reads during TWAP operations
             cached_index: unsafe { ptr::read_unaligned(ptr::addr_of!((*ptr).observation_index)) },
+            cached_filled: 0,
         };
+        reader.cached_filled = reader.count_filled();
 
         Ok(reader)
     }
@@ -317,6 +331,33 @@ impl<'a> ObservationReader<'a> {
         self.cached_index as usize
     }
 
+    /// Number of initialized observations in the ring, at most `OBSERVATION_NUM`.
+    ///
+    /// # Partially Filled Rings
+    ///
+    /// Raydium writes observations sequentially from slot 0, so a young pool's ring
+    /// is a run of initialized entries ending at `current_index` followed by zeros.
+    /// Traversals bounded by `OBSERVATION_NUM` would walk into those zeros and rely
+    /// on a zero-timestamp check to stop; bounding them by the filled length makes
+    /// the real history length explicit instead.
+    #[inline]
+    pub fn filled_count(&self) -> usize {
+        self.cached_filled as usize
+    }
+
+    /// Walks back from the current index counting contiguous initialized slots.
+    fn count_filled(&self) -> u16 {
+        let mut index = self.current_index();
+        let mut filled = 0u16;
+        while (filled as usize) < OBSERVATION_NUM
+            && self.get_observation(index).block_timestamp() != 0
+        {
+            filled += 1;
+            index = (index + OBSERVATION_NUM - 1) % OBSERVATION_NUM;
+        }
+        filled
+    }
+
     /// Check initialization state using unaligned read for packed struct compatibility.
     ///
     /// # Initialization Safety
@@ -376,7 +417,7 @@ impl<'a> PoolReader<'a> {
 
         // Ensure sufficient size for discriminator + PoolStatePartial fields
         require!(
-            data.len() >= 8 + size_of::<PoolStatePartial>(),
+            data.len() >= MIN_POOL_ACCOUNT_LEN,
             RaydiumObserverError::TooSmall
         );
 
@@ -416,6 +457,15 @@ impl<'a> PoolReader<'a> {
         (decimal_0, decimal_1)
     }
 
+    /// Base-10 exponent of prices quoted by this pool, `-mint_decimals_1`.
+    ///
+    /// Matches `DecimalPrice::implied_expo` for every observation of the pool,
+    /// so registration can lock the oracle's exponent before any price is read.
+    #[inline]
+    pub fn implied_expo(&self) -> i32 {
+        -(self.decimals().1 as i32)
+    }
+
     /// Get tick spacing configuration affecting price precision granularity.
     ///
     /// # Price Precision Impact
@@ -452,6 +502,22 @@ impl<'a> PoolReader<'a> {
         unsafe { ptr::read_unaligned(ptr::addr_of!((*self.base).sqrt_price_x64)) }
     }
 
+    /// `sqrt_price_x64` rejected unless it lies in Raydium's representable range.
+    ///
+    /// A live pool can never hold a sqrt price outside
+    /// `[MIN_SQRT_PRICE_X64, MAX_SQRT_PRICE_X64]`, so anything else means the
+    /// account bytes are corrupted or spoofed. Price math must read through this
+    /// accessor rather than trust the raw field.
+    #[inline]
+    pub fn checked_sqrt_price_x64(&self) -> Result<u128> {
+        let sqrt_price_x64 = self.sqrt_price_x64();
+        require!(
+            (MIN_SQRT_PRICE_X64..=MAX_SQRT_PRICE_X64).contains(&sqrt_price_x64),
+            RaydiumObserverError::MathError
+        );
+        Ok(sqrt_price_x64)
+    }
+
     /// Extract current tick representing the pool's active price level.
     ///
     /// # Price State Context
@@ -463,6 +529,19 @@ impl<'a> PoolReader<'a> {
     pub fn tick_current(&self) -> i32 {
         unsafe { ptr::read_unaligned(ptr::addr_of!((*self.base).tick_current)) }
     }
+
+    /// Estimate how many basis points a swap of `trade_size` token1 units would move price.
+    ///
+    /// # Manipulation Sizing
+    ///
+    /// Liquidity alone says little about attack cost because the same `L` means very
+    /// different depth at different price levels. Combining it with the current sqrt price
+    /// yields the price move a given amount of capital can buy, which is the quantity risk
+    /// systems actually budget against. See `estimate_price_impact_bps` for the math.
+    #[inline]
+    pub fn price_impact_bps(&self, trade_size: u128) -> Result<u32> {
+        estimate_price_impact_bps(self.liquidity(), self.checked_sqrt_price_x64()?, trade_size)
+    }
 }
 
 // ============ Zero-copy readers ============
@@ -480,6 +559,17 @@ impl<'a> PoolReader<'a> {
 /// Performs size validation before pointer creation to prevent buffer overflows.
 /// The 8-byte offset accounts for Anchor's account discriminator that precedes
 /// all account data in the Solana account model.
+///
+/// # Alignment Contract
+///
+/// The returned pointer is only guaranteed to be byte-aligned. It is sound to use
+/// solely with the `#[repr(C, packed)]` Raydium layouts in this module, whose
+/// alignment is 1, and only through `ptr::read_unaligned`/`addr_of!` field
+/// reads. Dereferencing it as a naturally aligned `T`, or instantiating it with a
+/// non-packed `T`, is undefined behaviour whenever the account data happens not
+/// to sit on a `T` boundary. Debug builds assert the pointer is aligned for `T`
+/// so a non-packed instantiation fails loudly in tests instead of silently
+/// working on whichever buffers happen to be aligned.
 #[inline]
 pub fn read_zc_ptr<T>(account_info: &AccountInfo) -> Result<*const T> {
     let data = account_info.try_borrow_data()?;
@@ -493,6 +583,12 @@ pub fn read_zc_ptr<T>(account_info: &AccountInfo) -> Result<*const T> {
     // Skip 8-byte Anchor discriminator to access actual account data
     let ptr = unsafe { data.as_ptr().add(8) as *const T };
 
+    debug_assert_eq!(
+        (ptr as usize) % align_of::<T>(),
+        0,
+        "read_zc_ptr: account data misaligned for a non-packed type"
+    );
+
     Ok(ptr)
 }
 
@@ -568,8 +664,14 @@ pub fn read_observation<'a>(
 ///
 /// # Network Configuration
 ///
-/// Currently hardcoded to DEVNET program ID for development safety.
-/// Production deployments should parameterize this based on network detection.
+/// The PDA is derived under `program_id`, the Raydium deployment the caller
+/// selected for its network, so a devnet observation cannot stand in for a
+/// mainnet pool's or the other way round.
+///
+/// # Errors
+///
+/// - `BadPda`: the observation is not `["observation", pool]` under `program_id`
+/// - `PoolMismatch`: the pool's own `observation_key` names a different account
 #[inline]
 pub fn verify_observation_pda_and_read_pool<'a>(
     pool_account_info: &'a AccountInfo,
@@ -578,15 +680,17 @@ pub fn verify_observation_pda_and_read_pool<'a>(
 ) -> Result<PoolReader<'a>> {
     // Derive expected observation account address using Raydium's PDA scheme
     // Seeds: "observation" + pool_pubkey ensures unique observation per pool
-    // let (derived, _) = Pubkey::find_program_address(&[
-    //     OBSERVATION_SEED.as_ref(),
-    //     pool_account_info.key.as_ref(),
-    // ],
-    //     &RAYDIUM_CLMM_PROGRAM_ID_DEVNET  // Network-aware program ID selection
-    // );
+    let (derived, _) = Pubkey::find_program_address(
+        &[OBSERVATION_SEED, pool_account_info.key.as_ref()],
+        program_id,
+    );
 
     // Verify provided observation account matches expected PDA derivation
-    // require_keys_eq!(derived, *observation_account_info.key, RaydiumObserverError::BadPda);
+    require_keys_eq!(
+        derived,
+        *observation_account_info.key,
+        RaydiumObserverError::BadPda
+    );
 
     // Create validated pool reader with ownership checks
     let pool = read_pool(pool_account_info, program_id)?;
@@ -88,3 +88,81 @@ pub const MIN_SQRT_PRICE_X64: u128 = 4_295_048_016u128;
 /// Maximum sqrt price value in Q64.64 format.
 /// Represents the upper bound of expressible price ratios to prevent overflow in price calculations.
 pub const MAX_SQRT_PRICE_X64: u128 = 79_226_673_521_066_979_257_578_248_091u128;
+
+/// Confidence multiplier (basis points) applied when only one price method is validated.
+///
+/// # Single-Method Penalty
+///
+/// Cross-validating TWAP against T2EMA is the primary defence against manipulation that
+/// fools one estimator but not the other. Operators may relax to a single method on thin
+/// pools where T2EMA cannot warm up, but the resulting price has strictly less evidence
+/// behind it; halving confidence makes that visible to every downstream threshold.
+pub const SINGLE_METHOD_CONFIDENCE_BPS: u32 = 5_000;
+
+/// Confidence (basis points) assigned when `ValidationPolicy::Fallback` prices a pool
+/// from its live tick alone.
+///
+/// # Spot Fallback
+///
+/// The spot tick is the last rung of the fallback chain, reached only on oracles that
+/// opted in and only when the ring holds no recent history at all. It is a single instant that one swap can set,
+/// so it carries the lowest confidence the observer assigns; aggregation then weighs
+/// it far below any averaged feed, and a strict confidence threshold refuses it.
+pub const SPOT_FALLBACK_CONFIDENCE_BPS: u32 = 1_000;
+
+/// Oldest the ring's newest observation may be, in seconds, before the ring is
+/// treated as holding no recent history at all.
+///
+/// # Sparse Pools
+///
+/// Raydium writes an observation at most every `OBSERVATION_UPDATE_DURATION`
+/// seconds and only when the pool is touched, so a quiet pool legitimately
+/// lags. Ten minutes tolerates that while still refusing a ring whose latest
+/// sample describes a market that no longer exists. Past it the ring can price
+/// nothing, which is also what qualifies a pool for the spot fallback.
+pub const MAX_OBSERVATION_STALENESS: i64 = 600;
+
+/// Confidence reduction (basis points) per tick of spacing beyond the finest grid.
+///
+/// # Spacing and Price Resolution
+///
+/// One CLMM tick is a 1.0001x price step, roughly one basis point. A pool with
+/// `tick_spacing = s` only has initializable boundaries every `s` ticks, so liquidity
+/// and therefore observable price movement is quantized to about `s` bps. Coarse
+/// grids are typical of volatile pairs and blur small moves the variance estimate
+/// would otherwise see, so each extra tick of spacing costs 0.1% of confidence.
+pub const TICK_SPACING_PENALTY_BPS_PER_TICK: u32 = 10;
+
+/// Upper bound on the tick-spacing confidence reduction (basis points).
+///
+/// Spacing describes resolution, not manipulation; capping the penalty at 25%
+/// keeps a well-behaved wide-spacing pool usable while variance and cross-method
+/// checks remain the dominant confidence inputs.
+pub const MAX_TICK_SPACING_PENALTY_BPS: u32 = 2_500;
+
+/// Minimum share of the requested window (basis points) the ring must cover under strict coverage.
+///
+/// # Young Pools
+///
+/// A ring that has not yet recorded a full window still yields a TWAP, just over
+/// a shorter span than configured. Outside strict mode that only scales confidence
+/// by the covered share; strict mode refuses anything covering less than half the
+/// request, where the averaging no longer provides the resistance it was sized for.
+pub const MIN_WINDOW_COVERAGE_BPS: u32 = 5_000;
+
+/// Largest tick change allowed between an interval and each of its neighbours.
+///
+/// # Spikes Versus Moves
+///
+/// A genuine repricing shifts the tick and stays there, so it differs from one
+/// neighbour only. An interval that sits more than this far from both sides and
+/// then reverts is what a tampered `tick_cumulative` looks like, or a flash-loan
+/// swap undone within one observation period. About 22% in a single ~15 second
+/// interval is far outside organic movement on any pool worth pricing.
+pub const MAX_INTERVAL_TICK_JUMP: i64 = 2_000;
+
+/// Manipulation risk added per interval excluded as a cumulative spike (basis points).
+///
+/// Exclusion keeps the spike out of the estimators, but somebody was able to
+/// move the pool that far, which is itself evidence the pool can be pushed.
+pub const CUMULATIVE_SPIKE_RISK_BPS: u32 = 1_000;
@@ -57,6 +57,25 @@ const POW10_LOOKUP: [u128; 19] = [
     1_000_000_000_000_000_000,
 ];
 
+/// Direction in which `ui_price_from_sqrt_q64` resolves a fractional result.
+///
+/// # Settlement Determinism
+///
+/// Integrators settling against the UI price need to know which way the last unit
+/// goes: a lender valuing collateral wants `Floor`, one valuing debt wants `Ceil`.
+/// The mode applies to the exact ratio, so it behaves the same whether the decimal
+/// adjustment scales up, scales down, or is absent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round toward zero, discarding the fraction.
+    Floor,
+    /// Round to the nearest integer, halves rounding up.
+    #[default]
+    Nearest,
+    /// Round away from zero whenever any fraction remains.
+    Ceil,
+}
+
 /// Multiply two Q64.64 fixed-point numbers with overflow protection.
 ///
 /// # Fixed-Point Arithmetic Strategy
@@ -245,50 +264,129 @@ pub fn get_sqrt_ratio_at_tick(tick: i32) -> Result<u128> {
 /// Example: USDC (6 decimals) / ETH (18 decimals) requires 10^(6-18) = 10^(-12) scaling
 /// to display price correctly as "USDC per ETH" rather than raw integer ratios.
 ///
-/// # Rounding Strategy for Division
+/// # Rounding Strategy
 ///
-/// When scaling down (negative decimal difference), adds half the divisor before division
-/// to implement banker's rounding. This prevents systematic bias that could accumulate
-/// in repeated calculations and provides more accurate price representations.
+/// The Q64.64 fraction and the decimal adjustment are folded into a single division,
+/// `price_x64 · 10^up / (2^64 · 10^down)`, and `rounding` resolves its remainder. Rounding
+/// once keeps the three paths consistent: scaling up no longer silently floors the
+/// fractional bits while scaling down rounds to nearest. `Nearest` reproduces the
+/// previous scale-down result except where the discarded fraction tipped a value
+/// across the half-way point.
 ///
 /// # Performance Optimization
 ///
 /// Uses precomputed powers of 10 lookup table to avoid expensive exponentiation during
 /// decimal conversions. Handles the most common decimal differences (±18) efficiently.
+/// Results beyond `u128` saturate, as the scale-up multiplication always has.
 #[inline(always)]
-pub fn ui_price_from_sqrt_q64(sqrt_price_x64: u128, decimal_0: u8, decimal_1: u8) -> Result<u128> {
+pub fn ui_price_from_sqrt_q64(
+    sqrt_price_x64: u128,
+    decimal_0: u8,
+    decimal_1: u8,
+    rounding: RoundingMode,
+) -> Result<u128> {
     // Calculate actual price by squaring sqrt price: price = (sqrt_price)²
     // This converts from sqrt representation back to actual token ratio
     let price_x64 = multiply_q64(sqrt_price_x64, sqrt_price_x64)?;
 
-    // Convert from Q64.64 fixed-point to integer by removing fractional bits
-    let price = price_x64 >> 64;
-
     // Calculate decimal adjustment needed for human-readable price display
     // Positive: token0 has more decimals, need to multiply to scale up
     // Negative: token1 has more decimals, need to divide to scale down
     let decimal_difference = decimal_0 as i8 - decimal_1 as i8;
 
-    let scaled = match decimal_difference {
+    let (scale_up, scale_down) = match decimal_difference {
         // No decimal adjustment needed - tokens have same precision
-        0 => price,
+        0 => (1, 1),
 
         // Scale up: token0 has more decimals than token1
-        // Multiply by 10^difference to adjust for decimal disparity
-        1..=18 => price.saturating_mul(POW10_LOOKUP[decimal_difference as usize]),
+        1..=18 => (POW10_LOOKUP[decimal_difference as usize], 1),
 
         // Scale down: token1 has more decimals than token0
-        // Divide by 10^|difference| with rounding for accuracy
-        -18..=-1 => {
-            let divisor = POW10_LOOKUP[(-decimal_difference) as usize];
-            // Add half divisor before division for banker's rounding
-            // This prevents systematic bias in repeated calculations
-            (price + (divisor >> 1)) / divisor
-        }
+        -18..=-1 => (1, POW10_LOOKUP[(-decimal_difference) as usize]),
 
         // Decimal difference exceeds lookup table range - unsupported
         _ => return Err(RaydiumObserverError::MathError.into()),
     };
 
-    Ok(scaled)
+    // Q64.64 price times at most 10^18 stays below 2^188, leaving U256 headroom for the bias
+    let numerator = U256::from(price_x64) * U256::from(scale_up);
+    let denominator = U256::from(scale_down) << 64;
+    let bias = match rounding {
+        RoundingMode::Floor => U256::ZERO,
+        RoundingMode::Nearest => denominator >> 1,
+        RoundingMode::Ceil => denominator - U256::ONE,
+    };
+    let scaled: U256 = (numerator + bias) / denominator;
+
+    Ok(if scaled > U256::from(u128::MAX) {
+        u128::MAX
+    } else {
+        scaled.as_u128()
+    })
+}
+
+/// Estimate the basis-point price move caused by swapping `trade_size` of token1 into the pool.
+///
+/// # CLMM Swap Mathematics
+///
+/// Within a single tick range a CLMM behaves like a constant-product pool with virtual
+/// reserves, so adding `Δy` of token1 moves the sqrt price by `Δ√P = Δy / L`. The
+/// resulting price ratio is `(1 + Δ√P/√P)²`, giving an impact of:
+///
+/// `impact = 2·r + r²` where `r = Δ√P / √P`
+///
+/// # Approximation Boundaries
+///
+/// The estimate assumes liquidity stays constant across the move, which is exact until
+/// the swap crosses an initialized tick and optimistic afterwards. That bias is acceptable
+/// for manipulation sizing: the figure answers "how far can this much capital push the
+/// price at the current depth", and liquidity thinning out beyond the active range only
+/// makes the real attack cheaper, never more expensive.
+///
+/// # Saturation Strategy
+///
+/// Results are capped at 10,000 bps so the estimate shares the scale of every other risk
+/// factor. Once a trade would double the sqrt price the pool is trivially manipulable and
+/// further resolution carries no information.
+///
+/// # Errors
+///
+/// Returns `MathError` when liquidity or sqrt price is zero, since an empty or
+/// uninitialized pool has no defined impact. A zero `trade_size` returns zero
+/// before that check, so a disabled impact factor never rejects an empty pool.
+pub fn estimate_price_impact_bps(
+    liquidity: u128,
+    sqrt_price_x64: u128,
+    trade_size: u128,
+) -> Result<u32> {
+    // A zero trade size disables the factor, so it must not depend on pool depth
+    if trade_size == 0 {
+        return Ok(0);
+    }
+
+    require!(
+        liquidity > 0 && sqrt_price_x64 > 0,
+        RaydiumObserverError::MathError
+    );
+
+    // Δ√P in Q64.64: trade_size < 2^128 so the shifted numerator stays below 2^192
+    let delta_sqrt_x64: U256 = (U256::from(trade_size) << 64) / U256::from(liquidity);
+    let sqrt_price = U256::from(sqrt_price_x64);
+
+    // A move of at least √P means the price at least quadruples
+    if delta_sqrt_x64 >= sqrt_price {
+        return Ok(10_000);
+    }
+
+    // r in Q64.64, strictly below 2^64 after the guard above
+    let ratio_x64: U256 = (delta_sqrt_x64 << 64) / sqrt_price;
+
+    // (2r + r²) · 10,000 evaluated as r·(2·2^64 + r)·10,000 / 2^128
+    let impact: U256 = (ratio_x64 * ((U256::ONE << 65) + ratio_x64) * U256::from(10_000u32)) >> 128;
+
+    Ok(if impact >= U256::from(10_000u32) {
+        10_000
+    } else {
+        impact.as_u32()
+    })
 }
@@ -1,9 +1,13 @@
 use crate::components::raydium_clmm_observer::raydium_accounts::ObservationReader;
 use crate::components::raydium_clmm_observer::raydium_constants::{
-    MAX_TICK, MIN_TICK, OBSERVATION_NUM, OBSERVATION_UPDATE_DURATION,
+    CUMULATIVE_SPIKE_RISK_BPS, MAX_INTERVAL_TICK_JUMP, MAX_OBSERVATION_STALENESS, MAX_TICK,
+    MAX_TICK_SPACING_PENALTY_BPS, MIN_TICK, OBSERVATION_NUM, OBSERVATION_UPDATE_DURATION,
+    TICK_SPACING_PENALTY_BPS_PER_TICK,
 };
+use crate::components::raydium_clmm_observer::sqrt_price_to_tick::get_sqrt_ratio_at_tick;
 use crate::error::RaydiumObserverError;
 use anchor_lang::prelude::*;
+use ethnum::U256;
 
 /// Fixed-point arithmetic configuration for TWAP calculations.
 ///
@@ -41,24 +45,147 @@ fn mul_fp(a: i128, b: i128) -> i128 {
     a.saturating_mul(b) >> FP_SHIFT
 }
 
-/// Locate observation indices for TWAP calculation over a specified time window.
+/// One exponential-smoothing step: `previous + alpha * (sample - previous)`.
 ///
-/// # Time Window Strategy
+/// `alpha_bps` is the weight of the new sample in basis points, clamped to
+/// 10,000 (which returns `sample` unchanged). Only the difference is scaled, so
+/// values far larger than a tick stay well clear of the fixed-point headroom.
+/// The shift floors, so a rising series settles within `10_000 / alpha_bps`
+/// units below a steady sample rather than exactly on it.
+#[inline]
+pub fn ema_step(previous: i128, sample: i128, alpha_bps: u16) -> i128 {
+    let alpha = (FP_ONE * (alpha_bps.min(10_000) as i128)) / 10_000i128;
+    previous.saturating_add(mul_fp(alpha, sample.saturating_sub(previous)))
+}
+
+/// Price of `twap_tick` relative to `current_tick`, in signed basis points.
 ///
-/// This function implements binary search through the circular observation buffer to find
-/// the optimal historical observation pair for TWAP calculation. The algorithm balances
-/// accuracy (longer time windows) with data availability (observations may be sparse).
+/// # Direction
 ///
-/// # Security Considerations
+/// Positive when the window averaged above spot, negative when below. The
+/// absolute tick distance used for `max_tick_deviation` cannot tell a pump
+/// that has not yet reached the average from a dump that has, and directional
+/// manipulation detection needs exactly that distinction.
 ///
-/// - Validates window size to prevent manipulation through micro-timeframes
-/// - Checks data freshness to avoid stale price propagation
-/// - Uses wrapping arithmetic to handle timestamp overflow gracefully
-pub fn find_observation_for_window(
+/// # Exact Ratio
+///
+/// One tick is only about one basis point; over wide gaps `1.0001^delta`
+/// drifts well away from `delta`, and asymmetrically (+1,000 ticks is about
+/// +10.5% while -1,000 is about -9.5%). The ratio is therefore taken from the
+/// Q64.64 sqrt price of the tick gap and squared in 256-bit space, truncating
+/// toward zero. Gaps beyond Raydium's tick range saturate at its bounds, and
+/// results beyond `i32` saturate.
+pub fn signed_deviation_bps(twap_tick: i64, current_tick: i32) -> i32 {
+    let delta = twap_tick
+        .saturating_sub(current_tick as i64)
+        .clamp(MIN_TICK as i64, MAX_TICK as i64) as i32;
+    let Ok(sqrt_ratio_x64) = get_sqrt_ratio_at_tick(delta) else {
+        return if delta > 0 { i32::MAX } else { i32::MIN };
+    };
+
+    // ratio * 2^128; the widest gap squares to about 2^192, leaving room for * 10_000
+    let ratio_x128 = U256::from(sqrt_ratio_x64) * U256::from(sqrt_ratio_x64);
+    let one_x128 = U256::ONE << 128;
+    let bps = |distance: U256| -> i64 {
+        let scaled: U256 = (distance * U256::from(10_000u32)) >> 128;
+        scaled.min(U256::from(i32::MAX as u32)).as_u64() as i64
+    };
+
+    if ratio_x128 >= one_x128 {
+        bps(ratio_x128 - one_x128) as i32
+    } else {
+        -bps(one_x128 - ratio_x128) as i32
+    }
+}
+
+/// Observations bracketing a TWAP window's start, with the start's position between them.
+///
+/// # Boundary Bias
+///
+/// Raydium writes observations on its own cadence, so the window start rarely lands
+/// exactly on one. Using the nearest earlier observation stretches the window back to
+/// it and over-weights whatever price held in the stretched part. With both neighbours
+/// and the offset of the target between them, the caller can linearly interpolate the
+/// tick cumulative at the exact boundary instead.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WindowBracket {
+    /// Latest observation at or before the window start, or the oldest one available.
+    pub index_before: usize,
+
+    /// Observation following `index_before`; equals it when there is nothing newer.
+    pub index_after: usize,
+
+    /// Most recent observation in the ring.
+    pub index_now: usize,
+
+    /// Seconds from `index_before` to the window start; zero when no interpolation applies.
+    pub offset_seconds: u32,
+
+    /// Seconds from `index_before` to `index_after`.
+    pub span_seconds: u32,
+
+    /// Seconds of the requested window older than the oldest observation reached.
+    /// Zero when the ring spans the whole window.
+    pub coverage_shortfall_seconds: u32,
+}
+
+impl WindowBracket {
+    /// True when the window start falls strictly between two observations.
+    #[inline]
+    pub fn needs_interpolation(&self) -> bool {
+        self.offset_seconds > 0 && self.offset_seconds < self.span_seconds
+    }
+
+    /// Tick cumulative at the window start, linearly interpolated between the bracket.
+    ///
+    /// The cumulative is piecewise linear between observations (the tick is constant
+    /// within an interval), so interpolation is exact for the interval's average tick.
+    /// Falls back to the `index_before` cumulative when no interpolation applies.
+    pub fn boundary_tick_cumulative(&self, observation_reader: &ObservationReader) -> i64 {
+        let before = observation_reader
+            .get_observation(self.index_before)
+            .tick_cumulative();
+        if !self.needs_interpolation() {
+            return before;
+        }
+
+        let after = observation_reader
+            .get_observation(self.index_after)
+            .tick_cumulative();
+        let delta = after.wrapping_sub(before) as i128;
+        let partial = delta * self.offset_seconds as i128 / self.span_seconds as i128;
+        before.wrapping_add(partial as i64)
+    }
+    /// TWAP endpoints `(index_then, index_now, seconds_elapsed)` for this bracket.
+    ///
+    /// Falls back to the newest observation twice, with one second elapsed, when
+    /// no earlier observation exists; that still yields a valid if less accurate
+    /// price estimate for sparse data.
+    pub fn endpoints(&self, observation_reader: &ObservationReader) -> (usize, usize, u32) {
+        let timestamp_now = observation_reader
+            .get_observation(self.index_now)
+            .block_timestamp() as i64;
+        let timestamp_then = observation_reader
+            .get_observation(self.index_before)
+            .block_timestamp() as i64;
+        let elapsed = timestamp_now.wrapping_sub(timestamp_then) as u32;
+
+        if elapsed == 0 {
+            return (self.index_now, self.index_now, 1);
+        }
+        (self.index_before, self.index_now, elapsed)
+    }
+}
+
+/// Finds the observations bracketing `current_timestamp - window_size`.
+///
+/// Applies the same window, freshness and initialization checks as
+/// `find_observation_for_window`, which is a thin wrapper over this search.
+pub fn find_window_bracket(
     observation_reader: &ObservationReader,
     current_timestamp: i64,
     window_size: u32,
-) -> Result<(usize, usize, u32)> {
+) -> Result<WindowBracket> {
     // Enforce minimum window size to prevent manipulation through ultra-short time periods
     // that could be gamed by coordinated trading within single blocks
     require!(
@@ -80,52 +207,138 @@ pub fn find_observation_for_window(
 
     // Be more permissive with staleness for integration testing and sparse data scenarios
     // Allow up to 10 minutes of staleness instead of strict 30 seconds
-    require!(staleness <= 600, RaydiumObserverError::InsufficientTime);
+    require!(
+        staleness <= MAX_OBSERVATION_STALENESS,
+        RaydiumObserverError::InsufficientTime
+    );
 
     let target_timestamp = current_timestamp.wrapping_sub(window_size as i64);
 
     // Walk backwards through circular buffer to find observation closest to target timestamp
-    // Limited to (OBSERVATION_NUM - 1) iterations to prevent infinite loops
-    let mut index_then = index_now;
-    for _ in 0..(OBSERVATION_NUM - 1) {
-        let previous_index = if index_then == 0 {
+    // Limited to the filled length so young pools stop at their oldest real observation
+    let mut index_before = index_now;
+    let mut index_after = index_now;
+    for _ in 0..observation_reader.filled_count().saturating_sub(1) {
+        let previous_index = if index_before == 0 {
             OBSERVATION_NUM - 1
         } else {
-            index_then - 1
+            index_before - 1
         };
         let previous_observation = observation_reader.get_observation(previous_index);
         let previous_timestamp = previous_observation.block_timestamp() as i64;
 
-        // Break on uninitialized observations (timestamp == 0)
+        // Unreachable within the filled length; kept as a guard against corrupt rings
         if previous_timestamp == 0 {
             break;
         }
 
-        // Use wrapping subtraction with signed comparison to handle timestamp overflow
-        // The (i64::MAX >> 1) threshold ensures correct temporal ordering even with wrap-around
-        let previous_before_equals_target =
-            target_timestamp.wrapping_sub(previous_timestamp) < (i64::MAX >> 1);
+        index_after = index_before;
+        index_before = previous_index;
 
-        if previous_before_equals_target {
-            index_then = previous_index;
+        // Timestamps are widened from u32, so a plain comparison cannot wrap. The former
+        // wrapping-difference test was also true for every observation after the target,
+        // stopping the walk after a single step regardless of the requested window
+        if previous_timestamp <= target_timestamp {
             break;
         }
-
-        index_then = previous_index;
     }
 
-    let observation_then = observation_reader.get_observation(index_then);
-    let elapsed = timestamp_now.wrapping_sub(observation_then.block_timestamp() as i64) as u32;
+    let timestamp_before = observation_reader
+        .get_observation(index_before)
+        .block_timestamp() as i64;
+    let timestamp_after = observation_reader
+        .get_observation(index_after)
+        .block_timestamp() as i64;
 
-    // For integration testing and sparse data scenarios, be more flexible
-    // Try to return the best available data even if not ideal
-    if elapsed == 0 {
-        // If we couldn't find any earlier observation, use current observation twice
-        // This provides a valid but less accurate price estimate
-        return Ok((index_now, index_now, 1));
+    // A target older than the oldest observation cannot be interpolated
+    let offset_seconds =
+        if target_timestamp > timestamp_before && target_timestamp < timestamp_after {
+            (target_timestamp - timestamp_before) as u32
+        } else {
+            0
+        };
+
+    Ok(WindowBracket {
+        index_before,
+        index_after,
+        index_now,
+        offset_seconds,
+        span_seconds: timestamp_after.saturating_sub(timestamp_before) as u32,
+        // Only non-zero when the walk ran out of ring before reaching the target
+        coverage_shortfall_seconds: timestamp_before.saturating_sub(target_timestamp).max(0) as u32,
+    })
+}
+
+/// Locate observation indices for TWAP calculation over a specified time window.
+///
+/// # Time Window Strategy
+///
+/// This function implements binary search through the circular observation buffer to find
+/// the optimal historical observation pair for TWAP calculation. The algorithm balances
+/// accuracy (longer time windows) with data availability (observations may be sparse).
+///
+/// # Security Considerations
+///
+/// - Validates window size to prevent manipulation through micro-timeframes
+/// - Checks data freshness to avoid stale price propagation
+/// - Uses wrapping arithmetic to handle timestamp overflow gracefully
+pub fn find_observation_for_window(
+    observation_reader: &ObservationReader,
+    current_timestamp: i64,
+    window_size: u32,
+) -> Result<(usize, usize, u32)> {
+    Ok(
+        find_window_bracket(observation_reader, current_timestamp, window_size)?
+            .endpoints(observation_reader),
+    )
+}
+
+/// Scales a confidence score (basis points) by the share of the window actually covered.
+///
+/// A ring covering half the requested window contributes half the evidence the
+/// window was sized for, so confidence halves with it. Multiplicative like
+/// `spacing_adjusted_confidence`, so the two adjustments compose in any order.
+pub fn coverage_adjusted_confidence(
+    confidence: u32,
+    window_seconds: u32,
+    shortfall_seconds: u32,
+) -> u32 {
+    if window_seconds == 0 {
+        return confidence;
     }
+    let covered = window_seconds.saturating_sub(shortfall_seconds) as u64;
+    ((confidence as u64 * covered) / window_seconds as u64) as u32
+}
 
-    Ok((index_then, index_now, elapsed))
+/// Shortest span a TWAP may be computed over for a caller asking for `min_seconds`.
+///
+/// Never below `OBSERVATION_UPDATE_DURATION`: a span shorter than one Raydium
+/// observation interval averages a single tick, which is exactly the
+/// micro-window an attacker can hold for the length of one transaction.
+#[inline]
+pub fn min_elapsed_seconds(min_seconds: u32) -> u32 {
+    core::cmp::max(min_seconds, OBSERVATION_UPDATE_DURATION)
+}
+
+/// Seconds by which `seconds_elapsed` falls short of `min_elapsed_seconds(min_seconds)`.
+///
+/// Strict mode rejects any shortfall with `InsufficientTime`. Relaxed mode exists
+/// only for testing and bootstrapping a cold pool: the span is accepted and the
+/// shortfall returned, so the caller can scale confidence down through
+/// `coverage_adjusted_confidence` exactly as it does for a young ring.
+pub fn min_elapsed_shortfall(seconds_elapsed: u32, min_seconds: u32, strict: bool) -> Result<u32> {
+    let shortfall = min_elapsed_seconds(min_seconds).saturating_sub(seconds_elapsed);
+    require!(
+        !strict || shortfall == 0,
+        RaydiumObserverError::InsufficientTime
+    );
+    Ok(shortfall)
+}
+
+/// Share of `window_seconds` covered by the ring, in basis points.
+#[inline]
+pub fn window_coverage_bps(window_seconds: u32, shortfall_seconds: u32) -> u32 {
+    coverage_adjusted_confidence(10_000, window_seconds, shortfall_seconds)
 }
 
 /// Calculate time-weighted average price tick from cumulative tick observations.
@@ -144,11 +357,21 @@ pub fn find_observation_for_window(
 /// Wrapping subtraction handles cumulative value overflow gracefully, as the
 /// mathematical difference remains correct even when individual cumulative values
 /// wrap around the integer boundary during long-running calculations.
+///
+/// # Multi-Wrap Detection
+///
+/// That only holds for a single wrap. A true delta beyond `i64::MAX` comes back
+/// reduced modulo 2^64, which for a sustained one-sided price shows up as an
+/// average of the opposite sign, often still inside the tick range. When
+/// `endpoint_ticks` (the ticks in force at the window's start and end) share a
+/// strict sign, an average of the other strict sign cannot be trusted and is
+/// rejected; `None` skips the direction check for callers without endpoint data.
 #[inline(always)]
 pub fn twap_tick_from_cumulatives(
     tick_cumulative_then: i64,
     tick_cumulative_now: i64,
     seconds_elapsed: u32,
+    endpoint_ticks: Option<(i64, i64)>,
 ) -> Result<i64> {
     // Handle edge case where no time has elapsed (use current tick)
     if seconds_elapsed == 0 {
@@ -172,9 +395,229 @@ pub fn twap_tick_from_cumulatives(
         RaydiumObserverError::TickOutOfBounds
     );
 
+    if let Some((tick_then, tick_now)) = endpoint_ticks {
+        let direction = tick_then.signum();
+        require!(
+            direction == 0 || tick_now.signum() != direction || tick.signum() != -direction,
+            RaydiumObserverError::TickOutOfBounds
+        );
+    }
+
     Ok(tick)
 }
 
+/// Time-weighted tick over exactly `[target_start, target_end]`.
+///
+/// # Sub-Window Interpolation
+///
+/// `twap_tick_from_cumulatives` can only average between two stored
+/// observations. This walks the ring once from `index_then` towards
+/// `index_now` and reads the tick cumulative at each target timestamp by linear
+/// interpolation inside the interval that contains it, the same rule
+/// `WindowBracket::boundary_tick_cumulative` applies to the window start. The
+/// tick is constant within an interval, so the result is the exact average over
+/// the requested span, at the cost of one pass and no per-interval division,
+/// which makes it a cheap cross-check against the recency-weighted estimators
+/// that take the same index pair.
+///
+/// When both targets fall on observations, the result equals
+/// `twap_tick_from_cumulatives` over those observations.
+///
+/// # Errors
+///
+/// - `InvalidWindow`: `target_end` is not after `target_start`
+/// - `InsufficientTime`: a target lies outside the observations walked, or the
+///   walk hits an unwritten slot first
+/// - `TickOutOfBounds`: the average falls outside Raydium's tick range
+pub fn twap_tick_interpolated(
+    observation_reader: &ObservationReader,
+    index_then: usize,
+    index_now: usize,
+    target_start: i64,
+    target_end: i64,
+) -> Result<i64> {
+    require!(
+        target_end > target_start,
+        RaydiumObserverError::InvalidWindow
+    );
+
+    let mut cumulative_start = None;
+    let mut cumulative_end = None;
+    let mut i = index_then;
+
+    for _ in 0..observation_reader.filled_count() {
+        if i == index_now {
+            break;
+        }
+        let j = (i + 1) % OBSERVATION_NUM;
+        let observation_i = observation_reader.get_observation(i);
+        let observation_j = observation_reader.get_observation(j);
+        let timestamp_i = observation_i.block_timestamp() as i64;
+        let timestamp_j = observation_j.block_timestamp() as i64;
+        if timestamp_i == 0 || timestamp_j == 0 {
+            break;
+        }
+
+        let cumulative_at = |target: i64| -> i64 {
+            let span = timestamp_j - timestamp_i;
+            if span <= 0 {
+                return observation_i.tick_cumulative();
+            }
+            let delta = observation_j
+                .tick_cumulative()
+                .wrapping_sub(observation_i.tick_cumulative()) as i128;
+            let partial = delta * (target - timestamp_i) as i128 / span as i128;
+            observation_i.tick_cumulative().wrapping_add(partial as i64)
+        };
+        let contains = |target: i64| timestamp_i <= target && target <= timestamp_j;
+
+        if cumulative_start.is_none() && contains(target_start) {
+            cumulative_start = Some(cumulative_at(target_start));
+        }
+        if cumulative_start.is_some() && contains(target_end) {
+            cumulative_end = Some(cumulative_at(target_end));
+            break;
+        }
+        i = j;
+    }
+
+    let (Some(cumulative_start), Some(cumulative_end)) = (cumulative_start, cumulative_end) else {
+        return err!(RaydiumObserverError::InsufficientTime);
+    };
+    let seconds_elapsed = u32::try_from(target_end - target_start)
+        .map_err(|_| RaydiumObserverError::InvalidWindow)?;
+    twap_tick_from_cumulatives(cumulative_start, cumulative_end, seconds_elapsed, None)
+}
+
+/// Average tick over the newest interval of the observation ring.
+///
+/// Derived from the last two observations' cumulatives, so it reflects what the
+/// ring last recorded rather than what the pool is doing now. Returns `None` when
+/// the ring holds fewer than two observations or they share a timestamp.
+pub fn latest_observation_tick(observation_reader: &ObservationReader) -> Option<i64> {
+    interval_tick_ending_at(observation_reader, observation_reader.current_index())
+}
+
+/// Average tick over the ring interval that ends at slot `index`.
+///
+/// Returns `None` when the ring holds fewer than two observations, the previous
+/// slot was never written, or both share a timestamp.
+pub fn interval_tick_ending_at(
+    observation_reader: &ObservationReader,
+    index: usize,
+) -> Option<i64> {
+    if observation_reader.filled_count() < 2 {
+        return None;
+    }
+
+    let index_previous = if index == 0 {
+        OBSERVATION_NUM - 1
+    } else {
+        index - 1
+    };
+    let now = observation_reader.get_observation(index);
+    let previous = observation_reader.get_observation(index_previous);
+    if previous.block_timestamp() == 0 {
+        return None;
+    }
+
+    let elapsed = now
+        .block_timestamp()
+        .checked_sub(previous.block_timestamp())?;
+    if elapsed == 0 {
+        return None;
+    }
+
+    Some(
+        now.tick_cumulative()
+            .wrapping_sub(previous.tick_cumulative())
+            / elapsed as i64,
+    )
+}
+
+/// Rejects an observation ring that has drifted away from the live pool tick.
+///
+/// # Desync Detection
+///
+/// The staleness check in `find_window_bracket` only looks at the newest
+/// timestamp. A ring can pass it while its recorded ticks trail a pool that has
+/// since moved far, for instance when observation writes stall behind swaps;
+/// every estimator built on that ring would then lag the market. Comparing the
+/// newest interval's tick with `tick_current` catches that before any price is
+/// derived. Rings too short to imply a tick are left to the other checks.
+pub fn check_ring_in_sync(
+    observation_reader: &ObservationReader,
+    current_tick: i32,
+    max_tick_deviation: i32,
+) -> Result<()> {
+    let Some(latest_tick) = latest_observation_tick(observation_reader) else {
+        return Ok(());
+    };
+
+    let deviation = latest_tick.abs_diff(current_tick as i64);
+    require!(
+        deviation <= max_tick_deviation.max(0) as u64,
+        RaydiumObserverError::StaleObservationRing
+    );
+    Ok(())
+}
+
+/// Whether the interval starting at slot `index` is an isolated cumulative spike.
+///
+/// # Neighbour Test
+///
+/// `interval_tick` is flagged only when it differs by more than
+/// `MAX_INTERVAL_TICK_JUMP` from both the interval ending at `index` and the one
+/// starting at the next slot. A single tampered `tick_cumulative` distorts the
+/// two intervals it bounds in opposite directions, so both are caught, while a
+/// real move that holds differs from one side only and is kept.
+///
+/// The first and newest intervals of the ring lack one neighbour and are never
+/// flagged; the newest is instead checked against the live tick by
+/// `check_ring_in_sync`.
+pub fn is_cumulative_spike(
+    observation_reader: &ObservationReader,
+    index: usize,
+    interval_tick: i64,
+) -> bool {
+    let following = (index + 2) % OBSERVATION_NUM;
+    let (Some(previous_tick), Some(next_tick)) = (
+        interval_tick_ending_at(observation_reader, index),
+        interval_tick_ending_at(observation_reader, following),
+    ) else {
+        return false;
+    };
+
+    let jump = |neighbour: i64| interval_tick.abs_diff(neighbour) > MAX_INTERVAL_TICK_JUMP as u64;
+    jump(previous_tick) && jump(next_tick)
+}
+
+/// Number of intervals between `index_then` and `index_now` that
+/// `is_cumulative_spike` excludes from the window estimators.
+pub fn count_cumulative_spikes(
+    observation_reader: &ObservationReader,
+    index_then: usize,
+    index_now: usize,
+) -> u32 {
+    let mut i = index_then;
+    let mut spikes = 0u32;
+
+    for _ in 0..observation_reader.filled_count() {
+        if i == index_now {
+            break;
+        }
+        let j = (i + 1) % OBSERVATION_NUM;
+        if let Some(tick) = interval_tick_ending_at(observation_reader, j) {
+            if is_cumulative_spike(observation_reader, i, tick) {
+                spikes += 1;
+            }
+        }
+        i = j;
+    }
+
+    spikes
+}
+
 /// Calculate T2EMA (Triple Exponential Moving Average with Lag Compensation) for trend analysis.
 ///
 /// # T2EMA Algorithm Rationale
@@ -192,6 +635,12 @@ pub fn twap_tick_from_cumulatives(
 /// avoiding floating-point operations that could introduce non-deterministic behavior
 /// across different hardware platforms. This is critical for consensus in blockchain
 /// environments where all nodes must produce identical results.
+///
+/// # Warm-Up
+///
+/// Fails with `T2emaNotWarm` when no interval between the endpoints survives the
+/// zero-duration and spike filters, as on a ring holding a single observation.
+/// There is nothing to smooth, and the zeroed EMAs are not an estimate.
 #[inline(always)]
 pub fn t2ema_tick(
     observation_reader: &ObservationReader,
@@ -218,7 +667,7 @@ pub fn t2ema_tick(
 
     loop {
         // Circuit breaker to prevent infinite loops in case of corrupted circular buffer indices
-        if iterations >= OBSERVATION_NUM {
+        if iterations >= observation_reader.filled_count() {
             break;
         }
 
@@ -254,6 +703,16 @@ pub fn t2ema_tick(
             .checked_div(delta_time)
             .ok_or(RaydiumObserverError::MathError)?;
 
+        // A spiked cumulative would drag both EMAs towards it; leave it out
+        if is_cumulative_spike(observation_reader, i, tick_average) {
+            i = j;
+            if i == index_now {
+                break;
+            }
+            iterations += 1;
+            continue;
+        }
+
         // Clamp tick values to valid range to prevent downstream calculation errors
         // Invalid ticks could propagate through the EMA and corrupt final results
         let tick_average_clamped = tick_average.clamp(MIN_TICK as i64, MAX_TICK as i64);
@@ -277,6 +736,10 @@ pub fn t2ema_tick(
         iterations += 1;
     }
 
+    // Without a single usable interval both EMAs are still zero, which would
+    // otherwise read as a confident tick 0
+    require!(!first, RaydiumObserverError::T2emaNotWarm);
+
     // Calculate T2EMA with lag compensation: 2*EMA1 - EMA2
     // This formula reduces the lag inherent in double exponential smoothing
     let t2_raw = 2i128.saturating_mul(ema1).saturating_sub(ema2);
@@ -307,10 +770,18 @@ pub fn t2ema_tick(
 /// - 0 = minimum confidence (high variance, volatile prices)
 ///
 /// This scaling allows for precise risk assessment in downstream applications.
+///
+/// # Tick Spacing
+///
+/// Variance is measured in ticks, but a pool can only express prices on its
+/// `tick_spacing` grid. The variance-derived score is therefore scaled down by
+/// `spacing_adjusted_confidence` so a coarse pool never reports the same certainty
+/// as a fine-grained one with identical observations.
 pub fn confidence_from_variance(
     observation_reader: &ObservationReader,
     index_then: usize,
     index_now: usize,
+    tick_spacing: u16,
 ) -> Result<u32> {
     let mut i = index_then;
     let mut n = 0u32;
@@ -320,7 +791,7 @@ pub fn confidence_from_variance(
 
     loop {
         // Circuit breaker to prevent infinite loops from corrupted circular buffer state
-        if iterations >= OBSERVATION_NUM {
+        if iterations >= observation_reader.filled_count() {
             break;
         }
 
@@ -356,6 +827,16 @@ pub fn confidence_from_variance(
             .checked_div(delta_time)
             .ok_or(RaydiumObserverError::MathError)? as i128;
 
+        // Spiked intervals are counted as risk by the caller, not as variance
+        if is_cumulative_spike(observation_reader, i, t as i64) {
+            i = j;
+            if i == index_now {
+                break;
+            }
+            iterations += 1;
+            continue;
+        }
+
         // Accumulate sample count and statistical moments for variance calculation
         n += 1;
         sum = sum.saturating_add(t);
@@ -393,7 +874,175 @@ pub fn confidence_from_variance(
     // Scale by 100 to convert to percentage-like representation, then invert
     let confidence = 10_000u32.saturating_sub((variance / 100).min(10_000));
 
-    Ok(confidence)
+    Ok(spacing_adjusted_confidence(confidence, tick_spacing))
+}
+
+/// Calculate confidence from an exponentially weighted variance of interval ticks.
+///
+/// # Recency Weighting
+///
+/// `confidence_from_variance` gives every interval in the window the same say, so
+/// a burst of volatility in the last few observations is diluted by a long calm
+/// history. Here each interval's average tick updates an EMA of the mean and an
+/// EMA of the squared deviation from it, with `alpha_bps` as the weight of the
+/// newest interval, so recent turbulence lowers confidence sooner.
+///
+/// # Fixed-Point Arithmetic
+///
+/// Shares the 32-bit fixed-point helpers with `t2ema_tick` so both recency-weighted
+/// estimators round identically. Variance is the standard incremental form:
+/// `var = (1 - alpha) * (var + alpha * diff²)` with `diff` measured against the
+/// mean before it absorbs the new interval.
+///
+/// # Scoring
+///
+/// The variance maps to a 0-10,000 confidence exactly as in the equal-weight
+/// method, including the tick spacing adjustment, so the two are interchangeable.
+pub fn ewma_confidence_from_observations(
+    observation_reader: &ObservationReader,
+    index_then: usize,
+    index_now: usize,
+    alpha_bps: u16,
+    tick_spacing: u16,
+) -> Result<u32> {
+    require!(
+        alpha_bps > 0 && alpha_bps <= 10_000,
+        RaydiumObserverError::InvalidWindow
+    );
+
+    let alpha = (FP_ONE * (alpha_bps as i128)) / 10_000i128;
+    let one_minus_alpha = FP_ONE - alpha;
+
+    let mut i = index_then;
+    let mut n = 0u32;
+    let mut mean: i128 = 0;
+    let mut variance: i128 = 0;
+    let mut iterations = 0usize;
+
+    loop {
+        // Circuit breaker to prevent infinite loops from corrupted circular buffer state
+        if iterations >= observation_reader.filled_count() {
+            break;
+        }
+
+        let j = (i + 1) % OBSERVATION_NUM;
+        let observation_i = observation_reader.get_observation(i);
+        let observation_j = observation_reader.get_observation(j);
+
+        let timestamp_i = observation_i.block_timestamp() as i64;
+        let timestamp_j = observation_j.block_timestamp() as i64;
+
+        if timestamp_i == 0 || timestamp_j == 0 {
+            break;
+        }
+
+        let delta_time = timestamp_j.saturating_sub(timestamp_i);
+
+        if delta_time == 0 {
+            i = j;
+            if i == index_now {
+                break;
+            }
+            iterations += 1;
+            continue;
+        }
+
+        let delta_tick = observation_j
+            .tick_cumulative()
+            .wrapping_sub(observation_i.tick_cumulative());
+        let tick_average = delta_tick
+            .checked_div(delta_time)
+            .ok_or(RaydiumObserverError::MathError)?;
+        if is_cumulative_spike(observation_reader, i, tick_average) {
+            i = j;
+            if i == index_now {
+                break;
+            }
+            iterations += 1;
+            continue;
+        }
+        let x = to_fp(tick_average.clamp(MIN_TICK as i64, MAX_TICK as i64));
+
+        n += 1;
+        if n == 1 {
+            mean = x;
+        } else {
+            let diff = x - mean;
+            mean += mul_fp(alpha, diff);
+            let diff_squared = mul_fp(diff, diff);
+            variance = mul_fp(
+                one_minus_alpha,
+                variance.saturating_add(mul_fp(alpha, diff_squared)),
+            );
+        }
+
+        i = j;
+        if i == index_now {
+            break;
+        }
+        iterations += 1;
+    }
+
+    // A single interval carries no dispersion information
+    if n <= 1 {
+        return Ok(0);
+    }
+
+    let variance = core::cmp::min((variance >> FP_SHIFT).max(0), u32::MAX as i128) as u32;
+    let confidence = 10_000u32.saturating_sub((variance / 100).min(10_000));
+
+    Ok(spacing_adjusted_confidence(confidence, tick_spacing))
+}
+
+/// Scales a confidence score (basis points) down for coarse tick spacing.
+///
+/// Spacing of 0 or 1 is the finest possible grid and leaves confidence untouched;
+/// each additional tick removes `TICK_SPACING_PENALTY_BPS_PER_TICK` of the score,
+/// up to `MAX_TICK_SPACING_PENALTY_BPS`. The reduction is multiplicative so it
+/// composes with the variance score instead of driving low scores negative.
+pub fn spacing_adjusted_confidence(confidence: u32, tick_spacing: u16) -> u32 {
+    let penalty_bps = (tick_spacing.saturating_sub(1) as u32)
+        .saturating_mul(TICK_SPACING_PENALTY_BPS_PER_TICK)
+        .min(MAX_TICK_SPACING_PENALTY_BPS);
+
+    // Both factors are at most 10_000, so the product fits comfortably in u64
+    ((confidence as u64 * (10_000 - penalty_bps) as u64) / 10_000) as u32
+}
+
+/// Individual risk factors behind the composite manipulation score, in basis points.
+///
+/// Kept separate from the composite so read-only callers can see which factor
+/// drives a high score; a pool flagged purely for thin liquidity calls for a
+/// different response than one whose estimators disagree.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ManipulationRiskFactors {
+    /// Inverse of the variance confidence.
+    pub variance_risk: u32,
+    /// Validated tick's distance from the live tick, amplified 5x.
+    pub deviation_risk: u32,
+    /// Fixed penalty for windows outside the optimal freshness band.
+    pub staleness_risk: u32,
+    /// Fixed penalty for pools below the minimum liquidity.
+    pub liquidity_risk: u32,
+    /// Reference trade's price impact, amplified 5x.
+    pub impact_risk: u32,
+    /// `CUMULATIVE_SPIKE_RISK_BPS` per interval excluded as a cumulative spike.
+    pub spike_risk: u32,
+}
+
+impl ManipulationRiskFactors {
+    /// Saturating sum of every factor, capped at 10,000.
+    pub fn composite(&self) -> u32 {
+        let total_risk = self
+            .variance_risk
+            .saturating_add(self.deviation_risk)
+            .saturating_add(self.staleness_risk)
+            .saturating_add(self.liquidity_risk)
+            .saturating_add(self.impact_risk)
+            .saturating_add(self.spike_risk);
+
+        core::cmp::min(total_risk, 10_000u32)
+    }
 }
 
 /// Assess manipulation risk by combining multiple risk factors into composite score.
@@ -405,6 +1054,8 @@ pub fn confidence_from_variance(
 /// 2. **Deviation Risk**: Magnitude of price deviation from current levels
 /// 3. **Staleness Risk**: Data freshness and update frequency
 /// 4. **Liquidity Risk**: Available liquidity depth for manipulation resistance
+/// 5. **Price Impact Risk**: How far a reference trade could push the pool price
+/// 6. **Spike Risk**: Intervals dropped from the estimators as cumulative spikes
 ///
 /// # Risk Scoring Design
 ///
@@ -424,7 +1075,32 @@ pub fn assess_manipulation_risk(
     seconds_elapsed: u32,
     liquidity_weight: u128,
     min_liquidity: u128,
+    price_impact_bps: u32,
+    cumulative_spikes: u32,
 ) -> u32 {
+    manipulation_risk_factors(
+        variance_confidence,
+        deviation_vs_current,
+        seconds_elapsed,
+        liquidity_weight,
+        min_liquidity,
+        price_impact_bps,
+        cumulative_spikes,
+    )
+    .composite()
+}
+
+/// Scores each input of `assess_manipulation_risk` without combining them.
+#[inline]
+pub fn manipulation_risk_factors(
+    variance_confidence: u32,
+    deviation_vs_current: i32,
+    seconds_elapsed: u32,
+    liquidity_weight: u128,
+    min_liquidity: u128,
+    price_impact_bps: u32,
+    cumulative_spikes: u32,
+) -> ManipulationRiskFactors {
     // Convert confidence to risk: low confidence = high variance risk
     let variance_risk = 10_000u32.saturating_sub(variance_confidence);
 
@@ -451,12 +1127,23 @@ pub fn assess_manipulation_risk(
         500 // Low risk: adequate manipulation resistance
     };
 
-    // Combine all risk factors with saturation arithmetic to prevent overflow
-    // Cap total risk at maximum value to maintain consistent risk scale
-    let total_risk = variance_risk
-        .saturating_add(deviation_risk)
-        .saturating_add(stale_risk)
-        .saturating_add(liquidity_risk);
+    // Penalize pools where a reference-sized trade moves price materially
+    // One tick is roughly one basis point, so impact is amplified like tick deviation:
+    // a trade that can buy an N-bps move is as suspicious as an observed N-tick drift
+    let impact_risk = core::cmp::min(10_000u32, price_impact_bps.saturating_mul(5));
+
+    // Excluded spikes no longer bias the price, but show the pool could be pushed
+    let spike_risk = core::cmp::min(
+        10_000u32,
+        cumulative_spikes.saturating_mul(CUMULATIVE_SPIKE_RISK_BPS),
+    );
 
-    core::cmp::min(total_risk, 10_000u32)
+    ManipulationRiskFactors {
+        variance_risk,
+        deviation_risk,
+        staleness_risk: stale_risk,
+        liquidity_risk,
+        impact_risk,
+        spike_risk,
+    }
 }
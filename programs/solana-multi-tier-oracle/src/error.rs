@@ -72,6 +72,110 @@ pub enum StateError {
     MismatchedExponent,
     #[msg("Non-monotonic timestamps detected in price data")]
     NonMonotonicTimestamps,
+    #[msg("Proposal is not in the approved state")]
+    ProposalNotApproved,
+    #[msg("Veto window for this proposal has closed")]
+    VetoWindowClosed,
+    #[msg("Voting on this proposal is closed")]
+    VotingClosed,
+    #[msg("Member has already voted on this proposal")]
+    AlreadyVoted,
+    #[msg("Proposal yes-stake does not meet the quorum threshold")]
+    QuorumNotMet,
+    #[msg("Proposal execution delay or veto window has not elapsed")]
+    ExecutionDelayNotElapsed,
+    #[msg("Proposer stake is below the proposal threshold")]
+    InsufficientProposerStake,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Observed price exponent does not match the oracle's canonical exponent")]
+    ExponentMismatch,
+    #[msg("Oracle is in maintenance mode; price updates are paused")]
+    MaintenanceActive,
+    #[msg("Historical chunk is linked to a different oracle")]
+    ChunkOracleMismatch,
+    #[msg("Removing this member would leave fewer members than the multisig threshold")]
+    RemovalBreaksThreshold,
+    #[msg("Cannot remove the last remaining admin")]
+    CannotRemoveLastAdmin,
+    #[msg("Manipulation cooldown or decay period exceeds the maximum window")]
+    InvalidManipulationDecay,
+    #[msg("Feed exponent is outside the supported range")]
+    InvalidFeedExpo,
+    #[msg("Emergency admin transfer requires two distinct signers")]
+    DualSignatureRequired,
+    #[msg("Heartbeat submitted before the minimum interval elapsed")]
+    HeartbeatTooFrequent,
+    #[msg("Fresh feed prices disagree beyond the allowed spread")]
+    FeedsDisagree,
+    #[msg("Price update submitted before the update cooldown elapsed")]
+    UpdateTooSoon,
+    #[msg("Update cooldown exceeds the maximum window")]
+    InvalidUpdateCooldown,
+    #[msg("TWAP window ramp duration exceeds the maximum window")]
+    InvalidTwapRamp,
+    #[msg("Published price is older than the caller's maximum age")]
+    StalePrice,
+    #[msg("Oracle batch is empty or exceeds the maximum batch size")]
+    InvalidBatchSize,
+    #[msg("Oracle batch accounts are missing, misordered or not at their expected addresses")]
+    InvalidBatchAccounts,
+    #[msg("Oracle batch initializes the same asset more than once")]
+    DuplicateBatchAsset,
+    #[msg("No price observer is available for this source type")]
+    UnsupportedSourceType,
+    #[msg("Historical chunk is not linked from the oracle at this slot")]
+    UnlinkedHistoricalChunk,
+    #[msg("Account data is not aligned for zero-copy access")]
+    MisalignedAccountData,
+    #[msg("Multisig threshold equals the member count, so no member could ever be removed")]
+    MembershipFrozen,
+    #[msg("Invalid smoothing factor: must be > 0 and <= 10_000 basis points")]
+    InvalidSmoothingFactor,
+    #[msg("Updater allowlist exceeds the maximum number of keys")]
+    TooManyUpdaters,
+    #[msg("Invalid updater key: cannot be default Pubkey")]
+    InvalidUpdaterKey,
+    #[msg("Duplicate key in updater allowlist")]
+    DuplicateUpdater,
+    #[msg("Historical chunk belongs to the rotating ring and cannot be closed")]
+    ActiveHistoricalChunk,
+    #[msg("Historical chunk still holds price points")]
+    HistoricalChunkNotEmpty,
+    #[msg("Closing this chunk would leave fewer than the minimum historical chunks")]
+    TooFewHistoricalChunks,
+    #[msg("No price has been published yet")]
+    PriceNotYetAvailable,
+    #[msg("Computed price is outside the keeper's expected bounds")]
+    PriceOutsideExpectedBounds,
+    #[msg("Source threshold scaling must be between 1 and 10000 basis points")]
+    InvalidSourceThreshold,
+    #[msg("Chunk accounts must be distinct historical chunks of this oracle in slot order")]
+    InvalidChunkSet,
+    #[msg("Source reading is dated further in the future than the allowed clock skew")]
+    FutureTimestamp,
+    #[msg("Clock skew tolerance exceeds the maximum allowed")]
+    InvalidClockSkew,
+    #[msg("Escalation rungs must be valid scores with arming at or below the emergency rung")]
+    InvalidEscalationLadder,
+    #[msg("Tier weights must sum to 10000 basis points with a spread bound between 1 and 10000")]
+    InvalidTierConfig,
+    #[msg("Historical chunk stores price points in a layout this program cannot read")]
+    UnsupportedPointLayout,
+    #[msg("Fewer distinct permission holders signed than the multisig threshold requires")]
+    InsufficientSigners,
+    #[msg("Oracle account is not in a schema version this program can migrate")]
+    UnsupportedStateVersion,
+    #[msg("Feed spread bound must be between 1 and 10000 basis points")]
+    InvalidFeedSpread,
+    #[msg("Minimum observation count must be between 1 and the observation ring size")]
+    InvalidMinObservations,
+    #[msg("Liquidity drop bound must be between 1 and 10000 basis points")]
+    InvalidLiquidityDropBound,
+    #[msg("Total governance stake cannot exceed u64::MAX")]
+    ExcessiveTotalStake,
+    #[msg("Feed exponent does not match the exponent its source prices in")]
+    FeedExpoMismatch,
 }
 
 #[error_code]
@@ -96,10 +200,20 @@ pub enum RaydiumObserverError {
     TickOutOfBounds,
     #[msg("Raydium CLMM Observer: Math Error")]
     MathError,
-    #[msg("Raydium CLMM Observer: Excessive tick deviation")]
-    ExcessiveDeviation,
+    #[msg("Raydium CLMM Observer: Published tick deviates too far from the live pool tick")]
+    ExcessiveCurrentDeviation,
     #[msg("Update Price Instruction: Invalid Observation PDA")]
     InvalidObservationPda,
     #[msg("Update Price Instruction: Invalid TWAP price fetched")]
     InvalidPrice,
+    #[msg("Raydium CLMM Observer: Observation ring out of sync with the live pool tick")]
+    StaleObservationRing,
+    #[msg("Raydium CLMM Observer: Observation ring covers too little of the requested window")]
+    InsufficientCoverage,
+    #[msg("Raydium CLMM Observer: Observation ring holds too few samples for this source")]
+    InsufficientObservations,
+    #[msg("Raydium CLMM Observer: TWAP and T2EMA estimates diverge beyond the allowed deviation")]
+    ExcessiveMethodDivergence,
+    #[msg("Raydium CLMM Observer: Too few usable intervals in the window for T2EMA to warm up")]
+    T2emaNotWarm,
 }
@@ -0,0 +1,119 @@
+use crate::components::raydium_clmm_observer::{
+    fetch_raydium_price::{observe_raydium_price, ConfidenceMethod, RaydiumParams},
+    raydium_constants::{RAYDIUM_CLMM_PROGRAM_ID_DEVNET, RAYDIUM_CLMM_PROGRAM_ID_MAINNET},
+    twap::ManipulationRiskFactors,
+};
+use crate::error::StateError;
+use crate::state::oracle_state::OracleState;
+use crate::state::price_feed::SourceType;
+use anchor_lang::prelude::*;
+
+/// Read-phase subset of `UpdatePriceConfig` that shapes the manipulation score.
+///
+/// Pass the same values the keeper uses for `update_price` to see the score the
+/// next update would observe.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct AssessManipulationConfig {
+    pub window_seconds: u32,
+    pub min_seconds: u32,
+    pub min_liquidity: u128,
+    pub max_tick_deviation: i32,
+    pub alpha_basis_points: u16,
+    pub impact_trade_size: u128,
+    pub confidence_method: ConfidenceMethod,
+    pub strict_coverage: bool,
+    pub use_mainnet: bool,
+}
+
+/// Return data of the manipulation view: the observed score and what drives it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct ManipulationAssessment {
+    /// Composite score (basis points) the pool's current data produces.
+    pub manipulation_score: u32,
+    /// The threshold this oracle holds DEX feeds to, for comparison without a second read.
+    pub manipulation_threshold: u16,
+    pub factors: ManipulationRiskFactors,
+    /// Timestamp of the newest observation behind the assessment.
+    pub observed_at: i64,
+}
+
+/// Read-only manipulation assessment of a registered Raydium feed via return data.
+///
+/// # Scope
+///
+/// Runs the same observer pipeline as `update_price` against the live pool and
+/// observation accounts and reports the composite score with its factors,
+/// without writing anything. The score is the pool-observed one, before the
+/// update folds in a liquidity shock against the feed's previous depth and the
+/// decayed peak of earlier spikes; both depend on the state an update would
+/// write, so a view cannot reproduce them without mutating.
+///
+/// Like `get_price`, it takes no signer, so risk teams can poll it freely,
+/// including during maintenance.
+#[derive(Accounts)]
+pub struct AssessCurrentManipulation<'info> {
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    /// CHECK: Raydium CLMM pool account (validated in logic)
+    pub raydium_pool: AccountInfo<'info>,
+
+    /// CHECK: Raydium CLMM observation account (validated in logic)
+    pub raydium_observation: AccountInfo<'info>,
+}
+
+pub fn assess_current_manipulation(
+    ctx: Context<AssessCurrentManipulation>,
+    config: AssessManipulationConfig,
+) -> Result<ManipulationAssessment> {
+    let current_time = Clock::get()?.unix_timestamp;
+    OracleState::check_data_alignment(
+        &ctx.accounts
+            .oracle_state
+            .to_account_info()
+            .try_borrow_data()?,
+    )?;
+    let oracle_state = ctx.accounts.oracle_state.load()?;
+
+    // Only pools the oracle actually prices from are worth assessing
+    require!(
+        oracle_state
+            .price_feeds
+            .iter()
+            .any(|feed| feed.source_address == *ctx.accounts.raydium_pool.key),
+        StateError::InvalidSourceAddress
+    );
+
+    let raydium_program_id = if config.use_mainnet {
+        &RAYDIUM_CLMM_PROGRAM_ID_MAINNET
+    } else {
+        &RAYDIUM_CLMM_PROGRAM_ID_DEVNET
+    };
+
+    let (observed, factors) = observe_raydium_price(
+        &ctx.accounts.raydium_pool,
+        &ctx.accounts.raydium_observation,
+        raydium_program_id,
+        RaydiumParams {
+            window_seconds: config.window_seconds,
+            min_seconds: config.min_seconds,
+            min_liquidity: config.min_liquidity,
+            max_tick_deviation: config.max_tick_deviation,
+            alpha_basis_points: config.alpha_basis_points,
+            validation_policy: oracle_state.get_validation_policy(),
+            impact_trade_size: config.impact_trade_size,
+            confidence_method: config.confidence_method,
+            strict_coverage: config.strict_coverage,
+            strict_min_elapsed: !oracle_state.flags.is_min_elapsed_relaxed(),
+            allow_spot_fallback: oracle_state.flags.is_spot_fallback_enabled(),
+            min_observations: oracle_state.observation_floor(),
+            timestamp: current_time,
+        },
+    )?;
+
+    Ok(ManipulationAssessment {
+        manipulation_score: observed.manipulation_score,
+        manipulation_threshold: oracle_state.manipulation_threshold_for(SourceType::DEX.as_u8()),
+        factors,
+        observed_at: observed.timestamp,
+    })
+}
@@ -0,0 +1,68 @@
+use crate::state::historical_chunk::HistoricalChunk;
+use crate::state::oracle_state::OracleState;
+use crate::state::snapshot_status::SnapshotStatusCode;
+use crate::utils::constants::HISTORICAL_CHUNK_SEED;
+use anchor_lang::prelude::*;
+
+/// Reports whether recent history is dense enough to back a redemption.
+///
+/// # Return Data Encoding
+///
+/// Redemption logic lives in downstream programs that call this over CPI. The
+/// rich `SnapshotStatus` enum is an internal type whose Borsh layout would shift
+/// whenever a field is added, so the result is returned as a pinned
+/// `SnapshotStatusCode` triple that stays stable across upgrades.
+#[derive(Accounts)]
+pub struct CheckRedemptionEligibility<'info> {
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [HISTORICAL_CHUNK_SEED, oracle_state.key().as_ref(), &[0]],
+        bump,
+    )]
+    pub historical_chunk_0: AccountLoader<'info, HistoricalChunk>,
+
+    #[account(
+        seeds = [HISTORICAL_CHUNK_SEED, oracle_state.key().as_ref(), &[1]],
+        bump,
+    )]
+    pub historical_chunk_1: AccountLoader<'info, HistoricalChunk>,
+
+    #[account(
+        seeds = [HISTORICAL_CHUNK_SEED, oracle_state.key().as_ref(), &[2]],
+        bump,
+    )]
+    pub historical_chunk_2: AccountLoader<'info, HistoricalChunk>,
+}
+
+pub fn check_redemption_eligibility(
+    ctx: Context<CheckRedemptionEligibility>,
+    required_hours: u16,
+) -> Result<SnapshotStatusCode> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let oracle_state = ctx.accounts.oracle_state.load()?;
+    let historical_chunk_0 = ctx.accounts.historical_chunk_0.load()?;
+    let historical_chunk_1 = ctx.accounts.historical_chunk_1.load()?;
+    let historical_chunk_2 = ctx.accounts.historical_chunk_2.load()?;
+
+    let oracle_key = ctx.accounts.oracle_state.key();
+    historical_chunk_0.verify_oracle_link(&oracle_key)?;
+    historical_chunk_1.verify_oracle_link(&oracle_key)?;
+    historical_chunk_2.verify_oracle_link(&oracle_key)?;
+    oracle_state.verify_historical_chunk(0, &ctx.accounts.historical_chunk_0.key())?;
+    oracle_state.verify_historical_chunk(1, &ctx.accounts.historical_chunk_1.key())?;
+    oracle_state.verify_historical_chunk(2, &ctx.accounts.historical_chunk_2.key())?;
+
+    let status = oracle_state.check_snapshot_requirements_from_history(
+        [
+            &*historical_chunk_0,
+            &*historical_chunk_1,
+            &*historical_chunk_2,
+        ],
+        timestamp_now,
+        required_hours,
+    );
+
+    Ok(SnapshotStatusCode::from(&status))
+}
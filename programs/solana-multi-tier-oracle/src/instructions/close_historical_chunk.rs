@@ -0,0 +1,78 @@
+use crate::error::StateError;
+use crate::state::governance_state::GovernanceState;
+use crate::state::historical_chunk::HistoricalChunk;
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::{GOVERNANCE_SEED, HISTORICAL_CHUNK_SEED};
+use crate::utils::events::HistoricalChunkClosed;
+use anchor_lang::prelude::*;
+
+/// Closes an empty historical chunk outside the rotating ring and refunds its rent.
+///
+/// Only chunks linked beyond the three `update_price` rotates through can be
+/// pruned; `OracleState::unlink_historical_chunk` holds the rules. Admin-gated
+/// because the refund can go to any recipient.
+#[derive(Accounts)]
+#[instruction(chunk_index: u8)]
+pub struct CloseHistoricalChunk<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [HISTORICAL_CHUNK_SEED, oracle_state.key().as_ref(), &[chunk_index]],
+        bump,
+    )]
+    pub historical_chunk: AccountLoader<'info, HistoricalChunk>,
+
+    /// CHECK: Receives the chunk's rent; any account may be credited
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn close_historical_chunk(ctx: Context<CloseHistoricalChunk>, chunk_index: u8) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    oracle_state.require_not_emergency()?;
+
+    governance_state.check_admin(&ctx.accounts.authority.key())?;
+
+    {
+        // Released before the exit-time close zeroes the account
+        let chunk = ctx.accounts.historical_chunk.load()?;
+        oracle_state.unlink_historical_chunk(
+            chunk_index as usize,
+            &ctx.accounts.historical_chunk.key(),
+            &chunk,
+            &ctx.accounts.oracle_state.key(),
+        )?;
+    }
+
+    emit!(HistoricalChunkClosed {
+        oracle: ctx.accounts.oracle_state.key(),
+        chunk: ctx.accounts.historical_chunk.key(),
+        chunk_index,
+        recipient: ctx.accounts.recipient.key(),
+        closed_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use crate::error::StateError;
+use crate::state::governance_state::GovernanceState;
+use crate::state::oracle_state::OracleState;
+use crate::state::proposal::{Proposal, ProposalStatus};
+use crate::utils::constants::{GOVERNANCE_SEED, PROPOSAL_SEED};
+use crate::utils::events::ProposalCreated;
+use anchor_lang::prelude::*;
+
+/// Opens a governance proposal for stake-weighted voting.
+///
+/// Proposal accounts are PDAs keyed by `(governance, proposal_id)`, so a reused
+/// identifier fails at account creation instead of overwriting a live proposal.
+/// The current members and their stakes are frozen into the proposal here and
+/// decide every vote cast on it.
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CreateProposal<'info> {
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [PROPOSAL_SEED, governance_state.key().as_ref(), &proposal_id.to_le_bytes()],
+        bump,
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_proposal(ctx: Context<CreateProposal>, proposal_id: u64) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    governance_state.check_proposer(&ctx.accounts.proposer.key())?;
+
+    let mut proposal = ctx.accounts.proposal.load_init()?;
+    proposal.governance = ctx.accounts.governance_state.key();
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.proposal_id = proposal_id;
+    proposal.created_at = timestamp_now;
+    proposal.approved_at = 0;
+    proposal.yes_stake = 0;
+    proposal.no_stake = 0;
+    proposal.voted_members = 0;
+    proposal.snapshot_electorate(&governance_state);
+    proposal.set_status(ProposalStatus::Pending);
+    proposal.bump = ctx.bumps.proposal;
+
+    emit!(ProposalCreated {
+        governance: ctx.accounts.governance_state.key(),
+        proposal: ctx.accounts.proposal.key(),
+        proposal_id,
+        proposer: ctx.accounts.proposer.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use crate::state::oracle_state::OracleState;
+use crate::utils::events::EmergencyKillSwitchActivated;
+use anchor_lang::prelude::*;
+
+/// Immediately halts the oracle on the emergency admin's signature alone.
+///
+/// No governance member is involved: this is the fast path for incidents where
+/// waiting on a proposal would leave consumers exposed. The authority check
+/// lives in `OracleState::activate_kill_switch` so it is covered by unit tests.
+#[derive(Accounts)]
+pub struct EmergencyKillSwitch<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    pub emergency_admin: Signer<'info>,
+}
+
+pub fn emergency_kill_switch(ctx: Context<EmergencyKillSwitch>) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    oracle_state.activate_kill_switch(&ctx.accounts.emergency_admin.key())?;
+
+    emit!(EmergencyKillSwitchActivated {
+        oracle: ctx.accounts.oracle_state.key(),
+        activated_by: ctx.accounts.emergency_admin.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use crate::error::StateError;
+use crate::state::governance_state::GovernanceState;
+use crate::state::oracle_state::OracleState;
+use crate::state::proposal::Proposal;
+use crate::utils::constants::{GOVERNANCE_SEED, PROPOSAL_SEED};
+use crate::utils::events::ProposalExecuted;
+use anchor_lang::prelude::*;
+
+/// Finalises an approved proposal after its execution delay and veto window.
+///
+/// Quorum is re-evaluated at execution time against the stakes the proposal
+/// froze when it opened, so only a change to `quorum_threshold` itself can
+/// strand an approval; rebalancing member stakes mid-vote cannot.
+///
+/// # No Payload
+///
+/// Proposals do not yet carry an action, so execution only records the
+/// outcome: the proposal becomes `Executed` and `ProposalExecuted` is emitted.
+/// No oracle or governance setting changes here. Configuration changes go
+/// through their own instructions under `MODIFY_CONFIG` and threshold signing;
+/// an executed proposal is the on-chain record that governance approved one.
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    #[account(
+        mut,
+        seeds = [
+            PROPOSAL_SEED,
+            governance_state.key().as_ref(),
+            &proposal.load()?.proposal_id.to_le_bytes()
+        ],
+        bump = proposal.load()?.bump
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+
+    pub executor: Signer<'info>,
+}
+
+pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let governance_state = ctx.accounts.governance_state.load()?;
+    let mut proposal = ctx.accounts.proposal.load_mut()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+    require_keys_eq!(
+        proposal.governance,
+        ctx.accounts.governance_state.key(),
+        StateError::UnauthorizedCaller
+    );
+    require!(
+        governance_state
+            .find_member(&ctx.accounts.executor.key())
+            .is_some(),
+        StateError::UnauthorizedCaller
+    );
+
+    proposal.execute(&governance_state, timestamp_now)?;
+
+    emit!(ProposalExecuted {
+        governance: ctx.accounts.governance_state.key(),
+        proposal: ctx.accounts.proposal.key(),
+        proposal_id: proposal.proposal_id,
+        executed_by: ctx.accounts.executor.key(),
+        yes_stake: proposal.yes_stake,
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
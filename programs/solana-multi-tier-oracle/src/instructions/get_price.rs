@@ -0,0 +1,47 @@
+use crate::state::oracle_state::{OracleState, PriceReading};
+use anchor_lang::prelude::*;
+
+/// Read-only access to the oracle's last published price via return data.
+///
+/// # Why an Instruction
+///
+/// Programs composing with the oracle over CPI cannot deserialize the zero-copy
+/// account layout without taking a dependency on it; returning `PriceReading` keeps
+/// that layout private while still reporting which feeds backed the price. The
+/// instruction takes no signer and mutates nothing, so it stays available during
+/// maintenance when updates are paused.
+///
+/// `max_age` is the caller's staleness bound in seconds; zero returns the price
+/// regardless of age. `target_expo`, when set, converts the returned price to
+/// the caller's own exponent with `PriceData::to_scale`, so a consumer with a
+/// fixed-point scale of its own need not redo the checked conversion.
+#[derive(Accounts)]
+pub struct GetPrice<'info> {
+    pub oracle_state: AccountLoader<'info, OracleState>,
+}
+
+pub fn get_price(
+    ctx: Context<GetPrice>,
+    max_age: u32,
+    target_expo: Option<i32>,
+) -> Result<PriceReading> {
+    let now = Clock::get()?.unix_timestamp;
+    OracleState::check_data_alignment(
+        &ctx.accounts
+            .oracle_state
+            .to_account_info()
+            .try_borrow_data()?,
+    )?;
+    let mut reading = ctx
+        .accounts
+        .oracle_state
+        .load()?
+        .read_price_reading(now, max_age)?;
+
+    if let Some(target_expo) = target_expo {
+        reading.price.price = reading.price.to_scale(target_expo)?;
+        reading.price.expo = target_expo;
+    }
+
+    Ok(reading)
+}
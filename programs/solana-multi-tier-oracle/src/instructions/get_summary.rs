@@ -0,0 +1,23 @@
+use crate::state::oracle_state::{OracleState, OracleSummary};
+use anchor_lang::prelude::*;
+
+/// Read-only `OracleSummary` via return data, for dashboards and indexers.
+///
+/// Simulating this instruction returns a few dozen bytes instead of the whole
+/// zero-copy account. Like `get_price` it takes no signer and mutates nothing,
+/// and it never fails on oracle status: emergency and maintenance are reported
+/// through the summary's flags.
+#[derive(Accounts)]
+pub struct GetSummary<'info> {
+    pub oracle_state: AccountLoader<'info, OracleState>,
+}
+
+pub fn get_summary(ctx: Context<GetSummary>) -> Result<OracleSummary> {
+    OracleState::check_data_alignment(
+        &ctx.accounts
+            .oracle_state
+            .to_account_info()
+            .try_borrow_data()?,
+    )?;
+    Ok(ctx.accounts.oracle_state.load()?.to_summary())
+}
@@ -0,0 +1,59 @@
+use crate::error::StateError;
+use crate::state::governance_state::GovernanceState;
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::{GOVERNANCE_SEED, MIN_HEARTBEAT_INTERVAL};
+use crate::utils::events::Heartbeat as HeartbeatEvent;
+use anchor_lang::prelude::*;
+
+/// Keeper liveness signal that does not publish a price.
+///
+/// # Why Separate From `update_price`
+///
+/// A keeper running against a flat market has nothing new to publish, yet
+/// consumers still need evidence that someone is watching. Reusing
+/// `update_price` for that would push redundant history and re-run pool reads;
+/// a heartbeat only touches `last_update` and the nonce. It emits `Heartbeat`
+/// rather than `PriceUpdated` so indexers never mistake it for a new price.
+/// Both are keeper duties, so both go through `OracleState::check_updater`:
+/// whoever the updater policy admits to publish may also signal liveness, and
+/// no one else can.
+#[derive(Accounts)]
+pub struct Heartbeat<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub keeper: Signer<'info>,
+}
+
+pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    oracle_state.check_updater(&governance_state, &ctx.accounts.keeper.key())?;
+
+    let update_nonce = oracle_state.record_heartbeat(timestamp_now, MIN_HEARTBEAT_INTERVAL)?;
+
+    emit!(HeartbeatEvent {
+        oracle: ctx.accounts.oracle_state.key(),
+        keeper: ctx.accounts.keeper.key(),
+        update_nonce,
+        price_timestamp: oracle_state.current_price.timestamp,
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
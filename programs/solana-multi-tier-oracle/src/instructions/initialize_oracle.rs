@@ -1,11 +1,12 @@
 use crate::error::StateError;
-use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::governance_state::{GovernanceParams, GovernanceState, Permissions};
 use crate::state::historical_chunk::{HistoricalChunk, PricePoint};
 use crate::state::oracle_state::{OracleState, PriceData, StateFlags, Version};
 use crate::utils::constants::{
-    BUFFER_SIZE, DEFAULT_VETO_PERIOD, GOVERNANCE_SEED, HISTORICAL_CHUNK_SEED,
-    MAX_CONFIDENCE_THRESHOLD, MAX_MANIPULATION_THRESHOLD, MAX_MULTISIG_MEMBERS,
-    MAX_QUORUM_THRESHOLD, MAX_TWAP_WINDOW, ORACLE_STATE_SEED,
+    BUFFER_SIZE, DEFAULT_MANIPULATION_COOLDOWN, DEFAULT_MANIPULATION_DECAY_PERIOD,
+    DEFAULT_VETO_PERIOD, GOVERNANCE_SEED, HISTORICAL_CHUNK_SEED, MAX_CONFIDENCE_THRESHOLD,
+    MAX_MANIPULATION_THRESHOLD, MAX_MULTISIG_MEMBERS, MAX_TWAP_WINDOW, ORACLE_STATE_SEED,
+    PRICE_POINT_LAYOUT_VERSION,
 };
 use crate::utils::events::OracleInitialized;
 /// Comprehensive oracle initialization with governance integration and historical data architecture.
@@ -82,6 +83,11 @@ pub struct OracleConfig {
     /// manipulation is detected, trading availability for security.
     pub enable_circuit_breaker: bool,
 
+    /// Per-oracle ceiling on every TWAP window this oracle will accept, in seconds.
+    /// Zero defers to the program-wide `MAX_TWAP_WINDOW`; a nonzero value may only
+    /// tighten that bound and must leave room for `twap_window` itself.
+    pub max_twap_window_override: u32,
+
     /// Embedded governance configuration for decentralized control.
     /// Integrated into oracle config to ensure governance is established
     /// simultaneously with oracle creation, preventing governance gaps.
@@ -147,6 +153,23 @@ pub struct GovernanceConfig {
     pub proposal_threshold: u64,
 }
 
+impl GovernanceConfig {
+    /// Timing and threshold parameters the governance account starts with.
+    ///
+    /// The timelock initially matches the execution delay and the veto window
+    /// starts at `DEFAULT_VETO_PERIOD`; both can be retuned afterwards.
+    pub fn initial_params(&self) -> GovernanceParams {
+        GovernanceParams {
+            voting_period: self.voting_period,
+            execution_delay: self.execution_delay,
+            timelock_duration: self.execution_delay,
+            veto_period: DEFAULT_VETO_PERIOD,
+            quorum_threshold: self.quorum_threshold,
+            proposal_threshold: self.proposal_threshold,
+        }
+    }
+}
+
 /// Account structure for oracle initialization with comprehensive validation requirements.
 ///
 /// # PDA Determinism Strategy
@@ -367,30 +390,49 @@ fn validate_initial_members_and_authority_admin(
     Ok(())
 }
 
-/// Orchestrate comprehensive oracle system initialization with full validation.
+/// Rejects multi-member governance sets whose membership could never change.
 ///
-/// # Atomic Initialization Strategy
+/// # Not a Deadlock
 ///
-/// Performs all validation before any account modifications to ensure either
-/// complete success or complete failure, preventing partial initialization states
-/// that could leave the oracle system in an inconsistent or vulnerable condition.
+/// Every founding member is seeded with the same stake, so a stake quorum of
+/// `quorum_threshold` basis points needs `ceil(quorum_threshold * member_count / 10_000)`
+/// yes votes. Any quorum within `MAX_QUORUM_THRESHOLD` is therefore reachable
+/// by the founding set alone (quorum bounds are checked before this runs), and
+/// a threshold within the member count can always be signed for. Every
+/// configuration that reaches this check can pass proposals.
 ///
-/// # Security-First Validation Pipeline
+/// # Policy: Removable Membership
 ///
-/// Implements comprehensive validation in phases:
-/// 1. Asset identifier and seed validation
-/// 2. Oracle parameter bounds checking  
-/// 3. Governance configuration validation
-/// 4. Cross-account relationship establishment
+/// Removing a member requires the remaining set to still meet the multisig
+/// threshold. With `multisig_threshold == member_count` and more than one member,
+/// no member can ever be removed, so a lost or compromised key (including an
+/// admin's) stays in governance for good, and with no path to add members the
+/// set can never be rotated either. Such a set still works; this program
+/// declines to create it as a deliberate policy, because key rotation is the
+/// recovery path for every other governance failure. A single-member governance
+/// is exempt: its sole member is also the last admin, which removal already
+/// protects regardless of threshold.
+#[inline(always)]
+fn validate_removable_membership(governance_config: &GovernanceConfig) -> Result<()> {
+    require!(
+        governance_config.member_count == 1
+            || governance_config.multisig_threshold < governance_config.member_count,
+        StateError::MembershipFrozen
+    );
+
+    Ok(())
+}
+
+/// Runs every configuration check that precedes account creation.
 ///
-/// # Account Relationship Architecture
+/// Covers phases 1-3 of initialization: asset identifier canonicalization and
+/// seed binding, oracle parameter bounds, and governance bootstrap rules. Kept
+/// free of account access so the single and batch initialization paths apply
+/// exactly the same rules, and so a batch can reject a bad entry before any
+/// of its accounts are created.
 ///
-/// Establishes a complex web of account relationships that enable the oracle
-/// to function as a cohesive system while maintaining clear separation of
-/// concerns for security and maintainability.
-pub fn initialize_oracle(ctx: Context<InitializeOracle>, config: OracleConfig) -> Result<()> {
-    let timestamp_now = Clock::get()?.unix_timestamp;
-
+/// Returns the canonical asset identifier used for event emission.
+pub fn validate_oracle_config(config: &OracleConfig, authority: &Pubkey) -> Result<String> {
     // Phase 1: Asset Identifier Validation and Canonicalization
     // Ensures consistent asset identification across the ecosystem
     let canonical_asset_id = canonicalize_asset_id(&config.asset_id);
@@ -408,6 +450,15 @@ pub fn initialize_oracle(ctx: Context<InitializeOracle>, config: OracleConfig) -
         StateError::InvalidTWAPWindow
     );
 
+    // Window ceiling validation - an override only tightens the global cap, and
+    // one below the configured window would reject the oracle's first update
+    require!(
+        config.max_twap_window_override == 0
+            || (config.max_twap_window_override <= MAX_TWAP_WINDOW
+                && config.max_twap_window_override >= config.twap_window),
+        StateError::InvalidTWAPWindow
+    );
+
     // Confidence threshold validation - controls quality gate for price acceptance
     // Higher values require more stable price behavior before accepting updates
     require!(
@@ -447,50 +498,67 @@ pub fn initialize_oracle(ctx: Context<InitializeOracle>, config: OracleConfig) -
         StateError::InvalidMultisigThreshold
     );
 
-    // Timing parameter validation - ensures governance operations have reasonable timeframes
-    require!(
-        governance_config.voting_period > 0 && governance_config.execution_delay >= 0,
-        StateError::InvalidTimingParameters
-    );
+    // Timing, quorum and proposal threshold validation - the same bounds
+    // `set_governance_params` enforces on every later retune
+    governance_config.initial_params().validate()?;
 
-    // Quorum validation - ensures meaningful participation requirements for valid votes
+    // Founding stake validation - every member starts at the proposal threshold,
+    // and the combined stake must fit the u64 proposal tallies
     require!(
-        governance_config.quorum_threshold > 0
-            && governance_config.quorum_threshold <= MAX_QUORUM_THRESHOLD,
-        StateError::InvalidQuorumThreshold
+        governance_config
+            .proposal_threshold
+            .checked_mul(governance_config.member_count as u64)
+            .is_some(),
+        StateError::ExcessiveTotalStake
     );
 
-    // Proposal threshold validation - economic barrier to prevent spam proposals
-    require!(
-        governance_config.proposal_threshold > 0,
-        StateError::InvalidProposalThreshold
-    );
+    // Membership policy - at least one member must stay removable for key rotation
+    validate_removable_membership(governance_config)?;
 
     // Member and authority validation - ensures governance is properly bootstrapped
     validate_initial_members_and_authority_admin(
         &governance_config.initial_members,
         governance_config.member_count,
-        &ctx.accounts.authority.key(),
+        authority,
         &governance_config.member_permissions,
     )?;
 
-    // Phase 4: Account Initialization and State Setup
-    // Initialize all accounts with validated configuration parameters
+    Ok(canonical_asset_id)
+}
 
-    let mut oracle_state = ctx.accounts.oracle_state.load_init()?;
-    let mut governance_state = ctx.accounts.governance_state.load_init()?;
-    let mut historical_chunk_0 = ctx.accounts.historical_chunk_0.load_init()?;
-    let mut historical_chunk_1 = ctx.accounts.historical_chunk_1.load_init()?;
-    let mut historical_chunk_2 = ctx.accounts.historical_chunk_2.load_init()?;
+/// Addresses and PDA bumps of the five accounts backing one oracle.
+///
+/// The governance address itself is not needed to populate state (governance
+/// points back at the oracle, not the other way round), but its bump is cached
+/// on the oracle for later address re-derivation.
+pub struct OracleAccountSet {
+    pub oracle_state: Pubkey,
+    pub historical_chunks: [Pubkey; 3],
+    pub oracle_bump: u8,
+    pub governance_bump: u8,
+    pub chunk_bumps: [u8; 3],
+}
+
+/// Writes the initial contents of an oracle's state, governance and history accounts.
+///
+/// Assumes `config` already passed [`validate_oracle_config`]. Operates on plain
+/// mutable references rather than loaders so the batch instruction, which
+/// creates its accounts by hand, shares the exact layout logic with the
+/// single-oracle path.
+pub fn populate_oracle_accounts(
+    config: &OracleConfig,
+    authority: Pubkey,
+    accounts: &OracleAccountSet,
+    timestamp_now: i64,
+    oracle_state: &mut OracleState,
+    governance_state: &mut GovernanceState,
+    historical_chunks: [&mut HistoricalChunk; 3],
+) {
+    let governance_config = &config.governance_config;
 
     // Oracle state initialization with comprehensive configuration
-    oracle_state.authority = ctx.accounts.authority.key();
-    oracle_state.version = Version {
-        major: 0,
-        minor: 1,
-        patch: 0,
-        _padding: 0,
-    };
+    oracle_state.authority = authority;
+    oracle_state.version = Version::CURRENT;
 
     // Initialize state flags and configure circuit breaker if enabled
     oracle_state.flags = StateFlags::new();
@@ -501,35 +569,38 @@ pub fn initialize_oracle(ctx: Context<InitializeOracle>, config: OracleConfig) -
     // Initialize price data with default values - will be populated by first price update
     oracle_state.current_price = PriceData::default();
     oracle_state.twap_window = config.twap_window;
+    oracle_state.max_twap_window_override = config.max_twap_window_override;
     oracle_state.current_chunk_index = 0; // Start with first historical chunk
     oracle_state.max_chunk_size = BUFFER_SIZE as u16;
     oracle_state.confidence_threshold = config.confidence_threshold;
     oracle_state.manipulation_threshold = config.manipulation_threshold;
+    oracle_state.manipulation_cooldown = DEFAULT_MANIPULATION_COOLDOWN;
+    oracle_state.manipulation_decay_period = DEFAULT_MANIPULATION_DECAY_PERIOD;
     oracle_state.asset_seed = config.asset_seed;
 
     // Store PDA bumps for future address validation
-    oracle_state.bump = ctx.bumps.oracle_state;
-    oracle_state.governance_bump = ctx.bumps.governance_state;
+    oracle_state.bump = accounts.oracle_bump;
+    oracle_state.governance_bump = accounts.governance_bump;
 
     // Establish links to historical chunks for circular buffer management
-    oracle_state.historical_chunks[0] = ctx.accounts.historical_chunk_0.key();
-    oracle_state.historical_chunks[1] = ctx.accounts.historical_chunk_1.key();
-    oracle_state.historical_chunks[2] = ctx.accounts.historical_chunk_2.key();
+    oracle_state.historical_chunks[..accounts.historical_chunks.len()]
+        .copy_from_slice(&accounts.historical_chunks);
 
     oracle_state.emergency_admin = config.emergency_admin;
     oracle_state.last_update = 0; // No updates yet
 
     // Governance state initialization with comprehensive parameters
-    governance_state.proposal_threshold = governance_config.proposal_threshold;
-    governance_state.voting_period = governance_config.voting_period;
-    governance_state.execution_delay = governance_config.execution_delay;
-    governance_state.timelock_duration = governance_config.execution_delay; // Initial timelock matches execution delay
-    governance_state.veto_period = DEFAULT_VETO_PERIOD;
-    governance_state.quorum_threshold = governance_config.quorum_threshold;
+    let params = governance_config.initial_params();
+    governance_state.proposal_threshold = params.proposal_threshold;
+    governance_state.voting_period = params.voting_period;
+    governance_state.execution_delay = params.execution_delay;
+    governance_state.timelock_duration = params.timelock_duration;
+    governance_state.veto_period = params.veto_period;
+    governance_state.quorum_threshold = params.quorum_threshold;
     governance_state.multi_sig_threshold = governance_config.multisig_threshold;
     governance_state.active_member_count = governance_config.member_count;
-    governance_state.bump = ctx.bumps.governance_state;
-    governance_state.oracle_state = ctx.accounts.oracle_state.key();
+    governance_state.bump = accounts.governance_bump;
+    governance_state.oracle_state = accounts.oracle_state;
 
     // Initialize governance members and permissions
     // Using fixed-size arrays to enable zero-copy access patterns
@@ -537,36 +608,100 @@ pub fn initialize_oracle(ctx: Context<InitializeOracle>, config: OracleConfig) -
         if i < governance_config.member_count as usize {
             governance_state.multisig_members[i] = governance_config.initial_members[i];
             governance_state.member_permissions[i] = governance_config.member_permissions[i];
+            // Every founding member starts with exactly the proposal threshold so the
+            // stake-weighted tally behaves as one-member-one-vote until stakes are recorded
+            governance_state.member_stakes[i] = governance_config.proposal_threshold;
         } else {
             // Clear unused slots with default values for security
             governance_state.multisig_members[i] = Pubkey::default();
             governance_state.member_permissions[i] = Permissions::default();
+            governance_state.member_stakes[i] = 0;
         }
     }
 
     // Historical chunk initialization - establish circular buffer structure
-    // Each chunk is initialized with default price points and linked to the next chunk
-
-    historical_chunk_0.chunk_id = 0;
-    historical_chunk_0.creation_timestamp = timestamp_now;
-    historical_chunk_0.price_points = [PricePoint::default(); BUFFER_SIZE];
-    historical_chunk_0.next_chunk = ctx.accounts.historical_chunk_1.key(); // Points to chunk 1
-    historical_chunk_0.oracle_state = ctx.accounts.oracle_state.key();
-    historical_chunk_0.bump = ctx.bumps.historical_chunk_0;
-
-    historical_chunk_1.chunk_id = 1;
-    historical_chunk_1.creation_timestamp = timestamp_now;
-    historical_chunk_1.price_points = [PricePoint::default(); BUFFER_SIZE];
-    historical_chunk_1.next_chunk = ctx.accounts.historical_chunk_2.key(); // Points to chunk 2
-    historical_chunk_1.oracle_state = ctx.accounts.oracle_state.key();
-    historical_chunk_1.bump = ctx.bumps.historical_chunk_1;
-
-    historical_chunk_2.chunk_id = 2;
-    historical_chunk_2.creation_timestamp = timestamp_now;
-    historical_chunk_2.price_points = [PricePoint::default(); BUFFER_SIZE];
-    historical_chunk_2.next_chunk = Pubkey::default(); // End of circular buffer for now
-    historical_chunk_2.oracle_state = ctx.accounts.oracle_state.key();
-    historical_chunk_2.bump = ctx.bumps.historical_chunk_2;
+    // Each chunk is initialized with default price points and linked to the next chunk;
+    // the last chunk terminates the chain for now
+    for (i, chunk) in historical_chunks.into_iter().enumerate() {
+        chunk.chunk_id = i as u16;
+        chunk.creation_timestamp = timestamp_now;
+        chunk.price_points = [PricePoint::default(); BUFFER_SIZE];
+        chunk.next_chunk = accounts
+            .historical_chunks
+            .get(i + 1)
+            .copied()
+            .unwrap_or_default();
+        chunk.oracle_state = accounts.oracle_state;
+        chunk.bump = accounts.chunk_bumps[i];
+        chunk.point_layout = PRICE_POINT_LAYOUT_VERSION;
+    }
+}
+
+/// Orchestrate comprehensive oracle system initialization with full validation.
+///
+/// # Atomic Initialization Strategy
+///
+/// Performs all validation before any account modifications to ensure either
+/// complete success or complete failure, preventing partial initialization states
+/// that could leave the oracle system in an inconsistent or vulnerable condition.
+///
+/// # Security-First Validation Pipeline
+///
+/// Implements comprehensive validation in phases:
+/// 1. Asset identifier and seed validation
+/// 2. Oracle parameter bounds checking  
+/// 3. Governance configuration validation
+/// 4. Cross-account relationship establishment
+///
+/// # Account Relationship Architecture
+///
+/// Establishes a complex web of account relationships that enable the oracle
+/// to function as a cohesive system while maintaining clear separation of
+/// concerns for security and maintainability.
+pub fn initialize_oracle(ctx: Context<InitializeOracle>, config: OracleConfig) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    // Phases 1-3: configuration validation shared with batch initialization
+    let canonical_asset_id = validate_oracle_config(&config, &ctx.accounts.authority.key())?;
+    let governance_config = &config.governance_config;
+
+    // Phase 4: Account Initialization and State Setup
+    // Initialize all accounts with validated configuration parameters
+    let account_set = OracleAccountSet {
+        oracle_state: ctx.accounts.oracle_state.key(),
+        historical_chunks: [
+            ctx.accounts.historical_chunk_0.key(),
+            ctx.accounts.historical_chunk_1.key(),
+            ctx.accounts.historical_chunk_2.key(),
+        ],
+        oracle_bump: ctx.bumps.oracle_state,
+        governance_bump: ctx.bumps.governance_state,
+        chunk_bumps: [
+            ctx.bumps.historical_chunk_0,
+            ctx.bumps.historical_chunk_1,
+            ctx.bumps.historical_chunk_2,
+        ],
+    };
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_init()?;
+    let mut governance_state = ctx.accounts.governance_state.load_init()?;
+    let mut historical_chunk_0 = ctx.accounts.historical_chunk_0.load_init()?;
+    let mut historical_chunk_1 = ctx.accounts.historical_chunk_1.load_init()?;
+    let mut historical_chunk_2 = ctx.accounts.historical_chunk_2.load_init()?;
+
+    populate_oracle_accounts(
+        &config,
+        ctx.accounts.authority.key(),
+        &account_set,
+        timestamp_now,
+        &mut oracle_state,
+        &mut governance_state,
+        [
+            &mut historical_chunk_0,
+            &mut historical_chunk_1,
+            &mut historical_chunk_2,
+        ],
+    );
 
     // Phase 5: Event Emission for Transparency and Monitoring
     // Emit comprehensive initialization event for off-chain monitoring and indexing
@@ -580,6 +715,7 @@ pub fn initialize_oracle(ctx: Context<InitializeOracle>, config: OracleConfig) -
         manipulation_threshold: config.manipulation_threshold,
         governance_members: governance_config.member_count,
         multisig_threshold: governance_config.multisig_threshold,
+        governance_digest: governance_state.config_digest(),
     });
 
     Ok(())
@@ -0,0 +1,345 @@
+use crate::error::StateError;
+use crate::instructions::initialize_oracle::{
+    populate_oracle_accounts, validate_oracle_config, GovernanceConfig, OracleAccountSet,
+    OracleConfig,
+};
+use crate::state::governance_state::GovernanceState;
+use crate::state::historical_chunk::HistoricalChunk;
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::{
+    GOVERNANCE_SEED, HISTORICAL_CHUNK_SEED, MAX_ORACLE_BATCH_SIZE, ORACLE_BATCH_ACCOUNTS_PER_ENTRY,
+    ORACLE_STATE_SEED,
+};
+use crate::utils::events::OracleInitialized;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::AccountsExit;
+
+/// Per-asset portion of a batch initialization.
+///
+/// Carries every `OracleConfig` field except the governance configuration,
+/// which the whole batch shares. A full `GovernanceConfig` serializes to
+/// roughly 670 bytes, so repeating it per asset would overflow the 1232-byte
+/// transaction packet before a second oracle fit.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BatchOracleEntry {
+    pub asset_id: String,
+    pub asset_seed: [u8; 32],
+    pub twap_window: u32,
+    pub confidence_threshold: u16,
+    pub manipulation_threshold: u16,
+    pub emergency_admin: Pubkey,
+    pub enable_circuit_breaker: bool,
+    pub max_twap_window_override: u32,
+}
+
+impl BatchOracleEntry {
+    /// Expands the entry into the configuration a standalone
+    /// `initialize_oracle` call would have received.
+    pub fn to_oracle_config(&self, governance_config: &GovernanceConfig) -> OracleConfig {
+        OracleConfig {
+            asset_id: self.asset_id.clone(),
+            asset_seed: self.asset_seed,
+            twap_window: self.twap_window,
+            confidence_threshold: self.confidence_threshold,
+            manipulation_threshold: self.manipulation_threshold,
+            emergency_admin: self.emergency_admin,
+            enable_circuit_breaker: self.enable_circuit_breaker,
+            max_twap_window_override: self.max_twap_window_override,
+            governance_config: governance_config.clone(),
+        }
+    }
+}
+
+/// Initializes several oracles, each with its own governance and history, in one transaction.
+///
+/// # Account Layout
+///
+/// The oracle accounts are not known statically, so they arrive through
+/// `remaining_accounts` in groups of `ORACLE_BATCH_ACCOUNTS_PER_ENTRY`, one group
+/// per entry and in entry order:
+/// `[oracle_state, governance_state, historical_chunk_0, historical_chunk_1, historical_chunk_2]`.
+/// Every address is re-derived on chain and must match, and every account must
+/// be writable and not yet created. The authority and system program are shared.
+///
+/// # Atomicity
+///
+/// All entries are validated with the same rules as `initialize_oracle` before
+/// any account is created, and any later failure aborts the transaction, so a
+/// batch either creates every oracle or none of them.
+#[derive(Accounts)]
+pub struct InitializeOracleBatch<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Validates every entry of a batch against the shared governance configuration.
+///
+/// Enforces the batch size bound and rejects entries that would target the
+/// same oracle PDA, then runs each expanded config through the single-oracle
+/// validation. Returns the expanded configs paired with their canonical asset
+/// identifiers, in entry order.
+pub fn validate_oracle_batch(
+    entries: &[BatchOracleEntry],
+    governance_config: &GovernanceConfig,
+    authority: &Pubkey,
+) -> Result<Vec<(OracleConfig, String)>> {
+    require!(
+        !entries.is_empty() && entries.len() <= MAX_ORACLE_BATCH_SIZE,
+        StateError::InvalidBatchSize
+    );
+
+    let mut validated = Vec::with_capacity(entries.len());
+    for (i, entry) in entries.iter().enumerate() {
+        // Same seed means same oracle PDA; catch it here rather than as an
+        // opaque system program failure on the second create
+        require!(
+            entries[..i]
+                .iter()
+                .all(|earlier| earlier.asset_seed != entry.asset_seed),
+            StateError::DuplicateBatchAsset
+        );
+
+        let config = entry.to_oracle_config(governance_config);
+        let canonical_asset_id = validate_oracle_config(&config, authority)?;
+        validated.push((config, canonical_asset_id));
+    }
+
+    Ok(validated)
+}
+
+pub fn initialize_oracle_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, InitializeOracleBatch<'info>>,
+    entries: Vec<BatchOracleEntry>,
+    governance_config: GovernanceConfig,
+) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+    let authority = ctx.accounts.authority.key();
+    let program_id = ctx.program_id;
+
+    // Validate the whole batch before touching any account
+    let validated = validate_oracle_batch(&entries, &governance_config, &authority)?;
+
+    require!(
+        ctx.remaining_accounts.len() == validated.len() * ORACLE_BATCH_ACCOUNTS_PER_ENTRY,
+        StateError::InvalidBatchAccounts
+    );
+
+    let rent = Rent::get()?;
+    let payer = ctx.accounts.authority.to_account_info();
+    let system = ctx.accounts.system_program.to_account_info();
+
+    for ((config, canonical_asset_id), group) in validated.into_iter().zip(
+        ctx.remaining_accounts
+            .chunks_exact(ORACLE_BATCH_ACCOUNTS_PER_ENTRY),
+    ) {
+        let oracle_info = &group[0];
+        let governance_info = &group[1];
+        let chunk_infos = &group[2..];
+
+        // Address derivation mirrors the seeds on `InitializeOracle`
+        let (oracle_key, oracle_bump) =
+            Pubkey::find_program_address(&[ORACLE_STATE_SEED, &config.asset_seed], program_id);
+        require_keys_eq!(
+            oracle_info.key(),
+            oracle_key,
+            StateError::InvalidBatchAccounts
+        );
+
+        let (governance_key, governance_bump) =
+            Pubkey::find_program_address(&[GOVERNANCE_SEED, oracle_key.as_ref()], program_id);
+        require_keys_eq!(
+            governance_info.key(),
+            governance_key,
+            StateError::InvalidBatchAccounts
+        );
+
+        let mut chunk_keys = [Pubkey::default(); 3];
+        let mut chunk_bumps = [0u8; 3];
+        for (i, chunk_info) in chunk_infos.iter().enumerate() {
+            let (chunk_key, chunk_bump) = Pubkey::find_program_address(
+                &[HISTORICAL_CHUNK_SEED, oracle_key.as_ref(), &[i as u8]],
+                program_id,
+            );
+            require_keys_eq!(
+                chunk_info.key(),
+                chunk_key,
+                StateError::InvalidBatchAccounts
+            );
+            chunk_keys[i] = chunk_key;
+            chunk_bumps[i] = chunk_bump;
+        }
+
+        create_pda_account(
+            &payer,
+            oracle_info,
+            &system,
+            8 + OracleState::INIT_SPACE,
+            &[ORACLE_STATE_SEED, &config.asset_seed, &[oracle_bump]],
+            program_id,
+            &rent,
+        )?;
+        create_pda_account(
+            &payer,
+            governance_info,
+            &system,
+            8 + GovernanceState::INIT_SPACE,
+            &[GOVERNANCE_SEED, oracle_key.as_ref(), &[governance_bump]],
+            program_id,
+            &rent,
+        )?;
+        for (i, chunk_info) in chunk_infos.iter().enumerate() {
+            create_pda_account(
+                &payer,
+                chunk_info,
+                &system,
+                8 + HistoricalChunk::INIT_SPACE,
+                &[
+                    HISTORICAL_CHUNK_SEED,
+                    oracle_key.as_ref(),
+                    &[i as u8],
+                    &[chunk_bumps[i]],
+                ],
+                program_id,
+                &rent,
+            )?;
+        }
+
+        let oracle_loader =
+            AccountLoader::<OracleState>::try_from_unchecked(program_id, oracle_info)?;
+        let governance_loader =
+            AccountLoader::<GovernanceState>::try_from_unchecked(program_id, governance_info)?;
+        let chunk_loaders = [
+            AccountLoader::<HistoricalChunk>::try_from_unchecked(program_id, &chunk_infos[0])?,
+            AccountLoader::<HistoricalChunk>::try_from_unchecked(program_id, &chunk_infos[1])?,
+            AccountLoader::<HistoricalChunk>::try_from_unchecked(program_id, &chunk_infos[2])?,
+        ];
+
+        let account_set = OracleAccountSet {
+            oracle_state: oracle_key,
+            historical_chunks: chunk_keys,
+            oracle_bump,
+            governance_bump,
+            chunk_bumps,
+        };
+
+        let governance_digest = {
+            let mut oracle_state = oracle_loader.load_init()?;
+            let mut governance_state = governance_loader.load_init()?;
+            let mut chunk_0 = chunk_loaders[0].load_init()?;
+            let mut chunk_1 = chunk_loaders[1].load_init()?;
+            let mut chunk_2 = chunk_loaders[2].load_init()?;
+
+            populate_oracle_accounts(
+                &config,
+                authority,
+                &account_set,
+                timestamp_now,
+                &mut oracle_state,
+                &mut governance_state,
+                [&mut chunk_0, &mut chunk_1, &mut chunk_2],
+            );
+
+            governance_state.config_digest()
+        };
+
+        // Accounts created by hand are not persisted by Anchor's exit routine,
+        // so write the discriminators explicitly once the data borrows end
+        oracle_loader.exit(program_id)?;
+        governance_loader.exit(program_id)?;
+        for chunk_loader in &chunk_loaders {
+            chunk_loader.exit(program_id)?;
+        }
+
+        emit!(OracleInitialized {
+            oracle_state: oracle_key,
+            asset_id: canonical_asset_id,
+            authority,
+            emergency_admin: config.emergency_admin,
+            twap_window: config.twap_window,
+            confidence_threshold: config.confidence_threshold,
+            manipulation_threshold: config.manipulation_threshold,
+            governance_members: governance_config.member_count,
+            multisig_threshold: governance_config.multisig_threshold,
+            governance_digest,
+        });
+    }
+
+    Ok(())
+}
+
+/// Creates a program-owned PDA the way Anchor's `init` constraint does.
+///
+/// An address that already holds lamports (someone pre-funded it to grief
+/// `create_account`) is topped up to rent exemption, then allocated and
+/// assigned in place. Any existing data or foreign owner is rejected outright.
+fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system: &AccountInfo<'info>,
+    space: usize,
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+    rent: &Rent,
+) -> Result<()> {
+    require!(
+        target.is_writable && target.data_is_empty() && target.owner == &system_program::ID,
+        StateError::InvalidBatchAccounts
+    );
+
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+    let required_lamports = rent.minimum_balance(space);
+    let current_lamports = target.lamports();
+
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system.clone(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+                signer_seeds,
+            ),
+            required_lamports,
+            space as u64,
+            program_id,
+        );
+    }
+
+    let top_up = required_lamports.saturating_sub(current_lamports);
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system.clone(),
+            system_program::Allocate {
+                account_to_allocate: target.clone(),
+            },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system.clone(),
+            system_program::Assign {
+                account_to_assign: target.clone(),
+            },
+            signer_seeds,
+        ),
+        program_id,
+    )
+}
@@ -0,0 +1,60 @@
+use crate::error::StateError;
+use crate::state::governance_state::GovernanceState;
+use crate::state::oracle_state::{OracleState, UpdaterPolicy};
+use crate::utils::constants::GOVERNANCE_SEED;
+use crate::utils::events::UpdatersChanged;
+use anchor_lang::prelude::*;
+
+/// Sets who may publish prices and, for `Allowlist`, the vetted keeper set.
+///
+/// Admin-gated rather than `MODIFY_CONFIG`: listing a keeper lets a key outside
+/// the multisig publish prices, which is a trust decision on the order of
+/// membership changes rather than a parameter tweak.
+#[derive(Accounts)]
+pub struct ManageUpdaters<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn manage_updaters(
+    ctx: Context<ManageUpdaters>,
+    policy: UpdaterPolicy,
+    updaters: Vec<Pubkey>,
+) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    oracle_state.require_not_emergency()?;
+
+    governance_state.check_admin(&ctx.accounts.authority.key())?;
+
+    let previous_policy = oracle_state.get_updater_policy();
+    oracle_state.set_updaters(policy, &updaters)?;
+
+    emit!(UpdatersChanged {
+        oracle: ctx.accounts.oracle_state.key(),
+        previous_policy,
+        policy,
+        updater_count: updaters.len() as u8,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,132 @@
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::legacy_oracle_state::LegacyOracleState;
+use crate::state::oracle_state::{OracleState, Version};
+use crate::utils::constants::GOVERNANCE_SEED;
+use crate::utils::events::OracleStateMigrated;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+
+/// Upgrades a v0.1 oracle account to the current schema in place.
+///
+/// # Why a Migration
+///
+/// The per-feed smoothing, fallback and liquidity-history fields and the
+/// oracle-level policy fields needed 800 more bytes than `reserved` held, so the
+/// account grew from 2,736 to 3,536 bytes and `Version` moved to 0.2.0. Every
+/// other instruction loads the oracle through `AccountLoader<OracleState>`, which
+/// refuses the short v0.1 account outright; this instruction is the only way to
+/// bring one forward, and it keeps the oracle's address, bumps and links.
+///
+/// # Procedure
+///
+/// 1. Check the account is a program-owned `OracleState` of exactly the v0.1
+///    size, then copy its body to the heap.
+/// 2. Top the account up to rent exemption at the new size, paid by `authority`.
+/// 3. Resize, and write the current layout over the old bytes through
+///    `LegacyOracleState::upgrade_into`.
+///
+/// Gated on `MODIFY_CONFIG`: migration picks the canonical exponent, which is
+/// the same decision `register_price_feed` makes under governance.
+#[derive(Accounts)]
+pub struct MigrateOracleState<'info> {
+    /// CHECK: a v0.1 account is too short for `AccountLoader<OracleState>`; the
+    /// owner is checked here and the discriminator, size and version by hand
+    /// before any byte is written.
+    #[account(mut, owner = crate::ID)]
+    pub oracle_state: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_oracle_state(ctx: Context<MigrateOracleState>, canonical_expo: i32) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+    let oracle_info = ctx.accounts.oracle_state.to_account_info();
+
+    let governance_state = ctx.accounts.governance_state.load()?;
+    require_keys_eq!(
+        governance_state.oracle_state,
+        oracle_info.key(),
+        StateError::UnauthorizedCaller
+    );
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+
+    let legacy_body = copy_legacy_body(&oracle_info)?;
+    let legacy: &LegacyOracleState = bytemuck::from_bytes(bytemuck::cast_slice(&legacy_body));
+
+    let new_len = OracleState::DISCRIMINATOR.len() + OracleState::INIT_SPACE;
+    let top_up = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(oracle_info.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: oracle_info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    oracle_info.resize(new_len)?;
+
+    {
+        let mut data = oracle_info.try_borrow_mut_data()?;
+        let oracle_state: &mut OracleState =
+            bytemuck::from_bytes_mut(&mut data[OracleState::DISCRIMINATOR.len()..new_len]);
+        legacy.upgrade_into(oracle_state, canonical_expo)?;
+    }
+
+    emit!(OracleStateMigrated {
+        oracle: oracle_info.key(),
+        from_version: legacy.version,
+        to_version: Version::CURRENT,
+        account_size: new_len as u32,
+        canonical_expo,
+        migrated_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
+
+/// Copies a v0.1 oracle's body out of the account so it can be resized underneath.
+///
+/// The copy is held in `u128` words, which gives it the struct's alignment on the
+/// heap without ever placing the 2.7 KB struct on the 4 KB SBF stack.
+///
+/// # Errors
+///
+/// - `InvalidAccount`: the discriminator is not `OracleState`'s
+/// - `UnsupportedStateVersion`: the account is not exactly the v0.1 size, which
+///   also rejects an oracle that has already been migrated
+fn copy_legacy_body(oracle_info: &AccountInfo) -> Result<Vec<u128>> {
+    let data = oracle_info.try_borrow_data()?;
+    let discriminator_len = OracleState::DISCRIMINATOR.len();
+
+    require!(
+        data.len() == discriminator_len + LegacyOracleState::SIZE,
+        StateError::UnsupportedStateVersion
+    );
+    require!(
+        data[..discriminator_len] == *OracleState::DISCRIMINATOR,
+        StateError::InvalidAccount
+    );
+
+    let mut body = vec![0u128; LegacyOracleState::SIZE / core::mem::size_of::<u128>()];
+    bytemuck::cast_slice_mut::<u128, u8>(&mut body).copy_from_slice(&data[discriminator_len..]);
+    Ok(body)
+}
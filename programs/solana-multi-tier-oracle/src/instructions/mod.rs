@@ -1,7 +1,73 @@
+pub mod assess_current_manipulation;
+pub mod check_redemption_eligibility;
+pub mod close_historical_chunk;
+pub mod create_proposal;
+pub mod emergency_kill_switch;
+pub mod execute_proposal;
+pub mod get_price;
+pub mod get_summary;
+pub mod heartbeat;
 pub mod initialize_oracle;
+pub mod initialize_oracle_batch;
+pub mod manage_updaters;
+pub mod migrate_oracle_state;
+pub mod ramp_twap_window;
 pub mod register_price_feed;
+pub mod remove_member;
+pub mod repair_chunk_links;
+pub mod resume_oracle;
+pub mod set_escalation_ladder;
+pub mod set_feed_spread;
+pub mod set_governance_params;
+pub mod set_liquidity_drop_bound;
+pub mod set_maintenance;
+pub mod set_manipulation_decay;
+pub mod set_member_stake;
+pub mod set_min_observations;
+pub mod set_relaxed_min_elapsed;
+pub mod set_source_thresholds;
+pub mod set_spot_fallback;
+pub mod set_tier_config;
+pub mod set_update_cooldown;
+pub mod set_validation_policy;
+pub mod transfer_emergency_admin;
 pub mod update_price;
+pub mod veto_proposal;
+pub mod vote_proposal;
 
+pub use assess_current_manipulation::*;
+pub use check_redemption_eligibility::*;
+pub use close_historical_chunk::*;
+pub use create_proposal::*;
+pub use emergency_kill_switch::*;
+pub use execute_proposal::*;
+pub use get_price::*;
+pub use get_summary::*;
+pub use heartbeat::*;
 pub use initialize_oracle::*;
+pub use initialize_oracle_batch::*;
+pub use manage_updaters::*;
+pub use migrate_oracle_state::*;
+pub use ramp_twap_window::*;
 pub use register_price_feed::*;
+pub use remove_member::*;
+pub use repair_chunk_links::*;
+pub use resume_oracle::*;
+pub use set_escalation_ladder::*;
+pub use set_feed_spread::*;
+pub use set_governance_params::*;
+pub use set_liquidity_drop_bound::*;
+pub use set_maintenance::*;
+pub use set_manipulation_decay::*;
+pub use set_member_stake::*;
+pub use set_min_observations::*;
+pub use set_relaxed_min_elapsed::*;
+pub use set_source_thresholds::*;
+pub use set_spot_fallback::*;
+pub use set_tier_config::*;
+pub use set_update_cooldown::*;
+pub use set_validation_policy::*;
+pub use transfer_emergency_admin::*;
 pub use update_price::*;
+pub use veto_proposal::*;
+pub use vote_proposal::*;
@@ -0,0 +1,78 @@
+use crate::components::raydium_clmm_observer::raydium_constants::OBSERVATION_UPDATE_DURATION;
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::{GOVERNANCE_SEED, MAX_TWAP_WINDOW, MIN_HISTORICAL_INTERVAL};
+use crate::utils::events::TwapWindowRampStarted;
+use anchor_lang::prelude::*;
+
+/// Moves the TWAP window to a new value gradually instead of in one step.
+///
+/// The target must satisfy the same bounds and cadence alignment that
+/// `update_price` enforces, including the oracle's own window ceiling, so no
+/// point of the ramp can brick updates. The
+/// duration is capped at `MAX_TWAP_WINDOW`; zero switches immediately.
+#[derive(Accounts)]
+pub struct RampTwapWindow<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn ramp_twap_window(
+    ctx: Context<RampTwapWindow>,
+    target_window: u32,
+    ramp_duration: u32,
+) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    oracle_state.require_not_emergency()?;
+
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+
+    let min_window = core::cmp::max(MIN_HISTORICAL_INTERVAL as u32, OBSERVATION_UPDATE_DURATION);
+    require!(
+        target_window >= min_window && target_window.is_multiple_of(OBSERVATION_UPDATE_DURATION),
+        StateError::InvalidTWAPWindow
+    );
+    oracle_state.check_twap_window(target_window)?;
+    require!(
+        ramp_duration <= MAX_TWAP_WINDOW,
+        StateError::InvalidTwapRamp
+    );
+
+    let from_window = oracle_state.start_twap_ramp(
+        target_window,
+        ramp_duration,
+        timestamp_now,
+        OBSERVATION_UPDATE_DURATION,
+    );
+
+    emit!(TwapWindowRampStarted {
+        oracle: ctx.accounts.oracle_state.key(),
+        from_window,
+        target_window,
+        ramp_duration,
+        started_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
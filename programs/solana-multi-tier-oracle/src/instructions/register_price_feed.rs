@@ -1,12 +1,16 @@
+use crate::components::price_source::Observer;
+use crate::components::raydium_clmm_observer::raydium_accounts::{
+    PoolReader, MIN_POOL_ACCOUNT_LEN,
+};
 use crate::error::StateError;
 use crate::state::governance_state::{GovernanceState, Permissions};
 use crate::state::oracle_state::OracleState;
 use crate::state::price_feed::{FeedFlags, PriceFeed, SourceType};
 use crate::utils::constants::{
-    GOVERNANCE_SEED, MAX_FEED_WEIGHT, MAX_PRICE_FEEDS, MIN_AMM_LIQUIDITY, MIN_CLMM_LIQUIDITY,
-    ORACLE_STATE_SEED, WEIGHT_PRECISION,
+    GOVERNANCE_SEED, MAX_FEED_EXPO, MAX_FEED_WEIGHT, MAX_PRICE_FEEDS, MIN_AMM_LIQUIDITY,
+    MIN_CLMM_LIQUIDITY, MIN_FEED_EXPO, ORACLE_STATE_SEED, WEIGHT_PRECISION,
 };
-use crate::utils::events::PriceFeedRegistered;
+use crate::utils::events::{PriceFeedRegistered, WeightsRebalanced};
 use anchor_lang::prelude::*;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -16,6 +20,8 @@ pub struct PriceFeedConfig {
     pub weight: u16,
     pub min_liquidity: u128,
     pub staleness_threshold: u32,
+    pub expo: i32,
+    pub normalize: bool, // Scale existing weights down instead of rejecting an over-budget feed
     pub asset_seed: [u8; 32],
 }
 
@@ -85,6 +91,94 @@ impl PriceFeedConfig {
             }
         }
     }
+
+    /// Rejects native exponents outside `MIN_FEED_EXPO..=MAX_FEED_EXPO`.
+    ///
+    /// The stored exponent drives normalization for the feed's whole lifetime, so a
+    /// typo here would scale every future price by orders of magnitude. The range
+    /// covers every SPL token precision while keeping rescaling factors well inside
+    /// i128.
+    pub fn validate_expo(&self) -> Result<()> {
+        require!(
+            (MIN_FEED_EXPO..=MAX_FEED_EXPO).contains(&self.expo),
+            StateError::InvalidFeedExpo
+        );
+        Ok(())
+    }
+
+    /// Rejects source types no on-chain observer can read.
+    ///
+    /// `update_price` only ever writes a feed through `fetch_price`, which
+    /// serves the types `Observer::for_source` knows. A feed of any other type
+    /// would keep its registration placeholder forever while holding weight and
+    /// a slot, so it is refused here until an observer or push path exists.
+    pub fn validate_source_type(&self) -> Result<()> {
+        require!(
+            Observer::for_source(self.source_type).is_some(),
+            StateError::UnsupportedSourceType
+        );
+        Ok(())
+    }
+
+    /// Minimum sanity check that the source account exists and looks like a pool.
+    ///
+    /// # Why Independent of Strict Mode
+    ///
+    /// Strict mode answers "is this program trusted?", which operators may reasonably
+    /// disable during bring-up. Whether the account holds anything at all is a different
+    /// question: a feed pointing at an empty system account can never produce a price and
+    /// would only surface as failed updates later. Rejecting it at registration is cheap
+    /// and applies in every mode.
+    ///
+    /// Pool-backed types (DEX, Aggregator) must also carry at least
+    /// `MIN_POOL_ACCOUNT_LEN` bytes of data so the observer can map its layout. CEX and
+    /// Oracle sources are pushed off-chain and only need to exist.
+    pub fn validate_source_account(&self, feed_source: &AccountInfo) -> Result<()> {
+        require!(feed_source.lamports() > 0, StateError::InvalidSourceAddress);
+
+        let min_data_len = match self.source_type {
+            SourceType::DEX | SourceType::Aggregator => MIN_POOL_ACCOUNT_LEN,
+            SourceType::CEX | SourceType::Oracle => 0,
+        };
+        require!(
+            feed_source.data_len() >= min_data_len,
+            StateError::InvalidSourceAddress
+        );
+
+        Ok(())
+    }
+
+    /// Exponent this feed's prices arrive in, used to lock the oracle's
+    /// canonical exponent when it is the first feed registered.
+    ///
+    /// DEX feeds read it from the pool's quote-token decimals, the same source
+    /// `update_price` checks every observation against, so a mistyped `expo`
+    /// cannot lock a scale the pool never produces. Other source types are
+    /// pushed off-chain and declare their exponent in `expo`.
+    pub fn canonical_expo(&self, feed_source: &AccountInfo) -> Result<i32> {
+        match self.source_type {
+            SourceType::DEX => Ok(PoolReader::new_ptr(feed_source)?.implied_expo()),
+            SourceType::CEX | SourceType::Oracle | SourceType::Aggregator => Ok(self.expo),
+        }
+    }
+
+    /// Rejects an `expo` that differs from the exponent the source prices in.
+    ///
+    /// The feed stores `expo` as the scale of every reading it records, while a
+    /// DEX pool always prices in its `implied_expo`. A coarser `expo` would
+    /// truncate each reading as it is rescaled into the feed, and a finer one
+    /// could overflow it, so the two must agree exactly.
+    ///
+    /// # Errors
+    ///
+    /// - `FeedExpoMismatch`: `expo` is not the source's exponent
+    pub fn validate_source_expo(&self, feed_source: &AccountInfo) -> Result<()> {
+        require!(
+            self.expo == self.canonical_expo(feed_source)?,
+            StateError::FeedExpoMismatch
+        );
+        Ok(())
+    }
 }
 
 struct ValidationContext<'a> {
@@ -230,16 +324,25 @@ fn validate_feed_registration(
         return Err(convert_validation_error(weight_result.error_flags).into());
     }
 
-    let total_weight_result = ctx.validate_total_weight(feed_config.weight)?;
-    if !total_weight_result.is_valid {
-        return Err(convert_validation_error(total_weight_result.error_flags).into());
+    // Normalized registrations make room by rescaling instead of failing here
+    if !feed_config.normalize {
+        let total_weight_result = ctx.validate_total_weight(feed_config.weight)?;
+        if !total_weight_result.is_valid {
+            return Err(convert_validation_error(total_weight_result.error_flags).into());
+        }
     }
 
+    feed_config.validate_source_type()?;
+    feed_config.validate_expo()?;
+
     let source_result = feed_config.validate_source_address();
     if !source_result.is_valid {
         return Err(convert_validation_error(source_result.error_flags).into());
     }
 
+    feed_config.validate_source_account(feed_source)?;
+    feed_config.validate_source_expo(feed_source)?;
+
     let program_result =
         validate_source_program_ownership(feed_source, feed_config.source_type, governance_state);
     if !program_result.is_valid {
@@ -249,24 +352,34 @@ fn validate_feed_registration(
     Ok(())
 }
 
-fn create_price_feed(feed_config: &PriceFeedConfig, timestamp: i64) -> PriceFeed {
+/// Builds the feed a registration stores, stamped at `timestamp`.
+///
+/// The feed starts with `READ_FAILED` set: it holds no price until its first
+/// good read, and a zero `last_price` stamped at registration time must not
+/// count as a fresh reading. Aggregation and the consensus check skip it, and
+/// with `last_good_update` at zero it has no fallback price either.
+pub(crate) fn create_price_feed(feed_config: &PriceFeedConfig, timestamp: i64) -> PriceFeed {
     let mut flags = FeedFlags::new();
     flags.set(FeedFlags::ACTIVE);
+    flags.set(FeedFlags::READ_FAILED);
 
     PriceFeed {
         source_address: feed_config.source_address,
         last_price: 0,
+        ewma_price: 0,
         volume_24h: 0,
         liquidity_depth: 0,
+        prev_liquidity_depth: 0,
         last_conf: 0,
         last_update: timestamp,
-        last_expo: 0,
+        last_good_update: 0,
+        last_expo: feed_config.expo,
         weight: feed_config.weight,
         lp_concentration: 0,
         manipulation_score: 0,
         source_type: feed_config.source_type.as_u8(),
         flags,
-        _padding: [0; 4],
+        _padding: [0; 12],
     }
 }
 
@@ -311,6 +424,8 @@ pub fn register_price_feed(
         StateError::UnauthorizedCaller
     );
 
+    oracle_state.require_not_emergency()?;
+
     let validation_context = ValidationContext::new(&oracle_state)?;
 
     validation_context.validate_oracle_constraints()?;
@@ -325,15 +440,32 @@ pub fn register_price_feed(
         &governance_state,
     )?;
 
-    let final_total_weight = validation_context
-        .current_total_weight
+    if feed_config.normalize && oracle_state.rebalance_weights_for(feed_config.weight)? {
+        emit!(WeightsRebalanced {
+            oracle: ctx.accounts.oracle_state.key(),
+            weights: oracle_state
+                .active_feeds()
+                .iter()
+                .map(|feed| feed.weight)
+                .collect(),
+            incoming_weight: feed_config.weight,
+            timestamp: timestamp_now,
+        });
+    }
+
+    let final_total_weight = oracle_state
+        .active_feeds()
+        .iter()
+        .map(|feed| feed.weight as u32)
+        .sum::<u32>()
         .checked_add(feed_config.weight as u32)
         .ok_or(StateError::ExcessiveTotalWeight)?;
 
-    let active_feed_count = oracle_state.active_feed_count;
-    let feed_index = oracle_state.active_feed_count as usize;
-    oracle_state.price_feeds[feed_index] = create_price_feed(&feed_config, timestamp_now);
-    oracle_state.set_active_feed_count(active_feed_count + 1)?;
+    let canonical_expo = feed_config.canonical_expo(&ctx.accounts.feed_source)?;
+    oracle_state.lock_expected_expo(canonical_expo);
+
+    let feed_index =
+        oracle_state.claim_feed_slot(create_price_feed(&feed_config, timestamp_now))?;
 
     emit!(PriceFeedRegistered {
         oracle: ctx.accounts.oracle_state.key(),
@@ -342,6 +474,7 @@ pub fn register_price_feed(
         weight: feed_config.weight,
         feed_index: feed_index as u32,
         total_weight: final_total_weight,
+        weight_headroom: oracle_state.weight_headroom(),
         timestamp: timestamp_now,
     });
 
@@ -0,0 +1,73 @@
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::GOVERNANCE_SEED;
+use crate::utils::events::MemberRemoved;
+use anchor_lang::prelude::*;
+
+/// Removes a member from the governance multisig.
+///
+/// Removal is the one membership change that can leave governance unable to act,
+/// so the handler defers entirely to `GovernanceState::remove_member`, which refuses
+/// any removal that would strand the multisig threshold or the last admin.
+///
+/// # Threshold Signing
+///
+/// A single member able to remove others could shrink the multisig down to
+/// the members it controls. Like stake changes, removal needs
+/// `multi_sig_threshold` distinct `MODIFY_CONFIG` holders to sign: `authority`
+/// plus co-signers passed as signer accounts in `remaining_accounts`.
+#[derive(Accounts)]
+pub struct RemoveMember<'info> {
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn remove_member(ctx: Context<RemoveMember>, member: Pubkey) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut governance_state = ctx.accounts.governance_state.load_mut()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    let signers: Vec<Pubkey> = core::iter::once(ctx.accounts.authority.key())
+        .chain(
+            ctx.remaining_accounts
+                .iter()
+                .filter(|account| account.is_signer)
+                .map(|account| account.key()),
+        )
+        .collect();
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+    governance_state.check_threshold_signers(&signers, Permissions::MODIFY_CONFIG)?;
+
+    let (member_index, _) = governance_state
+        .find_member(&member)
+        .ok_or(StateError::UnauthorizedCaller)?;
+
+    governance_state.remove_member(member_index)?;
+
+    emit!(MemberRemoved {
+        governance: ctx.accounts.governance_state.key(),
+        member,
+        removed_by: ctx.accounts.authority.key(),
+        remaining_members: governance_state.active_member_count,
+        governance_digest: governance_state.config_digest(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
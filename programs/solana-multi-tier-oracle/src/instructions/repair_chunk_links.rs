@@ -0,0 +1,88 @@
+use crate::error::StateError;
+use crate::state::governance_state::GovernanceState;
+use crate::state::historical_chunk::HistoricalChunk;
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::{GOVERNANCE_SEED, HISTORICAL_CHUNK_SEED, MAX_HISTORICAL_CHUNKS};
+use crate::utils::events::ChunkLinksRepaired;
+use anchor_lang::prelude::*;
+
+/// Re-derives the oracle's historical chunk links after a migration or bug.
+///
+/// # Account Layout
+///
+/// The chunks arrive writable through `remaining_accounts` in slot order, one
+/// per slot to keep. Each address must be the chunk PDA for its slot under this
+/// oracle, which on its own rules out another oracle's chunks; the stored
+/// back-reference is checked as well by `OracleState::repair_chunk_links`.
+/// Admin-gated because slots past the supplied set are unlinked.
+#[derive(Accounts)]
+pub struct RepairChunkLinks<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn repair_chunk_links<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RepairChunkLinks<'info>>,
+) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+    let oracle_key = ctx.accounts.oracle_state.key();
+    let program_id = ctx.program_id;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        oracle_key,
+        StateError::UnauthorizedCaller
+    );
+
+    oracle_state.require_not_emergency()?;
+
+    governance_state.check_admin(&ctx.accounts.authority.key())?;
+
+    require!(
+        ctx.remaining_accounts.len() <= MAX_HISTORICAL_CHUNKS,
+        StateError::InvalidChunkSet
+    );
+
+    let mut chunk_keys = Vec::with_capacity(ctx.remaining_accounts.len());
+    let mut loaders = Vec::with_capacity(ctx.remaining_accounts.len());
+    for (slot, chunk_info) in ctx.remaining_accounts.iter().enumerate() {
+        let (expected_key, _) = Pubkey::find_program_address(
+            &[HISTORICAL_CHUNK_SEED, oracle_key.as_ref(), &[slot as u8]],
+            program_id,
+        );
+        require_keys_eq!(chunk_info.key(), expected_key, StateError::InvalidChunkSet);
+
+        chunk_keys.push(expected_key);
+        loaders.push(AccountLoader::<HistoricalChunk>::try_from(chunk_info)?);
+    }
+
+    let mut guards = loaders
+        .iter()
+        .map(|loader| loader.load_mut())
+        .collect::<Result<Vec<_>>>()?;
+    let mut chunks: Vec<&mut HistoricalChunk> =
+        guards.iter_mut().map(|chunk| &mut **chunk).collect();
+
+    let links_rewritten = oracle_state.repair_chunk_links(&oracle_key, &chunk_keys, &mut chunks)?;
+
+    emit!(ChunkLinksRepaired {
+        oracle: oracle_key,
+        chunk_count: chunk_keys.len() as u8,
+        links_rewritten,
+        repaired_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
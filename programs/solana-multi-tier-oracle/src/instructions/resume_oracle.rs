@@ -0,0 +1,65 @@
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::GOVERNANCE_SEED;
+use crate::utils::events::OracleResumed;
+use anchor_lang::prelude::*;
+
+/// Lifts an emergency halt once governance agrees the incident is over.
+///
+/// # Recovery Path
+///
+/// The kill switch, feed disagreement, a feed shortage and the escalation
+/// ladder all leave the oracle in emergency mode, and without this instruction
+/// only a program upgrade could bring it back. Resuming clears both
+/// `EMERGENCY_MODE` and `CIRCUIT_BREAKER_ENABLED` and needs
+/// `multi_sig_threshold` distinct `MODIFY_CONFIG` holders to sign: `authority`
+/// plus co-signers passed as signer accounts in `remaining_accounts`. It is
+/// deliberately exempt from `require_not_emergency`, which would otherwise
+/// block the one instruction meant to run in an emergency.
+#[derive(Accounts)]
+pub struct ResumeOracle<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn resume_oracle(ctx: Context<ResumeOracle>) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    let signers: Vec<Pubkey> = core::iter::once(ctx.accounts.authority.key())
+        .chain(
+            ctx.remaining_accounts
+                .iter()
+                .filter(|account| account.is_signer)
+                .map(|account| account.key()),
+        )
+        .collect();
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+    oracle_state.resume_operation(&governance_state, &signers)?;
+
+    emit!(OracleResumed {
+        oracle: ctx.accounts.oracle_state.key(),
+        resumed_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
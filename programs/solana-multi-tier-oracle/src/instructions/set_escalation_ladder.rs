@@ -0,0 +1,56 @@
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::GOVERNANCE_SEED;
+use crate::utils::events::EscalationLadderUpdated;
+use anchor_lang::prelude::*;
+
+/// Sets the manipulation scores at which a suspicious update arms the circuit
+/// breaker or halts the oracle.
+///
+/// Scores over the threshold already keep a feed out of publication; the
+/// ladder decides when a reading is alarming enough to act on the whole oracle.
+#[derive(Accounts)]
+pub struct SetEscalationLadder<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_escalation_ladder(ctx: Context<SetEscalationLadder>, ladder: [u16; 2]) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    oracle_state.require_not_emergency()?;
+
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+
+    let previous_ladder = oracle_state.escalation_ladder;
+    oracle_state.set_escalation_ladder(ladder)?;
+
+    emit!(EscalationLadderUpdated {
+        oracle: ctx.accounts.oracle_state.key(),
+        previous_ladder,
+        ladder,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
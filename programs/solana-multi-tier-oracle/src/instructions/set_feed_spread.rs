@@ -0,0 +1,56 @@
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::GOVERNANCE_SEED;
+use crate::utils::events::FeedSpreadUpdated;
+use anchor_lang::prelude::*;
+
+/// Sets how far fresh feeds may spread before an update refuses to publish.
+///
+/// A wider bound lets a diverging source through to the aggregate and keeps it
+/// away from the circuit breaker, so the bound is oracle configuration rather
+/// than something each updater picks per call.
+#[derive(Accounts)]
+pub struct SetFeedSpread<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_feed_spread(ctx: Context<SetFeedSpread>, max_spread_bps: u16) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    oracle_state.require_not_emergency()?;
+
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+
+    let previous_max_spread_bps = oracle_state.max_feed_spread_bps;
+    oracle_state.set_feed_spread(max_spread_bps)?;
+
+    emit!(FeedSpreadUpdated {
+        oracle: ctx.accounts.oracle_state.key(),
+        previous_max_spread_bps,
+        max_spread_bps,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceParams, GovernanceState, Permissions};
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::GOVERNANCE_SEED;
+use crate::utils::events::GovernanceParamsUpdated;
+use anchor_lang::prelude::*;
+
+/// Retunes proposal timing and thresholds after bootstrap.
+///
+/// # Authorization
+///
+/// Proposals do not carry an execution payload yet, so this cannot be routed
+/// through `execute_proposal`. Quorum, thresholds and voting windows decide
+/// what every later proposal needs to pass, so one member must not retune
+/// them alone: like stake changes, the update needs `multi_sig_threshold`
+/// distinct `MODIFY_CONFIG` holders to sign, `authority` plus co-signers passed
+/// as signer accounts in `remaining_accounts`. The check runs against the
+/// threshold in force before the update. Once proposals can carry actions,
+/// this handler is the one an executed parameter proposal should apply.
+///
+/// # Validation
+///
+/// The new values must pass the same bounds `initialize_oracle` applies, so a
+/// retune can never reach a configuration bootstrap would have refused. The
+/// event carries both parameter sets and the resulting governance digest.
+#[derive(Accounts)]
+pub struct SetGovernanceParams<'info> {
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_governance_params(
+    ctx: Context<SetGovernanceParams>,
+    params: GovernanceParams,
+) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut governance_state = ctx.accounts.governance_state.load_mut()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    let signers: Vec<Pubkey> = core::iter::once(ctx.accounts.authority.key())
+        .chain(
+            ctx.remaining_accounts
+                .iter()
+                .filter(|account| account.is_signer)
+                .map(|account| account.key()),
+        )
+        .collect();
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+    governance_state.check_threshold_signers(&signers, Permissions::MODIFY_CONFIG)?;
+
+    let previous = governance_state.params();
+    governance_state.set_params(&params)?;
+
+    emit!(GovernanceParamsUpdated {
+        governance: ctx.accounts.governance_state.key(),
+        previous,
+        updated: params,
+        updated_by: ctx.accounts.authority.key(),
+        governance_digest: governance_state.config_digest(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::GOVERNANCE_SEED;
+use crate::utils::events::LiquidityDropBoundUpdated;
+use anchor_lang::prelude::*;
+
+/// Sets how far a feed's liquidity may fall between two reads before the read
+/// is flagged as a shock.
+///
+/// A shock adds to the feed's manipulation score, so a looser bound lets a
+/// pool that was just drained keep publishing at full trust. That makes it
+/// oracle configuration rather than a per-update choice of the keeper.
+#[derive(Accounts)]
+pub struct SetLiquidityDropBound<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_liquidity_drop_bound(
+    ctx: Context<SetLiquidityDropBound>,
+    max_drop_bps: u16,
+) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    oracle_state.require_not_emergency()?;
+
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+
+    let previous_max_drop_bps = oracle_state.max_liquidity_drop_bps;
+    oracle_state.set_liquidity_drop_bound(max_drop_bps)?;
+
+    emit!(LiquidityDropBoundUpdated {
+        oracle: ctx.accounts.oracle_state.key(),
+        previous_max_drop_bps,
+        max_drop_bps,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::{OracleState, StateFlags};
+use crate::utils::constants::GOVERNANCE_SEED;
+use crate::utils::events::MaintenanceModeChanged;
+use anchor_lang::prelude::*;
+
+/// Enters or leaves planned maintenance.
+///
+/// # Maintenance vs Emergency
+///
+/// Maintenance pauses `update_price` for scheduled work such as feed migrations
+/// without signalling that anything is wrong with the published price, so reads
+/// keep working. Emergency mode remains the tool for incidents. Because toggling
+/// maintenance is a configuration change rather than an incident response, it is
+/// gated on `MODIFY_CONFIG` instead of `EMERGENCY_HALT`.
+#[derive(Accounts)]
+pub struct SetMaintenance<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_maintenance(ctx: Context<SetMaintenance>, enabled: bool) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+
+    oracle_state
+        .flags
+        .set_to(StateFlags::MAINTENANCE_MODE, enabled);
+
+    emit!(MaintenanceModeChanged {
+        oracle: ctx.accounts.oracle_state.key(),
+        enabled,
+        changed_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::{GOVERNANCE_SEED, MAX_TWAP_WINDOW};
+use crate::utils::events::ManipulationDecayUpdated;
+use anchor_lang::prelude::*;
+
+/// Tunes how long a manipulation spike keeps a feed flagged.
+///
+/// Both knobs are capped at `MAX_TWAP_WINDOW`: remembering an attack for longer
+/// than the oracle can average over would keep a feed quarantined after every
+/// trace of the attack has left the price history.
+#[derive(Accounts)]
+pub struct SetManipulationDecay<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_manipulation_decay(
+    ctx: Context<SetManipulationDecay>,
+    cooldown: u32,
+    decay_period: u32,
+) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    oracle_state.require_not_emergency()?;
+
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+
+    require!(
+        cooldown <= MAX_TWAP_WINDOW && decay_period <= MAX_TWAP_WINDOW,
+        StateError::InvalidManipulationDecay
+    );
+
+    oracle_state.manipulation_cooldown = cooldown;
+    oracle_state.manipulation_decay_period = decay_period;
+
+    emit!(ManipulationDecayUpdated {
+        oracle: ctx.accounts.oracle_state.key(),
+        cooldown,
+        decay_period,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
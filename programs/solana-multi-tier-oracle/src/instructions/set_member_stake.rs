@@ -0,0 +1,73 @@
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::GOVERNANCE_SEED;
+use crate::utils::events::MemberStakeUpdated;
+use anchor_lang::prelude::*;
+
+/// Records the governance stake that weights a member's votes.
+///
+/// # Threshold Signing
+///
+/// Stake decides quorum, so a single member able to set stakes could zero the
+/// others, raise their own and pass proposals alone. The change therefore needs
+/// `multi_sig_threshold` distinct `MODIFY_CONFIG` holders to sign: `authority`
+/// plus co-signers passed as signer accounts in `remaining_accounts`. Open
+/// proposals are unaffected either way, having frozen their stakes at creation.
+/// Every change emits the before/after values for off-chain reconciliation.
+#[derive(Accounts)]
+pub struct SetMemberStake<'info> {
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_member_stake(ctx: Context<SetMemberStake>, member: Pubkey, stake: u64) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut governance_state = ctx.accounts.governance_state.load_mut()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    let signers: Vec<Pubkey> = core::iter::once(ctx.accounts.authority.key())
+        .chain(
+            ctx.remaining_accounts
+                .iter()
+                .filter(|account| account.is_signer)
+                .map(|account| account.key()),
+        )
+        .collect();
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+    governance_state.check_threshold_signers(&signers, Permissions::MODIFY_CONFIG)?;
+
+    let (member_index, _) = governance_state
+        .find_member(&member)
+        .ok_or(StateError::UnauthorizedCaller)?;
+    let previous_stake = governance_state.member_stakes[member_index];
+
+    governance_state.set_member_stake(member_index, stake)?;
+
+    emit!(MemberStakeUpdated {
+        governance: ctx.accounts.governance_state.key(),
+        member,
+        previous_stake,
+        new_stake: stake,
+        updated_by: ctx.accounts.authority.key(),
+        governance_digest: governance_state.config_digest(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
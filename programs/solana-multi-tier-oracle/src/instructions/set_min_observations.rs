@@ -0,0 +1,55 @@
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::GOVERNANCE_SEED;
+use crate::utils::events::MinObservationsUpdated;
+use anchor_lang::prelude::*;
+
+/// Sets how many filled observation slots a DEX feed needs before it is priced.
+///
+/// Lowering the floor lets younger, easier-to-steer pools publish, so it is
+/// oracle configuration rather than a per-update choice of the keeper.
+#[derive(Accounts)]
+pub struct SetMinObservations<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_min_observations(ctx: Context<SetMinObservations>, min_observations: u16) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    oracle_state.require_not_emergency()?;
+
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+
+    let previous_min_observations = oracle_state.min_observations;
+    oracle_state.set_min_observations(min_observations)?;
+
+    emit!(MinObservationsUpdated {
+        oracle: ctx.accounts.oracle_state.key(),
+        previous_min_observations,
+        min_observations,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
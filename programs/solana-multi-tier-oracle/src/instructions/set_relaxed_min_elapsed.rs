@@ -0,0 +1,59 @@
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::{OracleState, StateFlags};
+use crate::utils::constants::GOVERNANCE_SEED;
+use crate::utils::events::RelaxedMinElapsedChanged;
+use anchor_lang::prelude::*;
+
+/// Allows or forbids pricing DEX spans shorter than the keeper's `min_seconds`.
+///
+/// # Why Stored, Not Passed
+///
+/// A relaxed span is priced at reduced confidence rather than rejected, which
+/// is what a young pool or a test cluster needs and what a production oracle
+/// must not do by accident. When the switch rode in `UpdatePriceConfig`, any
+/// updater could flip it per call. Keeping it in the oracle's flags puts it
+/// behind `MODIFY_CONFIG`, and both `update_price` and
+/// `assess_current_manipulation` read the same stored value.
+#[derive(Accounts)]
+pub struct SetRelaxedMinElapsed<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_relaxed_min_elapsed(ctx: Context<SetRelaxedMinElapsed>, enabled: bool) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+
+    oracle_state
+        .flags
+        .set_to(StateFlags::RELAXED_MIN_ELAPSED, enabled);
+
+    emit!(RelaxedMinElapsedChanged {
+        oracle: ctx.accounts.oracle_state.key(),
+        enabled,
+        changed_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
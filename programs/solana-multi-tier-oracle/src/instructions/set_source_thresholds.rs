@@ -0,0 +1,58 @@
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::{GOVERNANCE_SEED, SOURCE_TYPE_COUNT};
+use crate::utils::events::SourceThresholdsUpdated;
+use anchor_lang::prelude::*;
+
+/// Scales the manipulation threshold per feed source type.
+///
+/// Lets governance hold flash-loanable DEX pools to a stricter score than
+/// external oracles without lowering the threshold for every feed at once.
+#[derive(Accounts)]
+pub struct SetSourceThresholds<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_source_thresholds(
+    ctx: Context<SetSourceThresholds>,
+    thresholds_bps: [u16; SOURCE_TYPE_COUNT],
+) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    oracle_state.require_not_emergency()?;
+
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+
+    let previous_thresholds_bps = oracle_state.source_threshold_bps;
+    oracle_state.set_source_thresholds(thresholds_bps)?;
+
+    emit!(SourceThresholdsUpdated {
+        oracle: ctx.accounts.oracle_state.key(),
+        previous_thresholds_bps,
+        thresholds_bps,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::{OracleState, StateFlags};
+use crate::utils::constants::GOVERNANCE_SEED;
+use crate::utils::events::SpotFallbackChanged;
+use anchor_lang::prelude::*;
+
+/// Opens or closes the spot rung of `ValidationPolicy::Fallback`.
+///
+/// # Opt-In
+///
+/// The live pool tick is a single instant that one swap can set. It lets a
+/// pool whose observation ring has gone quiet keep publishing, at minimal
+/// confidence, and that trade-off belongs to whoever configures the oracle,
+/// not to the updater choosing a policy for one call. Until a `MODIFY_CONFIG`
+/// holder enables it here, `Fallback` stops at the TWAP rung and a ring with
+/// no recent history rejects the read.
+#[derive(Accounts)]
+pub struct SetSpotFallback<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_spot_fallback(ctx: Context<SetSpotFallback>, enabled: bool) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+
+    oracle_state
+        .flags
+        .set_to(StateFlags::SPOT_FALLBACK_ENABLED, enabled);
+
+    emit!(SpotFallbackChanged {
+        oracle: ctx.accounts.oracle_state.key(),
+        enabled,
+        changed_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
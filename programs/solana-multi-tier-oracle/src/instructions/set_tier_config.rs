@@ -0,0 +1,63 @@
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::{GOVERNANCE_SEED, PRICE_TIER_COUNT};
+use crate::utils::events::TierConfigUpdated;
+use anchor_lang::prelude::*;
+
+/// Sets how the Oracle, CEX and DEX tiers are blended and how far a tier may
+/// stray from the most trusted one before it is left out.
+///
+/// Shifting weight between tiers changes whose market the published price
+/// follows, so the change is gated like any other configuration update.
+#[derive(Accounts)]
+pub struct SetTierConfig<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_tier_config(
+    ctx: Context<SetTierConfig>,
+    weights_bps: [u16; PRICE_TIER_COUNT],
+    max_spread_bps: u16,
+) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    oracle_state.require_not_emergency()?;
+
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+
+    let previous_weights_bps = oracle_state.tier_weights_bps;
+    let previous_max_spread_bps = oracle_state.max_tier_spread_bps;
+    oracle_state.set_tier_config(weights_bps, max_spread_bps)?;
+
+    emit!(TierConfigUpdated {
+        oracle: ctx.accounts.oracle_state.key(),
+        previous_weights_bps,
+        weights_bps,
+        previous_max_spread_bps,
+        max_spread_bps,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
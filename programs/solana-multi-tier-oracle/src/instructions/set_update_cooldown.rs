@@ -0,0 +1,58 @@
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::{GOVERNANCE_SEED, MAX_TWAP_WINDOW};
+use crate::utils::events::UpdateCooldownUpdated;
+use anchor_lang::prelude::*;
+
+/// Sets the minimum gap between published prices.
+///
+/// Capped at `MAX_TWAP_WINDOW`: a cooldown longer than the averaging window
+/// would let the published price fall further behind than the TWAP itself.
+#[derive(Accounts)]
+pub struct SetUpdateCooldown<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_update_cooldown(ctx: Context<SetUpdateCooldown>, cooldown: u32) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    oracle_state.require_not_emergency()?;
+
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+
+    require!(
+        cooldown <= MAX_TWAP_WINDOW,
+        StateError::InvalidUpdateCooldown
+    );
+
+    oracle_state.update_cooldown = cooldown;
+
+    emit!(UpdateCooldownUpdated {
+        oracle: ctx.accounts.oracle_state.key(),
+        cooldown,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use crate::components::raydium_clmm_observer::fetch_raydium_price::ValidationPolicy;
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::GOVERNANCE_SEED;
+use crate::utils::events::ValidationPolicyUpdated;
+use anchor_lang::prelude::*;
+
+/// Sets which estimators must agree before a DEX read is accepted.
+///
+/// Every policy other than `Both` drops a cross-check to keep thin pools
+/// publishing, so relaxing it is oracle configuration rather than a choice
+/// the keeper makes per update. The spot rung of `Fallback` stays behind its
+/// own opt-in, `set_spot_fallback`.
+#[derive(Accounts)]
+pub struct SetValidationPolicy<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn set_validation_policy(
+    ctx: Context<SetValidationPolicy>,
+    policy: ValidationPolicy,
+) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    oracle_state.require_not_emergency()?;
+
+    governance_state
+        .check_member_permission(&ctx.accounts.authority.key(), Permissions::MODIFY_CONFIG)?;
+
+    let previous_policy = oracle_state.get_validation_policy();
+    oracle_state.set_validation_policy(policy);
+
+    emit!(ValidationPolicyUpdated {
+        oracle: ctx.accounts.oracle_state.key(),
+        previous_policy,
+        policy,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use crate::error::StateError;
+use crate::state::governance_state::GovernanceState;
+use crate::state::oracle_state::OracleState;
+use crate::utils::constants::GOVERNANCE_SEED;
+use crate::utils::events::EmergencyAdminTransferred;
+use anchor_lang::prelude::*;
+
+/// Rotates the oracle's emergency admin under a dual signature.
+///
+/// # Why Rotation Needs Two Keys
+///
+/// `initialize_oracle` expects the emergency admin to be a trusted multisig, and
+/// trusted signers change over time: keys are lost, custodians rotate, incident
+/// teams reorganize. Leaving the key fixed made it a permanent single point of
+/// failure. Rotation now needs the outgoing emergency admin, so governance cannot
+/// strip the fail-safe from under the incident team, and a governance admin, so a
+/// compromised emergency key cannot hand itself off to an attacker.
+#[derive(Accounts)]
+pub struct TransferEmergencyAdmin<'info> {
+    #[account(mut)]
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    pub emergency_admin: Signer<'info>,
+
+    pub governance_admin: Signer<'info>,
+}
+
+pub fn transfer_emergency_admin(
+    ctx: Context<TransferEmergencyAdmin>,
+    new_admin: Pubkey,
+) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
+    let governance_state = ctx.accounts.governance_state.load()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    let previous_admin = oracle_state.transfer_emergency_admin(
+        &governance_state,
+        &ctx.accounts.emergency_admin.key(),
+        &ctx.accounts.governance_admin.key(),
+        new_admin,
+    )?;
+
+    emit!(EmergencyAdminTransferred {
+        oracle: ctx.accounts.oracle_state.key(),
+        previous_admin,
+        new_admin,
+        approved_by: ctx.accounts.governance_admin.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
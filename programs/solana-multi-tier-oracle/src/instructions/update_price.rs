@@ -1,5 +1,8 @@
+use crate::components::price_source::{
+    check_sample_timestamp, fetch_price, SourceAccounts, SourceParams,
+};
 use crate::components::raydium_clmm_observer::{
-    fetch_raydium_price::{fetch_raydium_price_from_observations, RaydiumParams},
+    fetch_raydium_price::{ConfidenceMethod, DecimalPrice, RaydiumParams},
     raydium_constants::{
         OBSERVATION_SEED, OBSERVATION_UPDATE_DURATION, RAYDIUM_CLMM_PROGRAM_ID_DEVNET,
         RAYDIUM_CLMM_PROGRAM_ID_MAINNET,
@@ -7,20 +10,31 @@ use crate::components::raydium_clmm_observer::{
 };
 use crate::error::{RaydiumObserverError, StateError};
 use crate::utils::constants::{
-    BUFFER_SIZE, GOVERNANCE_SEED, HISTORICAL_CHUNK_SEED, MAX_TWAP_WINDOW, MIN_HISTORICAL_INTERVAL,
-    ORACLE_STATE_SEED,
+    BUFFER_SIZE, GOVERNANCE_SEED, HISTORICAL_CHUNK_SEED, MIN_HEALTHY_FEEDS,
+    MIN_HISTORICAL_INTERVAL, ORACLE_STATE_SEED,
 };
 use crate::{
     components::{twap, ui_price_from_sqrt_q64},
     state::{
         governance_state::{GovernanceState, Permissions},
         historical_chunk::{HistoricalChunk, PricePoint},
-        oracle_state::{OracleState, PriceData},
-        price_feed::{FeedFlags, SourceType},
+        oracle_state::{ManipulationSeverity, OracleState, PriceData},
+        price_feed::{rescale_price, SourceType},
+    },
+    utils::events::{
+        CircuitBreakerTriggered, FeedBreakerTripped, LiquidityShock, ManipulationEscalated,
+        PriceUpdated, SaturationWarning, UpdateSkipped,
     },
-    utils::events::{PriceUpdated, SaturationWarning},
 };
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+
+/// Pre-image of `CircuitBreakerTriggered.reason_hash` when feeds disagree.
+const FEEDS_DISAGREE_REASON: &[u8] = b"feeds_disagree";
+
+/// Pre-image of `CircuitBreakerTriggered.reason_hash` when per-feed breakers
+/// leave too few healthy feeds.
+const FEED_BREAKERS_REASON: &[u8] = b"feed_breakers";
 
 #[derive(AnchorDeserialize, AnchorSerialize, Clone, Debug)]
 pub struct UpdatePriceConfig {
@@ -29,10 +43,316 @@ pub struct UpdatePriceConfig {
     pub min_liquidity: u128,
     pub max_tick_deviation: i32,
     pub alpha_basis_points: u16,
+    pub impact_trade_size: u128,
+    pub confidence_method: ConfidenceMethod,
+    pub weighting_scheme: WeightingScheme,
+    pub feed_ewma_alpha_bps: u16, // Weight of each new reading in the feed's smoothed price
+    pub soft_skip: bool,          // Skip recoverable read failures, recording only the failed read
+    pub strict_coverage: bool,    // Reject windows the observation ring barely covers
+    pub max_clock_skew: u32, // Future-dating tolerated on external source timestamps, up to MAX_CLOCK_SKEW
+    pub history_deviation_bps: u16, // Move from the last stored point that forces an off-schedule history push; zero disables
+    pub require_window_alignment: bool, // Reject a window off the observation cadence; false rounds it down instead
+    pub force: bool,                    // Admin override of the update cooldown
+    pub max_saturation_events: u8,      // SaturationWarning events emitted per TWAP pass
+    pub dry_run: bool, // Compute and return the price without committing state or events
+    pub expected_min: Option<i128>, // Keeper's lowest plausible published price, in the oracle's exponent
+    pub expected_max: Option<i128>, // Keeper's highest plausible published price, in the oracle's exponent
+    pub window_short: u32,          // Fast TWAP reported alongside the published one; zero disables
+    pub window_long: u32,           // Slow TWAP reported alongside the published one; zero disables
     pub asset_seed: [u8; 32],
     pub use_mainnet: bool, // Network flag for Raydium program selection
 }
 
+/// How historical samples are weighted when averaging price.
+///
+/// # Why Selectable
+///
+/// Confidence weighting lets high-quality samples dominate, which resists a
+/// burst of low-confidence prints, but it means the result is no longer the
+/// textbook time-weighted average some integrators reconcile against. The
+/// confidence component of `TWAPResult` is time-weighted under either scheme.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WeightingScheme {
+    /// Each sample weighs its duration only.
+    TimeOnly,
+    /// Each sample weighs duration scaled by its clamped confidence.
+    #[default]
+    ConfidenceWeighted,
+}
+
+impl WeightingScheme {
+    /// Weight for a sample held for `time_delta` seconds at confidence `conf_weight`.
+    #[inline]
+    pub fn price_weight(self, time_delta: u128, conf_weight: u128) -> u128 {
+        match self {
+            WeightingScheme::TimeOnly => time_delta,
+            WeightingScheme::ConfidenceWeighted => time_delta.saturating_mul(conf_weight),
+        }
+    }
+}
+
+/// Recoverable reasons an update can be skipped under `soft_skip`.
+///
+/// # Why Soft-Skip
+///
+/// Keepers batch updates across many oracles in one transaction. A single pool
+/// that is momentarily volatile or stale should not revert the whole batch, so
+/// conditions that clear on their own are reported through `UpdateSkipped` and
+/// the instruction succeeds without touching the published price. The pool's
+/// feed only records the failed read, which starts its last-good-price grace.
+///
+/// # Stable Codes
+///
+/// Discriminants are emitted as `reason_code` and indexed off-chain, so they
+/// are pinned explicitly and must never be renumbered or reused. Zero is kept
+/// free so an unset code is never mistaken for a real reason.
+///
+/// # What Stays Fatal
+///
+/// Anything that points at the wrong account (owner, size, PDA, pool link) or a
+/// malformed config is not transient and always reverts.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u16)]
+pub enum SkipReason {
+    /// TWAP and T2EMA, or either and the live tick, disagree beyond tolerance.
+    ExcessiveDeviation = 1,
+    /// The observation ring has not been written recently enough, or lags the live tick.
+    StaleObservations = 2,
+    /// The observation ring holds no usable sample yet, or too little to cover the window.
+    ObservationsNotReady = 3,
+    /// A derived tick fell outside Raydium's representable range.
+    TickOutOfBounds = 4,
+    /// The fetched price was not strictly positive.
+    NonPositivePrice = 5,
+}
+
+impl SkipReason {
+    /// Stable wire code for `UpdateSkipped.reason_code`.
+    #[inline]
+    pub fn code(self) -> u16 {
+        self as u16
+    }
+
+    /// Classifies `err`, returning `None` for hard errors that must revert.
+    ///
+    /// Both programs' error enums start at the same offset, so a match compares
+    /// the variant name as well as the code number.
+    pub fn from_error(err: &Error) -> Option<Self> {
+        let Error::AnchorError(actual) = err else {
+            return None;
+        };
+
+        [
+            (
+                RaydiumObserverError::ExcessiveCurrentDeviation,
+                SkipReason::ExcessiveDeviation,
+            ),
+            (
+                RaydiumObserverError::ExcessiveMethodDivergence,
+                SkipReason::ExcessiveDeviation,
+            ),
+            (
+                RaydiumObserverError::InsufficientTime,
+                SkipReason::StaleObservations,
+            ),
+            (
+                RaydiumObserverError::StaleObservationRing,
+                SkipReason::StaleObservations,
+            ),
+            (
+                RaydiumObserverError::InvalidIndex,
+                SkipReason::ObservationsNotReady,
+            ),
+            (
+                RaydiumObserverError::InsufficientCoverage,
+                SkipReason::ObservationsNotReady,
+            ),
+            (
+                RaydiumObserverError::InsufficientObservations,
+                SkipReason::ObservationsNotReady,
+            ),
+            (
+                RaydiumObserverError::T2emaNotWarm,
+                SkipReason::ObservationsNotReady,
+            ),
+            (
+                RaydiumObserverError::TickOutOfBounds,
+                SkipReason::TickOutOfBounds,
+            ),
+            (
+                RaydiumObserverError::InvalidPrice,
+                SkipReason::NonPositivePrice,
+            ),
+        ]
+        .into_iter()
+        .find_map(|(variant, reason)| match Error::from(variant) {
+            Error::AnchorError(expected)
+                if expected.error_code_number == actual.error_code_number
+                    && expected.error_name == actual.error_name =>
+            {
+                Some(reason)
+            }
+            _ => None,
+        })
+    }
+}
+
+/// Result of a read phase that may be soft-skipped.
+#[derive(Debug)]
+pub enum ReadOutcome<T> {
+    Ready(T),
+    Skipped(SkipReason),
+}
+
+/// Applies the soft-skip policy to a read-phase `result`.
+///
+/// Only call this on work that precedes the update's state writes: returning
+/// `Ok` from the instruction commits whatever was written before the skip. The
+/// caller then records the failed read on the pool's feed and nothing else, so
+/// a skip never moves the published price, the history or the update nonce.
+pub fn soft_skip<T>(result: Result<T>, enabled: bool) -> Result<ReadOutcome<T>> {
+    match result {
+        Ok(value) => Ok(ReadOutcome::Ready(value)),
+        Err(err) if enabled => match SkipReason::from_error(&err) {
+            Some(reason) => Ok(ReadOutcome::Skipped(reason)),
+            None => Err(err),
+        },
+        Err(err) => Err(err),
+    }
+}
+
+/// Combines the pool's observed risk with a liquidity-shock term, capped at 10_000.
+///
+/// The shock's drop in basis points is added as-is, so a pool that lost most of
+/// its depth is pushed towards the manipulation threshold even when its price
+/// path looked calm.
+pub fn raw_manipulation_score(observed: u32, liquidity_shock: Option<u16>) -> u16 {
+    let shocked = observed.saturating_add(liquidity_shock.unwrap_or(0) as u32);
+    core::cmp::min(shocked, 10_000) as u16
+}
+
+/// The pool's own reading, in the exponent its feed stores.
+///
+/// # Why Not the History TWAP
+///
+/// The history TWAP averages what the oracle published, which is already the
+/// blend across every feed. Recorded on a feed, it would make each feed echo
+/// the aggregate, so two pools could never disagree and the consensus gate
+/// could never trip. The observer's price is this pool's own windowed estimate,
+/// so recording it keeps each feed independent of the others.
+pub fn pool_reading(decimal_price: &DecimalPrice, feed_expo: i32, now: i64) -> Result<PriceData> {
+    let mut reading = decimal_price.to_price_data(now, feed_expo);
+    reading.price = rescale_price(reading.price, decimal_price.implied_expo(), feed_expo)?;
+    Ok(reading)
+}
+
+/// Reject a price outside the keeper's asserted `[expected_min, expected_max]`.
+///
+/// # Why Keeper Bounds
+///
+/// A keeper usually knows roughly where an asset trades from sources the oracle
+/// cannot see. Asserting that range lets a misconfigured pool or a corrupted read
+/// fail in the keeper's own simulation instead of being published. Either bound
+/// may be omitted, and both are inclusive and in the oracle's published exponent.
+pub fn check_expected_bounds(
+    price: i128,
+    expected_min: Option<i128>,
+    expected_max: Option<i128>,
+) -> Result<()> {
+    require!(
+        expected_min.is_none_or(|min| price >= min) && expected_max.is_none_or(|max| price <= max),
+        StateError::PriceOutsideExpectedBounds
+    );
+    Ok(())
+}
+
+/// The tiered aggregate `update_price` publishes, held to the keeper's bounds.
+///
+/// The bounds guard what readers will see, so they apply to the blend across
+/// every registered feed rather than to the one pool read in this call: a sane
+/// TWAP can still publish an implausible price when another tier drags the
+/// blend, and an outlying pool can be outweighed by the tiers around it.
+pub fn bounded_aggregate(
+    oracle_state: &OracleState,
+    now: i64,
+    expected_min: Option<i128>,
+    expected_max: Option<i128>,
+) -> Result<PriceData> {
+    let aggregate = oracle_state.aggregate_tiered(now)?;
+    check_expected_bounds(aggregate.price, expected_min, expected_max)?;
+    Ok(aggregate)
+}
+
+/// The Raydium window actually averaged for a requested `window_seconds`.
+///
+/// # Alignment
+///
+/// Raydium records an observation every `OBSERVATION_UPDATE_DURATION` seconds,
+/// so a window that is a whole number of intervals gives every interval the
+/// same weight. Strict mode keeps the historical behaviour of rejecting any
+/// other window outright. Relaxed mode rounds it down to the cadence instead,
+/// so a keeper asking for 3,601 seconds averages over 3,600 rather than
+/// failing; the bounds checks then apply to the rounded window.
+pub fn aligned_window_seconds(window_seconds: u32, require_alignment: bool) -> Result<u32> {
+    let remainder = window_seconds % OBSERVATION_UPDATE_DURATION;
+    require!(
+        remainder == 0 || !require_alignment,
+        StateError::InvalidTWAPWindow
+    );
+    Ok(window_seconds - remainder)
+}
+
+/// Why an update writes a point to history.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HistoryPush {
+    /// The regular cadence came due.
+    Scheduled,
+    /// The price moved sharply before the cadence came due.
+    Forced,
+}
+
+/// Decides whether publishing `price` at `now` writes a history point.
+///
+/// # Cadence
+///
+/// A point is scheduled once `MIN_HISTORICAL_INTERVAL` has passed since the
+/// last scheduled point, so forced points never shift the sampling grid.
+///
+/// # Forced Pushes
+///
+/// Between scheduled points, a price more than `deviation_bps` away from the
+/// last stored point is pushed anyway so the TWAP sees the move when it
+/// happened rather than up to an interval later. A forced point is never
+/// followed by another one, which bounds off-schedule writes to one per
+/// interval and keeps a volatile market from flushing the buffer. Zero
+/// `deviation_bps` disables forcing.
+pub fn history_push_kind(
+    chunk: &HistoricalChunk,
+    price: i128,
+    now: i64,
+    deviation_bps: u16,
+) -> Option<HistoryPush> {
+    let Some(latest) = chunk.latest() else {
+        return Some(HistoryPush::Scheduled);
+    };
+    let anchor = chunk.latest_scheduled().unwrap_or(latest);
+    if now - anchor.timestamp >= MIN_HISTORICAL_INTERVAL {
+        return Some(HistoryPush::Scheduled);
+    }
+    if deviation_bps == 0 || chunk.latest_is_forced() {
+        return None;
+    }
+
+    let exceeds = match latest.price.unsigned_abs() {
+        0 => price != 0,
+        base => price
+            .abs_diff(latest.price)
+            .checked_mul(10_000)
+            .is_none_or(|scaled| scaled / base > deviation_bps as u128),
+    };
+    exceeds.then_some(HistoryPush::Forced)
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct TWAPResult {
     pub twap_price: i128,
@@ -41,11 +361,97 @@ pub struct TWAPResult {
     pub covered_time_span: u64,
     pub oldest_timestamp: i64,
     pub newest_timestamp: i64,
+    /// Accumulation steps that overflowed and fell back to saturating arithmetic.
+    pub saturation_fallbacks: u32,
+    /// `SaturationWarning` events emitted, at most the caller's per-pass limit.
+    pub saturation_warnings: u32,
+    /// TWAP over the short divergence window, `None` when that window is disabled.
+    pub twap_short: Option<i128>,
+    /// TWAP over the long divergence window, `None` when that window is disabled.
+    pub twap_long: Option<i128>,
 }
 
-#[inline]
-fn tail_index(chunk: &HistoricalChunk) -> usize {
-    (chunk.head as usize + BUFFER_SIZE - chunk.count as usize) % BUFFER_SIZE
+/// Time-weighted mean over a secondary window, fed by the published TWAP's pass.
+///
+/// # Shared Traversal
+///
+/// A fast and a slow average differ only in where their window starts, so they
+/// ride along the chunk traversal that computes the published TWAP rather than
+/// walking the history twice more. Each follows the same rules as that pass: the
+/// start is clamped to the oldest stored point, and the first point inside the
+/// window is extended back to the start. Only the published price reports
+/// saturation, so these sums saturate silently.
+#[derive(Clone, Copy)]
+struct WindowAccumulator {
+    cutoff: i64,
+    previous: Option<PricePoint>,
+    weighted_sum: i128,
+    total_weight: u128,
+}
+
+impl WindowAccumulator {
+    /// Accumulator for the `window_seconds` ending at `current_time`; `None` when disabled.
+    fn new(window_seconds: u32, current_time: i64, oldest_available: Option<i64>) -> Option<Self> {
+        if window_seconds == 0 {
+            return None;
+        }
+        let requested = current_time - window_seconds as i64;
+        Some(Self {
+            cutoff: oldest_available.map_or(requested, |oldest| requested.max(oldest)),
+            previous: None,
+            weighted_sum: 0,
+            total_weight: 0,
+        })
+    }
+
+    /// Adds the segment ending at `point`, which must already be a valid sample.
+    fn observe(&mut self, point: &PricePoint, weighting_scheme: WeightingScheme) {
+        if point.timestamp < self.cutoff {
+            return;
+        }
+        if self.previous.is_none() && point.timestamp > self.cutoff {
+            self.previous = Some(PricePoint {
+                timestamp: self.cutoff,
+                ..*point
+            });
+        }
+        if let Some(previous) = self.previous {
+            let dt = point.timestamp - previous.timestamp;
+            if dt <= 0 {
+                return;
+            }
+            self.add_segment(&previous, dt as u128, weighting_scheme);
+        }
+        self.previous = Some(*point);
+    }
+
+    /// Closes the window at `current_time` and returns its mean.
+    fn finish(mut self, current_time: i64, weighting_scheme: WeightingScheme) -> Option<i128> {
+        if let Some(last) = self.previous {
+            let dt = current_time - last.timestamp;
+            if dt > 0 {
+                self.add_segment(&last, dt as u128, weighting_scheme);
+            }
+        }
+        (self.total_weight > 0)
+            .then(|| self.weighted_sum / i128::try_from(self.total_weight).unwrap_or(i128::MAX))
+    }
+
+    fn add_segment(
+        &mut self,
+        point: &PricePoint,
+        time_delta: u128,
+        weighting_scheme: WeightingScheme,
+    ) {
+        let conf_weight = (core::cmp::min(point.conf, 10_000) as u128).max(1);
+        let weight = weighting_scheme.price_weight(time_delta, conf_weight);
+        self.weighted_sum = self.weighted_sum.saturating_add(
+            point
+                .price
+                .saturating_mul(i128::try_from(weight).unwrap_or(i128::MAX)),
+        );
+        self.total_weight = self.total_weight.saturating_add(weight);
+    }
 }
 
 #[inline]
@@ -53,11 +459,14 @@ fn step_forward(index: usize) -> usize {
     (index + 1) % BUFFER_SIZE
 }
 
-fn stream_twap_from_chunks(
+pub(crate) fn stream_twap_from_chunks(
     chunks: &[&HistoricalChunk], // Flexible slice for future extensibility
     window_seconds: u32,
+    divergence_windows: [u32; 2], // [short, long] windows averaged in the same pass
+    weighting_scheme: WeightingScheme,
     current_time: i64,
     oracle_key: &Pubkey, // Added for event emission
+    max_saturation_events: u32,
 ) -> Result<TWAPResult> {
     let requested_cutoff_time = current_time - window_seconds as i64;
 
@@ -71,31 +480,20 @@ fn stream_twap_from_chunks(
     let mut data_points_used: u32 = 0;
     let mut actual_cutoff_time = requested_cutoff_time;
     let mut saturation_events_emitted: u32 = 0;
-    const MAX_SATURATION_EVENTS_PER_CALL: u32 = 3; // Noise control limit
+    let mut saturation_fallbacks: u32 = 0;
 
     // First pass: find the oldest available data point across all chunks
-    let find_oldest_timestamp = || -> Option<i64> {
-        let mut earliest: Option<i64> = None;
-        for chunk in chunks.iter() {
-            if chunk.count == 0 {
-                continue;
-            }
-            let mut idx = tail_index(chunk);
-            for _ in 0..chunk.count {
-                let p = chunk.price_points[idx];
-                idx = step_forward(idx);
-                if p.price > 0 && p.timestamp > 0 {
-                    earliest = Some(earliest.map_or(p.timestamp, |e| e.min(p.timestamp)));
-                    break; // tail-forward makes this chunk's earliest; no need to scan further
-                }
-            }
-        }
-        earliest
-    };
+    let oldest_available = chunks
+        .iter()
+        .filter_map(|chunk| chunk.oldest())
+        .map(|point| point.timestamp)
+        .min();
+    let mut divergence = divergence_windows
+        .map(|window| WindowAccumulator::new(window, current_time, oldest_available));
 
     // If we don't have enough historical data to cover the full window,
     // adjust the cutoff time to use whatever data we have
-    if let Some(oldest_available) = find_oldest_timestamp() {
+    if let Some(oldest_available) = oldest_available {
         if oldest_available > requested_cutoff_time {
             actual_cutoff_time = oldest_available;
         }
@@ -112,11 +510,18 @@ fn stream_twap_from_chunks(
                 return Ok(());
             }
 
-            let mut index = tail_index(chunk);
+            let mut index = chunk.tail_index();
             for _ in 0..chunk.count {
-                let point = chunk.price_points[index];
+                let point = chunk.point_at(index)?;
                 index = step_forward(index);
 
+                // The divergence windows may reach past the published window's start
+                if point.price > 0 && point.timestamp > 0 {
+                    for window in divergence.iter_mut().flatten() {
+                        window.observe(&point, weighting_scheme);
+                    }
+                }
+
                 if point.timestamp < actual_cutoff_time {
                     continue;
                 }
@@ -151,9 +556,9 @@ fn stream_twap_from_chunks(
                     // Clamp confidence to prevent overweighting from buggy feeds
                     let conf_sample = core::cmp::min(prev_point.conf, 10_000);
 
-                    // Use confidence-scaled time weighting (higher conf = more weight) for price
+                    // Price weight follows the configured scheme; confidence-scaled by default
                     let conf_weight = (conf_sample as u128).max(1);
-                    let combined_weight = time_delta.saturating_mul(conf_weight);
+                    let combined_weight = weighting_scheme.price_weight(time_delta, conf_weight);
 
                     let price_weighted =
                         (prev_point.price as i128).checked_mul(combined_weight as i128);
@@ -182,7 +587,8 @@ fn stream_twap_from_chunks(
                         }
                         _ => {
                             // Hit saturation fallback - emit warning event with noise control
-                            if *events_counter < MAX_SATURATION_EVENTS_PER_CALL {
+                            saturation_fallbacks = saturation_fallbacks.saturating_add(1);
+                            if *events_counter < max_saturation_events {
                                 emit!(SaturationWarning {
                                     oracle: *oracle_key,
                                     operation: format!("TWAP_weight_calculation:{}", chunk_name),
@@ -240,7 +646,8 @@ fn stream_twap_from_chunks(
             // Clamp confidence for final calculation too
             let last_conf_sample = core::cmp::min(last_point.conf, 10_000);
             let last_conf_weight = (last_conf_sample as u128).max(1);
-            let last_combined_weight = last_time_weight.saturating_mul(last_conf_weight);
+            let last_combined_weight =
+                weighting_scheme.price_weight(last_time_weight, last_conf_weight);
 
             let last_price_weighted =
                 (last_point.price as i128).checked_mul(last_combined_weight as i128);
@@ -268,7 +675,8 @@ fn stream_twap_from_chunks(
                 }
                 _ => {
                     // Hit saturation fallback for final calculation - emit warning event with noise control
-                    if saturation_events_emitted < MAX_SATURATION_EVENTS_PER_CALL {
+                    saturation_fallbacks = saturation_fallbacks.saturating_add(1);
+                    if saturation_events_emitted < max_saturation_events {
                         emit!(SaturationWarning {
                             oracle: *oracle_key,
                             operation: "TWAP_final_calculation".to_string(),
@@ -307,6 +715,8 @@ fn stream_twap_from_chunks(
     };
 
     let covered_span = (current_time - oldest).max(0) as u64;
+    let [twap_short, twap_long] =
+        divergence.map(|window| window.and_then(|w| w.finish(current_time, weighting_scheme)));
 
     Ok(TWAPResult {
         twap_price,
@@ -315,10 +725,14 @@ fn stream_twap_from_chunks(
         covered_time_span: covered_span,
         oldest_timestamp: oldest,
         newest_timestamp: newest,
+        saturation_fallbacks,
+        saturation_warnings: saturation_events_emitted,
+        twap_short,
+        twap_long,
     })
 }
 
-fn order_chunks<'a>(
+pub(crate) fn order_chunks<'a>(
     c0: &'a HistoricalChunk,
     c1: &'a HistoricalChunk,
     c2: &'a HistoricalChunk,
@@ -408,20 +822,75 @@ pub struct UpdatePrice<'info> {
     pub authority: Signer<'info>,
 }
 
-pub fn update_price(ctx: Context<UpdatePrice>, config: UpdatePriceConfig) -> Result<()> {
+/// Publishes a new price, or with `dry_run` computes it without committing anything.
+///
+/// # Return Data
+///
+/// Returns the price this call published, or `None` when the update ended
+/// without publishing (soft skip, a tripped breaker, or feed disagreement).
+///
+/// # Dry Run
+///
+/// Keepers simulate updates to estimate compute and preview the result, which
+/// only means something if the simulation runs the real pipeline. A dry run
+/// therefore executes `apply_update` unchanged except that it emits no events
+/// and returns before the history push; the oracle account, the only one
+/// written before that point, is restored from a snapshot afterwards.
+pub fn update_price(
+    ctx: Context<UpdatePrice>,
+    config: UpdatePriceConfig,
+) -> Result<Option<PriceData>> {
+    if !config.dry_run {
+        return apply_update(&ctx, &config);
+    }
+
+    let oracle_info = ctx.accounts.oracle_state.to_account_info();
+    let snapshot = oracle_info.try_borrow_data()?.to_vec();
+    let outcome = apply_update(&ctx, &config)?;
+    oracle_info
+        .try_borrow_mut_data()?
+        .copy_from_slice(&snapshot);
+
+    Ok(outcome)
+}
+
+/// `emit!` for the update pipeline, silenced during a dry run.
+fn emit_update_event<E: anchor_lang::Event>(config: &UpdatePriceConfig, event: E) {
+    if !config.dry_run {
+        emit!(event);
+    }
+}
+
+fn apply_update(
+    ctx: &Context<UpdatePrice>,
+    config: &UpdatePriceConfig,
+) -> Result<Option<PriceData>> {
     let current_time = Clock::get()?.unix_timestamp;
 
+    // Surface a misaligned account as an error before bytemuck would panic on it
+    OracleState::check_data_alignment(
+        &ctx.accounts
+            .oracle_state
+            .to_account_info()
+            .try_borrow_data()?,
+    )?;
     let mut oracle_state = ctx.accounts.oracle_state.load_mut()?;
     let governance_state = ctx.accounts.governance_state.load()?;
 
-    require!(
-        !oracle_state.flags.is_emergency_mode(),
-        StateError::CircuitBreakerActive
-    );
-    // require!(
-    //     oracle_state.active_feed_count > 0,
-    //     StateError::NoActiveFeeds
-    // );
+    oracle_state.require_operational()?;
+    oracle_state.check_updater(&governance_state, &ctx.accounts.authority.key())?;
+    oracle_state.check_update_timestamp(current_time)?;
+
+    // Only a full governance admin may publish inside the cooldown
+    if config.force {
+        governance_state.check_admin(&ctx.accounts.authority.key())?;
+    } else {
+        oracle_state.check_update_cooldown(current_time)?;
+    }
+
+    // Resolve the target feed before any account or Raydium work, so an empty
+    // feed table fails as such rather than as an unknown pool
+    let feed_index = oracle_state.feed_index_for(ctx.accounts.raydium_pool.key)?;
 
     // // Bind governance PDA to oracle state authority
     // require_keys_eq!(
@@ -434,6 +903,15 @@ pub fn update_price(ctx: Context<UpdatePrice>, config: UpdatePriceConfig) -> Res
     let mut historical_chunk_1 = ctx.accounts.historical_chunk_1.load_mut()?;
     let mut historical_chunk_2 = ctx.accounts.historical_chunk_2.load_mut()?;
 
+    // Chunks are PDA-derived, but the stored back-reference is the authoritative link
+    let oracle_key = ctx.accounts.oracle_state.key();
+    current_historical_chunk.verify_oracle_link(&oracle_key)?;
+    historical_chunk_1.verify_oracle_link(&oracle_key)?;
+    historical_chunk_2.verify_oracle_link(&oracle_key)?;
+    oracle_state.verify_historical_chunk(0, &ctx.accounts.historical_chunk_0.key())?;
+    oracle_state.verify_historical_chunk(1, &ctx.accounts.historical_chunk_1.key())?;
+    oracle_state.verify_historical_chunk(2, &ctx.accounts.historical_chunk_2.key())?;
+
     // Select Raydium program ID based on network configuration
     let raydium_program_id = if config.use_mainnet {
         &RAYDIUM_CLMM_PROGRAM_ID_MAINNET
@@ -441,25 +919,21 @@ pub fn update_price(ctx: Context<UpdatePrice>, config: UpdatePriceConfig) -> Res
         &RAYDIUM_CLMM_PROGRAM_ID_DEVNET
     };
 
-    // let (expected_observation_pda, _bump) = Pubkey::find_program_address(
-    //     &[OBSERVATION_SEED, ctx.accounts.raydium_pool.key.as_ref()],
-    //     raydium_program_id,
-    // );
-
-    // require_keys_eq!(
-    //     expected_observation_pda,
-    //     ctx.accounts.raydium_observation.key(),
-    //     RaydiumObserverError::InvalidObservationPda
-    // );
+    // The observation is bound to the pool by `verify_observation_pda_and_read_pool`
+    // inside `fetch_price`: a foreign observation fails with `BadPda` or
+    // `PoolMismatch`, neither of which soft-skips
 
     let manipulation_threshold = oracle_state.manipulation_threshold;
     let confidence_threshold = oracle_state.confidence_threshold;
-    let oracle_twap_window = oracle_state.twap_window;
 
-    require!(
-        oracle_twap_window <= MAX_TWAP_WINDOW,
-        StateError::InvalidTWAPWindow
-    );
+    // Follow any governance ramp; intermediate windows are snapped down to the
+    // observation cadence so they pass the alignment check below
+    oracle_state.settle_twap_ramp(current_time);
+    let effective_window = oracle_state.effective_twap_window(current_time);
+    let oracle_twap_window = effective_window - effective_window % OBSERVATION_UPDATE_DURATION;
+
+    // Bounded by this oracle's own ceiling, which never exceeds MAX_TWAP_WINDOW
+    oracle_state.check_twap_window(oracle_twap_window)?;
 
     // Validate minimum window to fail fast before Raydium fetch
     let min_window = core::cmp::max(MIN_HISTORICAL_INTERVAL as u32, OBSERVATION_UPDATE_DURATION);
@@ -468,41 +942,95 @@ pub fn update_price(ctx: Context<UpdatePrice>, config: UpdatePriceConfig) -> Res
         StateError::InvalidTWAPWindow
     );
 
-    // Validate Raydium config window against same bounds
+    // Validate Raydium config window against same bounds, after any rounding
+    let window_seconds =
+        aligned_window_seconds(config.window_seconds, config.require_window_alignment)?;
+    require!(window_seconds >= min_window, StateError::InvalidTWAPWindow);
+    oracle_state.check_twap_window(window_seconds)?;
+
+    // Divergence windows are optional, but a set pair must be ordered fast to slow
+    let window_ceiling = oracle_state.twap_window_ceiling();
     require!(
-        config.window_seconds >= min_window && config.window_seconds <= MAX_TWAP_WINDOW,
+        config.window_short <= window_ceiling
+            && config.window_long <= window_ceiling
+            && (config.window_short == 0
+                || config.window_long == 0
+                || config.window_short <= config.window_long),
         StateError::InvalidTWAPWindow
     );
 
-    // Optional: align windows to update cadence for predictable weight distribution
     require!(
-        oracle_twap_window % OBSERVATION_UPDATE_DURATION == 0,
-        StateError::InvalidTWAPWindow
+        (1..=10_000).contains(&config.feed_ewma_alpha_bps),
+        StateError::InvalidSmoothingFactor
     );
+
+    // Align the oracle window to update cadence for predictable weight distribution
     require!(
-        config.window_seconds % OBSERVATION_UPDATE_DURATION == 0,
+        oracle_twap_window % OBSERVATION_UPDATE_DURATION == 0,
         StateError::InvalidTWAPWindow
     );
 
-    //governance_state.check_member_permission(&ctx.accounts.authority.key(), Permissions::UPDATE_PRICE)?;
-
     let params = RaydiumParams {
-        window_seconds: config.window_seconds,
+        window_seconds,
         min_seconds: config.min_seconds,
         min_liquidity: config.min_liquidity,
         max_tick_deviation: config.max_tick_deviation,
         alpha_basis_points: config.alpha_basis_points,
+        validation_policy: oracle_state.get_validation_policy(),
+        impact_trade_size: config.impact_trade_size,
+        confidence_method: config.confidence_method,
+        strict_coverage: config.strict_coverage,
+        strict_min_elapsed: !oracle_state.flags.is_min_elapsed_relaxed(),
+        allow_spot_fallback: oracle_state.flags.is_spot_fallback_enabled(),
+        min_observations: oracle_state.observation_floor(),
         timestamp: current_time,
     };
 
-    let decimal_price = fetch_raydium_price_from_observations(
-        &ctx.accounts.raydium_pool,
-        &ctx.accounts.raydium_observation,
-        raydium_program_id,
-        params,
+    // Only a finished TWAP ramp has been settled so far, which changes no window
+    // in force, so a recoverable failure here can still be reported as a skip
+    // without persisting a partial update
+    let fetched = fetch_price(
+        SourceType::DEX,
+        SourceAccounts {
+            price_account: &ctx.accounts.raydium_pool,
+            history_account: &ctx.accounts.raydium_observation,
+            program_id: raydium_program_id,
+        },
+        SourceParams::RaydiumClmm(params),
+    );
+
+    let decimal_price = match soft_skip(fetched, config.soft_skip)? {
+        ReadOutcome::Ready(price) => price,
+        ReadOutcome::Skipped(reason) => {
+            msg!("Update skipped: {:?}", reason);
+            // The one write a skip commits: mark the feed so aggregation serves
+            // its last good price at reduced weight
+            oracle_state.price_feeds[feed_index].record_failed_read(current_time);
+            emit_update_event(
+                config,
+                UpdateSkipped {
+                    oracle: ctx.accounts.oracle_state.key(),
+                    reason_code: reason.code(),
+                    skipped_by: ctx.accounts.authority.key(),
+                    timestamp: current_time,
+                },
+            );
+            return Ok(None);
+        }
+    };
+
+    // Pool observations carry the cluster clock and are exempt; the check still
+    // runs so an external source wired in here inherits it
+    check_sample_timestamp(
+        SourceType::DEX,
+        decimal_price.timestamp,
+        current_time,
+        config.max_clock_skew,
     )?;
 
-    require!(decimal_price.price > 0, RaydiumObserverError::InvalidPrice);
+    // Reject prices whose decimal scaling disagrees with the canonical expo locked
+    // when the first feed was registered
+    oracle_state.check_expected_expo(decimal_price.implied_expo())?;
     // require!(
     //     decimal_price.confidence >= confidence_threshold as u32,
     //     StateError::LowConfidence
@@ -520,14 +1048,19 @@ pub fn update_price(ctx: Context<UpdatePrice>, config: UpdatePriceConfig) -> Res
 
     let twap_result = if is_first_run {
         // For first run, use the current Raydium price as TWAP with overflow protection
-        let twap_price_i128 = core::cmp::min(decimal_price.price, i128::MAX as u128) as i128;
+        let seed = decimal_price.to_price_data(current_time, oracle_state.current_price.expo);
         TWAPResult {
-            twap_price: twap_price_i128,
-            twap_confidence: decimal_price.confidence as u64,
+            twap_price: seed.price,
+            twap_confidence: seed.conf,
             data_points_used: 1,
             covered_time_span: 0,
             oldest_timestamp: current_time,
             newest_timestamp: current_time,
+            saturation_fallbacks: 0,
+            saturation_warnings: 0,
+            // With no history every window collapses onto the seed price
+            twap_short: (config.window_short > 0).then_some(seed.price),
+            twap_long: (config.window_long > 0).then_some(seed.price),
         }
     } else {
         // Order chunks chronologically for proper TWAP calculation
@@ -540,41 +1073,154 @@ pub fn update_price(ctx: Context<UpdatePrice>, config: UpdatePriceConfig) -> Res
         stream_twap_from_chunks(
             &[oldest, middle, newest],
             oracle_twap_window,
+            [config.window_short, config.window_long],
+            config.weighting_scheme,
             current_time,
             &ctx.accounts.oracle_state.key(),
+            // A dry run must not log, so it mutes the warnings outright
+            if config.dry_run {
+                0
+            } else {
+                config.max_saturation_events as u32
+            },
         )?
     };
 
-    if let Some(feed_index) = oracle_state
-        .price_feeds
-        .iter()
-        .position(|feed| feed.source_address == *ctx.accounts.raydium_pool.key)
+    // Every fallback is recorded, including those past the event limit
+    oracle_state.record_saturation(twap_result.saturation_fallbacks);
+
+    // Record the reading on the feed resolved up front
     {
+        let depth = core::cmp::min(decimal_price.liquidity_depth, i128::MAX as u128) as i128;
+        let max_drop_bps = oracle_state.liquidity_drop_bps();
+        let liquidity_shock =
+            oracle_state.price_feeds[feed_index].record_liquidity(depth, max_drop_bps);
+
+        if let Some(drop_bps) = liquidity_shock {
+            let feed = &oracle_state.price_feeds[feed_index];
+            emit_update_event(
+                config,
+                LiquidityShock {
+                    oracle: ctx.accounts.oracle_state.key(),
+                    feed: feed.source_address,
+                    previous_depth: feed.prev_liquidity_depth,
+                    current_depth: feed.liquidity_depth,
+                    drop_bps,
+                    timestamp: current_time,
+                },
+            );
+        }
+
+        let raw_manipulation_score =
+            raw_manipulation_score(decimal_price.manipulation_score, liquidity_shock);
+
+        // Blend before borrowing the feed so a past spike survives a benign read
+        let manipulation_score =
+            oracle_state.blend_manipulation_score(feed_index, raw_manipulation_score, current_time);
+
         let feed = &mut oracle_state.price_feeds[feed_index];
 
-        feed.last_price = twap_result.twap_price;
-        feed.last_update = current_time;
-        feed.last_conf = twap_result.twap_confidence;
+        // Store in the feed's native exponent; readers normalize on the way out
+        let reading = pool_reading(&decimal_price, feed.last_expo, current_time)?;
+        feed.record_price(reading.price, config.feed_ewma_alpha_bps);
+        feed.record_good_read(current_time);
+        feed.last_conf = reading.conf;
         feed.volume_24h = 0;
-        feed.liquidity_depth =
-            core::cmp::min(decimal_price.liquidity_depth, i128::MAX as u128) as i128;
         feed.lp_concentration = 0;
-        feed.manipulation_score = core::cmp::min(decimal_price.manipulation_score, 10_000) as u16;
-        feed.set_source_type(SourceType::DEX);
-        feed.flags.set(FeedFlags::ACTIVE);
-    } else {
-        return Err(StateError::InvalidSourceAddress.into());
+        feed.manipulation_score = manipulation_score;
     }
 
-    oracle_state.current_price = PriceData {
-        price: twap_result.twap_price,
-        conf: twap_result.twap_confidence,
-        timestamp: current_time,
-        expo: oracle_state.current_price.expo,
-        _padding: [0; 12],
-    };
+    // A score far past the threshold may arm the breaker or halt outright; a halt
+    // returns success so the emergency flag persists
+    let scored_feed = &oracle_state.price_feeds[feed_index];
+    let (scored_source, scored_score) =
+        (scored_feed.source_address, scored_feed.manipulation_score);
+    let scored_threshold = oracle_state.manipulation_threshold_for(scored_feed.source_type);
+    let severity = oracle_state.escalate_manipulation(scored_score, scored_threshold);
+    if severity >= ManipulationSeverity::BreakerArmed {
+        emit_update_event(
+            config,
+            ManipulationEscalated {
+                oracle: ctx.accounts.oracle_state.key(),
+                feed: scored_source,
+                manipulation_score: scored_score,
+                manipulation_threshold: scored_threshold,
+                severity,
+                timestamp: current_time,
+            },
+        );
+    }
+    if severity == ManipulationSeverity::Emergency {
+        return Ok(None);
+    }
+
+    // Isolate manipulated feeds first; only halt the oracle if too few healthy ones remain
+    if !oracle_state.enforce_feed_breakers(MIN_HEALTHY_FEEDS)? {
+        emit_update_event(
+            config,
+            CircuitBreakerTriggered {
+                oracle: ctx.accounts.oracle_state.key(),
+                triggered_by: ctx.accounts.authority.key(),
+                timestamp: current_time,
+                manipulation_score: decimal_price.manipulation_score,
+                reason_hash: keccak::hashv(&[FEED_BREAKERS_REASON]).0,
+            },
+        );
+        return Ok(None);
+    }
+
+    // A tripped feed's reading is kept for its breaker state but never published;
+    // the oracle stays live on the remaining feeds' updates
+    let updated_feed = &oracle_state.price_feeds[feed_index];
+    if updated_feed.flags.is_breaker_tripped() {
+        emit_update_event(
+            config,
+            FeedBreakerTripped {
+                oracle: ctx.accounts.oracle_state.key(),
+                feed: updated_feed.source_address,
+                manipulation_score: updated_feed.manipulation_score,
+                manipulation_threshold: oracle_state
+                    .manipulation_threshold_for(updated_feed.source_type),
+                timestamp: current_time,
+            },
+        );
+        return Ok(None);
+    }
+
+    // Refuse to publish over disagreeing sources; with the breaker armed, succeed
+    // without publishing so the emergency flag and the fresh feed reading persist
+    if !oracle_state.enforce_feed_consensus(current_time)? {
+        emit_update_event(
+            config,
+            CircuitBreakerTriggered {
+                oracle: ctx.accounts.oracle_state.key(),
+                triggered_by: ctx.accounts.authority.key(),
+                timestamp: current_time,
+                manipulation_score: decimal_price.manipulation_score,
+                reason_hash: keccak::hashv(&[FEEDS_DISAGREE_REASON]).0,
+            },
+        );
+        return Ok(None);
+    }
+
+    // Publish the blend across every registered feed, not just the one read here;
+    // a blend outside the keeper's bounds reverts every write above
+    oracle_state.current_price = bounded_aggregate(
+        &oracle_state,
+        current_time,
+        config.expected_min,
+        config.expected_max,
+    )?;
 
     oracle_state.last_update = current_time;
+    let update_nonce = oracle_state.advance_update_nonce()?;
+    let participation = oracle_state.feed_participation(current_time);
+    oracle_state.last_sources_mask = participation.sources_mask;
+
+    // History is the one write a dry run cannot undo, so it stops here
+    if config.dry_run {
+        return Ok(Some(oracle_state.current_price));
+    }
 
     let chunks = (
         &*current_historical_chunk,
@@ -594,34 +1240,48 @@ pub fn update_price(ctx: Context<UpdatePrice>, config: UpdatePriceConfig) -> Res
         _ => &mut historical_chunk_2,
     };
 
-    let should_push = match active_chunk.latest() {
-        Some(last_point) => {
-            let time_delta = current_time - last_point.timestamp;
-            time_delta >= MIN_HISTORICAL_INTERVAL
-        }
-        None => true,
+    let new_point = PricePoint {
+        price: oracle_state.current_price.price,
+        conf: oracle_state.current_price.conf,
+        timestamp: current_time,
+        volume: 0,
     };
 
-    if should_push {
-        let new_point = PricePoint {
-            price: twap_result.twap_price,
-            conf: twap_result.twap_confidence,
-            timestamp: current_time,
-            volume: 0,
-        };
-        active_chunk.push(new_point);
+    if is_first_run {
+        // The seed is the start segment the next update's TWAP extends to now
+        active_chunk.seed(new_point)?;
+    } else if let Some(kind) = history_push_kind(
+        active_chunk,
+        new_point.price,
+        current_time,
+        config.history_deviation_bps,
+    ) {
+        match kind {
+            HistoryPush::Scheduled => active_chunk.push_checked(new_point)?,
+            HistoryPush::Forced => active_chunk.push_forced(new_point)?,
+        }
     }
 
-    emit!(PriceUpdated {
-        oracle: ctx.accounts.oracle_state.key(),
-        price: twap_result.twap_price,
-        confidence: twap_result.twap_confidence,
-        timestamp: current_time,
-        twap_window: oracle_twap_window,
-        raydium_pools_used: 1,
-        observed_manipulation_score: decimal_price.manipulation_score,
-        raydium_network_mainnet: config.use_mainnet as u8,
-    });
+    emit_update_event(
+        config,
+        PriceUpdated {
+            oracle: ctx.accounts.oracle_state.key(),
+            price: oracle_state.current_price.price,
+            confidence: oracle_state.current_price.conf,
+            timestamp: current_time,
+            twap_window: oracle_twap_window,
+            raydium_pools_used: 1,
+            observed_manipulation_score: decimal_price.manipulation_score,
+            raydium_network_mainnet: config.use_mainnet as u8,
+            update_nonce,
+            feeds_aggregated: participation.aggregated,
+            feeds_excluded_stale: participation.excluded_stale,
+            feeds_excluded_manipulated: participation.excluded_manipulated,
+            twap_deviation_bps: decimal_price.twap_deviation_bps,
+            twap_short: twap_result.twap_short,
+            twap_long: twap_result.twap_long,
+        },
+    );
 
-    Ok(())
+    Ok(Some(oracle_state.current_price))
 }
@@ -0,0 +1,73 @@
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::OracleState;
+use crate::state::proposal::Proposal;
+use crate::utils::constants::{GOVERNANCE_SEED, PROPOSAL_SEED};
+use crate::utils::events::ProposalVetoed;
+use anchor_lang::prelude::*;
+
+/// Emergency cancellation of an approved governance proposal.
+///
+/// # Veto Semantics
+///
+/// The veto is the last line of defence between approval and execution. It is
+/// only available while the proposal sits inside `GovernanceState.veto_period`
+/// measured from its approval timestamp; once that window elapses the approving
+/// majority's decision stands and the proposal can only be executed. Vetoing is
+/// gated on the dedicated `Permissions::VETO` bit so that guardians can be
+/// appointed without also granting them the power to author or execute changes.
+#[derive(Accounts)]
+pub struct VetoProposal<'info> {
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    #[account(
+        mut,
+        seeds = [
+            PROPOSAL_SEED,
+            governance_state.key().as_ref(),
+            &proposal.load()?.proposal_id.to_le_bytes()
+        ],
+        bump = proposal.load()?.bump
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+
+    pub vetoer: Signer<'info>,
+}
+
+pub fn veto_proposal(ctx: Context<VetoProposal>) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let governance_state = ctx.accounts.governance_state.load()?;
+    let mut proposal = ctx.accounts.proposal.load_mut()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+    require_keys_eq!(
+        proposal.governance,
+        ctx.accounts.governance_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    governance_state.check_member_permission(&ctx.accounts.vetoer.key(), Permissions::VETO)?;
+
+    proposal.veto(timestamp_now, governance_state.veto_period)?;
+
+    emit!(ProposalVetoed {
+        governance: ctx.accounts.governance_state.key(),
+        proposal: ctx.accounts.proposal.key(),
+        proposal_id: proposal.proposal_id,
+        vetoed_by: ctx.accounts.vetoer.key(),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use crate::error::StateError;
+use crate::state::governance_state::GovernanceState;
+use crate::state::oracle_state::OracleState;
+use crate::state::proposal::{Proposal, ProposalStatus};
+use crate::utils::constants::{GOVERNANCE_SEED, PROPOSAL_SEED};
+use crate::utils::events::ProposalVoteCast;
+use anchor_lang::prelude::*;
+
+/// Casts a stake-weighted vote on a pending proposal.
+///
+/// Any active member may vote regardless of permission bits; their influence is
+/// the stake they held when the proposal opened, not a role. The vote
+/// that lifts yes-stake over quorum approves the proposal and starts its veto
+/// window in the same transaction.
+#[derive(Accounts)]
+pub struct VoteProposal<'info> {
+    pub oracle_state: AccountLoader<'info, OracleState>,
+
+    #[account(
+        seeds = [GOVERNANCE_SEED, oracle_state.key().as_ref()],
+        bump
+    )]
+    pub governance_state: AccountLoader<'info, GovernanceState>,
+
+    #[account(
+        mut,
+        seeds = [
+            PROPOSAL_SEED,
+            governance_state.key().as_ref(),
+            &proposal.load()?.proposal_id.to_le_bytes()
+        ],
+        bump = proposal.load()?.bump
+    )]
+    pub proposal: AccountLoader<'info, Proposal>,
+
+    pub voter: Signer<'info>,
+}
+
+pub fn vote_proposal(ctx: Context<VoteProposal>, approve: bool) -> Result<()> {
+    let timestamp_now = Clock::get()?.unix_timestamp;
+
+    let governance_state = ctx.accounts.governance_state.load()?;
+    let mut proposal = ctx.accounts.proposal.load_mut()?;
+
+    require_keys_eq!(
+        governance_state.oracle_state,
+        ctx.accounts.oracle_state.key(),
+        StateError::UnauthorizedCaller
+    );
+    require_keys_eq!(
+        proposal.governance,
+        ctx.accounts.governance_state.key(),
+        StateError::UnauthorizedCaller
+    );
+
+    let voter = ctx.accounts.voter.key();
+    proposal.record_vote(&governance_state, &voter, approve, timestamp_now)?;
+    let stake = proposal
+        .electorate_slot(&voter)
+        .map_or(0, |slot| proposal.electorate_stakes[slot]);
+
+    emit!(ProposalVoteCast {
+        governance: ctx.accounts.governance_state.key(),
+        proposal: ctx.accounts.proposal.key(),
+        voter,
+        approve,
+        stake,
+        yes_stake: proposal.yes_stake,
+        no_stake: proposal.no_stake,
+        approved: proposal.get_status() == Some(ProposalStatus::Approved),
+        timestamp: timestamp_now,
+    });
+
+    Ok(())
+}
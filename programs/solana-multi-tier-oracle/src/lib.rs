@@ -7,7 +7,11 @@ pub mod instructions;
 pub mod state;
 pub mod utils;
 
+use components::raydium_clmm_observer::fetch_raydium_price::ValidationPolicy;
 use instructions::*;
+use state::governance_state::GovernanceParams;
+use state::oracle_state::{OracleSummary, PriceData, PriceReading, UpdaterPolicy};
+use state::snapshot_status::SnapshotStatusCode;
 
 declare_id!("4CVNsAY1CA9nANqBGJ4BBJAcUvPR2eTbidLu3nMewPad");
 
@@ -19,6 +23,18 @@ pub mod solana_multi_tier_oracle {
         instructions::initialize_oracle::initialize_oracle(ctx, config)
     }
 
+    pub fn initialize_oracle_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InitializeOracleBatch<'info>>,
+        entries: Vec<BatchOracleEntry>,
+        governance_config: GovernanceConfig,
+    ) -> Result<()> {
+        instructions::initialize_oracle_batch::initialize_oracle_batch(
+            ctx,
+            entries,
+            governance_config,
+        )
+    }
+
     pub fn register_price_feed(
         ctx: Context<RegisterPriceFeed>,
         feed_config: PriceFeedConfig,
@@ -26,7 +42,203 @@ pub mod solana_multi_tier_oracle {
         instructions::register_price_feed::register_price_feed(ctx, feed_config)
     }
 
-    pub fn update_price(ctx: Context<UpdatePrice>, config: UpdatePriceConfig) -> Result<()> {
+    pub fn update_price(
+        ctx: Context<UpdatePrice>,
+        config: UpdatePriceConfig,
+    ) -> Result<Option<PriceData>> {
         instructions::update_price::update_price(ctx, config)
     }
+
+    pub fn set_member_stake(
+        ctx: Context<SetMemberStake>,
+        member: Pubkey,
+        stake: u64,
+    ) -> Result<()> {
+        instructions::set_member_stake::set_member_stake(ctx, member, stake)
+    }
+
+    pub fn set_governance_params(
+        ctx: Context<SetGovernanceParams>,
+        params: GovernanceParams,
+    ) -> Result<()> {
+        instructions::set_governance_params::set_governance_params(ctx, params)
+    }
+
+    pub fn create_proposal(ctx: Context<CreateProposal>, proposal_id: u64) -> Result<()> {
+        instructions::create_proposal::create_proposal(ctx, proposal_id)
+    }
+
+    pub fn vote_proposal(ctx: Context<VoteProposal>, approve: bool) -> Result<()> {
+        instructions::vote_proposal::vote_proposal(ctx, approve)
+    }
+
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        instructions::execute_proposal::execute_proposal(ctx)
+    }
+
+    pub fn veto_proposal(ctx: Context<VetoProposal>) -> Result<()> {
+        instructions::veto_proposal::veto_proposal(ctx)
+    }
+
+    pub fn set_maintenance(ctx: Context<SetMaintenance>, enabled: bool) -> Result<()> {
+        instructions::set_maintenance::set_maintenance(ctx, enabled)
+    }
+
+    pub fn set_relaxed_min_elapsed(
+        ctx: Context<SetRelaxedMinElapsed>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_relaxed_min_elapsed::set_relaxed_min_elapsed(ctx, enabled)
+    }
+
+    pub fn set_spot_fallback(ctx: Context<SetSpotFallback>, enabled: bool) -> Result<()> {
+        instructions::set_spot_fallback::set_spot_fallback(ctx, enabled)
+    }
+
+    pub fn migrate_oracle_state(
+        ctx: Context<MigrateOracleState>,
+        canonical_expo: i32,
+    ) -> Result<()> {
+        instructions::migrate_oracle_state::migrate_oracle_state(ctx, canonical_expo)
+    }
+
+    pub fn get_price(
+        ctx: Context<GetPrice>,
+        max_age: u32,
+        target_expo: Option<i32>,
+    ) -> Result<PriceReading> {
+        instructions::get_price::get_price(ctx, max_age, target_expo)
+    }
+
+    pub fn get_summary(ctx: Context<GetSummary>) -> Result<OracleSummary> {
+        instructions::get_summary::get_summary(ctx)
+    }
+
+    pub fn assess_current_manipulation(
+        ctx: Context<AssessCurrentManipulation>,
+        config: AssessManipulationConfig,
+    ) -> Result<ManipulationAssessment> {
+        instructions::assess_current_manipulation::assess_current_manipulation(ctx, config)
+    }
+
+    pub fn remove_member(ctx: Context<RemoveMember>, member: Pubkey) -> Result<()> {
+        instructions::remove_member::remove_member(ctx, member)
+    }
+
+    pub fn set_manipulation_decay(
+        ctx: Context<SetManipulationDecay>,
+        cooldown: u32,
+        decay_period: u32,
+    ) -> Result<()> {
+        instructions::set_manipulation_decay::set_manipulation_decay(ctx, cooldown, decay_period)
+    }
+
+    pub fn set_escalation_ladder(
+        ctx: Context<SetEscalationLadder>,
+        ladder: [u16; 2],
+    ) -> Result<()> {
+        instructions::set_escalation_ladder::set_escalation_ladder(ctx, ladder)
+    }
+
+    pub fn set_source_thresholds(
+        ctx: Context<SetSourceThresholds>,
+        thresholds_bps: [u16; 4],
+    ) -> Result<()> {
+        instructions::set_source_thresholds::set_source_thresholds(ctx, thresholds_bps)
+    }
+
+    pub fn set_feed_spread(ctx: Context<SetFeedSpread>, max_spread_bps: u16) -> Result<()> {
+        instructions::set_feed_spread::set_feed_spread(ctx, max_spread_bps)
+    }
+
+    pub fn set_liquidity_drop_bound(
+        ctx: Context<SetLiquidityDropBound>,
+        max_drop_bps: u16,
+    ) -> Result<()> {
+        instructions::set_liquidity_drop_bound::set_liquidity_drop_bound(ctx, max_drop_bps)
+    }
+
+    pub fn set_min_observations(
+        ctx: Context<SetMinObservations>,
+        min_observations: u16,
+    ) -> Result<()> {
+        instructions::set_min_observations::set_min_observations(ctx, min_observations)
+    }
+
+    pub fn set_tier_config(
+        ctx: Context<SetTierConfig>,
+        weights_bps: [u16; 3],
+        max_spread_bps: u16,
+    ) -> Result<()> {
+        instructions::set_tier_config::set_tier_config(ctx, weights_bps, max_spread_bps)
+    }
+
+    pub fn set_update_cooldown(ctx: Context<SetUpdateCooldown>, cooldown: u32) -> Result<()> {
+        instructions::set_update_cooldown::set_update_cooldown(ctx, cooldown)
+    }
+
+    pub fn set_validation_policy(
+        ctx: Context<SetValidationPolicy>,
+        policy: ValidationPolicy,
+    ) -> Result<()> {
+        instructions::set_validation_policy::set_validation_policy(ctx, policy)
+    }
+
+    pub fn manage_updaters(
+        ctx: Context<ManageUpdaters>,
+        policy: UpdaterPolicy,
+        updaters: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::manage_updaters::manage_updaters(ctx, policy, updaters)
+    }
+
+    pub fn close_historical_chunk(
+        ctx: Context<CloseHistoricalChunk>,
+        chunk_index: u8,
+    ) -> Result<()> {
+        instructions::close_historical_chunk::close_historical_chunk(ctx, chunk_index)
+    }
+
+    pub fn repair_chunk_links<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RepairChunkLinks<'info>>,
+    ) -> Result<()> {
+        instructions::repair_chunk_links::repair_chunk_links(ctx)
+    }
+
+    pub fn ramp_twap_window(
+        ctx: Context<RampTwapWindow>,
+        target_window: u32,
+        ramp_duration: u32,
+    ) -> Result<()> {
+        instructions::ramp_twap_window::ramp_twap_window(ctx, target_window, ramp_duration)
+    }
+
+    pub fn transfer_emergency_admin(
+        ctx: Context<TransferEmergencyAdmin>,
+        new_admin: Pubkey,
+    ) -> Result<()> {
+        instructions::transfer_emergency_admin::transfer_emergency_admin(ctx, new_admin)
+    }
+
+    pub fn emergency_kill_switch(ctx: Context<EmergencyKillSwitch>) -> Result<()> {
+        instructions::emergency_kill_switch::emergency_kill_switch(ctx)
+    }
+
+    pub fn resume_oracle(ctx: Context<ResumeOracle>) -> Result<()> {
+        instructions::resume_oracle::resume_oracle(ctx)
+    }
+
+    pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+        instructions::heartbeat::heartbeat(ctx)
+    }
+
+    pub fn check_redemption_eligibility(
+        ctx: Context<CheckRedemptionEligibility>,
+        required_hours: u16,
+    ) -> Result<SnapshotStatusCode> {
+        instructions::check_redemption_eligibility::check_redemption_eligibility(
+            ctx,
+            required_hours,
+        )
+    }
 }
@@ -1,6 +1,7 @@
 use crate::error::StateError;
-use crate::utils::constants::{MAX_ALLOWED_PROGRAMS, MAX_MULTISIG_MEMBERS};
+use crate::utils::constants::{MAX_ALLOWED_PROGRAMS, MAX_MULTISIG_MEMBERS, MAX_QUORUM_THRESHOLD};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
 use bytemuck::{Pod, Zeroable};
 
 /// Multi-signature governance state with granular permission management for oracle operations.
@@ -89,9 +90,63 @@ pub struct GovernanceState {
     /// Parallel array structure optimizes cache locality for permission checks.
     pub member_permissions: [Permissions; MAX_MULTISIG_MEMBERS],
 
+    /// Recorded governance stake for each multisig member, parallel to `multisig_members`.
+    /// Votes are weighted by these values so influence tracks economic commitment
+    /// rather than headcount.
+    pub member_stakes: [u64; MAX_MULTISIG_MEMBERS],
+
     /// Reserved space for future governance features without breaking changes.
     /// Sized to accommodate common governance extensions while maintaining rent exemption.
-    pub reserved: [u8; 512],
+    pub reserved: [u8; 384],
+}
+
+/// Proposal timing and threshold parameters that governance may retune after bootstrap.
+///
+/// Grouped so an update is applied as one validated unit: checking, say, a new
+/// `execution_delay` in isolation from the `veto_period` it will run alongside
+/// would let two individually valid calls assemble a combination no single
+/// review ever saw.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GovernanceParams {
+    pub voting_period: i64,
+    pub execution_delay: i64,
+    pub timelock_duration: i64,
+    pub veto_period: i64,
+    pub quorum_threshold: u16,
+    pub proposal_threshold: u64,
+}
+
+impl GovernanceParams {
+    /// Bounds shared by bootstrap and later updates.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidTimingParameters`: `voting_period` is not positive, or any of
+    ///   `execution_delay`, `timelock_duration`, `veto_period` is negative
+    /// - `InvalidQuorumThreshold`: quorum is zero or above `MAX_QUORUM_THRESHOLD`
+    /// - `InvalidProposalThreshold`: a zero stake barrier would let anyone propose
+    ///
+    /// A zero delay or veto period is allowed; it only removes a safety window,
+    /// which is a policy choice rather than a broken configuration.
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.voting_period > 0
+                && self.execution_delay >= 0
+                && self.timelock_duration >= 0
+                && self.veto_period >= 0,
+            StateError::InvalidTimingParameters
+        );
+        require!(
+            self.quorum_threshold > 0 && self.quorum_threshold <= MAX_QUORUM_THRESHOLD,
+            StateError::InvalidQuorumThreshold
+        );
+        require!(
+            self.proposal_threshold > 0,
+            StateError::InvalidProposalThreshold
+        );
+
+        Ok(())
+    }
 }
 
 /// Compact bitfield for governance permission flags with zero-copy performance.
@@ -155,6 +210,11 @@ impl Permissions {
     /// Administrative permission for oracle maintenance and source quality management.
     pub const REMOVE_FEED: Self = Self(0b0100_0000);
 
+    /// Allows cancelling approved proposals during their veto window.
+    /// Deliberately kept out of ADMIN_ALL so the veto acts as an independent check
+    /// on the members who approve and execute proposals.
+    pub const VETO: Self = Self(0b1000_0000);
+
     /// Comprehensive administrative role combining all management capabilities.
     /// Intentionally excludes VIEW_METRICS to demonstrate role composition patterns.
     /// Designed for full system administrators who need complete operational control.
@@ -172,6 +232,19 @@ impl Permissions {
     /// Designed for operators who manage day-to-day oracle operations without full control.
     pub const OPERATOR_ALL: Self = Self(Self::UPDATE_PRICE.0 | Self::VIEW_METRICS.0);
 
+    /// Incident-response role for members who watch the oracle and stop it.
+    /// Can trip the circuit breaker or halt outright, but cannot publish prices,
+    /// retune configuration, or change feeds, so a compromised risk key can
+    /// only pause the oracle, never steer it.
+    pub const RISK_MANAGER: Self =
+        Self(Self::VIEW_METRICS.0 | Self::TRIGGER_CIRCUIT_BREAKER.0 | Self::EMERGENCY_HALT.0);
+
+    /// Source-curation role for members who maintain the feed set.
+    /// Registers and retires feeds with monitoring access to judge their quality,
+    /// without the configuration or emergency powers that belong to admins.
+    pub const FEED_MANAGER: Self =
+        Self(Self::ADD_FEED.0 | Self::REMOVE_FEED.0 | Self::VIEW_METRICS.0);
+
     /// Validation mask for all currently recognized permission bits.
     /// Used for forward-compatible deserialization that ignores future permission additions.
     pub const VALID_MASK: u64 = Self::UPDATE_PRICE.0
@@ -180,7 +253,8 @@ impl Permissions {
         | Self::VIEW_METRICS.0
         | Self::EMERGENCY_HALT.0
         | Self::ADD_FEED.0
-        | Self::REMOVE_FEED.0;
+        | Self::REMOVE_FEED.0
+        | Self::VETO.0;
 
     /// Creates empty permission set with no capabilities enabled.
     /// const fn enables compile-time initialization for secure default states.
@@ -289,6 +363,11 @@ impl Permissions {
         self.has(Self::REMOVE_FEED)
     }
 
+    #[inline(always)]
+    pub fn can_veto(self) -> bool {
+        self.has(Self::VETO)
+    }
+
     /// Verifies complete administrative role membership.
     /// Used for operations that require full administrative privileges.
     #[inline(always)]
@@ -303,6 +382,18 @@ impl Permissions {
         self.has_all(Self::OPERATOR_ALL)
     }
 
+    /// Verifies the complete incident-response role.
+    #[inline(always)]
+    pub fn is_risk_manager(self) -> bool {
+        self.has_all(Self::RISK_MANAGER)
+    }
+
+    /// Verifies the complete feed-curation role.
+    #[inline(always)]
+    pub fn is_feed_manager(self) -> bool {
+        self.has_all(Self::FEED_MANAGER)
+    }
+
     /// Composes custom roles by combining base role with additional permissions.
     /// Enables flexible role creation without hardcoding every possible combination.
     /// const fn allows compile-time role composition for optimal performance.
@@ -389,6 +480,10 @@ impl GovernanceState {
     /// Permission revocation requires the same security guarantees as granting to prevent
     /// privilege escalation through invalid member targeting. The bounds check ensures
     /// attackers cannot corrupt governance state by targeting invalid member indices.
+    ///
+    /// Stripping any `ADMIN_ALL` bit from the only admin is refused with
+    /// `CannotRemoveLastAdmin`: the member stays, but nobody could repair the
+    /// configuration afterwards, which is the same lockout as removing them.
     pub fn revoke_member_permission(
         &mut self,
         member_index: usize,
@@ -399,10 +494,29 @@ impl GovernanceState {
             StateError::UnauthorizedCaller
         );
 
-        self.member_permissions[member_index].revoke(permission);
+        let mut revoked = self.member_permissions[member_index];
+        revoked.revoke(permission);
+        let loses_admin = self.member_permissions[member_index].is_admin() && !revoked.is_admin();
+        require!(
+            !loses_admin || self.active_admin_count() > 1,
+            StateError::CannotRemoveLastAdmin
+        );
+
+        self.member_permissions[member_index] = revoked;
         Ok(())
     }
 
+    /// Number of active members holding the full `ADMIN_ALL` permission set.
+    ///
+    /// Inactive slots are never counted, so stale permissions left beyond
+    /// `active_member_count` cannot mask a governance with no reachable admin.
+    pub fn active_admin_count(&self) -> u8 {
+        self.member_permissions[..self.active_member_count as usize]
+            .iter()
+            .filter(|permissions| permissions.is_admin())
+            .count() as u8
+    }
+
     /// Retrieves permission set for a member with safe indexing.
     ///
     /// # Return Strategy
@@ -480,4 +594,330 @@ impl GovernanceState {
             Err(StateError::UnauthorizedCaller.into())
         }
     }
+
+    /// Authorizes an emergency halt on a single signature.
+    ///
+    /// # Why a Separate Gate
+    ///
+    /// Configuration, feed, and membership changes are threshold-gated: no one
+    /// member can push them through, because every one of them can steer the
+    /// published price. Halting only stops the oracle, and an incident needs it
+    /// stopped before a quorum can be gathered. Any active member holding
+    /// `EMERGENCY_HALT` may therefore act alone, as may the oracle's
+    /// `emergency_admin`, who need not be a member at all.
+    ///
+    /// Passing this check grants nothing but the halt; callers must not use it
+    /// to authorize any operation that resumes or reconfigures the oracle.
+    ///
+    /// # Errors
+    ///
+    /// - `UnauthorizedCaller`: neither the emergency admin nor an active member
+    /// - `InsufficientPermissions`: an active member without `EMERGENCY_HALT`
+    pub fn check_emergency_permission(
+        &self,
+        caller: &Pubkey,
+        emergency_admin: &Pubkey,
+    ) -> Result<()> {
+        if *emergency_admin != Pubkey::default() && caller == emergency_admin {
+            return Ok(());
+        }
+        self.check_member_permission(caller, Permissions::EMERGENCY_HALT)
+    }
+
+    /// Multisig threshold and the active members able to sign for `permission`.
+    ///
+    /// # Client Tooling
+    ///
+    /// Assembling a multisig transaction needs both numbers at once: how many
+    /// signatures the threshold demands and which keys can supply them. Holders
+    /// are matched with `has`, the same reading `check_member_permission` applies,
+    /// and listed in member-slot order.
+    ///
+    /// A holder list shorter than the threshold is returned as-is rather than
+    /// rejected; it tells the caller no valid signer set exists for that permission.
+    /// The list is bounded by `MAX_MULTISIG_MEMBERS`, so the allocation stays small
+    /// enough for on-chain use.
+    pub fn required_signers(&self, permission: Permissions) -> (u8, Vec<Pubkey>) {
+        let active = self.active_member_count as usize;
+        let holders = self.multisig_members[..active]
+            .iter()
+            .zip(&self.member_permissions[..active])
+            .filter(|(_, permissions)| permissions.has(permission))
+            .map(|(member, _)| *member)
+            .collect();
+
+        (self.multi_sig_threshold, holders)
+    }
+
+    /// Requires at least `multi_sig_threshold` distinct active members holding
+    /// `permission` among `signers`.
+    ///
+    /// # Threshold-Gated Changes
+    ///
+    /// Some changes move power between members rather than configuring the
+    /// oracle, so letting one permission holder make them alone would let that
+    /// holder vote themselves into control. Keys that are not members, lack the
+    /// permission, or appear more than once count at most once and only if they
+    /// qualify; callers pass the transaction's signer keys as-is.
+    ///
+    /// # Errors
+    ///
+    /// - `InsufficientSigners`: fewer qualifying signers than the threshold
+    pub fn check_threshold_signers(
+        &self,
+        signers: &[Pubkey],
+        permission: Permissions,
+    ) -> Result<()> {
+        let (threshold, holders) = self.required_signers(permission);
+        let signed = holders
+            .iter()
+            .filter(|holder| signers.contains(holder))
+            .count();
+        require!(
+            signed >= threshold as usize,
+            StateError::InsufficientSigners
+        );
+        Ok(())
+    }
+
+    /// Requires `member_key` to hold every `ADMIN_ALL` permission.
+    ///
+    /// `check_member_permission` passes when any requested bit is present, which
+    /// is the right reading for a single permission but would let an operator
+    /// satisfy a check against the full admin set.
+    pub fn check_admin(&self, member_key: &Pubkey) -> Result<()> {
+        let (_, permissions) = self
+            .find_member(member_key)
+            .ok_or(StateError::UnauthorizedCaller)?;
+        require!(permissions.is_admin(), StateError::InsufficientPermissions);
+        Ok(())
+    }
+
+    /// Keccak digest of the full governance configuration, excluding `reserved`.
+    ///
+    /// # Audit Pinning
+    ///
+    /// Monitoring records the digest emitted by each governance-mutating event and
+    /// recomputes it from account data between blocks; any mismatch means the
+    /// account changed without going through an instruction that announced it.
+    ///
+    /// # Canonical Encoding
+    ///
+    /// Fields are hashed in declaration order as little-endian bytes, with every
+    /// array included in full rather than only its active prefix, so a stray
+    /// write to an unused slot changes the digest too. `reserved` is left out so
+    /// carving new fields from it only changes the digest once they are used.
+    pub fn config_digest(&self) -> [u8; 32] {
+        keccak::hashv(&[
+            &self.proposal_threshold.to_le_bytes(),
+            &self.voting_period.to_le_bytes(),
+            &self.execution_delay.to_le_bytes(),
+            &self.timelock_duration.to_le_bytes(),
+            &self.veto_period.to_le_bytes(),
+            &self.quorum_threshold.to_le_bytes(),
+            &[
+                self.multi_sig_threshold,
+                self.active_member_count,
+                self.bump,
+                self.strict_mode_enabled,
+                self.allowed_dex_program_count,
+                self.allowed_aggregator_program_count,
+            ],
+            bytemuck::bytes_of(&self.allowed_dex_programs),
+            bytemuck::bytes_of(&self.allowed_aggregator_programs),
+            self.oracle_state.as_ref(),
+            bytemuck::bytes_of(&self.multisig_members),
+            bytemuck::bytes_of(&self.member_permissions),
+            bytemuck::bytes_of(&self.member_stakes),
+        ])
+        .0
+    }
+
+    /// Records the governance stake backing a member's votes.
+    ///
+    /// Shares the bounds check used by permission grants so stake can never be
+    /// attributed to an inactive slot, where it would silently inflate the total
+    /// stake used as the quorum denominator.
+    ///
+    /// # Total Stake Cap
+    ///
+    /// Proposals tally votes in `u64`, so the active members' combined stake is
+    /// kept within `u64::MAX`. A proposal's electorate is a snapshot of that
+    /// total, which lets every tally accept all of its voters' stake.
+    ///
+    /// # Errors
+    ///
+    /// - `UnauthorizedCaller`: `member_index` is not an active slot
+    /// - `ExcessiveTotalStake`: the new stake would push the total past `u64::MAX`
+    pub fn set_member_stake(&mut self, member_index: usize, stake: u64) -> Result<()> {
+        require!(
+            member_index < self.active_member_count as usize,
+            StateError::UnauthorizedCaller
+        );
+        let others = self.total_active_stake() - self.member_stakes[member_index] as u128;
+        require!(
+            others + stake as u128 <= u64::MAX as u128,
+            StateError::ExcessiveTotalStake
+        );
+
+        self.member_stakes[member_index] = stake;
+        Ok(())
+    }
+
+    /// Current proposal timing and threshold parameters.
+    pub fn params(&self) -> GovernanceParams {
+        GovernanceParams {
+            voting_period: self.voting_period,
+            execution_delay: self.execution_delay,
+            timelock_duration: self.timelock_duration,
+            veto_period: self.veto_period,
+            quorum_threshold: self.quorum_threshold,
+            proposal_threshold: self.proposal_threshold,
+        }
+    }
+
+    /// Replaces the proposal timing and threshold parameters after validating them.
+    ///
+    /// Nothing is written unless every bound holds, so a rejected update leaves
+    /// the previous parameters fully in force. Open proposals pick up the new
+    /// values at their next vote, veto or execution, exactly as they already do
+    /// for stake rebalancing.
+    pub fn set_params(&mut self, params: &GovernanceParams) -> Result<()> {
+        params.validate()?;
+
+        self.voting_period = params.voting_period;
+        self.execution_delay = params.execution_delay;
+        self.timelock_duration = params.timelock_duration;
+        self.veto_period = params.veto_period;
+        self.quorum_threshold = params.quorum_threshold;
+        self.proposal_threshold = params.proposal_threshold;
+        Ok(())
+    }
+
+    /// Sum of stake held by active members, the denominator for quorum decisions.
+    ///
+    /// Accumulated in u128 so sixteen maximal u64 stakes cannot overflow.
+    pub fn total_active_stake(&self) -> u128 {
+        self.member_stakes[..self.active_member_count as usize]
+            .iter()
+            .map(|stake| *stake as u128)
+            .sum()
+    }
+
+    /// Returns true when `yes_stake` reaches `quorum_threshold` basis points of total stake.
+    ///
+    /// # Integer Math
+    ///
+    /// The comparison is cross-multiplied (`yes * 10_000 >= total * quorum`) instead
+    /// of dividing, so no rounding can round a just-short tally up to quorum. A
+    /// governance with zero recorded stake can never reach quorum, which keeps an
+    /// uninitialised stake table from approving anything by default.
+    pub fn is_stake_quorum_met(&self, yes_stake: u64) -> bool {
+        self.is_stake_quorum_met_of(yes_stake, self.total_active_stake())
+    }
+
+    /// Quorum test of `is_stake_quorum_met` against an explicit `total_stake`,
+    /// such as the stake a proposal froze when it opened.
+    pub fn is_stake_quorum_met_of(&self, yes_stake: u64, total_stake: u128) -> bool {
+        if total_stake == 0 {
+            return false;
+        }
+
+        (yes_stake as u128) * (MAX_QUORUM_THRESHOLD as u128)
+            >= total_stake * (self.quorum_threshold as u128)
+    }
+
+    /// Returns true when `votes_cast` members reach `quorum_threshold` basis points of the
+    /// active membership.
+    ///
+    /// Head-count counterpart of `is_stake_quorum_met`, cross-multiplied the same way
+    /// so a tally one vote short is never rounded up to quorum. Votes beyond the
+    /// active member count are ignored rather than allowed to inflate participation,
+    /// and a governance with no active members can never reach quorum.
+    pub fn is_quorum_met(&self, votes_cast: u8) -> bool {
+        let members = self.active_member_count as u32;
+        if members == 0 {
+            return false;
+        }
+
+        let votes = (votes_cast as u32).min(members);
+        votes * MAX_QUORUM_THRESHOLD as u32 >= members * self.quorum_threshold as u32
+    }
+
+    /// Verifies that removing the member at `member_index` leaves governance operable.
+    ///
+    /// # Liveness Invariants
+    ///
+    /// Two conditions must survive any removal, otherwise governance bricks itself
+    /// with no on-chain path to recovery:
+    ///
+    /// - **Threshold satisfiable**: at least `multi_sig_threshold` members remain, so
+    ///   a multisig action can still collect enough signers
+    /// - **Admin present**: at least one remaining member holds `ADMIN_ALL`, so the
+    ///   configuration can still be repaired
+    ///
+    /// The threshold is checked first because it is the stricter structural failure;
+    /// a removal that breaks both reports `RemovalBreaksThreshold`.
+    pub fn can_remove_member(&self, member_index: usize) -> Result<()> {
+        let active = self.active_member_count as usize;
+        require!(member_index < active, StateError::UnauthorizedCaller);
+
+        let remaining = active - 1;
+        require!(
+            remaining >= self.multi_sig_threshold as usize,
+            StateError::RemovalBreaksThreshold
+        );
+
+        let removed_admins = self.member_permissions[member_index].is_admin() as u8;
+        require!(
+            self.active_admin_count() > removed_admins,
+            StateError::CannotRemoveLastAdmin
+        );
+
+        Ok(())
+    }
+
+    /// Removes an active member after `can_remove_member` approves it.
+    ///
+    /// # Slot Compaction
+    ///
+    /// Active members must stay contiguous at the front of the arrays because every
+    /// lookup is bounded by `active_member_count`. The last active member is moved
+    /// into the vacated slot and the freed tail slot is zeroed, so no stale key,
+    /// permission or stake lingers where a later `set_active_member_count` could
+    /// reactivate it.
+    ///
+    /// Open proposals are unaffected by the move: each records votes against the
+    /// member keys it froze at creation, not against these slot indices.
+    pub fn remove_member(&mut self, member_index: usize) -> Result<()> {
+        self.can_remove_member(member_index)?;
+
+        let last = self.active_member_count as usize - 1;
+        self.multisig_members[member_index] = self.multisig_members[last];
+        self.member_permissions[member_index] = self.member_permissions[last];
+        self.member_stakes[member_index] = self.member_stakes[last];
+
+        self.multisig_members[last] = Pubkey::default();
+        self.member_permissions[last] = Permissions::new();
+        self.member_stakes[last] = 0;
+        self.active_member_count -= 1;
+
+        Ok(())
+    }
+
+    /// Resolves a would-be proposer to their member slot if they clear `proposal_threshold`.
+    ///
+    /// Proposal creation is the one governance action gated purely on stake rather
+    /// than a permission bit: any member with enough skin in the game may open a
+    /// proposal, which is the economic spam barrier `GovernanceConfig` describes.
+    pub fn check_proposer(&self, member_key: &Pubkey) -> Result<usize> {
+        let (index, _) = self
+            .find_member(member_key)
+            .ok_or(StateError::UnauthorizedCaller)?;
+        require!(
+            self.member_stakes[index] >= self.proposal_threshold,
+            StateError::InsufficientProposerStake
+        );
+        Ok(index)
+    }
 }
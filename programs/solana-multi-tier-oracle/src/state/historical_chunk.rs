@@ -1,4 +1,5 @@
-use crate::utils::constants::BUFFER_SIZE;
+use crate::error::StateError;
+use crate::utils::constants::{BUFFER_SIZE, PRICE_POINT_LAYOUT_LEGACY, PRICE_POINT_LAYOUT_VERSION};
 use anchor_lang::prelude::*;
 use bytemuck::{Pod, Zeroable};
 
@@ -71,9 +72,25 @@ pub struct HistoricalChunk {
     /// Bump seed used for PDA derivation of this account.
     pub bump: u8,
 
+    /// One bit per `price_points` slot, set when that slot holds a point pushed
+    /// off-schedule because the price moved sharply.
+    ///
+    /// Forced points sharpen the TWAP's view of a fast move but are not part of
+    /// the 15-minute sampling cadence, so redemption checks skip them when
+    /// counting snapshots and measuring hourly density. Carved out of the
+    /// reserved tail, leaving the account size unchanged.
+    pub forced_points: [u8; BUFFER_SIZE / 8],
+
+    /// Layout of the entries in `price_points`, read through `point_at`.
+    ///
+    /// `PRICE_POINT_LAYOUT_LEGACY` (zero) marks a chunk created before the tag
+    /// existed, whose points are decoded by `PricePoint::from_legacy_bytes`.
+    /// Chunks initialized by this program carry `PRICE_POINT_LAYOUT_VERSION`.
+    pub point_layout: u8,
+
     /// Reserved space for future schema evolution without breaking changes.
     /// Prevents need for complex data migration when adding new functionality.
-    pub reserved: [u8; 511],
+    pub reserved: [u8; 494],
 }
 
 /// Individual price data point optimized for historical storage and analysis.
@@ -127,7 +144,97 @@ pub struct PricePoint {
     pub timestamp: i64,
 }
 
+impl PricePoint {
+    /// Encoded size of a point in `PRICE_POINT_LAYOUT_LEGACY`.
+    pub const LEGACY_SIZE: usize = 48;
+
+    /// Decodes a point stored in `PRICE_POINT_LAYOUT_LEGACY`.
+    ///
+    /// # Schema Evolution
+    ///
+    /// The legacy record is `price`, `volume`, `conf` and `timestamp` in that
+    /// order, little-endian and unpadded. Today's struct is byte-identical, but
+    /// the decode reads each field at its legacy offset rather than casting, so
+    /// once a later layout inserts or widens a field, old chunks keep decoding
+    /// correctly and any field the legacy record lacks is set to its default
+    /// here.
+    pub fn from_legacy_bytes(bytes: &[u8; Self::LEGACY_SIZE]) -> Self {
+        let (price, rest) = bytes.split_at(16);
+        let (volume, rest) = rest.split_at(16);
+        let (conf, timestamp) = rest.split_at(8);
+        // Every split above is of a fixed-size array, so the conversions cannot fail
+        Self {
+            price: i128::from_le_bytes(price.try_into().unwrap()),
+            volume: i128::from_le_bytes(volume.try_into().unwrap()),
+            conf: u64::from_le_bytes(conf.try_into().unwrap()),
+            timestamp: i64::from_le_bytes(timestamp.try_into().unwrap()),
+        }
+    }
+}
+
 impl HistoricalChunk {
+    /// Point stored at `slot`, decoded according to `point_layout`.
+    ///
+    /// Readers that must work across program upgrades go through this instead
+    /// of indexing `price_points`, which is only valid for the current layout.
+    /// A legacy chunk is read from the raw `price_points` bytes at
+    /// `slot * LEGACY_SIZE`, the stride it was written with.
+    ///
+    /// # Errors
+    ///
+    /// - `UnsupportedPointLayout`: the chunk carries a layout tag this program
+    ///   does not know, i.e. it was written by a newer version
+    pub fn point_at(&self, slot: usize) -> Result<PricePoint> {
+        let slot = slot % BUFFER_SIZE;
+        match self.point_layout {
+            PRICE_POINT_LAYOUT_VERSION => Ok(self.price_points[slot]),
+            PRICE_POINT_LAYOUT_LEGACY => {
+                // Legacy records are packed at their own stride from the start
+                // of the region, so the offset must not use today's stride. The
+                // region is never smaller than BUFFER_SIZE legacy records.
+                let offset = slot * PricePoint::LEGACY_SIZE;
+                let region = bytemuck::bytes_of(&self.price_points);
+                let legacy = region[offset..offset + PricePoint::LEGACY_SIZE]
+                    .try_into()
+                    .unwrap();
+                Ok(PricePoint::from_legacy_bytes(legacy))
+            }
+            _ => err!(StateError::UnsupportedPointLayout),
+        }
+    }
+
+    /// Rewrites a legacy chunk's points in the current layout and re-tags it.
+    ///
+    /// Writers call this before storing a point, so a chunk never holds points
+    /// of two layouts under one tag. Every slot is converted, including those
+    /// outside the retained window, which keeps `point_at` valid for any index.
+    /// A chunk already in the current layout is left untouched.
+    ///
+    /// # Errors
+    ///
+    /// - `UnsupportedPointLayout`: the chunk carries a layout tag this program
+    ///   does not know, so its points cannot be rewritten safely
+    pub fn upgrade_point_layout(&mut self) -> Result<()> {
+        match self.point_layout {
+            PRICE_POINT_LAYOUT_VERSION => Ok(()),
+            PRICE_POINT_LAYOUT_LEGACY => {
+                self.convert_legacy_points();
+                Ok(())
+            }
+            _ => err!(StateError::UnsupportedPointLayout),
+        }
+    }
+
+    fn convert_legacy_points(&mut self) {
+        let mut points = [PricePoint::default(); BUFFER_SIZE];
+        for (slot, point) in points.iter_mut().enumerate() {
+            // Legacy decoding cannot fail, only an unknown tag can
+            *point = self.point_at(slot).unwrap_or_default();
+        }
+        self.price_points = points;
+        self.point_layout = PRICE_POINT_LAYOUT_VERSION;
+    }
+
     /// Tests whether this chunk links to a subsequent chunk in the historical chain.
     ///
     /// This method enables efficient traversal of historical data across multiple accounts
@@ -138,6 +245,23 @@ impl HistoricalChunk {
         self.next_chunk != Pubkey::default()
     }
 
+    /// Confirms this chunk belongs to the oracle that is about to read or write it.
+    ///
+    /// # Defense in Depth
+    ///
+    /// Chunks are loaded by PDA, so a correct seed derivation already implies the
+    /// right owner. The stored back-reference is checked anyway because a seed bug
+    /// or derivation change would otherwise let one oracle silently fold another
+    /// oracle's history into its TWAP, and nothing downstream would notice.
+    pub fn verify_oracle_link(&self, oracle_key: &Pubkey) -> Result<()> {
+        require_keys_eq!(
+            self.oracle_state,
+            *oracle_key,
+            StateError::ChunkOracleMismatch
+        );
+        Ok(())
+    }
+
     /// Inserts a new price point using circular buffer semantics for O(1) performance.
     ///
     /// # Algorithm Design
@@ -160,9 +284,18 @@ impl HistoricalChunk {
     ///
     /// The bitwise AND operation for wraparound is only correct when BUFFER_SIZE is a
     /// power of 2. This constraint is enforced at compile time by the constants module.
+    ///
+    /// A legacy chunk is converted to the current layout before the write. A chunk
+    /// with an unknown layout tag is never reached from the update path, since
+    /// `push_checked` rejects it first.
     pub fn push(&mut self, point: PricePoint) {
+        if self.point_layout == PRICE_POINT_LAYOUT_LEGACY {
+            self.convert_legacy_points();
+        }
+
         // Overwrite the slot at head position - no need to shift existing elements
         self.price_points[self.head as usize] = point;
+        self.set_forced(self.head as usize, false);
 
         // Advance head with efficient bitwise wraparound (requires power-of-2 buffer size)
         self.head = (self.head + 1) & (BUFFER_SIZE as u16 - 1);
@@ -176,13 +309,95 @@ impl HistoricalChunk {
         }
     }
 
-    /// Retrieves the most recently inserted price point with zero-copy semantics.
+    /// Pushes a point only if it is not older than the chunk itself.
+    ///
+    /// Every point written through the update path is stamped with the clock at
+    /// update time, which can never precede the chunk's `creation_timestamp`. A
+    /// point that does is therefore backdated, typically by a faulty migration
+    /// replaying stale history, and would corrupt the time axis TWAP reads rely
+    /// on. It is rejected with `NonMonotonicTimestamps` and the chunk is left
+    /// unchanged. `push` stays unchecked for compaction, which moves points
+    /// between chunks with their original timestamps.
+    ///
+    /// A chunk with an unknown layout tag is rejected with
+    /// `UnsupportedPointLayout` rather than written in a layout it does not use.
+    pub fn push_checked(&mut self, point: PricePoint) -> Result<()> {
+        require!(
+            point.timestamp >= self.creation_timestamp,
+            StateError::NonMonotonicTimestamps
+        );
+        self.upgrade_point_layout()?;
+        self.push(point);
+        Ok(())
+    }
+
+    /// Pushes an off-schedule point under the same backdating guard as
+    /// `push_checked` and marks its slot as forced.
+    ///
+    /// The mark lives with the slot, so it is cleared as soon as a later push
+    /// overwrites that slot on wraparound.
+    pub fn push_forced(&mut self, point: PricePoint) -> Result<()> {
+        let slot = self.head as usize;
+        self.push_checked(point)?;
+        self.set_forced(slot, true);
+        Ok(())
+    }
+
+    /// Writes the bootstrap point of an oracle's history into an empty chunk.
+    ///
+    /// The first published price has no history to average, so it becomes the
+    /// start segment every later TWAP extends from until the cadence adds a
+    /// second point. Returns `false` and writes nothing when the chunk already
+    /// holds a point, so a retried or concurrent first run cannot seed twice.
+    pub fn seed(&mut self, point: PricePoint) -> Result<bool> {
+        if self.count > 0 {
+            return Ok(false);
+        }
+        self.push_checked(point)?;
+        Ok(true)
+    }
+
+    /// Whether the point in `slot` was pushed off-schedule.
+    #[inline]
+    pub fn is_forced(&self, slot: usize) -> bool {
+        self.forced_points[slot / 8] & (1 << (slot % 8)) != 0
+    }
+
+    /// Whether the most recent point was pushed off-schedule.
+    pub fn latest_is_forced(&self) -> bool {
+        self.latest_index().is_some_and(|slot| self.is_forced(slot))
+    }
+
+    /// The most recent point written on the regular schedule, skipping any
+    /// forced points pushed after it.
+    ///
+    /// The sampling cadence is measured from this point, so an off-schedule
+    /// push never delays the next scheduled one. Decoded through `point_at`;
+    /// `None` when the layout is unknown.
+    pub fn latest_scheduled(&self) -> Option<PricePoint> {
+        let newest = self.latest_index()?;
+        (0..self.count as usize)
+            .map(|back| (newest + BUFFER_SIZE - back) % BUFFER_SIZE)
+            .find(|&slot| !self.is_forced(slot))
+            .and_then(|slot| self.point_at(slot).ok())
+    }
+
+    fn set_forced(&mut self, slot: usize, forced: bool) {
+        let mask = 1 << (slot % 8);
+        if forced {
+            self.forced_points[slot / 8] |= mask;
+        } else {
+            self.forced_points[slot / 8] &= !mask;
+        }
+    }
+
+    /// Retrieves the most recently inserted price point.
     ///
     /// # Return Value Strategy
     ///
-    /// Returns `Option<&PricePoint>` rather than copying the data to avoid allocation
-    /// overhead. This enables efficient chaining of operations on the latest price
-    /// without heap pressure.
+    /// Returns the point decoded through `point_at` rather than a reference into
+    /// `price_points`, which would misread a legacy chunk. `None` when the chunk
+    /// is empty or its layout is unknown.
     ///
     /// # Index Calculation Rationale
     ///
@@ -190,18 +405,192 @@ impl HistoricalChunk {
     /// careful handling of the wraparound case when head = 0. The explicit conditional
     /// is more readable and equally efficient after compiler optimization compared to
     /// modular arithmetic approaches.
-    pub fn latest(&self) -> Option<&PricePoint> {
+    pub fn latest(&self) -> Option<PricePoint> {
+        self.latest_index()
+            .and_then(|index| self.point_at(index).ok())
+    }
+
+    /// Retrieves the oldest retained point, the FIFO front of the ring.
+    ///
+    /// Walks forward from `tail_index` past slots that were never written or
+    /// were zeroed by a reset, which hold a non-positive price or timestamp,
+    /// so the result is the first point a TWAP would actually use. Returns
+    /// `None` when no retained point is valid or the layout is unknown.
+    ///
+    /// Decoded through `point_at`, since the TWAP stream takes its cutoff from
+    /// this point and must agree with the points it then visits.
+    pub fn oldest(&self) -> Option<PricePoint> {
+        let tail = self.tail_index();
+        (0..self.count as usize)
+            .map_while(|offset| self.point_at(tail + offset).ok())
+            .find(|point| point.price > 0 && point.timestamp > 0)
+    }
+
+    /// Slot index of the most recently inserted point, `None` when empty.
+    fn latest_index(&self) -> Option<usize> {
         if self.count == 0 {
             None
         } else {
             // Calculate index of most recently inserted element
             // Handle wraparound case explicitly for clarity
-            let latest_index = if self.head == 0 {
+            Some(if self.head == 0 {
                 BUFFER_SIZE - 1 // Wrapped around, latest is at end of buffer
             } else {
                 (self.head - 1) as usize // Latest is immediately before head
-            };
-            Some(&self.price_points[latest_index])
+            })
+        }
+    }
+
+    /// Slot index of the oldest retained point.
+    ///
+    /// Derived from `head` and `count` rather than read from `tail`, so readers
+    /// that walk the ring agree with the write pointer even on chunks whose
+    /// `tail` was never maintained.
+    #[inline]
+    pub fn tail_index(&self) -> usize {
+        (self.head as usize + BUFFER_SIZE - self.count as usize) % BUFFER_SIZE
+    }
+
+    /// Seconds between the oldest and newest valid points, or `None` when the
+    /// chunk holds no point with a positive timestamp.
+    ///
+    /// Zeroed slots left behind by a reset or a partial migration are skipped at
+    /// both ends, matching the validity rule the TWAP stream applies. A chunk
+    /// with a single valid point spans `Some(0)`.
+    pub fn time_span(&self) -> Option<i64> {
+        let newest = self
+            .latest()
+            .filter(|point| point.timestamp > 0)
+            .map(|point| point.timestamp)
+            .or_else(|| self.valid_timestamps().last())?;
+        let oldest = self.valid_timestamps().next()?;
+        Some(newest.saturating_sub(oldest))
+    }
+
+    /// How densely the chunk samples its span, in basis points.
+    ///
+    /// # Density Model
+    ///
+    /// A chunk written every `expected_interval` seconds holds
+    /// `time_span / expected_interval + 1` points across its span; the ratio of
+    /// valid points actually held to that ideal count tells operators how much
+    /// of the window the keeper missed. Denser-than-expected history is capped at
+    /// 10,000 so the figure stays a coverage measure rather than a write rate.
+    ///
+    /// # Edge Cases
+    ///
+    /// An empty chunk, or a non-positive `expected_interval`, reports 0. A single
+    /// valid point has nothing to miss and reports full coverage.
+    pub fn coverage_ratio(&self, expected_interval: i64) -> u16 {
+        if expected_interval <= 0 {
+            return 0;
+        }
+        let Some(span) = self.time_span() else {
+            return 0;
+        };
+        let ideal = (span / expected_interval).saturating_add(1) as u64;
+        let actual = self.valid_timestamps().count() as u64;
+        (actual.saturating_mul(10_000) / ideal).min(10_000) as u16
+    }
+
+    /// Folds `other`'s points into this chunk, keeping chronological FIFO order.
+    ///
+    /// # Compaction Use
+    ///
+    /// Rotation can leave several partially filled chunks behind, each holding a
+    /// slice of history. Merging lets a compaction pass collapse them so TWAP
+    /// reads touch fewer accounts. `other` is left untouched; the caller decides
+    /// whether to reset or close it.
+    ///
+    /// # Ordering and Capacity
+    ///
+    /// Both FIFO views are already chronological, so a two-way merge by timestamp
+    /// yields the combined history; on equal timestamps this chunk's point comes
+    /// first. When the union exceeds `BUFFER_SIZE`, the oldest points are evicted,
+    /// exactly as repeated `push` calls would have done.
+    ///
+    /// # Backdated Points
+    ///
+    /// A point older than this chunk's `creation_timestamp` is dropped rather
+    /// than merged, the same invariant `push_checked` enforces on every write, so
+    /// compaction cannot move history into a chunk that postdates it.
+    ///
+    /// # Layout After Merge
+    ///
+    /// The result is rewritten from slot 0 with `tail = 0`, `count` set to the
+    /// retained length, and `head` one past the newest point. Unused slots are
+    /// zeroed so no stale point survives outside the `tail..tail + count` window.
+    /// Forced marks travel with their points, so a merged chunk still excludes
+    /// off-schedule points from redemption checks.
+    ///
+    /// Both chunks are read through `point_at`, and the result is written in
+    /// the current layout, so merging a legacy chunk converts it.
+    ///
+    /// Returns the number of points left out: backdated ones plus those evicted
+    /// to fit capacity.
+    ///
+    /// # Errors
+    ///
+    /// - `UnsupportedPointLayout`: either chunk carries an unknown layout tag;
+    ///   nothing is written
+    pub fn merge_from(&mut self, other: &HistoricalChunk) -> Result<usize> {
+        let offered = self.count as usize + other.count as usize;
+        let ours = self.fifo_points_since(self.creation_timestamp)?;
+        let theirs = other.fifo_points_since(self.creation_timestamp)?;
+
+        let mut merged = Vec::with_capacity(ours.len() + theirs.len());
+        let (mut i, mut j) = (0, 0);
+        while i < ours.len() && j < theirs.len() {
+            if theirs[j].0.timestamp < ours[i].0.timestamp {
+                merged.push(theirs[j]);
+                j += 1;
+            } else {
+                merged.push(ours[i]);
+                i += 1;
+            }
+        }
+        merged.extend_from_slice(&ours[i..]);
+        merged.extend_from_slice(&theirs[j..]);
+
+        let evicted = merged.len().saturating_sub(BUFFER_SIZE);
+        let retained = &merged[evicted..];
+        let left_out = offered - retained.len();
+
+        self.forced_points = [0; BUFFER_SIZE / 8];
+        for (slot, &(point, forced)) in retained.iter().enumerate() {
+            self.price_points[slot] = point;
+            self.set_forced(slot, forced);
         }
+        self.price_points[retained.len()..].fill(PricePoint::default());
+        self.point_layout = PRICE_POINT_LAYOUT_VERSION;
+        self.tail = 0;
+        self.count = retained.len() as u16;
+        self.head = (retained.len() & (BUFFER_SIZE - 1)) as u16;
+
+        Ok(left_out)
+    }
+
+    /// Copies the retained points stamped at or after `since` out in FIFO
+    /// order, oldest first, each with its forced mark.
+    fn fifo_points_since(&self, since: i64) -> Result<Vec<(PricePoint, bool)>> {
+        let tail = self.tail_index();
+        let mut points = Vec::with_capacity(self.count as usize);
+        for offset in 0..self.count as usize {
+            let slot = (tail + offset) % BUFFER_SIZE;
+            let point = self.point_at(slot)?;
+            if point.timestamp >= since {
+                points.push((point, self.is_forced(slot)));
+            }
+        }
+        Ok(points)
+    }
+
+    /// Timestamps of the retained points in FIFO order, skipping zeroed slots.
+    fn valid_timestamps(&self) -> impl Iterator<Item = i64> + '_ {
+        let tail = self.tail_index();
+        (0..self.count as usize)
+            .map_while(move |offset| self.point_at(tail + offset).ok())
+            .map(|point| point.timestamp)
+            .filter(|&timestamp| timestamp > 0)
     }
 }
@@ -0,0 +1,190 @@
+use crate::error::StateError;
+use crate::state::{
+    oracle_state::{OracleState, PriceData, StateFlags, UpdaterPolicy, Version},
+    price_feed::{rescale_price, FeedFlags, PriceFeed},
+};
+use crate::utils::constants::{
+    DEFAULT_MANIPULATION_COOLDOWN, DEFAULT_MANIPULATION_DECAY_PERIOD, MAX_FEED_EXPO,
+    MAX_HISTORICAL_CHUNKS, MAX_PRICE_FEEDS, MIN_FEED_EXPO,
+};
+use anchor_lang::prelude::*;
+use bytemuck::{Pod, Zeroable};
+
+/// `PriceFeed` as laid out by schema `Version::LEGACY`.
+///
+/// Field order and widths are frozen: this struct exists only to decode accounts
+/// written before the feed grew its smoothing, fallback and liquidity-history
+/// fields, and must never change again.
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+#[repr(C)]
+pub struct LegacyPriceFeed {
+    pub source_address: Pubkey,
+    pub last_price: i128,
+    pub volume_24h: i128,
+    pub liquidity_depth: i128,
+    pub last_conf: u64,
+    pub last_update: i64,
+    pub last_expo: i32,
+    pub weight: u16,
+    pub lp_concentration: u16,
+    pub manipulation_score: u16,
+    pub source_type: u8,
+    pub flags: FeedFlags,
+    pub _padding: [u8; 4],
+}
+
+impl LegacyPriceFeed {
+    /// The same feed in the current layout.
+    ///
+    /// A legacy feed's last reading is the only one it has, so it is treated as a
+    /// good one: `last_good_update` starts at `last_update`. Leaving it zero would
+    /// read as "never validated" and keep the feed out of the last-good-price
+    /// fallback until its next successful read.
+    ///
+    /// That same timestamp tells `record_price` the smoothed price is already
+    /// seeded, so `ewma_price` must start at `last_price` as well: left at zero,
+    /// the next update would blend from zero and drag the average far below the
+    /// market until enough updates have passed to wash the zero out.
+    ///
+    /// The reading is rescaled from its stored `last_expo` into `canonical_expo`,
+    /// which becomes the feed's exponent. v0.1 always stored zero there, and
+    /// `update_price` records every new reading in the feed's exponent, so a feed
+    /// left at zero would truncate each later reading to a whole number.
+    ///
+    /// # Errors
+    ///
+    /// - `MathOverflow`: the reading does not fit `canonical_expo`
+    pub fn upgrade(&self, canonical_expo: i32) -> Result<PriceFeed> {
+        let last_price = rescale_price(self.last_price, self.last_expo, canonical_expo)?;
+        Ok(PriceFeed {
+            source_address: self.source_address,
+            last_price,
+            ewma_price: last_price,
+            volume_24h: self.volume_24h,
+            liquidity_depth: self.liquidity_depth,
+            last_conf: self.last_conf,
+            last_update: self.last_update,
+            last_good_update: self.last_update,
+            last_expo: canonical_expo,
+            weight: self.weight,
+            lp_concentration: self.lp_concentration,
+            manipulation_score: self.manipulation_score,
+            source_type: self.source_type,
+            flags: self.flags,
+            ..PriceFeed::default()
+        })
+    }
+}
+
+/// `OracleState` as laid out by schema `Version::LEGACY`.
+///
+/// # Why a Frozen Copy
+///
+/// Oracle accounts are read in place, so a v0.1 account cannot be loaded as the
+/// current struct: it is 800 bytes short, and the feed array alone has shifted
+/// every field behind it. `migrate_oracle_state` decodes the old bytes through
+/// this struct, resizes the account and writes the current layout over it.
+#[derive(Clone, Copy, Pod, Zeroable)]
+#[repr(C)]
+pub struct LegacyOracleState {
+    pub authority: Pubkey,
+    pub version: Version,
+    pub flags: StateFlags,
+    pub last_update: i64,
+    pub current_price: PriceData,
+    pub price_feeds: [LegacyPriceFeed; MAX_PRICE_FEEDS],
+    pub twap_window: u32,
+    pub current_chunk_index: u16,
+    pub max_chunk_size: u16,
+    pub confidence_threshold: u16,
+    pub manipulation_threshold: u16,
+    pub active_feed_count: u8,
+    pub bump: u8,
+    pub governance_bump: u8,
+    pub historical_chunks: [Pubkey; MAX_HISTORICAL_CHUNKS],
+    pub emergency_admin: Pubkey,
+    pub asset_seed: [u8; 32],
+    pub reserved: [u8; 513],
+}
+
+impl LegacyOracleState {
+    /// Account size of a v0.1 oracle, discriminator excluded.
+    pub const SIZE: usize = core::mem::size_of::<Self>();
+
+    /// Overwrites `target` with this oracle in the current layout.
+    ///
+    /// # Field Mapping
+    ///
+    /// - Every v0.1 field is carried over unchanged, except that the version
+    ///   becomes `Version::CURRENT`.
+    /// - Feeds are upgraded one by one through `LegacyPriceFeed::upgrade`.
+    /// - Fields that `initialize_oracle` seeds with a non-zero default get that
+    ///   default, so a migrated oracle behaves like a freshly created one
+    ///   configured the same way. Everything else starts zeroed, which every
+    ///   newer field reads as "unset".
+    ///
+    /// # Exponent
+    ///
+    /// v0.1 never locked a canonical exponent, and an oracle with registered
+    /// feeds but no lock would reject every update. So `canonical_expo` is locked
+    /// whenever a feed is registered, and `current_price` and every feed reading
+    /// are rescaled into it; an oracle with no feeds is left unlocked for its
+    /// first registration.
+    ///
+    /// # Errors
+    ///
+    /// - `UnsupportedStateVersion`: this account is not at `Version::LEGACY`
+    /// - `InvalidFeedExpo`: `canonical_expo` is outside the supported range
+    /// - `MathOverflow`: the published price or a feed reading does not fit
+    ///   `canonical_expo`
+    pub fn upgrade_into(&self, target: &mut OracleState, canonical_expo: i32) -> Result<()> {
+        require!(
+            self.version == Version::LEGACY,
+            StateError::UnsupportedStateVersion
+        );
+        require!(
+            (MIN_FEED_EXPO..=MAX_FEED_EXPO).contains(&canonical_expo),
+            StateError::InvalidFeedExpo
+        );
+
+        // Written in place: the current struct is too large to build on the SBF stack
+        bytemuck::bytes_of_mut(target).fill(0);
+
+        target.authority = self.authority;
+        target.version = Version::CURRENT;
+        target.flags = self.flags;
+        target.last_update = self.last_update;
+        target.current_price = self.current_price;
+        for (feed, legacy) in target.price_feeds.iter_mut().zip(&self.price_feeds) {
+            *feed = legacy.upgrade(canonical_expo)?;
+        }
+        target.twap_window = self.twap_window;
+        target.current_chunk_index = self.current_chunk_index;
+        target.max_chunk_size = self.max_chunk_size;
+        target.confidence_threshold = self.confidence_threshold;
+        target.manipulation_threshold = self.manipulation_threshold;
+        target.active_feed_count = self.active_feed_count;
+        target.bump = self.bump;
+        target.governance_bump = self.governance_bump;
+        target.historical_chunks = self.historical_chunks;
+        target.emergency_admin = self.emergency_admin;
+        target.asset_seed = self.asset_seed;
+
+        target.manipulation_cooldown = DEFAULT_MANIPULATION_COOLDOWN;
+        target.manipulation_decay_period = DEFAULT_MANIPULATION_DECAY_PERIOD;
+
+        // v0.1 only accepted governance keepers; widening that is governance's call
+        target.updater_policy = UpdaterPolicy::GovernanceOnly.as_u8();
+
+        if self.active_feed_count > 0 {
+            target.current_price.price = rescale_price(
+                self.current_price.price,
+                self.current_price.expo,
+                canonical_expo,
+            )?;
+            target.lock_expected_expo(canonical_expo);
+        }
+
+        Ok(())
+    }
+}
@@ -1,13 +1,17 @@
 pub mod governance_state;
 pub mod historical_chunk;
+pub mod legacy_oracle_state;
 pub mod oracle_state;
 pub mod price_feed;
+pub mod proposal;
 pub mod snapshot_status;
 
 pub use governance_state::*;
 pub use historical_chunk::*;
+pub use legacy_oracle_state::*;
 pub use oracle_state::*;
 pub use price_feed::*;
+pub use proposal::*;
 pub use snapshot_status::*;
 
 #[cfg(test)]
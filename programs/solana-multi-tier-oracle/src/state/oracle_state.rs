@@ -1,13 +1,20 @@
+use crate::components::raydium_clmm_observer::{
+    fetch_raydium_price::ValidationPolicy, raydium_constants::OBSERVATION_NUM,
+};
 use crate::error::StateError;
 use crate::state::{
     governance_state::{GovernanceState, Permissions},
     historical_chunk::HistoricalChunk,
-    price_feed::PriceFeed,
+    price_feed::{rescale_price, PriceFeed, SourceType},
     snapshot_status::SnapshotStatus,
 };
 use crate::utils::constants::{
-    BUFFER_SIZE, MAX_HISTORICAL_CHUNKS, MAX_HOURS, MAX_LP_CONCENTRATION, MAX_PRICE_FEEDS,
-    MAX_SNAPSHOTS_PER_HOUR, MIN_TIME_SPAN_HOURS, SECONDS_PER_HOUR,
+    BUFFER_SIZE, DEFAULT_FEED_SPREAD_BPS, DEFAULT_LIQUIDITY_DROP_BPS, DEFAULT_MIN_OBSERVATIONS,
+    DEFAULT_TIER_SPREAD_BPS, FALLBACK_WEIGHT_BPS, FEED_AGE_BUCKET_BOUNDS, MAX_ALLOWED_UPDATERS,
+    MAX_EXTERNAL_STALENESS, MAX_HISTORICAL_CHUNKS, MAX_HOURS, MAX_LP_CONCENTRATION,
+    MAX_MANIPULATION_THRESHOLD, MAX_PRICE_FEEDS, MAX_SNAPSHOTS_PER_HOUR, MAX_SOURCE_THRESHOLD_BPS,
+    MAX_TWAP_WINDOW, MIN_HISTORICAL_CHUNKS, MIN_HISTORICAL_INTERVAL, MIN_TIME_SPAN_HOURS,
+    PRICE_TIER_COUNT, SECONDS_PER_HOUR, SOURCE_TYPE_COUNT, WEIGHT_PRECISION,
 };
 use anchor_lang::prelude::*;
 use bytemuck::{Pod, Zeroable};
@@ -115,11 +122,119 @@ pub struct OracleState {
     /// accidental misconfiguration.
     pub asset_seed: [u8; 32],
 
+    /// Non-zero once `current_price.expo` has been locked to the canonical exponent.
+    /// A zero exponent is a legitimate value (equal token decimals), so the lock
+    /// cannot be inferred from the exponent itself.
+    pub expo_initialized: u8,
+
+    /// Strictly increasing counter bumped on every successful price update.
+    /// Lives on the oracle rather than a historical chunk so it survives chunk
+    /// rotation, giving consumers an ordering that never depends on timestamps.
+    pub update_nonce: u64,
+
+    /// Timestamp at which each feed's manipulation peak was recorded, indexed like `price_feeds`.
+    pub manipulation_peak_at: [i64; MAX_PRICE_FEEDS],
+
+    /// Highest recent raw manipulation score per feed, the value that decays over time.
+    /// Kept apart from `PriceFeed.manipulation_score`, which holds the effective blended score.
+    pub manipulation_peak_score: [u16; MAX_PRICE_FEEDS],
+
+    /// Seconds a manipulation peak is held at full strength before it starts decaying.
+    pub manipulation_cooldown: u32,
+
+    /// Seconds over which a held peak decays linearly to zero after the cooldown.
+    /// Zero for both knobs reproduces the legacy overwrite-on-every-update behaviour.
+    pub manipulation_decay_period: u32,
+
+    /// Bit `i` is set when `price_feeds[i]` contributed to the current `current_price`.
+    /// Written on every update so post-mortems can tell which sources backed a price.
+    pub last_sources_mask: u32,
+
+    /// Minimum seconds between published prices; zero disables the limit.
+    /// Measured from `current_price.timestamp` so heartbeats never reset it.
+    pub update_cooldown: u32,
+
+    /// Window `twap_window` is ramping towards; meaningful only while a ramp is active.
+    pub target_twap_window: u32,
+
+    /// Seconds the active ramp takes to reach `target_twap_window`.
+    pub twap_ramp_duration: u32,
+
+    /// Unix timestamp the active ramp started at; zero when no ramp is in progress.
+    /// `twap_window` keeps the ramp's starting window until the ramp settles.
+    pub twap_ramp_started_at: i64,
+
+    /// Lifetime count of TWAP accumulations that overflowed and fell back to
+    /// saturating arithmetic. Each fallback loses precision silently, so a count
+    /// that keeps climbing points at a misconfigured exponent rather than noise.
+    pub saturation_count: u64,
+
+    /// Keepers permitted to publish under `UpdaterPolicy::Allowlist`.
+    /// Empty slots hold the default key, which can never sign.
+    pub allowed_updaters: [Pubkey; MAX_ALLOWED_UPDATERS],
+
+    /// Stored `UpdaterPolicy` discriminant; zero is `GovernanceOnly`, so
+    /// accounts created before the field existed stay governance-gated.
+    pub updater_policy: u8,
+
+    /// Explicit padding to the 16-byte alignment imposed by the `i128` feed prices.
+    pub _updater_padding: [u8; 15],
+
+    /// Per-`SourceType` scaling of `manipulation_threshold` in basis points, indexed
+    /// by discriminant. Zero reads as unscaled, so accounts created before the
+    /// field existed keep judging every source against the same threshold.
+    pub source_threshold_bps: [u16; SOURCE_TYPE_COUNT],
+
+    /// Manipulation scores at which a feed already over its threshold escalates
+    /// beyond having its price skipped: `[arm_breaker, emergency]`. Zero disables
+    /// a rung, so accounts created before the field existed never escalate.
+    pub escalation_ladder: [u16; 2],
+
+    /// Explicit padding to the 16-byte alignment imposed by the `i128` feed prices.
+    pub _escalation_padding: [u8; 4],
+
+    /// Blend weight of each `PriceTier` in basis points, indexed by discriminant.
+    /// All zero, as on accounts created before the field existed, weighs every
+    /// agreeing tier equally.
+    pub tier_weights_bps: [u16; PRICE_TIER_COUNT],
+
+    /// Widest divergence from the most trusted tier, in basis points, at which
+    /// another tier still joins the blend; zero reads as `DEFAULT_TIER_SPREAD_BPS`.
+    pub max_tier_spread_bps: u16,
+
+    /// Widest TWAP window this oracle accepts, fixed at initialization. Zero,
+    /// as on accounts created before the field existed, falls back to the
+    /// program-wide `MAX_TWAP_WINDOW`, which also caps any larger value.
+    pub max_twap_window_override: u32,
+
+    /// Explicit padding to the 16-byte alignment imposed by the `i128` feed prices.
+    pub _tier_padding: [u8; 4],
+
+    /// Widest min-to-max spread, in basis points, at which fresh feeds still
+    /// count as agreeing; zero reads as `DEFAULT_FEED_SPREAD_BPS`.
+    pub max_feed_spread_bps: u16,
+
+    /// Filled observation-ring slots a DEX feed needs before it is priced;
+    /// zero reads as `DEFAULT_MIN_OBSERVATIONS`.
+    pub min_observations: u16,
+
+    /// Depth drop between two reads of a feed, in basis points, beyond which
+    /// the read is flagged as a liquidity shock; zero reads as
+    /// `DEFAULT_LIQUIDITY_DROP_BPS`.
+    pub max_liquidity_drop_bps: u16,
+
+    /// Stored `ValidationPolicy` code for DEX reads; zero is `Both`, so
+    /// accounts created before the field existed keep cross-validating.
+    pub validation_policy: u8,
+
     /// Reserved space for future schema additions without breaking changes.
     /// Sized to accommodate common future fields while maintaining rent exemption.
-    pub reserved: [u8; 513],
+    pub reserved: [u8; 25],
 }
 
+// `last_sources_mask` has one bit per feed slot; widen it before raising the cap.
+const _: () = assert!(MAX_PRICE_FEEDS <= u32::BITS as usize);
+
 /// Compact bitfield for oracle operational state management.
 ///
 /// # Design Philosophy
@@ -180,13 +295,26 @@ impl StateFlags {
     /// Adds computational overhead but improves manipulation resistance.
     pub const TWAP_ENABLED: Self = Self(0b0001_0000);
 
+    /// Lets DEX feeds price an observation span shorter than the keeper's
+    /// `min_seconds`, at reduced confidence, instead of rejecting it.
+    /// Meant for bootstrapping a young pool or a test cluster, and set only by
+    /// a `MODIFY_CONFIG` holder so no updater can loosen the check on its own.
+    pub const RELAXED_MIN_ELAPSED: Self = Self(0b0010_0000);
+
+    /// Lets `ValidationPolicy::Fallback` publish a DEX pool's live tick when its
+    /// observation ring holds no recent history. One swap can set a spot tick,
+    /// so the rung is off unless a `MODIFY_CONFIG` holder opts the oracle in.
+    pub const SPOT_FALLBACK_ENABLED: Self = Self(0b0100_0000);
+
     /// Bitmask defining all currently valid flag positions.
     /// Used for forward-compatible deserialization that ignores unknown flags.
     pub const VALID_MASK: u32 = Self::CIRCUIT_BREAKER_ENABLED.0
         | Self::EMERGENCY_MODE.0
         | Self::UPGRADE_LOCKED.0
         | Self::MAINTENANCE_MODE.0
-        | Self::TWAP_ENABLED.0;
+        | Self::TWAP_ENABLED.0
+        | Self::RELAXED_MIN_ELAPSED.0
+        | Self::SPOT_FALLBACK_ENABLED.0;
 
     /// Creates empty flag set with all flags disabled.
     /// const fn enables compile-time initialization for static instances.
@@ -262,6 +390,16 @@ impl StateFlags {
         self.has(Self::TWAP_ENABLED)
     }
 
+    #[inline(always)]
+    pub fn is_min_elapsed_relaxed(self) -> bool {
+        self.has(Self::RELAXED_MIN_ELAPSED)
+    }
+
+    #[inline(always)]
+    pub fn is_spot_fallback_enabled(self) -> bool {
+        self.has(Self::SPOT_FALLBACK_ENABLED)
+    }
+
     /// Serialization helpers for account I/O operations.
 
     /// Extracts raw u32 value for storage in account data.
@@ -298,6 +436,24 @@ pub struct Version {
     pub _padding: u8,
 }
 
+impl Version {
+    /// Schema written by this program: the grown account with per-feed fallback,
+    /// smoothing and liquidity history and the oracle-level policy fields.
+    pub const CURRENT: Self = Self::new(0, 2, 0);
+
+    /// Original 2,736-byte schema, readable only through `LegacyOracleState`.
+    pub const LEGACY: Self = Self::new(0, 1, 0);
+
+    pub const fn new(major: u8, minor: u8, patch: u8) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            _padding: 0,
+        }
+    }
+}
+
 /// Standardized price representation with confidence intervals.
 ///
 /// # Design Considerations
@@ -341,6 +497,218 @@ pub struct PriceData {
     pub _padding: [u8; 12],
 }
 
+impl PriceData {
+    /// Whether this holds a published price rather than the zeroed default.
+    ///
+    /// A fresh oracle's `current_price` is all zeros, which reads as a price of
+    /// zero published at the epoch. Both fields are required: every update stamps
+    /// a non-zero timestamp, and a zero mantissa is never a meaningful quote.
+    #[inline(always)]
+    pub fn is_initialized(&self) -> bool {
+        self.timestamp != 0 && self.price != 0
+    }
+
+    /// Orders two prices by value, accounting for differing exponents.
+    ///
+    /// Both mantissas are brought to the finer of the two exponents, which only
+    /// ever multiplies, so no precision is lost and equal values compare equal
+    /// (`100 × 10^-6 == 1 × 10^-4`). A mantissa that cannot be represented at
+    /// the finer exponent yields `MathOverflow` instead of an ordering: a
+    /// saturated or truncated value could sort on the wrong side of the other
+    /// price, which is worse than no answer when picking a median.
+    ///
+    /// Only `price` and `expo` take part; confidence and timestamp are ignored.
+    pub fn cmp_scaled(&self, other: &PriceData) -> Result<core::cmp::Ordering> {
+        let common_expo = self.expo.min(other.expo);
+        let lhs = rescale_price(self.price, self.expo, common_expo)?;
+        let rhs = rescale_price(other.price, other.expo, common_expo)?;
+        Ok(lhs.cmp(&rhs))
+    }
+
+    /// The price mantissa re-expressed at `target_expo`, for consumers with their
+    /// own fixed-point scale.
+    ///
+    /// A finer target multiplies and fails with `MathOverflow` when the result
+    /// does not fit in an `i128`; a coarser target divides and truncates toward
+    /// zero, so a lending protocol reading at `-4` never sees more value than
+    /// the oracle published at `-6`.
+    pub fn to_scale(&self, target_expo: i32) -> Result<i128> {
+        rescale_price(self.price, self.expo, target_expo)
+    }
+}
+
+/// Per-update breakdown of which registered feeds took part in aggregation.
+///
+/// Every active feed lands in exactly one bucket, so the three counts always sum
+/// to the number of active feeds and monitoring can tell a healthy single-source
+/// oracle apart from one whose other sources have silently dropped out.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct FeedParticipation {
+    /// Feeds whose price went into the published blend.
+    pub aggregated: u8,
+
+    /// Feeds excluded because their last reading is older than the staleness bound.
+    pub excluded_stale: u8,
+
+    /// Feeds excluded because they are flagged, their manipulation score exceeds
+    /// their source's threshold, or their tier diverged from the reference tier.
+    pub excluded_manipulated: u8,
+
+    /// Bit `i` set for each `price_feeds[i]` counted in `aggregated`.
+    pub sources_mask: u32,
+}
+
+/// One tier's median reading and the feeds it was taken over.
+#[derive(Clone, Copy, Debug)]
+struct TierReading {
+    price: i128,
+    conf: u64,
+    /// Bit `i` set for each `price_feeds[i]` in the median.
+    members: u32,
+    /// Whether the median is over last-good fallbacks rather than fresh reads.
+    fallback: bool,
+}
+
+/// Return data of the read instruction: the published price and the feeds behind it.
+///
+/// Pairing the mask with the price lets a consumer reject a reading that rests on
+/// fewer sources than it requires without a second account read. The saturation
+/// count rides along for monitoring, so a TWAP that keeps overflowing is visible
+/// to the same poller that reads the price, as does the feed age histogram.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct PriceReading {
+    pub price: PriceData,
+    pub sources_mask: u32,
+    pub saturation_count: u64,
+    /// Active feeds per age bucket, as returned by `OracleState::price_age_histogram`.
+    pub feed_age_histogram: [u8; 5],
+}
+
+/// Return data of the summary view: the handful of fields dashboards poll.
+///
+/// The full account carries the feed table, link arrays and reserved space, and
+/// an indexer decoding it for five values pays for all of them on every poll.
+/// Unlike `PriceReading`, the price is reported as stored, with no emergency or
+/// freshness checks, because an indexer wants to see an unpublished or halted
+/// oracle as it is rather than receive an error; `flags` says which applies.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct OracleSummary {
+    pub authority: Pubkey,
+    pub current_price: PriceData,
+    pub last_update: i64,
+    pub active_feed_count: u8,
+    pub flags: StateFlags,
+    /// Feed weight still unallocated, as returned by `OracleState::weight_headroom`.
+    pub weight_headroom: u32,
+}
+
+/// Who may call `update_price` for an oracle.
+///
+/// # Bounded Permissionlessness
+///
+/// Governance membership carries voting stake and configuration rights, which is
+/// heavy machinery for a keeper whose only job is to push prices. The allowlist
+/// lets a deployment open updates to a vetted keeper set without enrolling each
+/// keeper in the multisig. Governance keepers remain accepted under `Allowlist`:
+/// widening the set must never lock out the members who were already trusted
+/// to update.
+///
+/// # No Open Policy
+///
+/// The updater picks call-time parameters such as the TWAP window, so even with
+/// every safety threshold stored on the oracle an anonymous caller still steers
+/// what gets published. Every policy therefore names who may update.
+///
+/// Explicit discriminants keep the stored byte stable across upgrades. `2`
+/// belonged to a retired `Open` policy and is never reused; an account still
+/// holding it decodes as `GovernanceOnly`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+#[repr(u8)]
+pub enum UpdaterPolicy {
+    /// Only governance members holding `UPDATE_PRICE`.
+    #[default]
+    GovernanceOnly = 0,
+
+    /// Governance keepers plus the keys listed in `allowed_updaters`.
+    Allowlist = 1,
+}
+
+impl UpdaterPolicy {
+    #[inline(always)]
+    pub const fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Decodes a stored discriminant, falling back to `GovernanceOnly`.
+    ///
+    /// An unknown byte means corrupted or future-versioned data; reading it as
+    /// the most restrictive policy fails closed instead of opening updates.
+    #[inline(always)]
+    pub const fn from_u8_or_default(value: u8) -> Self {
+        match value {
+            1 => Self::Allowlist,
+            _ => Self::GovernanceOnly,
+        }
+    }
+}
+
+/// How far `escalate_manipulation` went for one feed's manipulation score.
+///
+/// Ordered by severity so callers can compare against a rung.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, AnchorSerialize, AnchorDeserialize,
+)]
+#[repr(u8)]
+pub enum ManipulationSeverity {
+    /// At or under the feed's threshold; nothing to do.
+    Normal = 0,
+
+    /// Over the threshold: the feed's price is kept out of publication.
+    Suspicious = 1,
+
+    /// At or above the arm rung: the circuit breaker has been enabled.
+    BreakerArmed = 2,
+
+    /// At or above the emergency rung with the breaker enabled: emergency
+    /// mode has been entered.
+    Emergency = 3,
+}
+
+/// Trust tiers that `aggregate_tiered` prices separately before combining.
+///
+/// # Priority
+///
+/// Discriminants run from most to least trusted:
+/// - **Oracle**: external oracle networks, which have already aggregated and
+///   filtered many venues before publishing
+/// - **CEX**: centralised venues, deep but opaque and run by a single operator
+/// - **DEX**: on-chain pools, fully transparent but the only tier a flash loan
+///   can move within one transaction
+///
+/// Aggregators quote routes across DEX pools and inherit their exposure, so
+/// they are priced in the DEX tier.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PriceTier {
+    Oracle = 0,
+    Cex = 1,
+    Dex = 2,
+}
+
+impl PriceTier {
+    /// Every tier, most trusted first.
+    pub const PRIORITY: [Self; PRICE_TIER_COUNT] = [Self::Oracle, Self::Cex, Self::Dex];
+
+    /// The tier a feed of `source_type` is priced in.
+    pub const fn of(source_type: SourceType) -> Self {
+        match source_type {
+            SourceType::Oracle => Self::Oracle,
+            SourceType::CEX => Self::Cex,
+            SourceType::DEX | SourceType::Aggregator => Self::Dex,
+        }
+    }
+}
+
 impl OracleState {
     /// Updates the number of active price feeds with bounds checking.
     ///
@@ -374,6 +742,278 @@ impl OracleState {
         self.flags.is_circuit_breaker_enabled()
     }
 
+    /// Rejects any mutation while the oracle is in emergency mode.
+    ///
+    /// Emergency mode means the oracle's own state may be compromised, so nothing
+    /// that reconfigures it (feeds, cooldowns, TWAP window, decay) may run until
+    /// the incident is resolved. Instructions that only touch governance, and the
+    /// halt controls themselves, stay callable so the incident can be handled.
+    pub fn require_not_emergency(&self) -> Result<()> {
+        require!(
+            !self.flags.is_emergency_mode(),
+            StateError::CircuitBreakerActive
+        );
+        Ok(())
+    }
+
+    /// Rejects writes to the published price while the oracle is halted for any reason.
+    ///
+    /// # Halt Semantics
+    ///
+    /// Emergency mode and maintenance mode both pause the price path but report
+    /// distinct errors so operators and keepers can tell an incident from planned
+    /// work. Emergency takes precedence because it is the stronger condition: an
+    /// oracle that is both in maintenance and in an emergency is, first and
+    /// foremost, in an emergency.
+    ///
+    /// # Scope
+    ///
+    /// Only instructions that publish or refresh the price (`update_price`,
+    /// `heartbeat`) require full operation. Configuration instructions only call
+    /// `require_not_emergency`: maintenance exists precisely so that feeds and
+    /// parameters can be changed while no price is being written.
+    pub fn require_operational(&self) -> Result<()> {
+        self.require_not_emergency()?;
+        require!(
+            !self.flags.is_maintenance_mode(),
+            StateError::MaintenanceActive
+        );
+        Ok(())
+    }
+
+    /// Rejects a price update that arrives within `update_cooldown` of the last one.
+    ///
+    /// # Why Rate-Limit
+    ///
+    /// History pushes are already spaced by `MIN_HISTORICAL_INTERVAL`, but every
+    /// call still recomputes the TWAP and rewrites `current_price`. Back-to-back
+    /// updates burn compute and add noise without adding information, so the
+    /// oracle can insist on a minimum gap. Admin-forced updates bypass this check
+    /// in the instruction; heartbeats never call it.
+    pub fn check_update_cooldown(&self, now: i64) -> Result<()> {
+        let elapsed = now.saturating_sub(self.current_price.timestamp);
+        require!(
+            elapsed >= self.update_cooldown as i64,
+            StateError::UpdateTooSoon
+        );
+        Ok(())
+    }
+
+    /// TWAP window in force at `now`, following any active ramp.
+    ///
+    /// # Gradual Window Changes
+    ///
+    /// A TWAP over 30 minutes and one over 4 hours can sit far apart after a trend,
+    /// so swapping the window in one step moves the published price by that gap in a
+    /// single update. Ramping interpolates linearly from `twap_window` to
+    /// `target_twap_window` over `twap_ramp_duration` instead, spreading the change
+    /// across many updates. Once the duration has elapsed the target is returned
+    /// even if `settle_twap_ramp` has not yet committed it.
+    pub fn effective_twap_window(&self, now: i64) -> u32 {
+        if self.twap_ramp_started_at == 0 {
+            return self.twap_window;
+        }
+
+        let elapsed = now.saturating_sub(self.twap_ramp_started_at).max(0);
+        if elapsed >= self.twap_ramp_duration as i64 {
+            return self.target_twap_window;
+        }
+
+        let from = self.twap_window as i64;
+        let delta = self.target_twap_window as i64 - from;
+        (from + delta * elapsed / self.twap_ramp_duration as i64) as u32
+    }
+
+    /// Begins ramping the TWAP window to `target` over `duration` seconds.
+    ///
+    /// A ramp that is still in progress is restarted from the window currently in
+    /// force, so retargeting mid-ramp never jumps back to the old starting point.
+    /// `align` rounds that starting window down to the observation cadence, which
+    /// keeps it on the grid `update_price` requires. A zero duration applies the
+    /// target immediately. Returns the window the new ramp starts from.
+    pub fn start_twap_ramp(&mut self, target: u32, duration: u32, now: i64, align: u32) -> u32 {
+        let current = self.effective_twap_window(now);
+        let from = current - current % align.max(1);
+
+        self.twap_window = from;
+        if duration == 0 {
+            self.twap_window = target;
+            self.twap_ramp_started_at = 0;
+        } else {
+            self.target_twap_window = target;
+            self.twap_ramp_duration = duration;
+            self.twap_ramp_started_at = now;
+        }
+        from
+    }
+
+    /// Commits a finished ramp's target to `twap_window` and clears the ramp.
+    ///
+    /// Returns true when a ramp settled on this call. Settling is only bookkeeping;
+    /// `effective_twap_window` already reports the target once the ramp is over.
+    pub fn settle_twap_ramp(&mut self, now: i64) -> bool {
+        if self.twap_ramp_started_at == 0
+            || now.saturating_sub(self.twap_ramp_started_at) < self.twap_ramp_duration as i64
+        {
+            return false;
+        }
+
+        self.twap_window = self.target_twap_window;
+        self.twap_ramp_started_at = 0;
+        true
+    }
+
+    /// Widest TWAP window this oracle accepts.
+    ///
+    /// `MAX_TWAP_WINDOW` bounds what the program can compute; an oracle over a
+    /// thin or young pool may need a tighter bound, since the pool's observation
+    /// history cannot back a multi-day average anyway. The override can only
+    /// tighten: zero or anything above the global constant reads as the constant.
+    pub fn twap_window_ceiling(&self) -> u32 {
+        match self.max_twap_window_override {
+            0 => MAX_TWAP_WINDOW,
+            ceiling => ceiling.min(MAX_TWAP_WINDOW),
+        }
+    }
+
+    /// Rejects a TWAP window above `twap_window_ceiling` with `InvalidTWAPWindow`.
+    pub fn check_twap_window(&self, window: u32) -> Result<()> {
+        require!(
+            window <= self.twap_window_ceiling(),
+            StateError::InvalidTWAPWindow
+        );
+        Ok(())
+    }
+
+    /// Returns the last published price for read-only consumers.
+    ///
+    /// Maintenance deliberately does not block reads: the stored price is still the
+    /// last validated value and consumers can apply their own staleness policy.
+    /// Emergency mode does block reads, since it signals that the stored price
+    /// itself may be compromised. Before the first successful update there is no
+    /// price to return, and the zeroed placeholder is refused with
+    /// `PriceNotYetAvailable` rather than handed out as a price of zero.
+    pub fn read_price(&self) -> Result<PriceData> {
+        self.require_not_emergency()?;
+        require!(
+            self.current_price.is_initialized(),
+            StateError::PriceNotYetAvailable
+        );
+        Ok(self.current_price)
+    }
+
+    /// `read_price` with the caller's staleness bound applied.
+    ///
+    /// # Error Precedence
+    ///
+    /// Each failure has its own variant so consumers can branch on the reason:
+    ///
+    /// - `CircuitBreakerActive`: emergency mode, as in `read_price`
+    /// - `PriceNotYetAvailable`: no update has published a price yet
+    /// - `NonMonotonicTimestamps`: the price claims a time after `now`, which no
+    ///   honest update can produce; age is meaningless then, so this is checked first
+    /// - `StalePrice`: the price is more than `max_age` seconds old
+    ///
+    /// A `max_age` of zero disables the staleness bound, leaving the policy to
+    /// the consumer as before.
+    pub fn read_fresh_price(&self, now: i64, max_age: u32) -> Result<PriceData> {
+        let price = self.read_price()?;
+        require!(price.timestamp <= now, StateError::NonMonotonicTimestamps);
+        require!(
+            max_age == 0 || now - price.timestamp <= max_age as i64,
+            StateError::StalePrice
+        );
+        Ok(price)
+    }
+
+    /// `read_fresh_price` plus the bitmap of feeds that contributed to it and the
+    /// lifetime TWAP saturation count.
+    pub fn read_price_reading(&self, now: i64, max_age: u32) -> Result<PriceReading> {
+        Ok(PriceReading {
+            price: self.read_fresh_price(now, max_age)?,
+            sources_mask: self.last_sources_mask,
+            saturation_count: self.saturation_count,
+            feed_age_histogram: self.price_age_histogram(now),
+        })
+    }
+
+    /// Compact copy of the fields off-chain indexers poll, as returned by `get_summary`.
+    pub fn to_summary(&self) -> OracleSummary {
+        OracleSummary {
+            authority: self.authority,
+            current_price: self.current_price,
+            last_update: self.last_update,
+            active_feed_count: self.active_feed_count,
+            flags: self.flags,
+            weight_headroom: self.weight_headroom(),
+        }
+    }
+
+    /// Weight a further feed may still claim: `WEIGHT_PRECISION` less the
+    /// weights across the active feed range.
+    ///
+    /// The range is summed slot by slot exactly as registration sums it before
+    /// accepting a feed, so this is the largest weight the next
+    /// `register_price_feed` can succeed with unless it rebalances.
+    pub fn weight_headroom(&self) -> u32 {
+        let total_weight: u32 = self
+            .active_feeds()
+            .iter()
+            .map(|feed| feed.weight as u32)
+            .sum();
+        WEIGHT_PRECISION.saturating_sub(total_weight)
+    }
+
+    /// Counts active feeds by how long ago they were last refreshed.
+    ///
+    /// # Buckets
+    ///
+    /// Ages are `now - last_update`, split at `FEED_AGE_BUCKET_BOUNDS` into
+    /// under 1 minute, under 5 minutes, under 15 minutes, under 1 hour, and
+    /// older. A feed stamped in the future counts as fresh rather than being
+    /// dropped, so the buckets always sum to `active_feed_count`.
+    ///
+    /// # Why a Histogram
+    ///
+    /// The published price only says when the last update landed. A subset of
+    /// feeds can stop refreshing while the rest keep the oracle looking live,
+    /// and the aggregate quietly narrows onto fewer sources; spreading the feeds
+    /// over age buckets makes that visible in one read.
+    pub fn price_age_histogram(&self, now: i64) -> [u8; 5] {
+        let mut histogram = [0u8; 5];
+        for feed in self.active_feeds() {
+            let age = now.saturating_sub(feed.last_update);
+            let bucket = FEED_AGE_BUCKET_BOUNDS
+                .iter()
+                .position(|&bound| age < bound)
+                .unwrap_or(FEED_AGE_BUCKET_BOUNDS.len());
+            histogram[bucket] += 1;
+        }
+        histogram
+    }
+
+    /// Adds `fallbacks` saturated TWAP accumulation steps to the lifetime count.
+    ///
+    /// Counts every fallback, not just the ones that produced a `SaturationWarning`,
+    /// so the per-pass event cap never hides how often precision was lost.
+    pub fn record_saturation(&mut self, fallbacks: u32) {
+        self.saturation_count = self.saturation_count.saturating_add(fallbacks as u64);
+    }
+
+    /// Rejects an update whose clock runs behind the last published price.
+    ///
+    /// Published timestamps must never move backwards: history, the cooldown and
+    /// every consumer's age check measure from `current_price.timestamp`. Checked
+    /// ahead of `check_update_cooldown`, which would otherwise report a regressed
+    /// clock as `UpdateTooSoon`.
+    pub fn check_update_timestamp(&self, now: i64) -> Result<()> {
+        require!(
+            now >= self.current_price.timestamp,
+            StateError::NonMonotonicTimestamps
+        );
+        Ok(())
+    }
+
     /// Returns slice view of currently active price feeds.
     ///
     /// # Performance Rationale
@@ -390,81 +1030,1246 @@ impl OracleState {
         &self.price_feeds[..self.active_feed_count as usize]
     }
 
-    /// Validates all active feeds against manipulation detection criteria.
+    /// Active feeds of one `source_type`, in slot order.
     ///
-    /// # Anti-MEV Design
+    /// # Tiered Aggregation
     ///
-    /// This method implements multiple layers of manipulation resistance to protect
-    /// against maximum extractable value attacks that could compromise DeFi protocols:
+    /// Sources earn trust differently: a DEX pool is best summarised by a median
+    /// across pools, while an external oracle has already aggregated its inputs.
+    /// Filtering by tier lets each strategy see only its own feeds without
+    /// collecting them first. Types are read through `get_source_type`, so a feed
+    /// with an unknown discriminant is grouped with DEX feeds, the tier it is
+    /// judged as elsewhere. Feeds whose `ACTIVE` flag is cleared are skipped.
+    pub fn feeds_of_type(&self, source_type: SourceType) -> impl Iterator<Item = &PriceFeed> {
+        self.active_feeds()
+            .iter()
+            .filter(move |feed| feed.flags.is_active() && feed.get_source_type() == source_type)
+    }
+
+    /// Scales active feed weights down so `incoming_weight` fits within `WEIGHT_PRECISION`.
     ///
-    /// 1. **LP Concentration Limits**: Prevents single liquidity provider from
-    ///    controlling price discovery on individual feeds. Without this check, a whale
-    ///    could manipulate prices by concentrating liquidity in a single pool, creating
-    ///    artificial price signals that trigger cascading liquidations.
-    /// 2. **Manipulation Scoring**: Detects coordinated attacks across feeds
-    ///    using statistical analysis of price movements and volume patterns. This
-    ///    prevents sophisticated attacks where multiple feeds are manipulated in concert
-    ///    to create false consensus.
-    /// 3. **Active Feed Filtering**: Ignores disabled feeds to prevent attackers
-    ///    from gaming the system by temporarily disabling honest sources, which could
-    ///    artificially inflate the influence of remaining compromised feeds.
+    /// # Proportional Scaling
     ///
-    /// The early continue pattern optimizes for the common case where most feeds
-    /// are active, minimizing branch mispredictions in the hot path. This performance
-    /// optimization is crucial because manipulation checks run on every price update.
-    pub fn check_manipulation_resistance(&self) -> Result<()> {
-        for feed in self.active_feeds() {
-            // Skip manipulation checks for inactive feeds to prevent
-            // attackers from gaming the system by disabling honest sources
-            if !feed.flags.is_active() {
-                continue;
-            }
-
-            // Prevent single LP from controlling price discovery
-            if feed.lp_concentration > MAX_LP_CONCENTRATION {
-                return Err(StateError::ExcessiveLpConcentration.into());
-            }
+    /// Every active weight is multiplied by `(WEIGHT_PRECISION - incoming_weight) / total`
+    /// and rounded down, so the ratios operators chose between existing feeds survive
+    /// (up to one unit of rounding) and the new total never exceeds the precision.
+    ///
+    /// Returns `Ok(false)` and leaves weights untouched when the incoming feed already
+    /// fits, `Ok(true)` once weights have been rescaled.
+    ///
+    /// # Errors
+    ///
+    /// - `ExcessiveTotalWeight`: some existing feed would round down to zero weight,
+    ///   which would silently drop it from aggregation; nothing is modified
+    pub fn rebalance_weights_for(&mut self, incoming_weight: u16) -> Result<bool> {
+        let total: u32 = self
+            .active_feeds()
+            .iter()
+            .map(|feed| feed.weight as u32)
+            .sum();
+        if total + incoming_weight as u32 <= WEIGHT_PRECISION {
+            return Ok(false);
+        }
 
-            // Detect coordinated manipulation across multiple vectors
-            if feed.manipulation_score > self.manipulation_threshold {
-                return Err(StateError::ManipulationDetected.into());
-            }
+        let budget = WEIGHT_PRECISION.saturating_sub(incoming_weight as u32);
+        let mut scaled = [0u16; MAX_PRICE_FEEDS];
+        for (slot, feed) in scaled.iter_mut().zip(self.active_feeds()) {
+            // Bounded by the original weight since budget < total here
+            *slot = (feed.weight as u32 * budget / total) as u16;
+            require!(*slot > 0, StateError::ExcessiveTotalWeight);
         }
 
-        Ok(())
+        let active = self.active_feed_count as usize;
+        for (feed, weight) in self.price_feeds[..active].iter_mut().zip(scaled) {
+            feed.weight = weight;
+        }
+        Ok(true)
     }
 
-    /// Centralized permission validation with governance delegation.
-    ///
-    /// # Architecture Rationale
+    /// Stores `feed` in the first slot without an active feed and returns its index.
     ///
-    /// This method provides a clean interface for permission checking while maintaining
-    /// separation of concerns. The oracle state doesn't need to understand governance
-    /// implementation details - it simply delegates to the governance system.
+    /// # Why Scan Instead of Appending
     ///
-    /// # Performance Considerations
+    /// Appending at `active_feed_count` trusts the count to match the slots in
+    /// use. If the two ever disagree, for example a count left behind by an
+    /// interrupted migration, the append lands on a committed feed and silently
+    /// replaces it. Scanning for a slot whose `ACTIVE` flag is clear can only
+    /// ever fill empty space.
     ///
-    /// By accepting the governance state as a parameter rather than storing a reference,
-    /// this design avoids borrowing complications and enables the caller to optimize
-    /// governance account access patterns. The governance state is typically already
-    /// loaded for instruction validation, so this pattern avoids redundant account reads.
+    /// Every slot before the chosen one is active, so the count is raised to
+    /// cover the new slot whenever it was behind; a hole inside the active range
+    /// is reused without changing it.
     ///
-    /// # Security Design
+    /// # Errors
     ///
-    /// Centralizing permission logic in the governance state ensures consistent
-    /// authorization semantics across all oracle operations. This prevents divergent
-    /// permission implementations that could create security vulnerabilities.
-    pub fn check_permission(
-        governance: &GovernanceState,
-        caller: &Pubkey,
-        required_permission: Permissions,
-    ) -> Result<()> {
-        governance.check_member_permission(caller, required_permission)
+    /// - `TooManyFeeds`: every slot holds an active feed
+    pub fn claim_feed_slot(&mut self, feed: PriceFeed) -> Result<usize> {
+        let index = self
+            .price_feeds
+            .iter()
+            .position(|slot| !slot.flags.is_active())
+            .ok_or(StateError::TooManyFeeds)?;
+
+        self.price_feeds[index] = feed;
+        if index >= self.active_feed_count as usize {
+            self.set_active_feed_count(index as u8 + 1)?;
+        }
+        Ok(index)
     }
 
-    /// Validates snapshot quality for redemption eligibility using existing HistoricalChunk infrastructure.
+    /// Slot of the active feed reading from `source`.
     ///
-    /// # Architecture Benefits
+    /// An oracle with no feeds registered fails on that fact first: otherwise
+    /// the lookup below would report the source as unknown, which sends the
+    /// operator chasing a wrong pool address when the real problem is an empty
+    /// feed table.
+    ///
+    /// Deactivated slots never match, even when they still hold the address:
+    /// `claim_feed_slot` treats them as free, and an update must not be able to
+    /// revive a feed governance retired just by naming its pool.
+    ///
+    /// # Errors
+    ///
+    /// - `NoActiveFeeds`: `active_feed_count` is zero
+    /// - `InvalidSourceAddress`: feeds are registered, but no active one reads
+    ///   from `source`
+    pub fn feed_index_for(&self, source: &Pubkey) -> Result<usize> {
+        require!(self.active_feed_count > 0, StateError::NoActiveFeeds);
+        self.price_feeds
+            .iter()
+            .position(|feed| feed.flags.is_active() && feed.source_address == *source)
+            .ok_or_else(|| StateError::InvalidSourceAddress.into())
+    }
+
+    /// Classifies every active feed as aggregated, stale or manipulated at `now`.
+    ///
+    /// # Classification Order
+    ///
+    /// A feed is aggregated exactly when `aggregate_tiered` blends its price, so
+    /// `sources_mask` names the feeds behind the published value. Of the rest,
+    /// a feed is stale if it is `STALE`-flagged, its latest read failed, or its
+    /// reading is older than `MAX_EXTERNAL_STALENESS`; staleness wins over a
+    /// high score, since the score describes a reading that is no longer
+    /// current. Everything else was excluded for manipulation: a flag, a
+    /// tripped breaker, a score over its source's threshold, or a tier that
+    /// strayed from the reference tier.
+    ///
+    /// Feeds with the `ACTIVE` flag cleared are skipped entirely, matching
+    /// `check_manipulation_resistance`.
+    pub fn feed_participation(&self, now: i64) -> FeedParticipation {
+        let blended = self.blend_tiers(now).map_or(0, |(_, members)| members);
+        let mut participation = FeedParticipation::default();
+
+        for (index, feed) in self.active_feeds().iter().enumerate() {
+            if !feed.flags.is_active() {
+                continue;
+            }
+
+            let is_stale = feed.flags.is_stale()
+                || feed.flags.is_read_failed()
+                || feed.reading_age(now) > MAX_EXTERNAL_STALENESS as i64;
+
+            if blended & (1 << index) != 0 {
+                participation.aggregated += 1;
+                participation.sources_mask |= 1 << index;
+            } else if is_stale {
+                participation.excluded_stale += 1;
+            } else {
+                participation.excluded_manipulated += 1;
+            }
+        }
+
+        participation
+    }
+
+    /// Returns true when every fresh feed's price lies within `max_spread_bps` of the others.
+    ///
+    /// # Spread Definition
+    ///
+    /// Prices are normalized to the canonical exponent, then the spread is
+    /// `(max - min) / min` in basis points. Measuring against the minimum is the
+    /// stricter choice: the same absolute gap counts for more when prices are low.
+    ///
+    /// # Which Feeds Count
+    ///
+    /// Only active feeds that reported within `MAX_EXTERNAL_STALENESS` are compared,
+    /// the same freshness rule used for participation counts. A stale outlier is
+    /// already excluded from aggregation, so letting it veto a fresh consensus
+    /// would turn one dead source into an outage. Fewer than two fresh feeds agree
+    /// trivially.
+    ///
+    /// A non-positive fresh price, or one that cannot be normalized, reports
+    /// disagreement: no meaningful relative spread exists and neither value
+    /// should be trusted.
+    pub fn feeds_agree(&self, max_spread_bps: u16, now: i64) -> bool {
+        let mut min_price: Option<i128> = None;
+        let mut max_price: Option<i128> = None;
+
+        for feed in self.fresh_feeds(now) {
+            let Ok(price) = self.normalized_feed_price(feed) else {
+                return false;
+            };
+            if price <= 0 {
+                return false;
+            }
+
+            min_price = Some(min_price.map_or(price, |current| current.min(price)));
+            max_price = Some(max_price.map_or(price, |current| current.max(price)));
+        }
+
+        let (Some(min_price), Some(max_price)) = (min_price, max_price) else {
+            return true;
+        };
+
+        (max_price - min_price)
+            .checked_mul(10_000)
+            .map(|scaled| scaled / min_price <= max_spread_bps as i128)
+            .unwrap_or(false)
+    }
+
+    /// Active feeds with a current, trusted reading at `now`.
+    ///
+    /// A feed whose latest read failed is not fresh even inside its fallback
+    /// grace: its price is from an earlier round and should neither veto nor
+    /// anchor a comparison between current readings.
+    fn fresh_feeds(&self, now: i64) -> impl Iterator<Item = &PriceFeed> {
+        self.active_feeds()
+            .iter()
+            .filter(move |feed| self.is_fresh(feed, now))
+    }
+
+    /// Whether `feed` reported within `MAX_EXTERNAL_STALENESS` of `now` and is
+    /// trusted to be aggregated.
+    ///
+    /// Registration stamps `last_update` before any price exists, so a feed is
+    /// registered with `READ_FAILED` set and only turns fresh on its first good read.
+    fn is_fresh(&self, feed: &PriceFeed, now: i64) -> bool {
+        self.is_trusted(feed)
+            && !feed.flags.is_read_failed()
+            && now.saturating_sub(feed.last_update) <= MAX_EXTERNAL_STALENESS as i64
+    }
+
+    /// Whether `feed` failed its latest read but can still serve its last good
+    /// price at `now`; see `tier_readings`.
+    fn is_fallback(&self, feed: &PriceFeed, now: i64) -> bool {
+        self.is_trusted(feed)
+            && feed.flags.is_read_failed()
+            && feed.reading_age(now) <= MAX_EXTERNAL_STALENESS as i64
+    }
+
+    /// Active, unflagged and scored within its source's threshold.
+    fn is_trusted(&self, feed: &PriceFeed) -> bool {
+        feed.flags.is_active()
+            && !feed.flags.is_stale()
+            && !feed.flags.is_manipulation_detected()
+            && !feed.flags.is_breaker_tripped()
+            && feed.manipulation_score <= self.manipulation_threshold_for(feed.source_type)
+    }
+
+    /// Median `(price, conf)` of each tier's feeds, indexed by `PriceTier`.
+    ///
+    /// Feeds are read through `get_source_type`, so an unknown discriminant is
+    /// priced with the DEX tier. A tier with no usable feed is `None`; see
+    /// `tier_readings` for which feeds count.
+    pub fn tier_prices(&self, now: i64) -> [Option<(i128, u64)>; PRICE_TIER_COUNT] {
+        self.tier_readings(now)
+            .map(|reading| reading.map(|reading| (reading.price, reading.conf)))
+    }
+
+    /// Each tier's median together with the feeds it was taken over.
+    ///
+    /// # Last-Good-Price Fallback
+    ///
+    /// A pool that trips a validation check for one round is usually still a
+    /// sound source, and dropping it outright can leave its whole tier unpriced.
+    /// So a tier with no fresh feed is priced from the feeds whose latest read
+    /// failed, at their last validated price, as long as that price is within
+    /// `MAX_EXTERNAL_STALENESS`. A tier with any fresh feed ignores its
+    /// fallbacks: a median cannot discount an old price, and one current
+    /// reading says more about the market than any number of old ones.
+    ///
+    /// Feeds whose price cannot be normalized are left out rather than failing
+    /// the whole tier. A feed that has never had a good read is neither fresh
+    /// (it is registered with `READ_FAILED`) nor a fallback (it has no last good
+    /// price), so its zero placeholder can never price a tier or anchor the blend.
+    fn tier_readings(&self, now: i64) -> [Option<TierReading>; PRICE_TIER_COUNT] {
+        PriceTier::PRIORITY.map(|tier| {
+            self.tier_reading(tier, now, false)
+                .or_else(|| self.tier_reading(tier, now, true))
+        })
+    }
+
+    /// Median over `tier`'s fresh feeds, or over its fallbacks when `fallback` is set.
+    fn tier_reading(&self, tier: PriceTier, now: i64, fallback: bool) -> Option<TierReading> {
+        let mut readings = [(0i128, 0u64); MAX_PRICE_FEEDS];
+        let mut count = 0;
+        let mut members = 0u32;
+        for (index, feed) in self.active_feeds().iter().enumerate() {
+            let usable = if fallback {
+                self.is_fallback(feed, now)
+            } else {
+                self.is_fresh(feed, now)
+            };
+            if !usable || PriceTier::of(feed.get_source_type()) != tier {
+                continue;
+            }
+            if let Ok(price) = self.normalized_feed_price(feed) {
+                readings[count] = (price, feed.last_conf);
+                count += 1;
+                members |= 1 << index;
+            }
+        }
+
+        let (price, conf) = median_reading(&mut readings[..count])?;
+        Some(TierReading {
+            price,
+            conf,
+            members,
+            fallback,
+        })
+    }
+
+    /// Blends the per-tier medians into one price, stamped at `now`.
+    ///
+    /// This is the price `update_price` publishes once a feed has been read.
+    ///
+    /// # Pipeline
+    ///
+    /// 1. Each tier is priced as the median of its own fresh feeds, so a
+    ///    numerous tier cannot outvote a sparse but more trusted one. A tier
+    ///    with no fresh feed falls back to its last good prices.
+    /// 2. The most trusted tier priced from fresh feeds becomes the reference;
+    ///    see `PriceTier` for the order. Only when every tier is on fallbacks
+    ///    does the most trusted of those anchor the blend.
+    /// 3. Every other tier must sit within `max_tier_spread_bps` of the
+    ///    reference. A tier outside the bound is dropped, so when every other
+    ///    tier disagrees the result falls back to the reference alone.
+    /// 4. The remaining tiers are averaged by `tier_weights_bps`, price and
+    ///    confidence alike. Unset weights count every remaining tier equally,
+    ///    and a tier on fallbacks keeps `FALLBACK_WEIGHT_BPS` of its weight so
+    ///    an equally weighted fresh tier always outweighs it. Remaining tiers
+    ///    that all carry zero weight defer to the reference.
+    ///
+    /// # Errors
+    ///
+    /// - `NoActiveFeeds`: no tier has a fresh or fallback feed to price from
+    /// - `MathOverflow`: the weighted sum does not fit in an `i128`
+    pub fn aggregate_tiered(&self, now: i64) -> Result<PriceData> {
+        self.blend_tiers(now).map(|(price, _)| price)
+    }
+
+    /// `aggregate_tiered` together with the mask of feeds whose tier was blended.
+    fn blend_tiers(&self, now: i64) -> Result<(PriceData, u32)> {
+        let tiers = self.tier_readings(now);
+        let reference = tiers
+            .iter()
+            .flatten()
+            .find(|reading| !reading.fallback)
+            .or_else(|| tiers.iter().flatten().next())
+            .copied()
+            .ok_or(StateError::NoActiveFeeds)?;
+        let max_spread_bps = match self.max_tier_spread_bps {
+            0 => DEFAULT_TIER_SPREAD_BPS,
+            bps => bps,
+        };
+        let weights_configured = self.tier_weights_bps.iter().any(|&weight| weight > 0);
+
+        let mut weighted_price: i128 = 0;
+        let mut weighted_conf: u128 = 0;
+        let mut total_weight: i128 = 0;
+        let mut blended = 0u32;
+        for (tier, reading) in tiers.iter().enumerate() {
+            let Some(reading) = reading else {
+                continue;
+            };
+            if !within_spread(reference.price, reading.price, max_spread_bps) {
+                continue;
+            }
+            let configured = if weights_configured {
+                self.tier_weights_bps[tier] as i128
+            } else {
+                WEIGHT_PRECISION as i128
+            };
+            let weight = if reading.fallback {
+                configured * FALLBACK_WEIGHT_BPS as i128 / 10_000
+            } else {
+                configured
+            };
+            weighted_price = reading
+                .price
+                .checked_mul(weight)
+                .and_then(|term| weighted_price.checked_add(term))
+                .ok_or(StateError::MathOverflow)?;
+            weighted_conf += reading.conf as u128 * weight as u128;
+            total_weight += weight;
+            blended |= reading.members;
+        }
+
+        let (price, conf) = if total_weight == 0 {
+            (reference.price, reference.conf)
+        } else {
+            (
+                weighted_price / total_weight,
+                (weighted_conf / total_weight as u128) as u64,
+            )
+        };
+        let aggregate = PriceData {
+            price,
+            conf,
+            timestamp: now,
+            expo: self.current_price.expo,
+            _padding: [0; 12],
+        };
+        Ok((aggregate, blended))
+    }
+
+    /// Replaces the tier blend weights and the cross-tier divergence bound.
+    ///
+    /// Weights are indexed by `PriceTier` and must sum to `WEIGHT_PRECISION`; a
+    /// tier may be set to zero to keep it as a sanity check on the others without
+    /// letting it move the blend.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidTierConfig`: the weights do not sum to `WEIGHT_PRECISION`, or
+    ///   `max_spread_bps` is zero or above 10,000
+    pub fn set_tier_config(
+        &mut self,
+        weights_bps: [u16; PRICE_TIER_COUNT],
+        max_spread_bps: u16,
+    ) -> Result<()> {
+        let total: u32 = weights_bps.iter().map(|&weight| weight as u32).sum();
+        require!(
+            total == WEIGHT_PRECISION && (1..=10_000).contains(&max_spread_bps),
+            StateError::InvalidTierConfig
+        );
+        self.tier_weights_bps = weights_bps;
+        self.max_tier_spread_bps = max_spread_bps;
+        Ok(())
+    }
+
+    /// Spread bound `enforce_feed_consensus` applies, with zero read as
+    /// `DEFAULT_FEED_SPREAD_BPS`.
+    pub fn feed_spread_bps(&self) -> u16 {
+        match self.max_feed_spread_bps {
+            0 => DEFAULT_FEED_SPREAD_BPS,
+            bps => bps,
+        }
+    }
+
+    /// Replaces the cross-feed spread bound.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidFeedSpread`: `max_spread_bps` is zero or above 10,000
+    pub fn set_feed_spread(&mut self, max_spread_bps: u16) -> Result<()> {
+        require!(
+            (1..=10_000).contains(&max_spread_bps),
+            StateError::InvalidFeedSpread
+        );
+        self.max_feed_spread_bps = max_spread_bps;
+        Ok(())
+    }
+
+    /// Ring depth a DEX feed must reach before it is priced, with zero read as
+    /// `DEFAULT_MIN_OBSERVATIONS`.
+    ///
+    /// Stored rather than taken from the update config: a keeper that could
+    /// lower it per call could price a cold pool the oracle means to refuse.
+    pub fn observation_floor(&self) -> u16 {
+        match self.min_observations {
+            0 => DEFAULT_MIN_OBSERVATIONS,
+            count => count,
+        }
+    }
+
+    /// Replaces the ring depth DEX feeds need before they are priced.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidMinObservations`: `count` is zero or larger than a Raydium
+    ///   observation ring, which no pool could ever satisfy
+    pub fn set_min_observations(&mut self, count: u16) -> Result<()> {
+        require!(
+            (1..=OBSERVATION_NUM as u16).contains(&count),
+            StateError::InvalidMinObservations
+        );
+        self.min_observations = count;
+        Ok(())
+    }
+
+    /// Depth drop `record_liquidity` tolerates before flagging a shock, with
+    /// zero read as `DEFAULT_LIQUIDITY_DROP_BPS`.
+    ///
+    /// Stored rather than taken from the update config: a keeper that could
+    /// raise it per call could hide the liquidity pull that precedes a
+    /// manipulated print.
+    pub fn liquidity_drop_bps(&self) -> u16 {
+        match self.max_liquidity_drop_bps {
+            0 => DEFAULT_LIQUIDITY_DROP_BPS,
+            bps => bps,
+        }
+    }
+
+    /// Replaces the depth drop tolerated between two reads of a feed.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidLiquidityDropBound`: `max_drop_bps` is zero or above 10,000
+    pub fn set_liquidity_drop_bound(&mut self, max_drop_bps: u16) -> Result<()> {
+        require!(
+            (1..=10_000).contains(&max_drop_bps),
+            StateError::InvalidLiquidityDropBound
+        );
+        self.max_liquidity_drop_bps = max_drop_bps;
+        Ok(())
+    }
+
+    /// Gates `current_price` persistence on cross-feed agreement within `feed_spread_bps`.
+    ///
+    /// The bound is stored on the oracle rather than passed by the updater:
+    /// widening it decides which disagreements reach the circuit breaker, so
+    /// only a `MODIFY_CONFIG` holder may move it.
+    ///
+    /// # Outcomes
+    ///
+    /// - `Ok(true)`: feeds agree, the caller may publish
+    /// - `Ok(false)`: feeds disagree and the circuit breaker is enabled, so emergency
+    ///   mode has been entered; the caller must skip publishing and return success
+    ///   so the breaker state is actually persisted
+    /// - `Err(FeedsDisagree)`: feeds disagree and the breaker is disabled; the whole
+    ///   update is rejected and nothing is written
+    pub fn enforce_feed_consensus(&mut self, now: i64) -> Result<bool> {
+        if self.feeds_agree(self.feed_spread_bps(), now) {
+            return Ok(true);
+        }
+
+        require!(self.is_circuit_breaker_enabled(), StateError::FeedsDisagree);
+        self.flags.set(StateFlags::EMERGENCY_MODE);
+        Ok(false)
+    }
+
+    /// Applies the per-feed circuit breakers and escalates only when too few feeds remain.
+    ///
+    /// # Isolation Before Escalation
+    ///
+    /// Every active feed's `BREAKER_TRIPPED` flag is refreshed against
+    /// `manipulation_threshold`, which removes just the offending feeds from
+    /// aggregation. The oracle-wide breaker is reserved for the case where that
+    /// isolation leaves fewer than `min_healthy_feeds` active, untripped feeds,
+    /// since a price resting on that little would no longer be cross-checked.
+    ///
+    /// # Outcomes
+    ///
+    /// Mirrors `enforce_feed_consensus`:
+    /// - `Ok(true)`: enough healthy feeds remain, the caller may continue
+    /// - `Ok(false)`: too few remain and the circuit breaker is enabled, so emergency
+    ///   mode has been entered; the caller must return success without publishing
+    /// - `Err(ManipulationDetected)`: too few remain and the breaker is disabled
+    pub fn enforce_feed_breakers(&mut self, min_healthy_feeds: u8) -> Result<bool> {
+        let active_count = self.active_feed_count as usize;
+        let mut healthy: u8 = 0;
+
+        for index in 0..active_count {
+            let threshold = self.manipulation_threshold_for(self.price_feeds[index].source_type);
+            let feed = &mut self.price_feeds[index];
+            if feed.flags.is_active() && !feed.update_breaker(threshold) {
+                healthy += 1;
+            }
+        }
+
+        if healthy >= min_healthy_feeds {
+            return Ok(true);
+        }
+
+        require!(
+            self.is_circuit_breaker_enabled(),
+            StateError::ManipulationDetected
+        );
+        self.flags.set(StateFlags::EMERGENCY_MODE);
+        Ok(false)
+    }
+
+    /// Returns the canonical price exponent once it has been established.
+    ///
+    /// `None` means no feed has been registered yet, so there is nothing to be
+    /// consistent with.
+    #[inline(always)]
+    pub fn expected_expo(&self) -> Option<i32> {
+        (self.expo_initialized != 0).then_some(self.current_price.expo)
+    }
+
+    /// Returns a feed's last price normalized to the oracle's canonical exponent.
+    ///
+    /// Aggregation must never mix mantissas with different exponents; routing every
+    /// feed read through this method makes a feed quoting at 1e-8 directly comparable
+    /// with the oracle's published 1e-6 price.
+    #[inline]
+    pub fn normalized_feed_price(&self, feed: &PriceFeed) -> Result<i128> {
+        feed.price_in_expo(self.current_price.expo)
+    }
+
+    /// Locks the canonical exponent if no earlier registration has.
+    ///
+    /// # Locking Policy
+    ///
+    /// The first registered feed decides the exponent, derived from its pool's
+    /// token decimals, and it is stored in `current_price.expo`. Locking at
+    /// registration rather than on the first update means the exponent is an
+    /// administrative decision made under `ADD_FEED`: whichever updater happens
+    /// to land the first update, with whichever pool it supplies, cannot choose
+    /// it. Later registrations leave the lock untouched.
+    pub fn lock_expected_expo(&mut self, expo: i32) {
+        if self.expected_expo().is_none() {
+            self.current_price.expo = expo;
+            self.expo_initialized = 1;
+        }
+    }
+
+    /// Enforces that every incoming price shares the oracle's canonical exponent.
+    ///
+    /// Observations with a different exponent are rejected rather than rescaled:
+    /// a drifting exponent almost always means the wrong pool was supplied, and
+    /// silently rescaling would turn that misconfiguration into a price that is
+    /// off by whole orders of magnitude while looking perfectly healthy. An
+    /// oracle with no locked exponent has no registered feed to price from and
+    /// is rejected the same way.
+    pub fn check_expected_expo(&self, observed_expo: i32) -> Result<()> {
+        require!(
+            self.expected_expo() == Some(observed_expo),
+            StateError::ExponentMismatch
+        );
+        Ok(())
+    }
+
+    /// Advances the update nonce and returns the value assigned to this update.
+    ///
+    /// # Replay Disambiguation
+    ///
+    /// Two updates landing in the same slot can carry identical timestamps, which
+    /// leaves indexers unable to tell a re-emitted `PriceUpdated` from a fresh one.
+    /// The nonce is the tie-breaker: it starts at 1 for the first update and is
+    /// never reset. Overflow is reported rather than wrapped, since a wrapped nonce
+    /// would silently break the strict ordering consumers rely on.
+    pub fn advance_update_nonce(&mut self) -> Result<u64> {
+        self.update_nonce = self
+            .update_nonce
+            .checked_add(1)
+            .ok_or(StateError::MathOverflow)?;
+        Ok(self.update_nonce)
+    }
+
+    /// Marks the oracle as alive at `now` without publishing a new price.
+    ///
+    /// # Heartbeat vs Update
+    ///
+    /// When prices are flat, `update_price` may skip the history push and keepers
+    /// may skip updates entirely, leaving consumers unable to tell a quiet market
+    /// from an abandoned oracle. A heartbeat advances `last_update` and the update
+    /// nonce but leaves `current_price` (including its timestamp) and history
+    /// untouched, so price staleness checks still see the age of the real price.
+    ///
+    /// # Errors
+    ///
+    /// - Whatever `require_operational` rejects: a paused oracle is not live
+    /// - `HeartbeatTooFrequent`: less than `min_interval` seconds since the last
+    ///   update or heartbeat, which bounds the spam a keeper key can produce
+    ///
+    /// Returns the nonce assigned to this heartbeat.
+    pub fn record_heartbeat(&mut self, now: i64, min_interval: i64) -> Result<u64> {
+        self.require_operational()?;
+        require!(
+            now.saturating_sub(self.last_update) >= min_interval,
+            StateError::HeartbeatTooFrequent
+        );
+
+        let nonce = self.advance_update_nonce()?;
+        self.last_update = now;
+        Ok(nonce)
+    }
+
+    /// Chronological order of the three rotating historical chunks, oldest first.
+    ///
+    /// # Why Indices
+    ///
+    /// The newest chunk is always the one at `current_chunk_index`, and the two after
+    /// it in rotation order are progressively older. Expressing that as an index
+    /// permutation keeps the ordering rule in one place, independent of how callers
+    /// hold the chunks (loaded accounts, keys for logging, or event payloads).
+    #[inline(always)]
+    pub fn chunk_order(&self) -> [usize; 3] {
+        Self::chunk_order_for(self.current_chunk_index)
+    }
+
+    /// Index permutation for an arbitrary `current_chunk_index`, usable before the
+    /// oracle account is loaded.
+    #[inline(always)]
+    pub const fn chunk_order_for(current_chunk_index: u16) -> [usize; 3] {
+        let newest = (current_chunk_index % 3) as usize;
+        [(newest + 1) % 3, (newest + 2) % 3, newest]
+    }
+
+    /// Rejects account bytes that would place the state on a misaligned address.
+    ///
+    /// Unlike the packed Raydium layouts, `OracleState` is a naturally aligned
+    /// `repr(C)` struct that Anchor maps in place with `bytemuck`, which panics
+    /// on a misaligned cast. `data` is the full account buffer, discriminator
+    /// included; checking it first turns that abort into an ordinary
+    /// `MisalignedAccountData` error.
+    pub fn check_data_alignment(data: &[u8]) -> Result<()> {
+        let state_ptr = data.as_ptr().wrapping_add(8);
+        require!(
+            state_ptr.align_offset(core::mem::align_of::<OracleState>()) == 0,
+            StateError::MisalignedAccountData
+        );
+        Ok(())
+    }
+
+    /// Key of the historical chunk linked at `index`.
+    ///
+    /// Returns `None` both for an index past the end of `historical_chunks` and
+    /// for a slot still holding `Pubkey::default()`, so an unlinked slot can never
+    /// be mistaken for a real account address.
+    #[inline(always)]
+    pub fn historical_chunk_key(&self, index: usize) -> Option<Pubkey> {
+        self.historical_chunks
+            .get(index)
+            .copied()
+            .filter(|key| *key != Pubkey::default())
+    }
+
+    /// Requires `chunk_key` to be the chunk this oracle links at `index`.
+    ///
+    /// Complements `HistoricalChunk::verify_oracle_link`, which checks the
+    /// chunk's pointer back to the oracle; this checks the oracle's pointer to
+    /// the chunk, so both directions of the link must agree before history is
+    /// read or written.
+    pub fn verify_historical_chunk(&self, index: usize, chunk_key: &Pubkey) -> Result<()> {
+        require!(
+            self.historical_chunk_key(index) == Some(*chunk_key),
+            StateError::UnlinkedHistoricalChunk
+        );
+        Ok(())
+    }
+
+    /// Clears the link to an empty chunk outside the rotating ring so it can be closed.
+    ///
+    /// # Pruning Rules
+    ///
+    /// `update_price` rotates through slots `0..MIN_HISTORICAL_CHUNKS`, so those
+    /// slots, the active chunk among them, are never released. Beyond the ring a
+    /// chunk is released only while it holds no price points, since closing it
+    /// would otherwise destroy history, and only while the oracle keeps more than
+    /// `MIN_HISTORICAL_CHUNKS` links afterwards. Both directions of the link are
+    /// verified first so a caller cannot clear one slot by presenting another
+    /// oracle's chunk.
+    ///
+    /// # Errors
+    ///
+    /// - `UnlinkedHistoricalChunk`: `chunk_key` is not linked at `index`
+    /// - `ChunkOracleMismatch`: the chunk points at a different oracle
+    /// - `ActiveHistoricalChunk`: `index` is part of the rotating ring
+    /// - `HistoricalChunkNotEmpty`: the chunk still holds price points
+    /// - `TooFewHistoricalChunks`: no more than the minimum would remain linked
+    pub fn unlink_historical_chunk(
+        &mut self,
+        index: usize,
+        chunk_key: &Pubkey,
+        chunk: &HistoricalChunk,
+        oracle_key: &Pubkey,
+    ) -> Result<()> {
+        self.verify_historical_chunk(index, chunk_key)?;
+        chunk.verify_oracle_link(oracle_key)?;
+
+        require!(
+            index >= MIN_HISTORICAL_CHUNKS,
+            StateError::ActiveHistoricalChunk
+        );
+        require!(chunk.count == 0, StateError::HistoricalChunkNotEmpty);
+
+        let linked = (0..MAX_HISTORICAL_CHUNKS)
+            .filter(|slot| self.historical_chunk_key(*slot).is_some())
+            .count();
+        require!(
+            linked > MIN_HISTORICAL_CHUNKS,
+            StateError::TooFewHistoricalChunks
+        );
+
+        self.historical_chunks[index] = Pubkey::default();
+        Ok(())
+    }
+
+    /// Rewrites every historical chunk link into canonical order.
+    ///
+    /// # Canonical Order
+    ///
+    /// `chunks[i]` is the chunk at `chunk_keys[i]` and takes slot `i`: the oracle
+    /// links it at `historical_chunks[i]`, its `chunk_id` becomes `i`, and its
+    /// `next_chunk` points at the chunk in slot `i + 1`, with the last chunk
+    /// terminating the chain exactly as initialization leaves it. Slots past the
+    /// supplied set are cleared, so the caller must pass every chunk worth keeping.
+    ///
+    /// Every chunk's back-reference is checked before anything is written, so a
+    /// foreign chunk aborts the repair with all links untouched. Price points are
+    /// never moved; only the pointers between accounts change.
+    ///
+    /// Returns how many pointers, across the oracle and its chunks, had to change.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidChunkSet`: the key and chunk counts differ, a key repeats, or
+    ///   more than `MAX_HISTORICAL_CHUNKS` chunks are supplied
+    /// - `TooFewHistoricalChunks`: fewer chunks than the rotating ring needs
+    /// - `ChunkOracleMismatch`: a chunk points at a different oracle
+    pub fn repair_chunk_links(
+        &mut self,
+        oracle_key: &Pubkey,
+        chunk_keys: &[Pubkey],
+        chunks: &mut [&mut HistoricalChunk],
+    ) -> Result<u8> {
+        require!(
+            chunk_keys.len() == chunks.len() && chunk_keys.len() <= MAX_HISTORICAL_CHUNKS,
+            StateError::InvalidChunkSet
+        );
+        require!(
+            chunk_keys.len() >= MIN_HISTORICAL_CHUNKS,
+            StateError::TooFewHistoricalChunks
+        );
+        for (i, key) in chunk_keys.iter().enumerate() {
+            require!(
+                *key != Pubkey::default() && !chunk_keys[..i].contains(key),
+                StateError::InvalidChunkSet
+            );
+        }
+        for chunk in chunks.iter() {
+            chunk.verify_oracle_link(oracle_key)?;
+        }
+
+        let mut rewritten: u8 = 0;
+
+        for slot in 0..MAX_HISTORICAL_CHUNKS {
+            let key = chunk_keys.get(slot).copied().unwrap_or_default();
+            if self.historical_chunks[slot] != key {
+                self.historical_chunks[slot] = key;
+                rewritten += 1;
+            }
+        }
+
+        for (slot, chunk) in chunks.iter_mut().enumerate() {
+            let next = chunk_keys.get(slot + 1).copied().unwrap_or_default();
+            if chunk.next_chunk != next || chunk.chunk_id != slot as u16 {
+                chunk.next_chunk = next;
+                chunk.chunk_id = slot as u16;
+                rewritten += 1;
+            }
+        }
+
+        Ok(rewritten)
+    }
+
+    /// Peak manipulation score for `feed_index` after time decay at `now`.
+    ///
+    /// The peak is held at full strength for `manipulation_cooldown` seconds, then
+    /// falls linearly to zero over `manipulation_decay_period`. Linear decay keeps the
+    /// result exact in integer math and makes the quarantine horizon easy to reason
+    /// about: a peak is fully forgotten exactly `cooldown + decay_period` seconds
+    /// after it was recorded.
+    pub fn decayed_manipulation_peak(&self, feed_index: usize, now: i64) -> u16 {
+        let peak = self.manipulation_peak_score[feed_index];
+        let elapsed = now
+            .saturating_sub(self.manipulation_peak_at[feed_index])
+            .max(0) as u64;
+        let cooldown = self.manipulation_cooldown as u64;
+
+        if elapsed <= cooldown {
+            return peak;
+        }
+
+        let decay_period = self.manipulation_decay_period as u64;
+        let into_decay = elapsed - cooldown;
+        if into_decay >= decay_period {
+            return 0;
+        }
+
+        (peak as u64 * (decay_period - into_decay) / decay_period) as u16
+    }
+
+    /// Blends a fresh manipulation score with the feed's decayed history.
+    ///
+    /// # Attack Memory
+    ///
+    /// Overwriting the score on every update lets an attacker alternate manipulated
+    /// and benign updates so that any quarantine clears on the very next clean read.
+    /// The effective score is instead `max(raw, decayed_peak)`: a spike keeps the feed
+    /// flagged through the cooldown and then fades, while a new, higher spike resets
+    /// the peak and restarts the clock.
+    pub fn blend_manipulation_score(&mut self, feed_index: usize, raw_score: u16, now: i64) -> u16 {
+        let decayed_peak = self.decayed_manipulation_peak(feed_index, now);
+
+        if raw_score >= decayed_peak {
+            self.manipulation_peak_score[feed_index] = raw_score;
+            self.manipulation_peak_at[feed_index] = now;
+            raw_score
+        } else {
+            decayed_peak
+        }
+    }
+
+    /// Manipulation threshold a feed with raw `source_type` is held to.
+    ///
+    /// # Source-Specific Strictness
+    ///
+    /// A DEX pool can be pushed within a single transaction by a flash loan, while
+    /// an external oracle has already filtered its inputs, so the same raw score is
+    /// stronger evidence against a DEX feed. `source_threshold_bps` scales the
+    /// oracle-wide threshold down per source type, rounding down so a scaled
+    /// threshold is never looser than configured. Unknown discriminants are judged
+    /// as DEX, matching `SourceType::from_u8_or_default`; unset entries keep the
+    /// oracle-wide threshold.
+    pub fn manipulation_threshold_for(&self, source_type: u8) -> u16 {
+        let source = SourceType::from_u8_or_default(source_type);
+        let scale = match self.source_threshold_bps[source.as_u8() as usize] {
+            0 => MAX_SOURCE_THRESHOLD_BPS,
+            bps => bps,
+        };
+
+        (self.manipulation_threshold as u32 * scale as u32 / MAX_SOURCE_THRESHOLD_BPS as u32) as u16
+    }
+
+    /// Replaces the per-source threshold scaling, indexed by `SourceType` discriminant.
+    ///
+    /// Every entry must be set explicitly; `MAX_SOURCE_THRESHOLD_BPS` restores the
+    /// oracle-wide threshold for that source. Scaling can only tighten, since a
+    /// source judged more leniently than the configured threshold would let the
+    /// riskiest feed define what the oracle tolerates.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidSourceThreshold`: an entry is zero or above `MAX_SOURCE_THRESHOLD_BPS`
+    pub fn set_source_thresholds(
+        &mut self,
+        thresholds_bps: [u16; SOURCE_TYPE_COUNT],
+    ) -> Result<()> {
+        require!(
+            thresholds_bps
+                .iter()
+                .all(|bps| (1..=MAX_SOURCE_THRESHOLD_BPS).contains(bps)),
+            StateError::InvalidSourceThreshold
+        );
+        self.source_threshold_bps = thresholds_bps;
+        Ok(())
+    }
+
+    /// Escalates a feed's manipulation `score` along `escalation_ladder`.
+    ///
+    /// # Severity Ladder
+    ///
+    /// A score over `threshold` only keeps that feed's price out of publication,
+    /// which the per-feed breaker already does. A score far past it means the
+    /// pool is being pushed hard, and the ladder lets governance decide how much
+    /// further to go:
+    ///
+    /// - **Arm rung**: `CIRCUIT_BREAKER_ENABLED` is set, so a later disagreement
+    ///   or feed shortage halts the oracle instead of merely reverting the update
+    /// - **Emergency rung**: with the breaker enabled, including by the arm rung
+    ///   on this same call, `EMERGENCY_MODE` is set and the oracle stops
+    ///
+    /// Rungs only apply to scores over `threshold`, so a rung configured below
+    /// it takes effect at the threshold. Flags are only ever set here, never
+    /// cleared; recovery stays a governance decision, see `resume_operation`.
+    pub fn escalate_manipulation(&mut self, score: u16, threshold: u16) -> ManipulationSeverity {
+        if score <= threshold {
+            return ManipulationSeverity::Normal;
+        }
+
+        let [arm, emergency] = self.escalation_ladder;
+        let mut severity = ManipulationSeverity::Suspicious;
+        if arm != 0 && score >= arm {
+            self.flags.set(StateFlags::CIRCUIT_BREAKER_ENABLED);
+            severity = ManipulationSeverity::BreakerArmed;
+        }
+        if emergency != 0 && score >= emergency && self.is_circuit_breaker_enabled() {
+            self.flags.set(StateFlags::EMERGENCY_MODE);
+            severity = ManipulationSeverity::Emergency;
+        }
+        severity
+    }
+
+    /// Replaces the `[arm_breaker, emergency]` escalation rungs.
+    ///
+    /// Each rung is a manipulation score, zero to disable it. When both are
+    /// set, arming may not come after the emergency rung: a score high enough
+    /// to halt must also have armed the breaker the halt depends on.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidEscalationLadder`: a rung exceeds `MAX_MANIPULATION_THRESHOLD`,
+    ///   or the arm rung is above the emergency rung
+    pub fn set_escalation_ladder(&mut self, ladder: [u16; 2]) -> Result<()> {
+        let [arm, emergency] = ladder;
+        require!(
+            arm <= MAX_MANIPULATION_THRESHOLD
+                && emergency <= MAX_MANIPULATION_THRESHOLD
+                && (arm == 0 || emergency == 0 || arm <= emergency),
+            StateError::InvalidEscalationLadder
+        );
+        self.escalation_ladder = ladder;
+        Ok(())
+    }
+
+    /// Validates all active feeds against manipulation detection criteria.
+    ///
+    /// # Anti-MEV Design
+    ///
+    /// This method implements multiple layers of manipulation resistance to protect
+    /// against maximum extractable value attacks that could compromise DeFi protocols:
+    ///
+    /// 1. **LP Concentration Limits**: Prevents single liquidity provider from
+    ///    controlling price discovery on individual feeds. Without this check, a whale
+    ///    could manipulate prices by concentrating liquidity in a single pool, creating
+    ///    artificial price signals that trigger cascading liquidations.
+    /// 2. **Manipulation Scoring**: Detects coordinated attacks across feeds
+    ///    using statistical analysis of price movements and volume patterns. This
+    ///    prevents sophisticated attacks where multiple feeds are manipulated in concert
+    ///    to create false consensus.
+    /// 3. **Active Feed Filtering**: Ignores disabled feeds to prevent attackers
+    ///    from gaming the system by temporarily disabling honest sources, which could
+    ///    artificially inflate the influence of remaining compromised feeds.
+    ///
+    /// The early continue pattern optimizes for the common case where most feeds
+    /// are active, minimizing branch mispredictions in the hot path. This performance
+    /// optimization is crucial because manipulation checks run on every price update.
+    pub fn check_manipulation_resistance(&self) -> Result<()> {
+        for feed in self.active_feeds() {
+            // Skip manipulation checks for inactive feeds to prevent
+            // attackers from gaming the system by disabling honest sources
+            if !feed.flags.is_active() {
+                continue;
+            }
+
+            // Prevent single LP from controlling price discovery
+            if feed.lp_concentration > MAX_LP_CONCENTRATION {
+                return Err(StateError::ExcessiveLpConcentration.into());
+            }
+
+            // Detect coordinated manipulation across multiple vectors
+            if feed.manipulation_score > self.manipulation_threshold_for(feed.source_type) {
+                return Err(StateError::ManipulationDetected.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Centralized permission validation with governance delegation.
+    ///
+    /// # Architecture Rationale
+    ///
+    /// This method provides a clean interface for permission checking while maintaining
+    /// separation of concerns. The oracle state doesn't need to understand governance
+    /// implementation details - it simply delegates to the governance system.
+    ///
+    /// # Performance Considerations
+    ///
+    /// By accepting the governance state as a parameter rather than storing a reference,
+    /// this design avoids borrowing complications and enables the caller to optimize
+    /// governance account access patterns. The governance state is typically already
+    /// loaded for instruction validation, so this pattern avoids redundant account reads.
+    ///
+    /// # Security Design
+    ///
+    /// Centralizing permission logic in the governance state ensures consistent
+    /// authorization semantics across all oracle operations. This prevents divergent
+    /// permission implementations that could create security vulnerabilities.
+    pub fn check_permission(
+        governance: &GovernanceState,
+        caller: &Pubkey,
+        required_permission: Permissions,
+    ) -> Result<()> {
+        governance.check_member_permission(caller, required_permission)
+    }
+
+    /// Decoded DEX validation policy; see `ValidationPolicy::from_stored_u8`.
+    ///
+    /// Stored rather than taken from the update config: the single-method and
+    /// fallback policies each drop a cross-check, and a keeper that could pick
+    /// one per call could skip the check that would have rejected its price.
+    #[inline(always)]
+    pub fn get_validation_policy(&self) -> ValidationPolicy {
+        ValidationPolicy::from_stored_u8(self.validation_policy)
+    }
+
+    /// Replaces the validation policy DEX reads run under.
+    #[inline(always)]
+    pub fn set_validation_policy(&mut self, policy: ValidationPolicy) {
+        self.validation_policy = policy.as_stored_u8();
+    }
+
+    /// Decoded updater policy; see `UpdaterPolicy::from_u8_or_default`.
+    #[inline(always)]
+    pub fn get_updater_policy(&self) -> UpdaterPolicy {
+        UpdaterPolicy::from_u8_or_default(self.updater_policy)
+    }
+
+    /// Whether `key` occupies a slot in `allowed_updaters`.
+    pub fn is_allowed_updater(&self, key: &Pubkey) -> bool {
+        *key != Pubkey::default() && self.allowed_updaters.contains(key)
+    }
+
+    /// Rejects `caller` unless the oracle's updater policy admits it.
+    ///
+    /// # Errors
+    ///
+    /// - `UnauthorizedCaller`: `caller` is neither a governance member nor, under
+    ///   `Allowlist`, a listed keeper
+    /// - `InsufficientPermissions`: `caller` is a member without `UPDATE_PRICE`
+    ///   and is not listed
+    pub fn check_updater(&self, governance: &GovernanceState, caller: &Pubkey) -> Result<()> {
+        match self.get_updater_policy() {
+            UpdaterPolicy::Allowlist if self.is_allowed_updater(caller) => Ok(()),
+            UpdaterPolicy::Allowlist | UpdaterPolicy::GovernanceOnly => {
+                Self::check_permission(governance, caller, Permissions::UPDATE_PRICE)
+            }
+        }
+    }
+
+    /// Replaces the updater policy and allowlist in one step.
+    ///
+    /// The list is rewritten wholesale rather than edited slot by slot, so the
+    /// stored set always matches exactly what the admin signed for. Keys may be
+    /// supplied under any policy; they only take effect under `Allowlist`.
+    ///
+    /// # Errors
+    ///
+    /// - `TooManyUpdaters`: more than `MAX_ALLOWED_UPDATERS` keys
+    /// - `InvalidUpdaterKey`: the default key, which would mark an empty slot
+    /// - `DuplicateUpdater`: the same key listed twice
+    pub fn set_updaters(&mut self, policy: UpdaterPolicy, updaters: &[Pubkey]) -> Result<()> {
+        require!(
+            updaters.len() <= MAX_ALLOWED_UPDATERS,
+            StateError::TooManyUpdaters
+        );
+        for (i, updater) in updaters.iter().enumerate() {
+            require!(*updater != Pubkey::default(), StateError::InvalidUpdaterKey);
+            require!(
+                !updaters[..i].contains(updater),
+                StateError::DuplicateUpdater
+            );
+        }
+
+        self.allowed_updaters = [Pubkey::default(); MAX_ALLOWED_UPDATERS];
+        self.allowed_updaters[..updaters.len()].copy_from_slice(updaters);
+        self.updater_policy = policy.as_u8();
+        Ok(())
+    }
+
+    /// Rotates the emergency admin once both halves of the dual signature check out.
+    ///
+    /// # Dual Authorization
+    ///
+    /// The emergency admin can halt the oracle instantly, so neither side may
+    /// replace it alone: the outgoing admin proves the handover is intended, and a
+    /// governance member holding `ADMIN_ALL` proves the successor is acceptable to
+    /// the multisig. The two signers must be distinct keys; otherwise an emergency
+    /// admin that is also a governance admin could rotate itself unilaterally.
+    ///
+    /// # Errors
+    ///
+    /// - `InvalidEmergencyAdmin`: `new_admin` is the default key, which would
+    ///   silently remove the fail-safe rather than rotate it
+    /// - `UnauthorizedCaller`: `current_admin` is not the stored emergency admin,
+    ///   or `approver` is not a governance member
+    /// - `InsufficientPermissions`: `approver` is a member without `ADMIN_ALL`
+    /// - `DualSignatureRequired`: both roles were claimed by the same key
+    ///
+    /// Returns the previous emergency admin for event emission.
+    pub fn transfer_emergency_admin(
+        &mut self,
+        governance: &GovernanceState,
+        current_admin: &Pubkey,
+        approver: &Pubkey,
+        new_admin: Pubkey,
+    ) -> Result<Pubkey> {
+        require!(
+            new_admin != Pubkey::default(),
+            StateError::InvalidEmergencyAdmin
+        );
+        require_keys_eq!(
+            *current_admin,
+            self.emergency_admin,
+            StateError::UnauthorizedCaller
+        );
+        require_keys_neq!(*current_admin, *approver, StateError::DualSignatureRequired);
+
+        governance.check_admin(approver)?;
+
+        let previous_admin = self.emergency_admin;
+        self.emergency_admin = new_admin;
+        Ok(previous_admin)
+    }
+
+    /// Halts the oracle on the emergency admin's sole authority.
+    ///
+    /// # Why Unilateral
+    ///
+    /// Governance-gated halts wait on votes and timelocks, which is too slow while
+    /// an exploit is draining consumers. The emergency admin is the one key trusted
+    /// to act alone, and only in the safe direction: it can stop the oracle but has
+    /// no matching path to resume it; only a governance threshold can.
+    ///
+    /// Sets `EMERGENCY_MODE`, which blocks updates and reads, and arms
+    /// `CIRCUIT_BREAKER_ENABLED` so a trip before governance resumes the oracle
+    /// halts it again rather than merely reverting. Resuming is
+    /// `resume_operation`, which the emergency admin cannot call alone.
+    ///
+    /// # Errors
+    ///
+    /// - `UnauthorizedCaller`: `caller` is not the stored emergency admin
+    pub fn activate_kill_switch(&mut self, caller: &Pubkey) -> Result<()> {
+        require_keys_eq!(
+            *caller,
+            self.emergency_admin,
+            StateError::UnauthorizedCaller
+        );
+
+        self.flags.set(StateFlags::EMERGENCY_MODE);
+        self.flags.set(StateFlags::CIRCUIT_BREAKER_ENABLED);
+        Ok(())
+    }
+
+    /// Lifts an emergency halt and disarms the circuit breaker.
+    ///
+    /// # Why Threshold-Gated
+    ///
+    /// Every halt path (the kill switch, feed disagreement, a feed shortage and
+    /// the escalation ladder) sets `EMERGENCY_MODE`, and several arm
+    /// `CIRCUIT_BREAKER_ENABLED` on the way. Halting is safe to do alone;
+    /// declaring the incident over is not, since it puts the price back in front
+    /// of consumers. Resuming therefore needs `multi_sig_threshold` distinct
+    /// `MODIFY_CONFIG` holders among `signers`.
+    ///
+    /// Both flags are cleared together: a breaker left armed by the incident
+    /// would halt the oracle again on the first disagreement after recovery.
+    /// Maintenance mode is left as it is, since it was entered on purpose.
+    ///
+    /// # Errors
+    ///
+    /// - `InsufficientSigners`: fewer qualifying signers than the threshold
+    pub fn resume_operation(
+        &mut self,
+        governance: &GovernanceState,
+        signers: &[Pubkey],
+    ) -> Result<()> {
+        governance.check_threshold_signers(signers, Permissions::MODIFY_CONFIG)?;
+
+        self.flags.clear(StateFlags::EMERGENCY_MODE);
+        self.flags.clear(StateFlags::CIRCUIT_BREAKER_ENABLED);
+        Ok(())
+    }
+
+    /// Validates snapshot quality for redemption eligibility using existing HistoricalChunk infrastructure.
+    ///
+    /// # Architecture Benefits
     ///
     /// This design reuses existing HistoricalChunk infrastructure instead of maintaining
     /// separate snapshot buffers, providing several critical advantages:
@@ -505,49 +2310,140 @@ impl OracleState {
     /// - **Space Complexity**: O(1) with no heap allocation
     /// - **Typical Runtime**: <2ms for 3-chunk analysis
     /// - **Memory Efficiency**: Chunks likely already loaded for TWAP calculations
-    pub fn check_snapshot_requirements_from_history(
+    ///
+    /// Chunks are taken by reference so on-chain callers can pass loaded account
+    /// borrows directly; copying three chunks onto the BPF stack would overflow it.
+    pub fn check_snapshot_requirements_from_history<'a>(
         &self,
-        historical_chunks: &[HistoricalChunk],
+        historical_chunks: impl IntoIterator<Item = &'a HistoricalChunk>,
         current_timestamp: i64,
         required_hours: u16,
     ) -> SnapshotStatus {
         // Calculate validation window based on required hours (max 96h)
         let validation_hours = required_hours.min(MAX_HOURS);
-        let window_seconds = (validation_hours as i64) * SECONDS_PER_HOUR;
-        let window_start = current_timestamp - window_seconds;
 
         // Use stack-allocated array to avoid heap allocation and CU overhead
         // Maximum possible size: BUFFER_SIZE per chunk * 3 chunks for 96-hour support
         let mut valid_timestamps = [0i64; BUFFER_SIZE * 3]; // Support up to 3 chunks (96h)
-        let mut valid_count = 0usize;
-
-        // Traverse recent chunks (up to 3 for 96-hour window support)
-        for chunk in historical_chunks.iter().take(3) {
-            // Collect timestamps from this chunk's price points
-            for i in 0..chunk.count as usize {
-                if valid_count >= valid_timestamps.len() {
-                    break; // Array full - should not happen in normal operation
-                }
+        let valid_count = collect_window_timestamps(
+            historical_chunks,
+            current_timestamp,
+            validation_hours,
+            &mut valid_timestamps,
+        );
 
-                let price_point = &chunk.price_points[i];
+        // Delegate to common validation logic with slice of valid data and configurable hours
+        self.validate_timestamp_quality(&mut valid_timestamps[0..valid_count], validation_hours)
+    }
 
-                // Only include price points within our validation window
-                if price_point.timestamp >= window_start
-                    && price_point.timestamp <= current_timestamp
-                {
-                    valid_timestamps[valid_count] = price_point.timestamp;
-                    valid_count += 1;
+    /// Snapshot check that requires an explicit count and the full span together.
+    ///
+    /// # Why a Second Version
+    ///
+    /// `check_snapshot_requirements_from_history` asks for only half the cadence
+    /// count and stops at the first failing criterion. A window whose two ends are
+    /// present passes its span check however many points are missing in between,
+    /// so 145 of the 289 fence posts of a 72-hour window are enough. Here the
+    /// caller states how many points it needs, and both criteria must hold
+    /// before the history is accepted. The original method is kept unchanged
+    /// for existing callers.
+    ///
+    /// # Thresholds
+    ///
+    /// - **Count**: at least `required_count` points in the window. Zero asks for
+    ///   every fence post at `MIN_HISTORICAL_INTERVAL`, `hours * 4 + 1`.
+    /// - **Span**: newest minus oldest must cover `required_hours` in whole seconds
+    ///   (never less than `MIN_TIME_SPAN_HOURS`), not in truncated hours.
+    ///
+    /// # Reported Shortfall
+    ///
+    /// When both fail, the variant names the fix. If the points present are as
+    /// dense as their span allows, the history is simply too young and waiting
+    /// is the only cure, so `InsufficientTimeSpan` is returned. Otherwise updates
+    /// were missed inside the span and `InsufficientCount` is returned.
+    ///
+    /// Three 128-point chunks hold at most 384 points, one short of the 385
+    /// fence posts of a full 96-hour window. A 96-hour request needs an explicit,
+    /// lower `required_count`, and its span is met only if some updates were
+    /// spaced wider than `MIN_HISTORICAL_INTERVAL`.
+    pub fn check_snapshot_requirements_v2<'a>(
+        &self,
+        historical_chunks: impl IntoIterator<Item = &'a HistoricalChunk>,
+        current_timestamp: i64,
+        required_hours: u16,
+        required_count: u16,
+    ) -> SnapshotStatus {
+        let validation_hours = required_hours.min(MAX_HOURS);
+
+        let mut valid_timestamps = [0i64; BUFFER_SIZE * 3];
+        let valid_count = collect_window_timestamps(
+            historical_chunks,
+            current_timestamp,
+            validation_hours,
+            &mut valid_timestamps,
+        );
+        let timestamps = &mut valid_timestamps[0..valid_count];
+
+        if timestamps.is_empty() {
+            return SnapshotStatus::NoSnapshots;
+        }
+        timestamps.sort_unstable();
+
+        let snapshot_count = timestamps.len() as u16;
+        let required_count = if required_count == 0 {
+            let fence_posts = validation_hours as i64 * SECONDS_PER_HOUR / MIN_HISTORICAL_INTERVAL;
+            fence_posts as u16 + 1
+        } else {
+            required_count
+        };
+
+        let required_span_hours = validation_hours.max(MIN_TIME_SPAN_HOURS);
+        let span_seconds = timestamps[timestamps.len() - 1] - timestamps[0];
+        let span_hours = (span_seconds / SECONDS_PER_HOUR) as u16;
+
+        let count_short = snapshot_count < required_count;
+        let span_short = span_seconds < required_span_hours as i64 * SECONDS_PER_HOUR;
+
+        if count_short && span_short {
+            let dense_for_span = (span_seconds / MIN_HISTORICAL_INTERVAL) as u16 + 1;
+            return if snapshot_count >= dense_for_span {
+                SnapshotStatus::InsufficientTimeSpan {
+                    span_hours,
+                    required_hours: required_span_hours,
                 }
-            }
+            } else {
+                SnapshotStatus::InsufficientCount {
+                    found: snapshot_count,
+                    required: required_count,
+                }
+            };
+        }
+        if count_short {
+            return SnapshotStatus::InsufficientCount {
+                found: snapshot_count,
+                required: required_count,
+            };
+        }
+        if span_short {
+            return SnapshotStatus::InsufficientTimeSpan {
+                span_hours,
+                required_hours: required_span_hours,
+            };
+        }
 
-            // Note: Removed early termination optimization to avoid time-span validation conflicts.
-            // Early termination based on snapshot count alone could exit before collecting enough
-            // temporal diversity, causing validate_timestamp_quality() to fail time span requirements.
-            // The performance impact is minimal since we only process up to 3 chunks maximum.
+        let max_per_hour = max_hourly_density(timestamps, validation_hours as i64);
+        if max_per_hour > MAX_SNAPSHOTS_PER_HOUR {
+            return SnapshotStatus::ExcessiveClustering {
+                max_per_hour,
+                limit_per_hour: MAX_SNAPSHOTS_PER_HOUR,
+            };
         }
 
-        // Delegate to common validation logic with slice of valid data and configurable hours
-        self.validate_timestamp_quality(&mut valid_timestamps[0..valid_count], validation_hours)
+        SnapshotStatus::Sufficient {
+            snapshot_count,
+            time_span_hours: span_hours,
+            max_hourly_density: max_per_hour,
+        }
     }
 
     /// Internal method to perform timestamp quality validation with consistent criteria.
@@ -626,35 +2522,13 @@ impl OracleState {
         }
 
         // Check for excessive clustering by analyzing hourly distribution
-        let mut max_per_hour = 0u16;
-        let total_hours = (time_span_seconds / SECONDS_PER_HOUR) + 1; // Include partial hours
-
-        // Optimize clustering analysis with early termination for large hour spans
         let max_analysis_hours = required_hours.min(96); // Limit analysis to required window
-        for hour_offset in 0..total_hours.min(max_analysis_hours as i64) {
-            let hour_start = valid_timestamps[0] + (hour_offset * SECONDS_PER_HOUR);
-            let hour_end = hour_start + SECONDS_PER_HOUR;
-
-            let mut count_in_hour = 0u16;
-
-            // Linear scan through sorted timestamps (early termination when past hour_end)
-            for &timestamp in valid_timestamps.iter() {
-                if timestamp >= hour_start && timestamp < hour_end {
-                    count_in_hour += 1;
-                } else if timestamp >= hour_end {
-                    break; // Timestamps are sorted, no more in this hour
-                }
-            }
-
-            max_per_hour = max_per_hour.max(count_in_hour);
-
-            // Early termination if we already exceed threshold
-            if max_per_hour > MAX_SNAPSHOTS_PER_HOUR {
-                return SnapshotStatus::ExcessiveClustering {
-                    max_per_hour,
-                    limit_per_hour: MAX_SNAPSHOTS_PER_HOUR,
-                };
-            }
+        let max_per_hour = max_hourly_density(valid_timestamps, max_analysis_hours as i64);
+        if max_per_hour > MAX_SNAPSHOTS_PER_HOUR {
+            return SnapshotStatus::ExcessiveClustering {
+                max_per_hour,
+                limit_per_hour: MAX_SNAPSHOTS_PER_HOUR,
+            };
         }
 
         // All criteria satisfied - return success with summary statistics
@@ -665,3 +2539,141 @@ impl OracleState {
         }
     }
 }
+
+/// Copies into `out` the timestamps of up to three chunks that fall within the
+/// `hours` ending at `current_timestamp`, both ends inclusive, and returns how
+/// many were written.
+///
+/// Every chunk is scanned in full rather than stopping once enough points are
+/// found: stopping on count alone could return a dense but short cluster and
+/// fail the span check that the remaining chunks would have satisfied.
+///
+/// Points are decoded through `HistoricalChunk::point_at`, so chunks written
+/// in the legacy point layout are read at their own stride.
+///
+/// Forced points are skipped: they record sharp moves between scheduled
+/// snapshots, and counting them would both inflate the snapshot total and
+/// trip the hourly clustering limit the regular cadence is sized for.
+fn collect_window_timestamps<'a>(
+    historical_chunks: impl IntoIterator<Item = &'a HistoricalChunk>,
+    current_timestamp: i64,
+    hours: u16,
+    out: &mut [i64],
+) -> usize {
+    let window_start = current_timestamp - hours as i64 * SECONDS_PER_HOUR;
+    let mut written = 0usize;
+
+    for chunk in historical_chunks.into_iter().take(3) {
+        for slot in 0..chunk.count as usize {
+            if written >= out.len() {
+                break; // Array full - should not happen in normal operation
+            }
+            if chunk.is_forced(slot) {
+                continue;
+            }
+            // A chunk in a layout this program cannot decode contributes nothing,
+            // which can only make the snapshot check stricter
+            let Ok(price_point) = chunk.point_at(slot) else {
+                break;
+            };
+            if (window_start..=current_timestamp).contains(&price_point.timestamp) {
+                out[written] = price_point.timestamp;
+                written += 1;
+            }
+        }
+    }
+
+    written
+}
+
+/// Largest number of sorted timestamps falling in any one-hour bucket anchored at
+/// the first timestamp, considering only the first `analysis_hours` buckets.
+///
+/// # Single Pass
+///
+/// Because the input is sorted, every bucket is a contiguous run, so one scan
+/// that counts run lengths finds the maximum in O(N). Scanning each hour window
+/// separately cost O(hours × N), up to 96 × 384 comparisons on the redemption
+/// path. Timestamps past the analysed buckets are never reached.
+///
+/// # Early Exit
+///
+/// The scan stops at the first completed bucket whose count exceeds
+/// `MAX_SNAPSHOTS_PER_HOUR` and returns that count, since the caller rejects on
+/// it regardless of what later buckets hold.
+pub(crate) fn max_hourly_density(sorted_timestamps: &[i64], analysis_hours: i64) -> u16 {
+    let Some(&first) = sorted_timestamps.first() else {
+        return 0;
+    };
+
+    let mut max_per_hour = 0u16;
+    let mut current_bucket = 0i64;
+    let mut count_in_bucket = 0u16;
+
+    for &timestamp in sorted_timestamps {
+        let bucket = (timestamp - first) / SECONDS_PER_HOUR;
+        if bucket >= analysis_hours {
+            break;
+        }
+
+        if bucket != current_bucket {
+            max_per_hour = max_per_hour.max(count_in_bucket);
+            if max_per_hour > MAX_SNAPSHOTS_PER_HOUR {
+                return max_per_hour;
+            }
+            current_bucket = bucket;
+            count_in_bucket = 0;
+        }
+        count_in_bucket += 1;
+    }
+
+    max_per_hour.max(count_in_bucket)
+}
+
+/// Whether `price` lies within `max_spread_bps` of `reference`, measured
+/// against the reference. A gap too large to scale is out of bounds.
+fn within_spread(reference: i128, price: i128, max_spread_bps: u16) -> bool {
+    match reference.unsigned_abs() {
+        0 => price == 0,
+        base => price
+            .abs_diff(reference)
+            .checked_mul(10_000)
+            .is_some_and(|scaled| scaled / base <= max_spread_bps as u128),
+    }
+}
+
+/// Median of `(price, conf)` readings with a deterministic even-count rule.
+///
+/// # Ordering
+///
+/// Readings are sorted by price, then by confidence, so the two middle entries
+/// are the same no matter what order feeds occupy their slots in.
+///
+/// # Tie-Breaking
+///
+/// - Odd count: the middle reading, price and confidence together.
+/// - Even count: price is the mean of the two middle prices, rounded toward the
+///   lower one; confidence is the smaller of the two middle confidences. `conf`
+///   is a 0-10,000 quality score where higher is better, so the minimum is the
+///   most conservative figure either source vouches for.
+///
+/// Every client that applies these rules to the same readings reproduces the
+/// same median bit for bit. Returns `None` for an empty slice.
+pub fn median_reading(readings: &mut [(i128, u64)]) -> Option<(i128, u64)> {
+    if readings.is_empty() {
+        return None;
+    }
+
+    readings.sort_unstable();
+    let upper = readings.len() / 2;
+    if readings.len() % 2 == 1 {
+        return Some(readings[upper]);
+    }
+
+    let (low_price, low_conf) = readings[upper - 1];
+    let (high_price, high_conf) = readings[upper];
+    // Floor of the mean without forming the sum, which could overflow at the extremes
+    let price = (low_price >> 1) + (high_price >> 1) + (low_price & high_price & 1);
+
+    Some((price, low_conf.min(high_conf)))
+}
@@ -1,3 +1,9 @@
+use crate::components::raydium_clmm_observer::twap::ema_step;
+use crate::error::StateError;
+use crate::utils::constants::{
+    MAX_EXTERNAL_STALENESS, QUALITY_CONCENTRATION_WEIGHT, QUALITY_FRESHNESS_WEIGHT,
+    QUALITY_LIQUIDITY_WEIGHT, QUALITY_MANIPULATION_WEIGHT, QUALITY_REFERENCE_LIQUIDITY,
+};
 use anchor_lang::prelude::*;
 use bytemuck::{Pod, Zeroable};
 
@@ -32,6 +38,10 @@ pub struct PriceFeed {
     /// Signed to support negative prices for derivatives and spread instruments.
     pub last_price: i128,
 
+    /// Exponentially smoothed `last_price`, in the same native exponent.
+    /// Seeded with the first good reading and stepped toward each later one.
+    pub ewma_price: i128,
+
     /// 24-hour trading volume to assess market activity and manipulation resistance.
     /// Higher volume typically correlates with harder-to-manipulate prices.
     pub volume_24h: i128,
@@ -40,6 +50,10 @@ pub struct PriceFeed {
     /// Used to estimate how much capital would be needed to move the price significantly.
     pub liquidity_depth: i128,
 
+    /// Liquidity depth observed by the update before the latest one.
+    /// Compared against `liquidity_depth` to spot liquidity being pulled between updates.
+    pub prev_liquidity_depth: i128,
+
     /// Confidence interval for the last price reading.
     /// Higher values indicate less reliable data, used in weighted aggregation.
     pub last_conf: u64,
@@ -48,6 +62,10 @@ pub struct PriceFeed {
     /// Critical for staleness detection and temporal weighting in TWAP calculations.
     pub last_update: i64,
 
+    /// Unix timestamp of the most recent reading that passed validation.
+    /// Trails `last_update` only while `READ_FAILED` is set; zero before the first good read.
+    pub last_good_update: i64,
+
     /// Decimal exponent for price scaling (e.g., -6 for microunits).
     /// Enables consistent representation across assets with vastly different nominal values.
     pub last_expo: i32,
@@ -74,7 +92,7 @@ pub struct PriceFeed {
 
     /// Explicit padding ensures deterministic struct layout across platforms.
     /// Prevents subtle bugs from compiler-dependent field alignment decisions.
-    pub _padding: [u8; 4],
+    pub _padding: [u8; 12],
 }
 
 impl PriceFeed {
@@ -98,6 +116,209 @@ impl PriceFeed {
     pub fn is_source_type(self, source_type: SourceType) -> bool {
         self.source_type == source_type.as_u8()
     }
+
+    /// Returns `last_price` re-expressed in `target_expo`.
+    ///
+    /// Feeds keep their native exponent so a source quoting at 1e-8 does not lose
+    /// precision on the way in; aggregation normalizes every feed to the oracle's
+    /// canonical exponent through this method before comparing or weighting them.
+    #[inline]
+    pub fn price_in_expo(&self, target_expo: i32) -> Result<i128> {
+        rescale_price(self.last_price, self.last_expo, target_expo)
+    }
+
+    /// Stores a fresh raw price and steps `ewma_price` toward it by `alpha_bps`.
+    ///
+    /// # Why Smooth Per Feed
+    ///
+    /// `last_price` moves with every TWAP refresh, so a single jumpy pool shows
+    /// up unfiltered to anyone reading feeds individually. The smoothed value
+    /// lets such consumers follow the feed's trend without re-deriving it from
+    /// history. Before the first good read there is nothing to smooth from, so
+    /// that reading seeds the average directly; call this before
+    /// `record_good_read`, which is what marks the seed as taken.
+    #[inline]
+    pub fn record_price(&mut self, price: i128, alpha_bps: u16) {
+        self.ewma_price = if self.last_good_update == 0 {
+            price
+        } else {
+            ema_step(self.ewma_price, price, alpha_bps)
+        };
+        self.last_price = price;
+    }
+
+    /// Records a read that passed validation at `now`, clearing any earlier failure.
+    #[inline]
+    pub fn record_good_read(&mut self, now: i64) {
+        self.last_update = now;
+        self.last_good_update = now;
+        self.flags.clear(FeedFlags::READ_FAILED);
+    }
+
+    /// Records a read that failed validation at `now`.
+    ///
+    /// `last_price` and `last_good_update` are left alone so aggregation can
+    /// still fall back to the last validated value while it is recent enough.
+    #[inline]
+    pub fn record_failed_read(&mut self, now: i64) {
+        self.last_update = now;
+        self.flags.set(FeedFlags::READ_FAILED);
+    }
+
+    /// Trips or resets this feed's breaker against `manipulation_threshold`.
+    ///
+    /// The breaker follows the (decayed) manipulation score rather than latching,
+    /// so a feed rejoins aggregation on its own once the score has fallen back
+    /// under the threshold. Returns whether the breaker is now tripped.
+    #[inline]
+    pub fn update_breaker(&mut self, manipulation_threshold: u16) -> bool {
+        let tripped = self.manipulation_score > manipulation_threshold;
+        self.flags.set_to(FeedFlags::BREAKER_TRIPPED, tripped);
+        tripped
+    }
+
+    /// Seconds since the reading `last_price` holds was validated.
+    ///
+    /// Failed reads advance `last_update` without replacing the price, so for
+    /// those the age runs from `last_good_update`; a feed that has never had a
+    /// good read has no usable price and reports `i64::MAX`.
+    #[inline]
+    pub fn reading_age(&self, now: i64) -> i64 {
+        if !self.flags.is_read_failed() {
+            return now.saturating_sub(self.last_update);
+        }
+        if self.last_good_update == 0 {
+            return i64::MAX;
+        }
+        now.saturating_sub(self.last_good_update)
+    }
+
+    /// Single comparable quality metric in basis points (0-10,000).
+    ///
+    /// # Weighting
+    ///
+    /// Four components, each scored 0-10,000, are blended by the `QUALITY_*_WEIGHT`
+    /// constants, which sum to 10,000:
+    ///
+    /// - **Freshness (30%)**: falls linearly from full at `last_update == now` to
+    ///   zero at `MAX_EXTERNAL_STALENESS` seconds old, or when flagged `STALE`
+    /// - **Liquidity (30%)**: grows linearly with `liquidity_depth` up to
+    ///   `QUALITY_REFERENCE_LIQUIDITY`, beyond which deeper pools earn nothing extra
+    /// - **LP concentration (20%)**: the complement of `lp_concentration`
+    /// - **Manipulation (20%)**: the complement of `manipulation_score`
+    ///
+    /// The blend is then scaled by the manipulation complement once more, so a
+    /// high score drags down the whole result instead of only its 20% share, and
+    /// a feed flagged `MANIPULATION_DETECTED` scores zero outright. A feed that
+    /// may be manipulated should never outrank a clean one on depth alone.
+    ///
+    /// The score ranks sources for operators; aggregation weights are unaffected.
+    pub fn quality_score(&self, now: i64) -> u16 {
+        if self.flags.has(FeedFlags::MANIPULATION_DETECTED) {
+            return 0;
+        }
+
+        let max_age = MAX_EXTERNAL_STALENESS as i64;
+        let age = now.saturating_sub(self.last_update).max(0);
+        let freshness = if self.flags.has(FeedFlags::STALE) || age >= max_age {
+            0
+        } else {
+            ((max_age - age) * 10_000 / max_age) as u32
+        };
+
+        let liquidity = (self.liquidity_depth.clamp(0, QUALITY_REFERENCE_LIQUIDITY) * 10_000
+            / QUALITY_REFERENCE_LIQUIDITY) as u32;
+        let concentration = 10_000 - self.lp_concentration.min(10_000) as u32;
+        let clean = 10_000 - self.manipulation_score.min(10_000) as u32;
+
+        let blended = (freshness * QUALITY_FRESHNESS_WEIGHT
+            + liquidity * QUALITY_LIQUIDITY_WEIGHT
+            + concentration * QUALITY_CONCENTRATION_WEIGHT
+            + clean * QUALITY_MANIPULATION_WEIGHT)
+            / 10_000;
+
+        (blended * clean / 10_000) as u16
+    }
+
+    /// Drop from `prev_liquidity_depth` to `liquidity_depth` in basis points.
+    ///
+    /// Zero when depth held or grew, and when there is no positive prior reading
+    /// to compare against (a freshly registered feed).
+    pub fn liquidity_drop_bps(&self) -> u16 {
+        if self.prev_liquidity_depth <= 0 || self.liquidity_depth >= self.prev_liquidity_depth {
+            return 0;
+        }
+
+        let prev = self.prev_liquidity_depth.unsigned_abs();
+        let drop = prev - self.liquidity_depth.max(0).unsigned_abs();
+        // Depths near u128::MAX cannot be scaled up first; divide the base down instead
+        let bps = drop
+            .checked_mul(10_000)
+            .map_or_else(|| drop / (prev / 10_000), |scaled| scaled / prev);
+        core::cmp::min(bps, 10_000) as u16
+    }
+
+    /// Records a fresh liquidity reading and reports a shock.
+    ///
+    /// The current depth becomes the prior one before `depth` is stored. Returns
+    /// the drop in basis points when it exceeds `max_drop_bps`, `None` otherwise.
+    ///
+    /// # Why Track Drops
+    ///
+    /// Pulling liquidity right before trading is the cheapest way to move a CLMM
+    /// price, so a sharp fall in depth between two updates is treated as a
+    /// manipulation signal even when the price itself still looks plausible.
+    pub fn record_liquidity(&mut self, depth: i128, max_drop_bps: u16) -> Option<u16> {
+        self.prev_liquidity_depth = self.liquidity_depth;
+        self.liquidity_depth = depth;
+
+        let drop_bps = self.liquidity_drop_bps();
+        (drop_bps > max_drop_bps).then_some(drop_bps)
+    }
+}
+
+/// Indices of active feeds in `feeds`, best `quality_score` first.
+///
+/// Returns `(index, score)` pairs for dashboards; equal scores keep slot order so
+/// the ranking is deterministic across calls.
+pub fn rank_by_quality(feeds: &[PriceFeed], now: i64) -> Vec<(usize, u16)> {
+    let mut ranked: Vec<(usize, u16)> = feeds
+        .iter()
+        .enumerate()
+        .filter(|(_, feed)| feed.flags.has(FeedFlags::ACTIVE))
+        .map(|(index, feed)| (index, feed.quality_score(now)))
+        .collect();
+    // Stable sort keeps slot order among equal scores
+    ranked.sort_by_key(|&(_, score)| core::cmp::Reverse(score));
+    ranked
+}
+
+/// Converts `price * 10^from_expo` into the equivalent mantissa at `10^to_expo`.
+///
+/// # Rounding and Overflow
+///
+/// Moving to a coarser exponent divides and truncates toward zero, which is the
+/// conservative direction for both bids and asks. Moving to a finer exponent
+/// multiplies with overflow checking; a price that cannot be represented at the
+/// target precision is an error rather than a silently saturated value.
+pub fn rescale_price(price: i128, from_expo: i32, to_expo: i32) -> Result<i128> {
+    let shift = to_expo as i64 - from_expo as i64;
+    if shift == 0 {
+        return Ok(price);
+    }
+
+    let factor = u32::try_from(shift.unsigned_abs())
+        .ok()
+        .and_then(|exp| 10i128.checked_pow(exp));
+
+    if shift > 0 {
+        // Coarser target: an unrepresentable divisor means the price rounds to zero
+        Ok(factor.map_or(0, |divisor| price / divisor))
+    } else {
+        factor
+            .and_then(|multiplier| price.checked_mul(multiplier))
+            .ok_or_else(|| StateError::MathOverflow.into())
+    }
 }
 
 /// Compact bitfield for price feed status and quality indicators.
@@ -150,10 +371,22 @@ impl FeedFlags {
     /// Causes immediate feed quarantine pending manual review.
     pub const MANIPULATION_DETECTED: Self = Self(0b0000_1000);
 
+    /// The feed's most recent read failed validation; `last_price` is from an earlier round.
+    /// Aggregation may still fall back to that price within the staleness grace window.
+    pub const READ_FAILED: Self = Self(0b0001_0000);
+
+    /// Feed-level circuit breaker: the manipulation score exceeds the oracle threshold.
+    /// Excludes only this feed from aggregation; cleared once the score recovers.
+    pub const BREAKER_TRIPPED: Self = Self(0b0010_0000);
+
     /// Bitmask for all currently defined flags.
     /// Enables forward-compatible deserialization that gracefully handles unknown flags.
-    pub const VALID_MASK: u8 =
-        Self::ACTIVE.0 | Self::TRUSTED.0 | Self::STALE.0 | Self::MANIPULATION_DETECTED.0;
+    pub const VALID_MASK: u8 = Self::ACTIVE.0
+        | Self::TRUSTED.0
+        | Self::STALE.0
+        | Self::MANIPULATION_DETECTED.0
+        | Self::READ_FAILED.0
+        | Self::BREAKER_TRIPPED.0;
 
     /// Creates empty flag set with all indicators disabled.
     /// const fn allows compile-time initialization for default instances.
@@ -225,6 +458,16 @@ impl FeedFlags {
         self.has(Self::MANIPULATION_DETECTED)
     }
 
+    #[inline(always)]
+    pub fn is_read_failed(self) -> bool {
+        self.has(Self::READ_FAILED)
+    }
+
+    #[inline(always)]
+    pub fn is_breaker_tripped(self) -> bool {
+        self.has(Self::BREAKER_TRIPPED)
+    }
+
     /// Serialization utilities for account data persistence.
 
     /// Extracts raw u8 value for storage in account data.
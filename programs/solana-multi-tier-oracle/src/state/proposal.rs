@@ -0,0 +1,303 @@
+use crate::error::StateError;
+use crate::state::governance_state::GovernanceState;
+use crate::utils::constants::MAX_MULTISIG_MEMBERS;
+use anchor_lang::prelude::*;
+
+/// Governance proposal tracked through its approval, veto and execution lifecycle.
+///
+/// # Lifecycle Model
+///
+/// Proposals move through a strictly forward-only state machine so that no
+/// instruction can resurrect a proposal that governance already settled:
+///
+/// - **Pending**: open for deliberation, not yet actionable
+/// - **Approved**: passed governance but still inside its safety windows
+/// - **Executed** / **Vetoed**: terminal states that can never transition again
+///
+/// # Why a Separate Account
+///
+/// Keeping proposals out of `GovernanceState` bounds the governance account size
+/// regardless of how many proposals are in flight, and lets each proposal carry
+/// its own timestamps for veto and execution-delay enforcement without scanning
+/// a shared array on every governance check.
+#[account(zero_copy)]
+#[derive(InitSpace)]
+#[repr(C)]
+pub struct Proposal {
+    /// Governance account this proposal belongs to.
+    /// Prevents a proposal created under one oracle from being acted on by another.
+    pub governance: Pubkey,
+
+    /// Member that submitted the proposal, retained for audit trails.
+    pub proposer: Pubkey,
+
+    /// Monotonic identifier used in PDA derivation and off-chain indexing.
+    pub proposal_id: u64,
+
+    /// Unix timestamp when the proposal was created.
+    pub created_at: i64,
+
+    /// Unix timestamp when the proposal reached approval.
+    /// Anchors the veto window; zero while the proposal is still pending.
+    pub approved_at: i64,
+
+    /// Total member stake cast in favour of the proposal.
+    /// Cannot overflow: governance caps the combined member stake at `u64::MAX`.
+    pub yes_stake: u64,
+
+    /// Total member stake cast against the proposal.
+    /// Tracked for transparency; approval is decided by `yes_stake` against quorum.
+    pub no_stake: u64,
+
+    /// Current lifecycle stage stored as a raw `ProposalStatus` discriminant.
+    pub status: u8,
+
+    /// PDA bump seed cached to avoid recomputation on every lifecycle transition.
+    pub bump: u8,
+
+    /// Bitmap of `electorate` slots that already voted.
+    /// Sixteen bits cover `MAX_MULTISIG_MEMBERS` and make double voting a single AND.
+    pub voted_members: u16,
+
+    /// Member keys eligible to vote, copied from `multisig_members` when the
+    /// proposal opened. `voted_members` indexes this frozen list rather than the
+    /// live governance slots, which `remove_member` compacts, so a removal can
+    /// neither hand a slot's vote to its new occupant nor reset the moved
+    /// member's. Unused slots hold the default key, which can never sign.
+    pub electorate: [Pubkey; MAX_MULTISIG_MEMBERS],
+
+    /// Explicit padding so `electorate_stakes` starts on an 8-byte boundary.
+    pub _electorate_padding: [u8; 4],
+
+    /// Stake of each `electorate` member when the proposal opened; votes and
+    /// quorum are weighed against these rather than the live stake table, so
+    /// rebalancing stakes mid-vote can neither carry nor sink the proposal.
+    pub electorate_stakes: [u64; MAX_MULTISIG_MEMBERS],
+
+    /// Reserved space for execution payloads without migration. None is stored
+    /// yet, so executing a proposal changes nothing beyond its status.
+    pub reserved: [u8; 104],
+}
+
+/// Lifecycle stage of a governance proposal.
+///
+/// Explicit discriminants keep the stored byte stable across program upgrades,
+/// mirroring the approach used for `SourceType`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+#[repr(u8)]
+pub enum ProposalStatus {
+    /// Proposal is open and has not yet met its approval requirements.
+    Pending = 0,
+
+    /// Proposal passed and awaits execution once its safety windows elapse.
+    Approved = 1,
+
+    /// Proposal was executed; terminal. Execution records the outcome only and
+    /// applies no payload.
+    Executed = 2,
+
+    /// Proposal was cancelled by a veto holder during its veto window; terminal.
+    Vetoed = 3,
+}
+
+impl ProposalStatus {
+    #[inline(always)]
+    pub const fn as_u8(self) -> u8 {
+        self as u8
+    }
+
+    /// Decodes a stored discriminant, returning `None` for unknown values so
+    /// corrupted proposals are rejected rather than silently reinterpreted.
+    #[inline(always)]
+    pub const fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Pending),
+            1 => Some(Self::Approved),
+            2 => Some(Self::Executed),
+            3 => Some(Self::Vetoed),
+            _ => None,
+        }
+    }
+}
+
+impl Proposal {
+    /// Returns the decoded lifecycle stage, or `None` if the stored byte is unknown.
+    #[inline(always)]
+    pub fn get_status(&self) -> Option<ProposalStatus> {
+        ProposalStatus::from_u8(self.status)
+    }
+
+    #[inline(always)]
+    pub fn set_status(&mut self, status: ProposalStatus) {
+        self.status = status.as_u8();
+    }
+
+    /// Returns true while an approved proposal can still be vetoed at `now`.
+    ///
+    /// The window is inclusive of both endpoints so a veto submitted in the same
+    /// second as approval, or exactly at the deadline, is honoured. Saturating
+    /// addition keeps pathological `veto_period` values from wrapping the deadline
+    /// into the past.
+    pub fn is_within_veto_window(&self, now: i64, veto_period: i64) -> bool {
+        let deadline = self.approved_at.saturating_add(veto_period.max(0));
+        now >= self.approved_at && now <= deadline
+    }
+
+    /// Cancels an approved-but-not-executed proposal.
+    ///
+    /// # Errors
+    ///
+    /// - `ProposalNotApproved`: the proposal is pending, already executed or vetoed
+    /// - `VetoWindowClosed`: `now` falls outside `[approved_at, approved_at + veto_period]`
+    ///
+    /// Status is only mutated after every check passes, so a rejected veto leaves
+    /// the proposal untouched.
+    pub fn veto(&mut self, now: i64, veto_period: i64) -> Result<()> {
+        require!(
+            self.get_status() == Some(ProposalStatus::Approved),
+            StateError::ProposalNotApproved
+        );
+        require!(
+            self.is_within_veto_window(now, veto_period),
+            StateError::VetoWindowClosed
+        );
+
+        self.set_status(ProposalStatus::Vetoed);
+        Ok(())
+    }
+
+    /// Freezes the current active membership and their stakes as this
+    /// proposal's electorate.
+    pub fn snapshot_electorate(&mut self, governance: &GovernanceState) {
+        let active = governance.active_member_count as usize;
+        self.electorate = [Pubkey::default(); MAX_MULTISIG_MEMBERS];
+        self.electorate[..active].copy_from_slice(&governance.multisig_members[..active]);
+        self.electorate_stakes = [0; MAX_MULTISIG_MEMBERS];
+        self.electorate_stakes[..active].copy_from_slice(&governance.member_stakes[..active]);
+    }
+
+    /// Total stake frozen at creation, the quorum denominator for this proposal.
+    pub fn electorate_total_stake(&self) -> u128 {
+        self.electorate_stakes
+            .iter()
+            .map(|stake| *stake as u128)
+            .sum()
+    }
+
+    /// Whether `yes_stake` meets the governance quorum against the frozen stakes.
+    #[inline]
+    pub fn is_quorum_met(&self, governance: &GovernanceState) -> bool {
+        governance.is_stake_quorum_met_of(self.yes_stake, self.electorate_total_stake())
+    }
+
+    /// Position of `member` in the electorate, or `None` if it was not a member
+    /// when the proposal opened.
+    #[inline]
+    pub fn electorate_slot(&self, member: &Pubkey) -> Option<usize> {
+        if *member == Pubkey::default() {
+            return None;
+        }
+        self.electorate.iter().position(|key| key == member)
+    }
+
+    /// Returns true if `member` has already cast a vote on this proposal.
+    #[inline]
+    pub fn has_voted(&self, member: &Pubkey) -> bool {
+        self.electorate_slot(member)
+            .is_some_and(|slot| self.voted_members & (1u16 << slot) != 0)
+    }
+
+    /// Records a stake-weighted vote and approves the proposal once quorum is reached.
+    ///
+    /// # Voting Rules
+    ///
+    /// - Only pending proposals accept votes, and only until `created_at + voting_period`
+    /// - The voter must be in the electorate frozen at creation and still be an active
+    ///   member; members added later wait for the next proposal, removed ones lose their vote
+    /// - Each voter key votes at most once; the bitmap is checked before any tally moves
+    /// - The member's full stake as frozen at creation is added to the chosen side
+    ///
+    /// Approval happens eagerly on the vote that crosses quorum so `approved_at` marks
+    /// the start of the veto window precisely, rather than whenever someone next
+    /// touches the proposal.
+    pub fn record_vote(
+        &mut self,
+        governance: &GovernanceState,
+        voter: &Pubkey,
+        approve: bool,
+        now: i64,
+    ) -> Result<()> {
+        require!(
+            self.get_status() == Some(ProposalStatus::Pending),
+            StateError::VotingClosed
+        );
+        require!(
+            now <= self.created_at.saturating_add(governance.voting_period),
+            StateError::VotingClosed
+        );
+        require!(
+            governance.find_member(voter).is_some(),
+            StateError::UnauthorizedCaller
+        );
+        let slot = self
+            .electorate_slot(voter)
+            .ok_or(StateError::UnauthorizedCaller)?;
+        require!(
+            self.voted_members & (1u16 << slot) == 0,
+            StateError::AlreadyVoted
+        );
+
+        let stake = self.electorate_stakes[slot];
+        if approve {
+            self.yes_stake = self
+                .yes_stake
+                .checked_add(stake)
+                .ok_or(StateError::MathOverflow)?;
+        } else {
+            self.no_stake = self
+                .no_stake
+                .checked_add(stake)
+                .ok_or(StateError::MathOverflow)?;
+        }
+        self.voted_members |= 1u16 << slot;
+
+        if self.is_quorum_met(governance) {
+            self.set_status(ProposalStatus::Approved);
+            self.approved_at = now;
+        }
+
+        Ok(())
+    }
+
+    /// Marks an approved proposal as executed once every safety window has elapsed.
+    ///
+    /// Only the status moves: proposals carry no payload, so there is nothing
+    /// to apply.
+    ///
+    /// # Errors
+    ///
+    /// - `ProposalNotApproved`: the proposal never reached approval or is already settled
+    /// - `QuorumNotMet`: yes-stake no longer meets quorum, e.g. after governance
+    ///   raised `quorum_threshold`
+    /// - `ExecutionDelayNotElapsed`: `execution_delay` has not passed, or the veto
+    ///   window is still open; executing inside the veto window would let the
+    ///   approving side race the guardians
+    pub fn execute(&mut self, governance: &GovernanceState, now: i64) -> Result<()> {
+        require!(
+            self.get_status() == Some(ProposalStatus::Approved),
+            StateError::ProposalNotApproved
+        );
+        require!(self.is_quorum_met(governance), StateError::QuorumNotMet);
+
+        let delay_ends = self
+            .approved_at
+            .saturating_add(governance.execution_delay.max(0));
+        require!(
+            now >= delay_ends && !self.is_within_veto_window(now, governance.veto_period),
+            StateError::ExecutionDelayNotElapsed
+        );
+
+        self.set_status(ProposalStatus::Executed);
+        Ok(())
+    }
+}
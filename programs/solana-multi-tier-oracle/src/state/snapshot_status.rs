@@ -1,3 +1,6 @@
+use crate::utils::constants::MAX_SNAPSHOTS_PER_HOUR;
+use anchor_lang::prelude::*;
+
 /// Comprehensive snapshot quality assessment for oracle data integrity validation.
 ///
 /// # Oracle Data Quality Framework
@@ -136,6 +139,93 @@ pub enum SnapshotStatus {
 }
 
 impl SnapshotStatus {
+    /// Pinned wire discriminants for `as_code` / `from_code`.
+    ///
+    /// These values are ABI: programs reading redemption eligibility over CPI
+    /// branch on them, so existing codes must never be renumbered or reused and
+    /// new variants must take fresh values.
+    pub const CODE_SUFFICIENT: u8 = 0;
+    pub const CODE_INSUFFICIENT_COUNT: u8 = 1;
+    pub const CODE_INSUFFICIENT_TIME_SPAN: u8 = 2;
+    pub const CODE_EXCESSIVE_CLUSTERING: u8 = 3;
+    pub const CODE_NO_SNAPSHOTS: u8 = 4;
+
+    /// Compact `(discriminant, primary, secondary)` encoding for return data.
+    ///
+    /// # Payload Mapping
+    ///
+    /// | Variant                | primary          | secondary          |
+    /// |------------------------|------------------|--------------------|
+    /// | `Sufficient`           | `snapshot_count` | `time_span_hours`  |
+    /// | `InsufficientCount`    | `found`          | `required`         |
+    /// | `InsufficientTimeSpan` | `span_hours`     | `required_hours`   |
+    /// | `ExcessiveClustering`  | `max_per_hour`   | `limit_per_hour`   |
+    /// | `NoSnapshots`          | 0                | 0                  |
+    ///
+    /// Failure variants carry the observed value first and the requirement second
+    /// so consumers can report the shortfall without knowing the variant layout.
+    /// `Sufficient` drops `max_hourly_density`: passing the clustering check
+    /// already bounds it by `MAX_SNAPSHOTS_PER_HOUR`, and consumers gate on
+    /// count and coverage.
+    pub fn as_code(&self) -> (u8, u16, u16) {
+        match *self {
+            SnapshotStatus::Sufficient {
+                snapshot_count,
+                time_span_hours,
+                ..
+            } => (Self::CODE_SUFFICIENT, snapshot_count, time_span_hours),
+            SnapshotStatus::InsufficientCount { found, required } => {
+                (Self::CODE_INSUFFICIENT_COUNT, found, required)
+            }
+            SnapshotStatus::InsufficientTimeSpan {
+                span_hours,
+                required_hours,
+            } => (
+                Self::CODE_INSUFFICIENT_TIME_SPAN,
+                span_hours,
+                required_hours,
+            ),
+            SnapshotStatus::ExcessiveClustering {
+                max_per_hour,
+                limit_per_hour,
+            } => (
+                Self::CODE_EXCESSIVE_CLUSTERING,
+                max_per_hour,
+                limit_per_hour,
+            ),
+            SnapshotStatus::NoSnapshots => (Self::CODE_NO_SNAPSHOTS, 0, 0),
+        }
+    }
+
+    /// Decodes an `as_code` triple, returning `None` for unknown discriminants.
+    ///
+    /// A decoded `Sufficient` reports `max_hourly_density` as the conservative
+    /// upper bound `MAX_SNAPSHOTS_PER_HOUR`, since the exact value is not carried.
+    pub fn from_code(code: (u8, u16, u16)) -> Option<Self> {
+        let (discriminant, primary, secondary) = code;
+        match discriminant {
+            Self::CODE_SUFFICIENT => Some(SnapshotStatus::Sufficient {
+                snapshot_count: primary,
+                time_span_hours: secondary,
+                max_hourly_density: MAX_SNAPSHOTS_PER_HOUR,
+            }),
+            Self::CODE_INSUFFICIENT_COUNT => Some(SnapshotStatus::InsufficientCount {
+                found: primary,
+                required: secondary,
+            }),
+            Self::CODE_INSUFFICIENT_TIME_SPAN => Some(SnapshotStatus::InsufficientTimeSpan {
+                span_hours: primary,
+                required_hours: secondary,
+            }),
+            Self::CODE_EXCESSIVE_CLUSTERING => Some(SnapshotStatus::ExcessiveClustering {
+                max_per_hour: primary,
+                limit_per_hour: secondary,
+            }),
+            Self::CODE_NO_SNAPSHOTS => Some(SnapshotStatus::NoSnapshots),
+            _ => None,
+        }
+    }
+
     /// Fast boolean check for snapshot sufficiency with zero-cost abstraction.
     ///
     /// # Performance Optimization
@@ -185,3 +275,26 @@ impl SnapshotStatus {
         }
     }
 }
+
+/// Return-data form of `SnapshotStatus::as_code`.
+///
+/// Anchor's IDL cannot describe tuple types, so the triple is wrapped in a named
+/// struct with the same field order; the Borsh encoding is byte-identical to the
+/// tuple.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SnapshotStatusCode {
+    pub code: u8,
+    pub primary: u16,
+    pub secondary: u16,
+}
+
+impl From<&SnapshotStatus> for SnapshotStatusCode {
+    fn from(status: &SnapshotStatus) -> Self {
+        let (code, primary, secondary) = status.as_code();
+        Self {
+            code,
+            primary,
+            secondary,
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Active admin counting and the lockout guards built on it.
+//!
+//! `active_admin_count` backs both member removal and permission revocation,
+//! so governance can never be left without a member able to repair it.
+
+use super::helpers::{assert_state_error, governance_fixture};
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+
+/// Four active members: two admins, an operator and a feed manager.
+fn mixed_roles() -> GovernanceState {
+    let mut state = governance_fixture(4);
+    state.multi_sig_threshold = 1;
+    state.member_permissions[0] = Permissions::ADMIN_ALL;
+    state.member_permissions[1] = Permissions::OPERATOR_ALL;
+    state.member_permissions[2] = Permissions::ADMIN_ALL;
+    state.member_permissions[3] = Permissions::FEED_MANAGER;
+    state
+}
+
+#[test]
+fn counts_only_full_admins() {
+    let mut state = mixed_roles();
+    assert_eq!(state.active_admin_count(), 2);
+
+    // Every admin bit but one is not an admin
+    state.member_permissions[1] = Permissions::ADMIN_ALL;
+    state.member_permissions[1].revoke(Permissions::ADD_FEED);
+    assert_eq!(state.active_admin_count(), 2);
+}
+
+#[test]
+fn inactive_slots_are_not_counted() {
+    let mut state = mixed_roles();
+    state.member_permissions[5] = Permissions::ADMIN_ALL;
+    assert_eq!(state.active_admin_count(), 2);
+
+    state.set_active_member_count(2).unwrap();
+    assert_eq!(state.active_admin_count(), 1);
+}
+
+#[test]
+fn removing_one_of_two_admins_succeeds() {
+    let mut state = mixed_roles();
+
+    state.remove_member(0).expect("another admin remains");
+    assert_eq!(state.active_admin_count(), 1);
+}
+
+#[test]
+fn removing_the_last_admin_is_rejected() {
+    let mut state = mixed_roles();
+    state.remove_member(0).unwrap();
+
+    let admin_index = state.member_permissions[..state.active_member_count as usize]
+        .iter()
+        .position(|permissions| permissions.is_admin())
+        .unwrap();
+    assert_state_error(
+        state.remove_member(admin_index),
+        StateError::CannotRemoveLastAdmin,
+    );
+    assert_eq!(state.active_admin_count(), 1);
+}
+
+#[test]
+fn revoking_admin_from_the_last_admin_is_rejected() {
+    let mut state = mixed_roles();
+    state
+        .revoke_member_permission(0, Permissions::MODIFY_CONFIG)
+        .expect("member 2 is still an admin");
+    assert_eq!(state.active_admin_count(), 1);
+
+    assert_state_error(
+        state.revoke_member_permission(2, Permissions::EMERGENCY_HALT),
+        StateError::CannotRemoveLastAdmin,
+    );
+    assert_eq!(state.member_permissions[2], Permissions::ADMIN_ALL);
+}
+
+#[test]
+fn revocations_that_keep_the_admin_count_are_unaffected() {
+    let mut state = mixed_roles();
+    state
+        .revoke_member_permission(2, Permissions::ADMIN_ALL)
+        .unwrap();
+    assert_eq!(state.active_admin_count(), 1);
+
+    // Non-admins lose permissions freely, even with a single admin left
+    state
+        .revoke_member_permission(1, Permissions::UPDATE_PRICE)
+        .unwrap();
+    // Bits outside `ADMIN_ALL` can be taken from the last admin too
+    state.grant_member_permission(0, Permissions::VETO).unwrap();
+    state
+        .revoke_member_permission(0, Permissions::VETO)
+        .unwrap();
+    assert_eq!(state.active_admin_count(), 1);
+}
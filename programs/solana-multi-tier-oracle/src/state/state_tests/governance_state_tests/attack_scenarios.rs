@@ -80,6 +80,8 @@ fn member_removed_via_active_count_cannot_retain_privileges() {
 fn toggling_permissions_cannot_grant_unauthorized_bits() {
     let mut state = governance_fixture(2);
     let attacker_index = 0usize;
+    // A second admin keeps the last-admin guard from refusing the final revocations
+    state.member_permissions[1] = Permissions::ADMIN_ALL;
 
     // Simulate a captured key flipping permissions repeatedly.
     for (idx, perm) in PERMISSION_VARIANTS.iter().enumerate() {
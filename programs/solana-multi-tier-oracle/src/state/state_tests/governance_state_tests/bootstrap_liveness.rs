@@ -0,0 +1,96 @@
+//! Initialization-time rejection of governance sets whose membership could never change.
+//!
+//! `validate_oracle_config` is the single gate shared by standalone and batch
+//! initialization, so these tests drive it directly with a minimal valid
+//! oracle configuration and vary only the governance thresholds. The founding
+//! stake is checked at the same gate, since seeding it past `u64::MAX` would
+//! leave proposals unable to tally every vote.
+
+use super::helpers::{assert_state_error, deterministic_pubkey};
+use crate::error::StateError;
+use crate::instructions::initialize_oracle::{
+    validate_oracle_config, GovernanceConfig, OracleConfig,
+};
+use crate::state::governance_state::Permissions;
+use crate::utils::constants::{MAX_MULTISIG_MEMBERS, MAX_QUORUM_THRESHOLD};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::keccak;
+
+fn authority() -> Pubkey {
+    deterministic_pubkey(1)
+}
+
+fn config(member_count: u8, multisig_threshold: u8, quorum_threshold: u16) -> OracleConfig {
+    let mut initial_members = [Pubkey::default(); MAX_MULTISIG_MEMBERS];
+    let mut member_permissions = [Permissions::default(); MAX_MULTISIG_MEMBERS];
+    for i in 0..member_count as usize {
+        initial_members[i] = deterministic_pubkey(i as u8 + 1);
+        member_permissions[i] = Permissions::UPDATE_PRICE;
+    }
+    member_permissions[0] = Permissions::ADMIN_ALL;
+
+    OracleConfig {
+        asset_id: "SOL/USDC".to_string(),
+        asset_seed: keccak::hashv(&[b"sol/usdc"]).0,
+        twap_window: 3_600,
+        confidence_threshold: 500,
+        manipulation_threshold: 1_000,
+        emergency_admin: deterministic_pubkey(9),
+        enable_circuit_breaker: true,
+        max_twap_window_override: 0,
+        governance_config: GovernanceConfig {
+            member_count,
+            initial_members,
+            member_permissions,
+            multisig_threshold,
+            voting_period: 7_200,
+            execution_delay: 3_600,
+            quorum_threshold,
+            proposal_threshold: 1_000_000,
+        },
+    }
+}
+
+#[test]
+fn threshold_equal_to_member_count_is_rejected_as_frozen() {
+    // Three members, three required: no removal can ever keep the threshold,
+    // even though proposals could still pass with a unanimous quorum
+    assert_state_error(
+        validate_oracle_config(&config(3, 3, MAX_QUORUM_THRESHOLD), &authority()).map(|_| ()),
+        StateError::MembershipFrozen,
+    );
+    assert_state_error(
+        validate_oracle_config(&config(3, 3, 5_000), &authority()).map(|_| ()),
+        StateError::MembershipFrozen,
+    );
+}
+
+#[test]
+fn threshold_with_slack_is_accepted() {
+    for (members, threshold, quorum) in [(3, 2, 5_000), (3, 2, 6_000), (5, 3, 5_000), (3, 1, 5_000)]
+    {
+        let canonical = validate_oracle_config(&config(members, threshold, quorum), &authority());
+        assert_eq!(canonical.unwrap(), "sol/usdc");
+    }
+}
+
+#[test]
+fn single_member_governance_is_not_frozen() {
+    // The sole member is also the last admin, which removal protects anyway
+    assert!(validate_oracle_config(&config(1, 1, MAX_QUORUM_THRESHOLD), &authority()).is_ok());
+}
+
+#[test]
+fn founding_stake_must_fit_the_proposal_tallies() {
+    // Every founding member is seeded with the proposal threshold
+    let mut overflowing = config(3, 2, 5_000);
+    overflowing.governance_config.proposal_threshold = u64::MAX / 2;
+    assert_state_error(
+        validate_oracle_config(&overflowing, &authority()).map(|_| ()),
+        StateError::ExcessiveTotalStake,
+    );
+
+    let mut at_cap = config(3, 2, 5_000);
+    at_cap.governance_config.proposal_threshold = u64::MAX / 3;
+    assert!(validate_oracle_config(&at_cap, &authority()).is_ok());
+}
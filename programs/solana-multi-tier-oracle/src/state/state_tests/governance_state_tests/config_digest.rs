@@ -0,0 +1,92 @@
+//! Audit digest over the governance configuration.
+//!
+//! The digest must be a pure function of the configured fields: equal states
+//! hash equal, every configured field moves the hash, and `reserved` does not.
+
+use super::helpers::{
+    deterministic_pubkey, governance_fixture, governance_from_bytes, governance_to_bytes,
+};
+use crate::state::governance_state::{GovernanceState, Permissions};
+
+const MEMBERS: u8 = 4;
+
+type Mutation = (&'static str, fn(&mut GovernanceState));
+
+/// One mutation per configured field, each touching nothing else.
+const SINGLE_FIELD_MUTATIONS: &[Mutation] = &[
+    ("proposal_threshold", |s| s.proposal_threshold += 1),
+    ("voting_period", |s| s.voting_period += 1),
+    ("execution_delay", |s| s.execution_delay += 1),
+    ("timelock_duration", |s| s.timelock_duration += 1),
+    ("veto_period", |s| s.veto_period += 1),
+    ("quorum_threshold", |s| s.quorum_threshold += 1),
+    ("multi_sig_threshold", |s| s.multi_sig_threshold += 1),
+    ("active_member_count", |s| s.active_member_count -= 1),
+    ("bump", |s| s.bump -= 1),
+    ("strict_mode_enabled", |s| s.strict_mode_enabled = 1),
+    ("allowed_dex_program_count", |s| {
+        s.allowed_dex_program_count -= 1
+    }),
+    ("allowed_aggregator_program_count", |s| {
+        s.allowed_aggregator_program_count -= 1
+    }),
+    ("allowed_dex_programs", |s| {
+        s.allowed_dex_programs[0] = deterministic_pubkey(250)
+    }),
+    ("allowed_aggregator_programs", |s| {
+        s.allowed_aggregator_programs[0] = deterministic_pubkey(251)
+    }),
+    ("oracle_state", |s| {
+        s.oracle_state = deterministic_pubkey(252)
+    }),
+    ("multisig_members", |s| {
+        s.multisig_members[0] = deterministic_pubkey(253)
+    }),
+    ("member_permissions", |s| {
+        s.member_permissions[0] = Permissions::VIEW_METRICS
+    }),
+    ("member_stakes", |s| s.member_stakes[0] += 1),
+];
+
+#[test]
+fn identical_states_hash_equal() {
+    let state = governance_fixture(MEMBERS);
+    let copy = governance_from_bytes(&governance_to_bytes(&state));
+
+    assert_eq!(state.config_digest(), copy.config_digest());
+    assert_eq!(
+        governance_fixture(MEMBERS).config_digest(),
+        state.config_digest()
+    );
+}
+
+#[test]
+fn any_single_field_change_alters_digest() {
+    let baseline = governance_fixture(MEMBERS).config_digest();
+
+    for (field, mutate) in SINGLE_FIELD_MUTATIONS {
+        let mut state = governance_fixture(MEMBERS);
+        mutate(&mut state);
+        assert_ne!(state.config_digest(), baseline, "{field} not covered");
+    }
+}
+
+#[test]
+fn inactive_slots_are_covered() {
+    let baseline = governance_fixture(MEMBERS).config_digest();
+
+    let mut state = governance_fixture(MEMBERS);
+    state.member_stakes[MEMBERS as usize] = 1;
+
+    assert_ne!(state.config_digest(), baseline);
+}
+
+#[test]
+fn reserved_bytes_do_not_affect_digest() {
+    let baseline = governance_fixture(MEMBERS).config_digest();
+
+    let mut state = governance_fixture(MEMBERS);
+    state.reserved = [0xAB; 384];
+
+    assert_eq!(state.config_digest(), baseline);
+}
@@ -0,0 +1,93 @@
+//! Single-signer emergency halts versus threshold-gated configuration.
+//!
+//! The fixture's slot `i` holds the `i`-th permission atom, so slot 2 alone has
+//! MODIFY_CONFIG and slot 4 alone has EMERGENCY_HALT.
+
+use super::helpers::{assert_state_error, deterministic_pubkey, governance_fixture};
+use crate::error::StateError;
+use crate::state::governance_state::Permissions;
+use crate::state::proposal::ProposalStatus;
+use crate::state::state_tests::proposal_tests::helpers::{pending_proposal, APPROVED_AT};
+use anchor_lang::prelude::Pubkey;
+
+const CONFIG_MEMBER: u8 = 102;
+const HALT_MEMBER: u8 = 104;
+
+#[test]
+fn single_emergency_member_can_halt() {
+    let state = governance_fixture(8);
+
+    assert!(state
+        .check_emergency_permission(&deterministic_pubkey(HALT_MEMBER), &Pubkey::default())
+        .is_ok());
+}
+
+#[test]
+fn single_member_cannot_change_config() {
+    let state = governance_fixture(8);
+    let config_member = deterministic_pubkey(CONFIG_MEMBER);
+
+    // The emergency path does not stand in for configuration rights...
+    assert_state_error(
+        state.check_emergency_permission(&config_member, &Pubkey::default()),
+        StateError::InsufficientPermissions,
+    );
+    // ...and the halt holder has no configuration rights of its own
+    assert_state_error(
+        state.check_member_permission(
+            &deterministic_pubkey(HALT_MEMBER),
+            Permissions::MODIFY_CONFIG,
+        ),
+        StateError::InsufficientPermissions,
+    );
+
+    // A config proposal approved by one member's vote alone stays pending
+    let mut proposal = pending_proposal(&state);
+    proposal
+        .record_vote(
+            &state,
+            &deterministic_pubkey(CONFIG_MEMBER),
+            true,
+            APPROVED_AT,
+        )
+        .unwrap();
+    assert_eq!(proposal.get_status(), Some(ProposalStatus::Pending));
+    assert!(!state.is_quorum_met(1));
+}
+
+#[test]
+fn emergency_admin_acts_without_membership() {
+    let state = governance_fixture(8);
+    let emergency_admin = deterministic_pubkey(250);
+    assert!(state.find_member(&emergency_admin).is_none());
+
+    assert!(state
+        .check_emergency_permission(&emergency_admin, &emergency_admin)
+        .is_ok());
+}
+
+#[test]
+fn outsiders_and_unset_admin_are_rejected() {
+    let state = governance_fixture(8);
+
+    assert_state_error(
+        state.check_emergency_permission(&deterministic_pubkey(250), &Pubkey::default()),
+        StateError::UnauthorizedCaller,
+    );
+    // An unset emergency admin must not let the default key through
+    assert_state_error(
+        state.check_emergency_permission(&Pubkey::default(), &Pubkey::default()),
+        StateError::UnauthorizedCaller,
+    );
+}
+
+#[test]
+fn inactive_holders_lose_the_fast_path() {
+    let mut state = governance_fixture(8);
+    state.active_member_count = 4; // slot 4 falls outside the active range
+
+    assert_state_error(
+        state.check_emergency_permission(&deterministic_pubkey(HALT_MEMBER), &Pubkey::default()),
+        StateError::UnauthorizedCaller,
+    );
+}
@@ -0,0 +1,82 @@
+//! Member-count quorum at and around the basis-point boundary.
+//!
+//! Quorum needs `ceil(quorum_threshold · members / 10_000)` votes. Each case
+//! checks that count passes and one fewer fails, including thresholds where
+//! truncating division would have accepted the short tally.
+
+use super::helpers::governance_fixture;
+use crate::state::governance_state::GovernanceState;
+
+fn governance(members: u8, quorum_threshold: u16) -> GovernanceState {
+    let mut state = governance_fixture(members);
+    state.quorum_threshold = quorum_threshold;
+    state
+}
+
+/// Asserts that exactly `required` of `members` votes is the quorum boundary.
+fn assert_boundary(members: u8, quorum_threshold: u16, required: u8) {
+    let state = governance(members, quorum_threshold);
+
+    assert!(
+        state.is_quorum_met(required),
+        "{required}/{members} should meet {quorum_threshold} bps"
+    );
+    if required > 0 {
+        assert!(
+            !state.is_quorum_met(required - 1),
+            "{}/{members} should miss {quorum_threshold} bps",
+            required - 1
+        );
+    }
+    if required < members {
+        assert!(state.is_quorum_met(required + 1));
+    }
+}
+
+#[test]
+fn exact_fraction_is_inclusive() {
+    // 50% of 4 and 60% of 5 land on whole votes
+    assert_boundary(4, 5_000, 2);
+    assert_boundary(5, 6_000, 3);
+    assert_boundary(10, 2_500, 3);
+}
+
+#[test]
+fn fractional_requirement_rounds_up() {
+    // 2/3 of 3 members is 2 votes; 66.67% of 7 is 4.67, so 5 votes
+    assert_boundary(3, 6_667, 3);
+    assert_boundary(3, 6_666, 2);
+    assert_boundary(7, 6_667, 5);
+    // 51% of 16 is 8.16, so half the members fall short
+    assert_boundary(16, 5_100, 9);
+}
+
+#[test]
+fn one_basis_point_above_a_whole_vote_needs_another() {
+    // 5_000 bps of 2 is exactly one vote; 5_001 bps is not
+    assert_boundary(2, 5_000, 1);
+    assert_boundary(2, 5_001, 2);
+}
+
+#[test]
+fn unanimity_requires_every_member() {
+    for members in [1u8, 2, 9, 16] {
+        assert_boundary(members, 10_000, members);
+    }
+}
+
+#[test]
+fn votes_beyond_membership_do_not_count() {
+    let state = governance(4, 10_000);
+
+    assert!(state.is_quorum_met(u8::MAX));
+    assert!(!governance(4, 10_000).is_quorum_met(3));
+}
+
+#[test]
+fn empty_governance_never_reaches_quorum() {
+    let state = governance(0, 1);
+
+    assert!(!state.is_quorum_met(0));
+    assert!(!state.is_quorum_met(u8::MAX));
+}
@@ -34,7 +34,7 @@ pub(crate) const DEFAULT_ALLOWED_AGGREGATORS: usize = 2;
 /// permission pattern so assertions about additive/revocation semantics can be
 /// deterministic. Using a small, representative set of permissions exercises
 /// bitfield masks and collision behaviours without being exhaustive.
-pub(crate) const PERMISSION_VARIANTS: [Permissions; 7] = [
+pub(crate) const PERMISSION_VARIANTS: [Permissions; 8] = [
     Permissions::UPDATE_PRICE,
     Permissions::TRIGGER_CIRCUIT_BREAKER,
     Permissions::MODIFY_CONFIG,
@@ -42,6 +42,7 @@ pub(crate) const PERMISSION_VARIANTS: [Permissions; 7] = [
     Permissions::EMERGENCY_HALT,
     Permissions::ADD_FEED,
     Permissions::REMOVE_FEED,
+    Permissions::VETO,
 ];
 
 /// Generates a deterministic, non-default pubkey based on a simple seed.
@@ -89,7 +90,8 @@ pub(crate) fn governance_fixture(active_members: u8) -> GovernanceState {
         oracle_state: deterministic_pubkey(200),
         multisig_members: [Pubkey::default(); MAX_MULTISIG_MEMBERS],
         member_permissions: [Permissions::new(); MAX_MULTISIG_MEMBERS],
-        reserved: [0; 512],
+        member_stakes: [0; MAX_MULTISIG_MEMBERS],
+        reserved: [0; 384],
     };
 
     populate_allowed_programs(&mut state);
@@ -130,6 +132,13 @@ fn populate_members(state: &mut GovernanceState) {
         } else {
             Permissions::new()
         };
+        // Equal stakes reduce stake-weighted voting to one-member-one-vote,
+        // so tests opt into skewed stake distributions explicitly.
+        state.member_stakes[idx] = if idx < member_count {
+            state.proposal_threshold
+        } else {
+            0
+        };
     }
 }
 
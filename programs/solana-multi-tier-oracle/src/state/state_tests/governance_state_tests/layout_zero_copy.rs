@@ -24,7 +24,7 @@ use crate::state::governance_state::{GovernanceState, Permissions};
 use crate::utils::constants::MAX_ALLOWED_PROGRAMS;
 use anchor_lang::Space;
 use bytemuck::{bytes_of, Pod, Zeroable};
-use std::mem::{align_of, size_of};
+use std::mem::{align_of, offset_of, size_of};
 
 #[test]
 fn governance_state_layout_contract() {
@@ -52,6 +52,25 @@ fn governance_state_layout_contract() {
     );
 }
 
+/// Offsets of the fields other programs and clients locate by position.
+///
+/// If a schema change moves any of these intentionally, take the new field out
+/// of `reserved` where possible so only the `reserved` offset changes, and pin
+/// the new values in the same commit as the layout change.
+#[test]
+fn key_field_offsets_are_pinned() {
+    assert_eq!(offset_of!(GovernanceState, proposal_threshold), 0);
+    assert_eq!(offset_of!(GovernanceState, oracle_state), 560);
+    assert_eq!(offset_of!(GovernanceState, multisig_members), 592);
+    assert_eq!(offset_of!(GovernanceState, member_permissions), 1_104);
+    assert_eq!(offset_of!(GovernanceState, member_stakes), 1_232);
+    assert_eq!(
+        offset_of!(GovernanceState, reserved) + 384,
+        size_of::<GovernanceState>(),
+        "reserved must stay the final field so carving from it moves nothing else"
+    );
+}
+
 #[test]
 fn init_space_matches_struct_layout() {
     const DISCRIMINATOR: usize = 8;
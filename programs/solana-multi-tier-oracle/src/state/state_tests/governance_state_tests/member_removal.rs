@@ -0,0 +1,73 @@
+//! Member removal safety.
+//!
+//! A removal is only allowed if the multisig can still reach its threshold and
+//! at least one admin remains; either failure would leave governance with no
+//! on-chain path to repair itself.
+
+use super::helpers::{assert_state_error, governance_fixture};
+use crate::error::StateError;
+use crate::state::governance_state::Permissions;
+use anchor_lang::prelude::Pubkey;
+
+#[test]
+fn removal_that_drops_below_threshold_is_rejected() {
+    let mut state = governance_fixture(3);
+    state.multi_sig_threshold = 3;
+    state.member_permissions[0] = Permissions::ADMIN_ALL;
+
+    assert_state_error(
+        state.can_remove_member(2),
+        StateError::RemovalBreaksThreshold,
+    );
+    assert_state_error(state.remove_member(2), StateError::RemovalBreaksThreshold);
+    assert_eq!(
+        state.active_member_count, 3,
+        "rejected removal leaves state intact"
+    );
+}
+
+#[test]
+fn removal_of_last_admin_is_rejected() {
+    let mut state = governance_fixture(3);
+    state.multi_sig_threshold = 1;
+    for permissions in state.member_permissions.iter_mut() {
+        *permissions = Permissions::OPERATOR_ALL;
+    }
+    state.member_permissions[1] = Permissions::ADMIN_ALL;
+
+    assert_state_error(
+        state.can_remove_member(1),
+        StateError::CannotRemoveLastAdmin,
+    );
+    state
+        .can_remove_member(0)
+        .expect("removing a non-admin keeps the admin in place");
+}
+
+#[test]
+fn removal_keeping_threshold_satisfiable_succeeds_and_compacts() {
+    let mut state = governance_fixture(4);
+    state.multi_sig_threshold = 2;
+    state.member_permissions[0] = Permissions::ADMIN_ALL;
+    let last_member = state.multisig_members[3];
+    let last_permissions = state.member_permissions[3];
+    state.member_stakes[3] = 77;
+
+    state.remove_member(1).expect("threshold and admin remain");
+
+    assert_eq!(state.active_member_count, 3);
+    assert_eq!(state.multisig_members[1], last_member);
+    assert_eq!(state.member_permissions[1], last_permissions);
+    assert_eq!(state.member_stakes[1], 77);
+
+    // The vacated tail slot is scrubbed so it cannot be silently reactivated.
+    assert_eq!(state.multisig_members[3], Pubkey::default());
+    assert_eq!(state.member_permissions[3], Permissions::new());
+    assert_eq!(state.member_stakes[3], 0);
+}
+
+#[test]
+fn removal_of_inactive_slot_is_rejected() {
+    let state = governance_fixture(3);
+    assert_state_error(state.can_remove_member(3), StateError::UnauthorizedCaller);
+}
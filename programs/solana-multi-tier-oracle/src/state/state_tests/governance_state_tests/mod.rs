@@ -4,21 +4,39 @@
 //! controls. These tests are organised into focused modules so auditors can
 //! reason about coverage:
 //! - `helpers`: deterministic fixtures shared across suites.
+//! - `head_count_quorum`: member-count quorum at and around the basis-point boundary.
+//! - `bootstrap_liveness`: initialization rejects thresholds that freeze membership, and founding stake past `u64::MAX`.
+//! - `params_update`: validated, all-or-nothing retuning of proposal timing.
 //! - `permissions_unit`: atomic permission bit manipulation behaviour.
 //! - `core_unit_tests`: GovernanceState member-management primitives.
+//! - `config_digest`: audit digest coverage of every configured field.
+//! - `emergency_fast_path`: single-signer halts alongside threshold-gated config changes.
+//! - `member_removal`: liveness guards on threshold and admin coverage.
+//! - `admin_count`: active admin counting behind the removal and revocation lockout guards.
 //! - `layout_zero_copy`: byte-level ABI and zero-copy guarantees.
+//! - `required_signers`: threshold plus permission-holder view for client tooling.
+//! - `threshold_signers`: distinct permission holders required for stake, membership and parameter changes.
 //! - `property_tests`: proptest-based fuzzing of permission masks.
 //! - `serialization_and_integration`: round-trips plus OracleState coupling.
 //! - `attack_scenarios`: regression harness for common governance threats.
 //! - `timing_and_thresholds`: boundary validation for proposal timing knobs.
 //! - `stress_sequences`: rapid update simulations mirroring operator churn.
 
+pub mod admin_count;
 pub mod attack_scenarios;
+pub mod bootstrap_liveness;
+pub mod config_digest;
 pub mod core_unit_tests;
+pub mod emergency_fast_path;
+pub mod head_count_quorum;
 pub mod helpers;
 pub mod layout_zero_copy;
+pub mod member_removal;
+pub mod params_update;
 pub mod permissions_unit;
 pub mod property_tests;
+pub mod required_signers;
 pub mod serialization_and_integration;
 pub mod stress_sequences;
+pub mod threshold_signers;
 pub mod timing_and_thresholds;
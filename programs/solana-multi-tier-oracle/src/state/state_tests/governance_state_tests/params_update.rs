@@ -0,0 +1,117 @@
+//! Post-bootstrap retuning of proposal timing and thresholds.
+//!
+//! `set_params` must enforce the bounds `initialize_oracle` applies and must be
+//! all-or-nothing: a rejected update leaves every previous value in force.
+
+use super::helpers::{assert_state_error, governance_fixture};
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceParams, GovernanceState};
+use crate::utils::constants::MAX_QUORUM_THRESHOLD;
+
+fn valid_params() -> GovernanceParams {
+    GovernanceParams {
+        voting_period: 86_400,
+        execution_delay: 3_600,
+        timelock_duration: 7_200,
+        veto_period: 1_800,
+        quorum_threshold: 6_000,
+        proposal_threshold: 1_000,
+    }
+}
+
+fn assert_rejected(state: &mut GovernanceState, params: GovernanceParams, error: StateError) {
+    let before = state.params();
+    let digest = state.config_digest();
+
+    assert_state_error(state.set_params(&params), error);
+    assert_eq!(state.params(), before, "rejected update must not write");
+    assert_eq!(state.config_digest(), digest);
+}
+
+#[test]
+fn valid_update_replaces_every_field() {
+    let mut state = governance_fixture(3);
+    let digest_before = state.config_digest();
+    let params = valid_params();
+
+    state.set_params(&params).expect("valid parameters");
+
+    assert_eq!(state.params(), params);
+    assert_eq!(state.voting_period, 86_400);
+    assert_eq!(state.timelock_duration, 7_200);
+    assert_eq!(state.veto_period, 1_800);
+    assert_eq!(state.proposal_threshold, 1_000);
+    assert_ne!(state.config_digest(), digest_before);
+}
+
+#[test]
+fn boundary_values_are_accepted() {
+    let mut state = governance_fixture(2);
+    let params = GovernanceParams {
+        voting_period: 1,
+        execution_delay: 0,
+        timelock_duration: 0,
+        veto_period: 0,
+        quorum_threshold: MAX_QUORUM_THRESHOLD,
+        proposal_threshold: 1,
+    };
+
+    state.set_params(&params).expect("inclusive bounds");
+    assert_eq!(state.params(), params);
+}
+
+#[test]
+fn timing_violations_are_rejected() {
+    let mut state = governance_fixture(3);
+
+    for params in [
+        GovernanceParams {
+            voting_period: 0,
+            ..valid_params()
+        },
+        GovernanceParams {
+            execution_delay: -1,
+            ..valid_params()
+        },
+        GovernanceParams {
+            timelock_duration: -1,
+            ..valid_params()
+        },
+        GovernanceParams {
+            veto_period: i64::MIN,
+            ..valid_params()
+        },
+    ] {
+        assert_rejected(&mut state, params, StateError::InvalidTimingParameters);
+    }
+}
+
+#[test]
+fn quorum_outside_basis_points_is_rejected() {
+    let mut state = governance_fixture(3);
+
+    for quorum_threshold in [0, MAX_QUORUM_THRESHOLD + 1, u16::MAX] {
+        assert_rejected(
+            &mut state,
+            GovernanceParams {
+                quorum_threshold,
+                ..valid_params()
+            },
+            StateError::InvalidQuorumThreshold,
+        );
+    }
+}
+
+#[test]
+fn zero_proposal_threshold_is_rejected() {
+    let mut state = governance_fixture(3);
+
+    assert_rejected(
+        &mut state,
+        GovernanceParams {
+            proposal_threshold: 0,
+            ..valid_params()
+        },
+        StateError::InvalidProposalThreshold,
+    );
+}
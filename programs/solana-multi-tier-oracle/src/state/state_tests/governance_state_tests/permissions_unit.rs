@@ -14,9 +14,9 @@
 //!   only flip the requested capability. Tests assert these properties.
 //! - `VALID_MASK` exists to constrain valid bits; tests assert the mask covers
 //!   declared permissions to prevent accidental introduction of unknown bits.
-//! - Role helper methods (e.g., `is_admin`, `is_operator`) are composition
-//!   utilities — tests validate they reflect documented masks rather than
-//!   being magic checks.
+//! - Role helper methods (e.g., `is_admin`, `is_operator`, `is_risk_manager`)
+//!   are composition utilities — tests validate they reflect documented masks
+//!   rather than being magic checks.
 
 use super::helpers::{assert_permissions_sanitized, PERMISSION_VARIANTS};
 use crate::state::governance_state::Permissions;
@@ -127,3 +127,95 @@ fn valid_mask_covers_all_declared_permissions() {
         );
     }
 }
+
+#[test]
+fn role_presets_have_exact_bit_composition() {
+    assert_eq!(
+        Permissions::RISK_MANAGER.as_u64(),
+        Permissions::VIEW_METRICS.as_u64()
+            | Permissions::TRIGGER_CIRCUIT_BREAKER.as_u64()
+            | Permissions::EMERGENCY_HALT.as_u64()
+    );
+    assert_eq!(
+        Permissions::FEED_MANAGER.as_u64(),
+        Permissions::ADD_FEED.as_u64()
+            | Permissions::REMOVE_FEED.as_u64()
+            | Permissions::VIEW_METRICS.as_u64()
+    );
+}
+
+#[test]
+fn role_presets_are_subsets_of_valid_mask() {
+    for preset in [
+        Permissions::ADMIN_ALL,
+        Permissions::OPERATOR_ALL,
+        Permissions::RISK_MANAGER,
+        Permissions::FEED_MANAGER,
+    ] {
+        assert_eq!(
+            preset.as_u64() & !Permissions::VALID_MASK,
+            0,
+            "preset {preset:?} must not carry unknown bits"
+        );
+        assert_eq!(Permissions::from_u64_truncate(preset.as_u64()), preset);
+    }
+}
+
+#[test]
+fn risk_manager_can_stop_but_not_steer_the_oracle() {
+    let risk = Permissions::RISK_MANAGER;
+    assert!(risk.is_risk_manager());
+    assert!(risk.can_view_metrics());
+    assert!(risk.can_trigger_circuit_breaker());
+    assert!(risk.can_emergency_halt());
+
+    assert!(!risk.can_update_price());
+    assert!(!risk.can_modify_config());
+    assert!(!risk.can_add_feed());
+    assert!(!risk.can_remove_feed());
+    assert!(!risk.can_veto());
+    assert!(!risk.is_admin());
+    assert!(!risk.is_operator());
+    assert!(!risk.is_feed_manager());
+
+    let without_halt = Permissions::without_permissions(risk, Permissions::EMERGENCY_HALT);
+    assert!(!without_halt.is_risk_manager());
+}
+
+#[test]
+fn feed_manager_curates_feeds_without_admin_powers() {
+    let feeds = Permissions::FEED_MANAGER;
+    assert!(feeds.is_feed_manager());
+    assert!(feeds.can_add_feed());
+    assert!(feeds.can_remove_feed());
+    assert!(feeds.can_view_metrics());
+
+    assert!(!feeds.can_update_price());
+    assert!(!feeds.can_modify_config());
+    assert!(!feeds.can_trigger_circuit_breaker());
+    assert!(!feeds.can_emergency_halt());
+    assert!(!feeds.can_veto());
+    assert!(!feeds.is_admin());
+    assert!(!feeds.is_operator());
+    assert!(!feeds.is_risk_manager());
+
+    let without_removal = Permissions::without_permissions(feeds, Permissions::REMOVE_FEED);
+    assert!(!without_removal.is_feed_manager());
+}
+
+#[test]
+fn presets_compose_into_wider_roles() {
+    let admin_with_monitoring =
+        Permissions::with_permissions(Permissions::ADMIN_ALL, Permissions::VIEW_METRICS);
+    assert!(admin_with_monitoring.is_risk_manager());
+    assert!(admin_with_monitoring.is_feed_manager());
+
+    let combined =
+        Permissions::with_permissions(Permissions::RISK_MANAGER, Permissions::FEED_MANAGER);
+    assert!(combined.is_risk_manager());
+    assert!(combined.is_feed_manager());
+    assert!(
+        !combined.is_admin(),
+        "config and price powers are still missing"
+    );
+}
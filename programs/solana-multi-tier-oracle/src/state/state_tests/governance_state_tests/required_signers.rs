@@ -0,0 +1,48 @@
+//! Signer-set view used by client tooling to assemble multisig transactions.
+
+use super::helpers::{deterministic_pubkey, governance_fixture};
+use crate::state::governance_state::Permissions;
+
+#[test]
+fn lists_only_active_holders_of_the_permission() {
+    // Fixture slot `i` holds the `i`-th permission atom, so slot 5 alone has ADD_FEED
+    let mut state = governance_fixture(8);
+    state.multi_sig_threshold = 2;
+    state.member_permissions[1].grant(Permissions::ADD_FEED);
+    state.member_permissions[7] = Permissions::ADMIN_ALL;
+
+    let (threshold, signers) = state.required_signers(Permissions::ADD_FEED);
+
+    assert_eq!(threshold, 2);
+    assert_eq!(
+        signers,
+        vec![
+            deterministic_pubkey(101),
+            deterministic_pubkey(105),
+            deterministic_pubkey(107),
+        ]
+    );
+}
+
+#[test]
+fn inactive_slots_are_never_listed() {
+    let mut state = governance_fixture(4);
+    // Leftover data past the active range must not count as a signer
+    state.multisig_members[4] = deterministic_pubkey(104);
+    state.member_permissions[4] = Permissions::ADD_FEED;
+
+    let (threshold, signers) = state.required_signers(Permissions::ADD_FEED);
+
+    assert_eq!(threshold, 4);
+    assert!(signers.is_empty());
+}
+
+#[test]
+fn shortfall_is_reported_rather_than_rejected() {
+    let state = governance_fixture(8);
+
+    let (threshold, signers) = state.required_signers(Permissions::VETO);
+
+    assert_eq!(signers, vec![deterministic_pubkey(107)]);
+    assert!((signers.len() as u8) < threshold);
+}
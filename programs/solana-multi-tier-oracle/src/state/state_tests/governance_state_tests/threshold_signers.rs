@@ -0,0 +1,62 @@
+//! Multisig-threshold gating of stake, membership and parameter changes.
+//!
+//! Stake, membership and the governance parameters together decide what can
+//! pass, so `set_member_stake`, `remove_member` and `set_governance_params`
+//! need `multi_sig_threshold` distinct `MODIFY_CONFIG` holders among the
+//! transaction's signers. These tests pin which signers count towards that
+//! threshold.
+
+use super::helpers::{assert_state_error, deterministic_pubkey, governance_fixture};
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+
+/// Four members with a 2-of-N threshold where slots 0 to 2 hold `MODIFY_CONFIG`.
+fn governance() -> GovernanceState {
+    let mut state = governance_fixture(4);
+    state.multi_sig_threshold = 2;
+    state.member_permissions[0] = Permissions::ADMIN_ALL;
+    state.member_permissions[1].grant(Permissions::MODIFY_CONFIG);
+    state
+}
+
+#[test]
+fn single_holder_cannot_change_stakes_alone() {
+    let state = governance();
+
+    assert_state_error(
+        state.check_threshold_signers(&[deterministic_pubkey(100)], Permissions::MODIFY_CONFIG),
+        StateError::InsufficientSigners,
+    );
+}
+
+#[test]
+fn threshold_of_distinct_holders_is_accepted() {
+    let state = governance();
+
+    assert!(state
+        .check_threshold_signers(
+            &[deterministic_pubkey(100), deterministic_pubkey(102)],
+            Permissions::MODIFY_CONFIG,
+        )
+        .is_ok());
+}
+
+#[test]
+fn repeated_unqualified_and_outside_signers_do_not_count() {
+    let state = governance();
+    let admin = deterministic_pubkey(100);
+
+    for signers in [
+        // The same holder signing twice
+        vec![admin, admin],
+        // Slot 3 is a member without MODIFY_CONFIG
+        vec![admin, deterministic_pubkey(103)],
+        // Not a member at all
+        vec![admin, deterministic_pubkey(250)],
+    ] {
+        assert_state_error(
+            state.check_threshold_signers(&signers, Permissions::MODIFY_CONFIG),
+            StateError::InsufficientSigners,
+        );
+    }
+}
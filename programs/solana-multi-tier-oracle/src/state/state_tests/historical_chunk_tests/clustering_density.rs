@@ -0,0 +1,81 @@
+//! Equivalence of the single-pass clustering scan with the original nested scan.
+//!
+//! `max_hourly_density` replaced a per-hour rescan of the timestamp slice. The
+//! reference below is that nested loop verbatim, kept so randomized inputs can
+//! prove the rewrite changes cost, not results, including the early exit.
+
+use crate::state::oracle_state::max_hourly_density;
+use crate::utils::constants::{MAX_SNAPSHOTS_PER_HOUR, SECONDS_PER_HOUR};
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+fn nested_scan_reference(valid_timestamps: &[i64], max_analysis_hours: i64) -> u16 {
+    let time_span_seconds = valid_timestamps[valid_timestamps.len() - 1] - valid_timestamps[0];
+    let mut max_per_hour = 0u16;
+    let total_hours = (time_span_seconds / SECONDS_PER_HOUR) + 1;
+
+    for hour_offset in 0..total_hours.min(max_analysis_hours) {
+        let hour_start = valid_timestamps[0] + (hour_offset * SECONDS_PER_HOUR);
+        let hour_end = hour_start + SECONDS_PER_HOUR;
+
+        let mut count_in_hour = 0u16;
+        for &timestamp in valid_timestamps.iter() {
+            if timestamp >= hour_start && timestamp < hour_end {
+                count_in_hour += 1;
+            } else if timestamp >= hour_end {
+                break;
+            }
+        }
+
+        max_per_hour = max_per_hour.max(count_in_hour);
+        if max_per_hour > MAX_SNAPSHOTS_PER_HOUR {
+            return max_per_hour;
+        }
+    }
+
+    max_per_hour
+}
+
+#[test]
+fn empty_input_has_zero_density() {
+    assert_eq!(max_hourly_density(&[], 96), 0);
+}
+
+#[test]
+fn regular_cadence_reports_four_per_hour() {
+    let timestamps: Vec<i64> = (0..96 * 4).map(|i| 1_000 + i * 900).collect();
+    assert_eq!(max_hourly_density(&timestamps, 96), 4);
+}
+
+#[test]
+fn timestamps_past_the_analysis_window_are_ignored() {
+    // Hour 0 is sparse; hour 2 is clustered but outside a two-hour analysis window
+    let timestamps = [0, 7_200, 7_201, 7_202, 7_203, 7_204, 7_205];
+    assert_eq!(max_hourly_density(&timestamps, 2), 1);
+    assert_eq!(max_hourly_density(&timestamps, 3), 6);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig { cases: 256, .. ProptestConfig::default() })]
+    /// Property: for any sorted timestamp set and analysis window, the single pass
+    /// returns exactly what the nested scan returned.
+    fn single_pass_matches_nested_scan(
+        start in 0i64..2_000_000_000,
+        gaps in vec(0i64..2 * SECONDS_PER_HOUR, 1..384),
+        max_analysis_hours in 0i64..=96,
+    ) {
+        let mut timestamp = start;
+        let timestamps: Vec<i64> = gaps
+            .iter()
+            .map(|gap| {
+                timestamp += *gap;
+                timestamp
+            })
+            .collect();
+
+        prop_assert_eq!(
+            max_hourly_density(&timestamps, max_analysis_hours),
+            nested_scan_reference(&timestamps, max_analysis_hours)
+        );
+    }
+}
@@ -9,6 +9,9 @@ use super::helpers::{
     alternating_extreme_point, assert_chunk_invariants, assert_price_point_eq, collect_fifo_view,
     deterministic_price_point, empty_chunk, BUFFER_SIZE_U16,
 };
+use crate::error::StateError;
+use crate::state::historical_chunk::PricePoint;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
 use crate::utils::constants::BUFFER_SIZE;
 use anchor_lang::prelude::Pubkey;
 
@@ -164,10 +167,60 @@ fn latest_tracks_last_inserted_element() {
 
     let latest = chunk.latest().expect("buffer should contain data");
     let expected = deterministic_price_point(BUFFER_SIZE as i64 + 4);
-    assert_price_point_eq(latest, &expected);
+    assert_price_point_eq(&latest, &expected);
     assert_chunk_invariants!(chunk);
 }
 
+/// `oldest()` mirrors `latest()` at the FIFO front.
+///
+/// TWAP start clamping and snapshot spans both read the front of the ring, so
+/// it must follow the tail through wraparound and never surface a slot that
+/// holds no data.
+#[test]
+fn oldest_returns_none_when_empty() {
+    let chunk = empty_chunk();
+    assert!(chunk.oldest().is_none());
+}
+
+#[test]
+fn oldest_is_first_push_while_partially_filled() {
+    let mut chunk = empty_chunk();
+    for idx in 0..5 {
+        chunk.push(deterministic_price_point(idx));
+    }
+
+    let oldest = chunk.oldest().expect("buffer should contain data");
+    assert_price_point_eq(&oldest, &deterministic_price_point(0));
+}
+
+#[test]
+fn oldest_follows_tail_after_wraparound() {
+    let mut chunk = empty_chunk();
+    for idx in 0..(BUFFER_SIZE as i64 + 5) {
+        chunk.push(deterministic_price_point(idx));
+    }
+
+    // The first five points were evicted; the front now sits mid-array
+    let oldest = chunk.oldest().expect("buffer should contain data");
+    assert_price_point_eq(&oldest, &deterministic_price_point(5));
+    assert_chunk_invariants!(chunk);
+}
+
+#[test]
+fn oldest_skips_zeroed_slots_at_the_front() {
+    let mut chunk = empty_chunk();
+    chunk.push(PricePoint::default());
+    chunk.push(PricePoint::default());
+    chunk.push(deterministic_price_point(2));
+
+    let oldest = chunk.oldest().expect("a valid point is retained");
+    assert_price_point_eq(&oldest, &deterministic_price_point(2));
+
+    let mut zeroed = empty_chunk();
+    zeroed.push(PricePoint::default());
+    assert!(zeroed.oldest().is_none(), "zeroed slots are not data");
+}
+
 /// `next_chunk` uses the default zeroed `Pubkey` as a sentinel to represent
 /// the end of a linked chain of chunks.
 ///
@@ -260,3 +313,26 @@ fn alternating_extremes_do_not_corrupt_buffer() {
     );
     assert_chunk_invariants!(chunk);
 }
+
+/// The oracle back-reference must match the oracle performing the update.
+///
+/// Rationale:
+/// - PDA derivation is the first line of defence, but a seed regression would
+///   let a foreign chunk through; the stored link catches that case.
+/// - The matching case is asserted too so the check cannot be satisfied by
+///   rejecting everything.
+#[test]
+fn verify_oracle_link_rejects_foreign_chunk() {
+    let oracle_key = Pubkey::new_unique();
+    let mut chunk = empty_chunk();
+    chunk.oracle_state = oracle_key;
+
+    chunk
+        .verify_oracle_link(&oracle_key)
+        .expect("chunk linked to this oracle is accepted");
+
+    assert_state_error(
+        chunk.verify_oracle_link(&Pubkey::new_unique()),
+        StateError::ChunkOracleMismatch,
+    );
+}
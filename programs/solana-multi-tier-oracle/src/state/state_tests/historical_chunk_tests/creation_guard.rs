@@ -0,0 +1,64 @@
+//! Backdating guard on the update-path push.
+//!
+//! `push_checked` refuses points stamped before the chunk was created and
+//! leaves the buffer untouched when it does.
+
+use super::helpers::{collect_fifo_view, deterministic_price_point, empty_chunk};
+use crate::error::StateError;
+use crate::state::historical_chunk::HistoricalChunk;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+
+const CREATED_AT: i64 = 1_700_000_000;
+
+fn created_chunk() -> HistoricalChunk {
+    let mut chunk = empty_chunk();
+    chunk.creation_timestamp = CREATED_AT;
+    chunk
+}
+
+#[test]
+fn backdated_point_is_rejected() {
+    let mut chunk = created_chunk();
+    let mut point = deterministic_price_point(0);
+    point.timestamp = CREATED_AT - 1;
+
+    assert_state_error(
+        chunk.push_checked(point),
+        StateError::NonMonotonicTimestamps,
+    );
+    assert_eq!(chunk.count, 0);
+    assert_eq!(chunk.head, 0);
+    assert!(chunk.latest().is_none());
+}
+
+#[test]
+fn points_at_or_after_creation_are_accepted() {
+    let mut chunk = created_chunk();
+    let mut at_creation = deterministic_price_point(0);
+    at_creation.timestamp = CREATED_AT;
+    let forward = deterministic_price_point(4);
+    assert!(forward.timestamp > CREATED_AT);
+
+    chunk.push_checked(at_creation).unwrap();
+    chunk.push_checked(forward).unwrap();
+
+    let timestamps: Vec<i64> = collect_fifo_view(&chunk)
+        .iter()
+        .map(|point| point.timestamp)
+        .collect();
+    assert_eq!(timestamps, [CREATED_AT, forward.timestamp]);
+}
+
+#[test]
+fn rejection_does_not_disturb_existing_history() {
+    let mut chunk = created_chunk();
+    let accepted = deterministic_price_point(1);
+    chunk.push_checked(accepted).unwrap();
+
+    let mut backdated = deterministic_price_point(2);
+    backdated.timestamp = CREATED_AT - 3_600;
+    assert!(chunk.push_checked(backdated).is_err());
+
+    assert_eq!(chunk.count, 1);
+    assert_eq!(chunk.latest().unwrap().timestamp, accepted.timestamp);
+}
@@ -0,0 +1,109 @@
+//! Short and long TWAPs accumulated in the published TWAP's pass.
+//!
+//! Samples are held `STEP` seconds each, so every expected mean below is the
+//! plain average of the prices whose hold overlaps the window.
+
+use super::helpers::empty_chunk;
+use crate::instructions::update_price::{stream_twap_from_chunks, TWAPResult, WeightingScheme};
+use crate::state::historical_chunk::{HistoricalChunk, PricePoint};
+use anchor_lang::prelude::Pubkey;
+
+const START: i64 = 1_700_000_000;
+const STEP: i64 = 100;
+const SAMPLES: usize = 10;
+const NOW: i64 = START + SAMPLES as i64 * STEP;
+
+/// `SAMPLES` points from `START`, the i-th priced `price(i)`.
+fn chunk_with_prices(price: impl Fn(usize) -> i128) -> HistoricalChunk {
+    let mut chunk = empty_chunk();
+    for i in 0..SAMPLES {
+        chunk.push(PricePoint {
+            price: price(i),
+            conf: 10_000,
+            timestamp: START + i as i64 * STEP,
+            volume: 0,
+        });
+    }
+    chunk
+}
+
+fn stream(chunk: &HistoricalChunk, window_seconds: u32, divergence: [u32; 2]) -> TWAPResult {
+    let empty = empty_chunk();
+    stream_twap_from_chunks(
+        &[&empty, &empty, chunk],
+        window_seconds,
+        divergence,
+        WeightingScheme::TimeOnly,
+        NOW,
+        &Pubkey::new_unique(),
+        0,
+    )
+    .unwrap()
+}
+
+#[test]
+fn windows_agree_on_a_flat_series() {
+    let chunk = chunk_with_prices(|_| 100);
+    let result = stream(&chunk, 3_600, [300, 900]);
+
+    assert_eq!(result.twap_price, 100);
+    assert_eq!(result.twap_short, Some(100));
+    assert_eq!(result.twap_long, Some(100));
+}
+
+#[test]
+fn short_window_leads_a_rising_series() {
+    // Prices 100, 110, …, 190
+    let chunk = chunk_with_prices(|i| 100 + 10 * i as i128);
+    let result = stream(&chunk, 3_600, [300, 1_000]);
+
+    // Last three holds: (170 + 180 + 190) / 3
+    assert_eq!(result.twap_short, Some(180));
+    // Every hold: (100 + … + 190) / 10
+    assert_eq!(result.twap_long, Some(145));
+    assert!(result.twap_short > result.twap_long);
+}
+
+#[test]
+fn short_window_trails_a_falling_series() {
+    let chunk = chunk_with_prices(|i| 190 - 10 * i as i128);
+    let result = stream(&chunk, 3_600, [300, 1_000]);
+
+    assert_eq!(result.twap_short, Some(110));
+    assert_eq!(result.twap_long, Some(145));
+}
+
+#[test]
+fn window_matching_the_published_one_reproduces_it() {
+    let chunk = chunk_with_prices(|i| 100 + (i as i128 * 37) % 50);
+    let result = stream(&chunk, 600, [600, 600]);
+
+    assert_eq!(result.twap_short, Some(result.twap_price));
+    assert_eq!(result.twap_long, Some(result.twap_price));
+}
+
+#[test]
+fn long_window_may_reach_past_the_published_one() {
+    let chunk = chunk_with_prices(|i| 100 + 10 * i as i128);
+    let result = stream(&chunk, 300, [0, 1_000]);
+
+    assert_eq!(result.twap_price, 180);
+    assert_eq!(result.twap_long, Some(145));
+}
+
+#[test]
+fn window_beyond_history_is_clamped_to_the_oldest_point() {
+    let chunk = chunk_with_prices(|i| 100 + 10 * i as i128);
+    let result = stream(&chunk, 3_600, [0, 86_400]);
+
+    assert_eq!(result.twap_long, Some(145));
+}
+
+#[test]
+fn disabled_windows_report_nothing() {
+    let chunk = chunk_with_prices(|_| 100);
+    let result = stream(&chunk, 3_600, [0, 0]);
+
+    assert_eq!(result.twap_short, None);
+    assert_eq!(result.twap_long, None);
+}
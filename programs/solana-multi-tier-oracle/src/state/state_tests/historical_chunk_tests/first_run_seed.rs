@@ -0,0 +1,102 @@
+//! Bootstrap of an empty history by the oracle's first update.
+//!
+//! The first update has no history to average and publishes the pool price
+//! directly. Seeding that price as the first stored point gives the next
+//! update a real start segment instead of an empty window, and the guard keeps
+//! a retried first run from stacking a second seed on top.
+
+use super::helpers::empty_chunk;
+use crate::instructions::update_price::{
+    history_push_kind, stream_twap_from_chunks, TWAPResult, WeightingScheme,
+};
+use crate::state::historical_chunk::{HistoricalChunk, PricePoint};
+use crate::utils::constants::MIN_HISTORICAL_INTERVAL;
+use anchor_lang::prelude::Pubkey;
+
+const START: i64 = 1_700_000_000;
+
+fn point(price: i128, timestamp: i64) -> PricePoint {
+    PricePoint {
+        price,
+        volume: 0,
+        conf: 10_000,
+        timestamp,
+    }
+}
+
+fn fresh_chunk() -> HistoricalChunk {
+    let mut chunk = empty_chunk();
+    chunk.creation_timestamp = START;
+    chunk
+}
+
+fn twap_at(chunk: &HistoricalChunk, now: i64) -> TWAPResult {
+    let empty = empty_chunk();
+    stream_twap_from_chunks(
+        &[&empty, &empty, chunk],
+        3_600,
+        [0, 0],
+        WeightingScheme::TimeOnly,
+        now,
+        &Pubkey::new_unique(),
+        0,
+    )
+    .unwrap()
+}
+
+#[test]
+fn first_run_leaves_one_seeded_point() {
+    let mut chunk = fresh_chunk();
+
+    assert!(chunk.seed(point(1_000, START)).unwrap());
+    assert_eq!(chunk.count, 1);
+    assert_eq!(chunk.latest().unwrap().price, 1_000);
+    assert_eq!(chunk.latest().unwrap().timestamp, START);
+    assert!(!chunk.latest_is_forced());
+}
+
+#[test]
+fn seeding_twice_is_a_no_op() {
+    let mut chunk = fresh_chunk();
+    chunk.seed(point(1_000, START)).unwrap();
+
+    assert!(!chunk.seed(point(2_000, START + 60)).unwrap());
+    assert_eq!(chunk.count, 1);
+    assert_eq!(chunk.latest().unwrap().price, 1_000);
+}
+
+#[test]
+fn seed_keeps_the_backdating_guard() {
+    let mut chunk = fresh_chunk();
+    assert!(chunk.seed(point(1_000, START - 1)).is_err());
+    assert_eq!(chunk.count, 0);
+}
+
+#[test]
+fn seed_does_not_pull_the_next_scheduled_push_forward() {
+    let mut chunk = fresh_chunk();
+    chunk.seed(point(1_000, START)).unwrap();
+
+    assert_eq!(history_push_kind(&chunk, 1_000, START + 60, 0), None);
+    assert!(history_push_kind(&chunk, 1_000, START + MIN_HISTORICAL_INTERVAL, 0).is_some());
+}
+
+#[test]
+fn second_update_averages_from_the_seed_and_third_blends_two_points() {
+    let mut chunk = fresh_chunk();
+    chunk.seed(point(1_000, START)).unwrap();
+
+    // Second update: the seed is the start segment, held until now
+    let second_at = START + MIN_HISTORICAL_INTERVAL;
+    let second = twap_at(&chunk, second_at);
+    assert_eq!(second.twap_price, 1_000);
+    assert_eq!(second.oldest_timestamp, START);
+    assert_eq!(second.covered_time_span, MIN_HISTORICAL_INTERVAL as u64);
+
+    chunk.push_checked(point(2_000, second_at)).unwrap();
+
+    // Third update: both stored points carry equal time
+    let third = twap_at(&chunk, second_at + MIN_HISTORICAL_INTERVAL);
+    assert_eq!(third.data_points_used, 2);
+    assert_eq!(third.twap_price, 1_500);
+}
@@ -0,0 +1,217 @@
+//! Deviation-forced history pushes and their isolation from the sampling cadence.
+//!
+//! `history_push_kind` is the decision `update_price` makes on every publish.
+//! These tests drive it minute by minute, the way a keeper would, and assert
+//! that a quiet market is sampled on schedule only, that a sharp move is
+//! recorded immediately, and that forced points neither crowd the buffer nor
+//! count towards redemption snapshots.
+
+use super::helpers::{empty_chunk, minimal_oracle_state};
+use crate::instructions::update_price::{history_push_kind, HistoryPush};
+use crate::state::historical_chunk::{HistoricalChunk, PricePoint};
+use crate::state::snapshot_status::SnapshotStatus;
+use crate::utils::constants::MIN_HISTORICAL_INTERVAL;
+
+const START: i64 = 1_700_000_000;
+const DEVIATION_BPS: u16 = 100; // 1%
+
+fn point(price: i128, timestamp: i64) -> PricePoint {
+    PricePoint {
+        price,
+        volume: 0,
+        conf: 1_000,
+        timestamp,
+    }
+}
+
+fn fresh_chunk() -> HistoricalChunk {
+    let mut chunk = empty_chunk();
+    chunk.creation_timestamp = START;
+    chunk
+}
+
+/// Publishes `price_at(t)` once a minute for `minutes`, pushing as the
+/// instruction would, and returns the kind of every push made.
+fn run_minutely(
+    chunk: &mut HistoricalChunk,
+    minutes: i64,
+    price_at: impl Fn(i64) -> i128,
+) -> Vec<(i64, HistoryPush)> {
+    let mut pushes = Vec::new();
+    for minute in 0..minutes {
+        let now = START + minute * 60;
+        let price = price_at(now);
+        let Some(kind) = history_push_kind(chunk, price, now, DEVIATION_BPS) else {
+            continue;
+        };
+        match kind {
+            HistoryPush::Scheduled => chunk.push_checked(point(price, now)).unwrap(),
+            HistoryPush::Forced => chunk.push_forced(point(price, now)).unwrap(),
+        }
+        assert_eq!(chunk.latest_is_forced(), kind == HistoryPush::Forced);
+        pushes.push((now, kind));
+    }
+    pushes
+}
+
+#[test]
+fn flat_series_pushes_only_on_schedule() {
+    let mut chunk = fresh_chunk();
+    let pushes = run_minutely(&mut chunk, 120, |_| 1_000_000);
+
+    let expected: Vec<_> = (0..8)
+        .map(|i| (START + i * MIN_HISTORICAL_INTERVAL, HistoryPush::Scheduled))
+        .collect();
+    assert_eq!(pushes, expected);
+}
+
+#[test]
+fn sudden_jump_forces_an_off_schedule_push() {
+    let mut chunk = fresh_chunk();
+    let jump_at = START + 5 * 60;
+    let pushes = run_minutely(&mut chunk, 16, |now| {
+        if now >= jump_at {
+            1_050_000
+        } else {
+            1_000_000
+        }
+    });
+
+    assert_eq!(
+        pushes,
+        vec![
+            (START, HistoryPush::Scheduled),
+            (jump_at, HistoryPush::Forced),
+            // The cadence is still measured from the scheduled point, not the jump
+            (START + MIN_HISTORICAL_INTERVAL, HistoryPush::Scheduled),
+        ]
+    );
+    assert_eq!(
+        chunk.latest_scheduled().unwrap().timestamp,
+        START + MIN_HISTORICAL_INTERVAL
+    );
+}
+
+#[test]
+fn moves_within_the_threshold_wait_for_the_schedule() {
+    let mut chunk = fresh_chunk();
+    chunk.push_checked(point(1_000_000, START)).unwrap();
+
+    // Exactly 1% is not beyond the threshold
+    assert_eq!(
+        history_push_kind(&chunk, 1_010_000, START + 60, DEVIATION_BPS),
+        None
+    );
+    assert_eq!(
+        history_push_kind(&chunk, 990_000, START + 60, DEVIATION_BPS),
+        None
+    );
+    assert_eq!(
+        history_push_kind(&chunk, 989_000, START + 60, DEVIATION_BPS),
+        Some(HistoryPush::Forced)
+    );
+}
+
+#[test]
+fn zero_threshold_disables_forcing() {
+    let mut chunk = fresh_chunk();
+    chunk.push_checked(point(1_000_000, START)).unwrap();
+
+    assert_eq!(history_push_kind(&chunk, 2_000_000, START + 60, 0), None);
+    assert_eq!(
+        history_push_kind(&chunk, 2_000_000, START + MIN_HISTORICAL_INTERVAL, 0),
+        Some(HistoryPush::Scheduled)
+    );
+}
+
+#[test]
+fn forced_pushes_are_bounded_to_one_per_interval() {
+    let mut chunk = fresh_chunk();
+    // A price that swings 10% every minute would otherwise force a push each time
+    let pushes = run_minutely(&mut chunk, 60, |now| {
+        if (now - START) / 60 % 2 == 0 {
+            1_000_000
+        } else {
+            1_100_000
+        }
+    });
+
+    let forced = pushes
+        .iter()
+        .filter(|(_, kind)| *kind == HistoryPush::Forced)
+        .count();
+    let scheduled = pushes.len() - forced;
+    assert_eq!(scheduled, 4);
+    assert!(
+        forced <= scheduled,
+        "{forced} forced pushes for {scheduled} intervals"
+    );
+    assert_eq!(chunk.count as usize, pushes.len());
+}
+
+#[test]
+fn overwriting_a_forced_slot_clears_its_mark() {
+    let mut chunk = fresh_chunk();
+    chunk.head = 5;
+    chunk.push_forced(point(1, START)).unwrap();
+    assert!(chunk.is_forced(5));
+
+    chunk.head = 5;
+    chunk.push_checked(point(2, START + 1)).unwrap();
+    assert!(!chunk.is_forced(5));
+}
+
+#[test]
+fn merge_carries_forced_marks_with_their_points() {
+    let mut ours = fresh_chunk();
+    ours.push_checked(point(1, START)).unwrap();
+    ours.push_checked(point(3, START + 900)).unwrap();
+    let mut theirs = fresh_chunk();
+    theirs.push_forced(point(2, START + 300)).unwrap();
+
+    ours.merge_from(&theirs).unwrap();
+
+    let forced: Vec<_> = (0..ours.count as usize)
+        .map(|slot| ours.is_forced(slot))
+        .collect();
+    assert_eq!(forced, vec![false, true, false]);
+}
+
+#[test]
+fn forced_points_do_not_count_towards_redemption() {
+    let oracle_state = minimal_oracle_state();
+    let mut forced = fresh_chunk();
+    let mut unmarked = fresh_chunk();
+
+    // 24 hours of scheduled points, with a sharp move recorded after each of
+    // the first 30: eight points an hour where the cadence allows four
+    for i in 0..97 {
+        let scheduled = point(1_000_000, START + i * MIN_HISTORICAL_INTERVAL);
+        forced.push_checked(scheduled).unwrap();
+        unmarked.push_checked(scheduled).unwrap();
+        if i < 30 {
+            let jump = point(1_100_000, scheduled.timestamp + 300);
+            forced.push_forced(jump).unwrap();
+            unmarked.push_checked(jump).unwrap();
+        }
+    }
+    let now = START + 96 * MIN_HISTORICAL_INTERVAL;
+
+    let status = oracle_state.check_snapshot_requirements_v2([&forced], now, 24, 0);
+    assert!(
+        matches!(
+            status,
+            SnapshotStatus::Sufficient {
+                snapshot_count: 97,
+                ..
+            }
+        ),
+        "forced points must be invisible to redemption, got {status:?}"
+    );
+
+    let status = oracle_state.check_snapshot_requirements_v2([&unmarked], now, 24, 0);
+    assert!(
+        matches!(status, SnapshotStatus::ExcessiveClustering { .. }),
+        "the same points pushed on schedule would cluster, got {status:?}"
+    );
+}
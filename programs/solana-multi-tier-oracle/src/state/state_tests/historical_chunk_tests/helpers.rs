@@ -2,7 +2,8 @@ use crate::state::historical_chunk::{HistoricalChunk, PricePoint};
 use crate::state::oracle_state::{OracleState, PriceData, StateFlags, Version};
 use crate::state::price_feed::PriceFeed;
 use crate::utils::constants::{
-    BUFFER_SIZE, MAX_HISTORICAL_CHUNKS, MAX_PRICE_FEEDS, MIN_HISTORICAL_INTERVAL,
+    BUFFER_SIZE, MAX_ALLOWED_UPDATERS, MAX_HISTORICAL_CHUNKS, MAX_PRICE_FEEDS,
+    MIN_HISTORICAL_INTERVAL, PRICE_POINT_LAYOUT_VERSION, PRICE_TIER_COUNT, SOURCE_TYPE_COUNT,
 };
 use anchor_lang::prelude::Pubkey;
 use proptest::arbitrary::any;
@@ -79,7 +80,9 @@ pub(crate) fn empty_chunk() -> HistoricalChunk {
         oracle_state: Pubkey::default(),
         price_points: [PricePoint::default(); BUFFER_SIZE],
         bump: 0,
-        reserved: [0; 511],
+        forced_points: [0; BUFFER_SIZE / 8],
+        point_layout: PRICE_POINT_LAYOUT_VERSION,
+        reserved: [0; 494],
     }
 }
 
@@ -226,7 +229,33 @@ pub(crate) fn minimal_oracle_state() -> OracleState {
         historical_chunks: [Pubkey::default(); MAX_HISTORICAL_CHUNKS],
         emergency_admin: Pubkey::default(),
         asset_seed: [0; 32],
-        reserved: [0; 513],
+        expo_initialized: 0,
+        update_nonce: 0,
+        manipulation_peak_at: [0; MAX_PRICE_FEEDS],
+        manipulation_peak_score: [0; MAX_PRICE_FEEDS],
+        manipulation_cooldown: 0,
+        manipulation_decay_period: 0,
+        last_sources_mask: 0,
+        update_cooldown: 0,
+        target_twap_window: 0,
+        twap_ramp_duration: 0,
+        twap_ramp_started_at: 0,
+        saturation_count: 0,
+        allowed_updaters: [Pubkey::default(); MAX_ALLOWED_UPDATERS],
+        updater_policy: 0,
+        _updater_padding: [0; 15],
+        source_threshold_bps: [0; SOURCE_TYPE_COUNT],
+        escalation_ladder: [0; 2],
+        _escalation_padding: [0; 4],
+        tier_weights_bps: [0; PRICE_TIER_COUNT],
+        max_tier_spread_bps: 0,
+        max_twap_window_override: 0,
+        _tier_padding: [0; 4],
+        max_feed_spread_bps: 0,
+        min_observations: 0,
+        max_liquidity_drop_bps: 0,
+        validation_policy: 0,
+        reserved: [0; 25],
     }
 }
 
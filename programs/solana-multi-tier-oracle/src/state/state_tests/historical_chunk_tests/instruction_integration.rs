@@ -53,7 +53,9 @@ mod instruction_tests {
             chunk.next_chunk = Pubkey::default();
             chunk.oracle_state = Pubkey::new_unique();
             chunk.bump = 255;
-            chunk.reserved = [0; 511];
+            chunk.forced_points = [0; BUFFER_SIZE / 8];
+            chunk.point_layout = 0;
+            chunk.reserved = [0; 494];
         }
 
         // Simulate a sequence of update_price instruction executions that push historical data.
@@ -222,7 +224,7 @@ mod instruction_tests {
 
         // This assertion guards against accidental layout drift that would break
         // existing account allocations or rent calculations in deployment tooling.
-        // Calculation: 2+2+2+2 (metadata) + 8 (timestamp) + 32+32 (pubkeys) + (48*128) (price_points) + 1 (bump) + 511 (reserved)
+        // Calculation: 2+2+2+2 (metadata) + 8 (timestamp) + 32+32 (pubkeys) + (48*128) (price_points) + 1 (bump) + 16 (forced_points) + 1 (point_layout) + 494 (reserved)
         // With alignment padding: rounds up to 6736 due to 16-byte alignment requirement
         assert_eq!(
             EXPECTED_ACCOUNT_SIZE, 6736,
@@ -50,7 +50,9 @@ fn historical_chunk_layout_contract() {
         + 32 // oracle_state
         + EXPECTED_PRICE_POINT_SIZE * BUFFER_SIZE
         + 1 // bump
-        + 511; // reserved padding
+        + BUFFER_SIZE / 8 // forced_points
+        + 1 // point_layout
+        + 494; // reserved padding
 
     assert_eq!(
         size_of::<HistoricalChunk>(),
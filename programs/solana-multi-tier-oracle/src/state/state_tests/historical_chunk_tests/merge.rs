@@ -0,0 +1,135 @@
+//! Chunk compaction via `merge_from`.
+//!
+//! Merging must produce the same FIFO view as pushing the union of both
+//! histories in timestamp order, including eviction once capacity is exceeded,
+//! and must never carry a point older than the target chunk into it.
+
+use super::helpers::{
+    assert_price_point_eq, collect_fifo_view, deterministic_price_point, empty_chunk,
+};
+use crate::state::historical_chunk::{HistoricalChunk, PricePoint};
+use crate::utils::constants::BUFFER_SIZE;
+
+fn chunk_with_seeds(seeds: impl IntoIterator<Item = i64>) -> HistoricalChunk {
+    let mut chunk = empty_chunk();
+    for seed in seeds {
+        chunk.push(deterministic_price_point(seed));
+    }
+    chunk
+}
+
+fn assert_fifo_seeds(chunk: &HistoricalChunk, seeds: impl IntoIterator<Item = i64>) {
+    let expected: Vec<PricePoint> = seeds.into_iter().map(deterministic_price_point).collect();
+    let actual = collect_fifo_view(chunk);
+    assert_eq!(actual.len(), expected.len(), "point count");
+    for (actual, expected) in actual.iter().zip(&expected) {
+        assert_price_point_eq(actual, expected);
+    }
+}
+
+fn assert_layout_invariants(chunk: &HistoricalChunk) {
+    assert!(chunk.count as usize <= BUFFER_SIZE);
+    assert_eq!(
+        (chunk.tail as usize + chunk.count as usize) % BUFFER_SIZE,
+        chunk.head as usize,
+        "head must sit one past the newest point"
+    );
+}
+
+#[test]
+fn merging_two_half_full_chunks_fills_one() {
+    let half = BUFFER_SIZE as i64 / 2;
+    let mut older = chunk_with_seeds(0..half);
+    let newer = chunk_with_seeds(half..2 * half);
+
+    let evicted = older.merge_from(&newer).unwrap();
+
+    assert_eq!(evicted, 0);
+    assert_eq!(older.count as usize, BUFFER_SIZE);
+    assert_fifo_seeds(&older, 0..2 * half);
+    assert_layout_invariants(&older);
+    assert_price_point_eq(
+        &older.latest().unwrap(),
+        &deterministic_price_point(2 * half - 1),
+    );
+}
+
+#[test]
+fn merge_interleaves_by_timestamp() {
+    let mut evens = chunk_with_seeds((0..20).map(|i| i * 2));
+    let odds = chunk_with_seeds((0..20).map(|i| i * 2 + 1));
+
+    assert_eq!(evens.merge_from(&odds).unwrap(), 0);
+
+    assert_fifo_seeds(&evens, 0..40);
+    assert_layout_invariants(&evens);
+}
+
+#[test]
+fn overflowing_merge_evicts_oldest() {
+    let mut older = chunk_with_seeds(0..100);
+    let newer = chunk_with_seeds(100..200);
+
+    let evicted = older.merge_from(&newer).unwrap();
+
+    assert_eq!(evicted, 200 - BUFFER_SIZE);
+    assert_eq!(older.count as usize, BUFFER_SIZE);
+    assert_fifo_seeds(&older, (200 - BUFFER_SIZE as i64)..200);
+    assert_layout_invariants(&older);
+}
+
+#[test]
+fn merge_reads_wrapped_source_and_leaves_it_untouched() {
+    // Past capacity, so the source's tail has wrapped away from slot 0
+    let wrapped = chunk_with_seeds(0..BUFFER_SIZE as i64 + 10);
+    let source_view = collect_fifo_view(&wrapped);
+    let mut target = empty_chunk();
+
+    assert_eq!(target.merge_from(&wrapped).unwrap(), 0);
+
+    assert_fifo_seeds(&target, 10..BUFFER_SIZE as i64 + 10);
+    assert_layout_invariants(&target);
+    assert_eq!(collect_fifo_view(&wrapped).len(), source_view.len());
+    assert_eq!(wrapped.tail, 10);
+}
+
+#[test]
+fn merged_chunk_keeps_accepting_pushes() {
+    let mut chunk = chunk_with_seeds(0..10);
+    chunk.merge_from(&chunk_with_seeds(10..20)).unwrap();
+
+    chunk.push(deterministic_price_point(20));
+
+    assert_fifo_seeds(&chunk, 0..21);
+    assert_layout_invariants(&chunk);
+}
+
+#[test]
+fn points_older_than_the_target_chunk_are_dropped() {
+    let mut target = chunk_with_seeds(10..20);
+    target.creation_timestamp = deterministic_price_point(10).timestamp;
+    let older = chunk_with_seeds(0..15);
+
+    // Seeds 0..10 predate the target; 10..15 are merged alongside its own
+    assert_eq!(target.merge_from(&older).unwrap(), 10);
+
+    let view = collect_fifo_view(&target);
+    assert_eq!(view.len(), 15);
+    assert!(view
+        .iter()
+        .all(|point| point.timestamp >= target.creation_timestamp));
+    assert_layout_invariants(&target);
+}
+
+#[test]
+fn merge_walks_the_ring_from_head_and_count_not_a_stale_tail() {
+    // A source whose stored tail was never maintained still merges the points
+    // `head` and `count` place in the ring
+    let mut source = chunk_with_seeds(0..BUFFER_SIZE as i64 + 10);
+    source.tail = 0;
+    let mut target = empty_chunk();
+
+    assert_eq!(target.merge_from(&source).unwrap(), 0);
+
+    assert_fifo_seeds(&target, 10..BUFFER_SIZE as i64 + 10);
+}
@@ -1,18 +1,40 @@
 //! Test harness for `HistoricalChunk` circular buffer invariants and integration behaviour.
 //!
 //! The module is split into focused submodules to mirror the AUDIT checklist:
+//! - `clustering_density`: single-pass hourly density against the nested-scan reference.
 //! - `core_unit_tests`: deterministic unit coverage of push/latest/has_next primitives.
+//! - `creation_guard`: rejection of points backdated before chunk creation.
+//! - `divergence_windows`: short and long TWAPs accumulated in the published pass.
+//! - `first_run_seed`: bootstrap point written by the first update and the TWAPs it anchors.
+//! - `forced_history`: deviation-forced pushes, their bound, and redemption exclusion.
 //! - `layout_zero_copy`: byte-level layout + zero-copy trait contracts.
+//! - `merge`: compaction of two chunks into one chronological FIFO, dropping backdated points.
+//! - `point_layout`: version-tagged point decoding, including untagged legacy chunks.
 //! - `property_tests`: proptest-powered fuzzing of FIFO invariants under randomized input.
+//! - `saturation_tracking`: counted TWAP overflow fallbacks and the warning cap.
 //! - `serialization_and_integration`: serialization round-trips and OracleState coupling.
+//! - `snapshot_status_codes`: pinned return-data encoding of `SnapshotStatus`.
+//! - `time_span`: covered time range and sampling density of the FIFO window.
+//! - `twap_weighting`: time-only vs confidence-weighted TWAP accumulation.
 //! - `helpers`: shared fixtures, builders, and invariant assertions used across suites.
 //!
 //! Keeping the modules granular clarifies intent for auditors and makes it easy to
 //! extend coverage as new invariants are introduced.
 
+pub mod clustering_density;
 pub mod core_unit_tests;
+pub mod creation_guard;
+pub mod divergence_windows;
+pub mod first_run_seed;
+pub mod forced_history;
 pub mod helpers;
 pub mod instruction_integration;
 pub mod layout_zero_copy;
+pub mod merge;
+pub mod point_layout;
 pub mod property_tests;
+pub mod saturation_tracking;
 pub mod serialization_and_integration;
+pub mod snapshot_status_codes;
+pub mod time_span;
+pub mod twap_weighting;
@@ -0,0 +1,286 @@
+//! Decoding of price points across point-layout versions.
+//!
+//! A chunk created before points carried a layout tag has a zero tag byte and
+//! points in the legacy record. These tests write such a chunk byte by byte,
+//! the way an old program left it on-chain, and read it back through the
+//! version-aware path. Every ring reader goes through that path, and the first
+//! write converts the chunk so it never mixes layouts under one tag.
+
+use super::helpers::{chunk_from_bytes, empty_chunk};
+use crate::error::StateError;
+use crate::instructions::update_price::{stream_twap_from_chunks, WeightingScheme};
+use crate::state::historical_chunk::{HistoricalChunk, PricePoint};
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use crate::utils::constants::{PRICE_POINT_LAYOUT_LEGACY, PRICE_POINT_LAYOUT_VERSION};
+use anchor_lang::prelude::Pubkey;
+use std::mem::{offset_of, size_of};
+
+/// Encodes a point in the legacy record: price, volume, conf, timestamp.
+fn legacy_record(price: i128, volume: i128, conf: u64, timestamp: i64) -> Vec<u8> {
+    let mut record = Vec::with_capacity(PricePoint::LEGACY_SIZE);
+    record.extend_from_slice(&price.to_le_bytes());
+    record.extend_from_slice(&volume.to_le_bytes());
+    record.extend_from_slice(&conf.to_le_bytes());
+    record.extend_from_slice(&timestamp.to_le_bytes());
+    record
+}
+
+/// Raw bytes of an untagged chunk holding `records` from slot 0, packed at the
+/// legacy stride the way the old program wrote them.
+fn legacy_chunk(records: &[Vec<u8>]) -> HistoricalChunk {
+    let mut bytes = vec![0u8; size_of::<HistoricalChunk>()];
+    let points_at = offset_of!(HistoricalChunk, price_points);
+    for (slot, record) in records.iter().enumerate() {
+        let at = points_at + slot * PricePoint::LEGACY_SIZE;
+        bytes[at..at + PricePoint::LEGACY_SIZE].copy_from_slice(record);
+    }
+    let written = (records.len() as u16).to_le_bytes();
+    for at in [
+        offset_of!(HistoricalChunk, head),
+        offset_of!(HistoricalChunk, count),
+    ] {
+        bytes[at..at + 2].copy_from_slice(&written);
+    }
+    chunk_from_bytes(&bytes)
+}
+
+fn assert_point(point: PricePoint, price: i128, volume: i128, conf: u64, timestamp: i64) {
+    assert_eq!(point.price, price);
+    assert_eq!(point.volume, volume);
+    assert_eq!(point.conf, conf);
+    assert_eq!(point.timestamp, timestamp);
+}
+
+#[test]
+fn legacy_record_decodes_every_field() {
+    let record = legacy_record(-150_017_226, 42_000_000, 9_200, 1_700_000_000);
+
+    let point = PricePoint::from_legacy_bytes(record.as_slice().try_into().unwrap());
+
+    assert_point(point, -150_017_226, 42_000_000, 9_200, 1_700_000_000);
+}
+
+#[test]
+fn untagged_chunk_reads_through_the_legacy_path() {
+    let chunk = legacy_chunk(&[
+        legacy_record(100_000_000, 0, 1_000, 1_700_000_000),
+        legacy_record(101_000_000, 5, 2_000, 1_700_000_900),
+    ]);
+    assert_eq!(chunk.point_layout, PRICE_POINT_LAYOUT_LEGACY);
+
+    assert_point(
+        chunk.point_at(0).unwrap(),
+        100_000_000,
+        0,
+        1_000,
+        1_700_000_000,
+    );
+    assert_point(
+        chunk.point_at(1).unwrap(),
+        101_000_000,
+        5,
+        2_000,
+        1_700_000_900,
+    );
+    // Unwritten slots decode to the default point
+    assert_point(chunk.point_at(2).unwrap(), 0, 0, 0, 0);
+}
+
+#[test]
+fn tagged_chunk_reads_points_as_stored() {
+    let mut chunk = empty_chunk();
+    chunk.point_layout = PRICE_POINT_LAYOUT_VERSION;
+    chunk.push(PricePoint {
+        price: 7,
+        volume: 8,
+        conf: 9,
+        timestamp: 10,
+    });
+
+    assert_point(chunk.point_at(0).unwrap(), 7, 8, 9, 10);
+}
+
+#[test]
+fn layout_from_a_newer_program_is_refused() {
+    let mut chunk = empty_chunk();
+    chunk.point_layout = PRICE_POINT_LAYOUT_VERSION + 1;
+
+    assert_state_error(
+        chunk.point_at(0).map(|_| ()),
+        StateError::UnsupportedPointLayout,
+    );
+}
+
+#[test]
+fn legacy_points_past_slot_zero_decode_at_the_legacy_stride() {
+    let records: Vec<_> = (0..5)
+        .map(|slot| legacy_record(100 + slot, slot, 1_000, 1_700_000_000 + slot as i64))
+        .collect();
+    let chunk = legacy_chunk(&records);
+
+    for slot in 0..5 {
+        assert_point(
+            chunk.point_at(slot).unwrap(),
+            100 + slot as i128,
+            slot as i128,
+            1_000,
+            1_700_000_000 + slot as i64,
+        );
+    }
+}
+
+#[test]
+fn twap_stream_reads_a_legacy_chunk() {
+    let chunk = legacy_chunk(&[
+        legacy_record(100, 0, 10_000, 1_700_000_000),
+        legacy_record(300, 0, 10_000, 1_700_001_800),
+    ]);
+    let empty = empty_chunk();
+
+    let result = stream_twap_from_chunks(
+        &[&empty, &empty, &chunk],
+        3_600,
+        [0, 0],
+        WeightingScheme::TimeOnly,
+        1_700_003_600,
+        &Pubkey::new_unique(),
+        0,
+    )
+    .unwrap();
+
+    assert_eq!(result.data_points_used, 2);
+    assert_eq!(result.oldest_timestamp, 1_700_000_000);
+}
+
+#[test]
+fn twap_stream_refuses_a_chunk_from_a_newer_program() {
+    let mut chunk = empty_chunk();
+    chunk.push(PricePoint {
+        price: 100,
+        volume: 0,
+        conf: 10_000,
+        timestamp: 1_700_000_000,
+    });
+    chunk.point_layout = PRICE_POINT_LAYOUT_VERSION + 1;
+    let empty = empty_chunk();
+
+    let result = stream_twap_from_chunks(
+        &[&empty, &empty, &chunk],
+        3_600,
+        [0, 0],
+        WeightingScheme::TimeOnly,
+        1_700_003_600,
+        &Pubkey::new_unique(),
+        0,
+    );
+
+    assert_state_error(result.map(|_| ()), StateError::UnsupportedPointLayout);
+}
+
+/// Three legacy points on the 15-minute cadence, the newest at `1_700_001_800`.
+fn legacy_cadence_chunk() -> HistoricalChunk {
+    legacy_chunk(&[
+        legacy_record(100, 1, 1_000, 1_700_000_000),
+        legacy_record(200, 2, 2_000, 1_700_000_900),
+        legacy_record(300, 3, 3_000, 1_700_001_800),
+    ])
+}
+
+#[test]
+fn every_ring_reader_decodes_a_legacy_chunk() {
+    let chunk = legacy_cadence_chunk();
+
+    assert_point(chunk.latest().unwrap(), 300, 3, 3_000, 1_700_001_800);
+    assert_point(
+        chunk.latest_scheduled().unwrap(),
+        300,
+        3,
+        3_000,
+        1_700_001_800,
+    );
+    assert_point(chunk.oldest().unwrap(), 100, 1, 1_000, 1_700_000_000);
+    assert_eq!(chunk.time_span(), Some(1_800));
+    assert_eq!(chunk.coverage_ratio(900), 10_000);
+
+    let mut target = empty_chunk();
+    target.point_layout = PRICE_POINT_LAYOUT_VERSION;
+    assert_eq!(target.merge_from(&chunk).unwrap(), 0);
+    for (slot, (price, timestamp)) in [
+        (100, 1_700_000_000),
+        (200, 1_700_000_900),
+        (300, 1_700_001_800),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let point = target.point_at(slot).unwrap();
+        assert_eq!((point.price, point.timestamp), (price, timestamp));
+    }
+}
+
+#[test]
+fn first_write_converts_a_legacy_chunk() {
+    let mut chunk = legacy_cadence_chunk();
+
+    chunk
+        .push_checked(PricePoint {
+            price: 400,
+            volume: 4,
+            conf: 4_000,
+            timestamp: 1_700_002_700,
+        })
+        .unwrap();
+
+    assert_eq!(chunk.point_layout, PRICE_POINT_LAYOUT_VERSION);
+    assert_point(chunk.point_at(0).unwrap(), 100, 1, 1_000, 1_700_000_000);
+    assert_point(chunk.point_at(2).unwrap(), 300, 3, 3_000, 1_700_001_800);
+    assert_point(chunk.latest().unwrap(), 400, 4, 4_000, 1_700_002_700);
+    assert_eq!(chunk.time_span(), Some(2_700));
+}
+
+#[test]
+fn merging_converts_a_legacy_target() {
+    let mut chunk = legacy_cadence_chunk();
+    let empty = empty_chunk();
+
+    chunk.merge_from(&empty).unwrap();
+
+    assert_eq!(chunk.point_layout, PRICE_POINT_LAYOUT_VERSION);
+    assert_point(chunk.latest().unwrap(), 300, 3, 3_000, 1_700_001_800);
+}
+
+#[test]
+fn ring_readers_and_writers_refuse_a_layout_from_a_newer_program() {
+    let mut chunk = empty_chunk();
+    chunk.push(PricePoint {
+        price: 100,
+        volume: 0,
+        conf: 10_000,
+        timestamp: 1_700_000_000,
+    });
+    chunk.point_layout = PRICE_POINT_LAYOUT_VERSION + 1;
+
+    assert!(chunk.latest().is_none());
+    assert!(chunk.latest_scheduled().is_none());
+    assert!(chunk.time_span().is_none());
+    assert_eq!(chunk.coverage_ratio(900), 0);
+
+    let mut target = empty_chunk();
+    assert_state_error(
+        target.merge_from(&chunk).map(|_| ()),
+        StateError::UnsupportedPointLayout,
+    );
+    assert_eq!(target.count, 0);
+
+    let before = chunk.count;
+    assert_state_error(
+        chunk.push_checked(PricePoint {
+            price: 200,
+            volume: 0,
+            conf: 10_000,
+            timestamp: 1_700_000_900,
+        }),
+        StateError::UnsupportedPointLayout,
+    );
+    assert_eq!(chunk.count, before);
+}
@@ -48,7 +48,7 @@ proptest! {
             }
 
             let latest = chunk.latest().expect("latest should be available after first push");
-            assert_price_point_eq(latest, &point);
+            assert_price_point_eq(&latest, &point);
         }
 
         let expected_count = std::cmp::min(points.len(), BUFFER_SIZE) as u16;
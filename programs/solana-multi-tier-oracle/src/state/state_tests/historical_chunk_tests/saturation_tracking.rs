@@ -0,0 +1,105 @@
+//! Saturation fallbacks in TWAP accumulation.
+//!
+//! Prices near `i128::MAX` overflow the weighted sum on every step, which is how
+//! a misconfigured exponent shows up in practice. Every fallback must be counted
+//! even once the per-pass warning limit stops emitting events.
+
+use super::helpers::empty_chunk;
+use crate::instructions::update_price::{stream_twap_from_chunks, TWAPResult, WeightingScheme};
+use crate::state::historical_chunk::{HistoricalChunk, PricePoint};
+use crate::state::state_tests::oracle_state_tests::helpers::oracle_fixture;
+use anchor_lang::prelude::Pubkey;
+
+const START: i64 = 1_700_000_000;
+const STEP: i64 = 100;
+const SAMPLES: usize = 5;
+
+/// Samples large enough that `price · weight` overflows `i128` on every step.
+fn overflowing_chunk() -> HistoricalChunk {
+    let mut chunk = empty_chunk();
+    for i in 0..SAMPLES {
+        chunk.push(PricePoint {
+            price: i128::MAX / 2,
+            conf: 10_000,
+            timestamp: START + i as i64 * STEP,
+            volume: 0,
+        });
+    }
+    chunk
+}
+
+fn stream(chunk: &HistoricalChunk, max_saturation_events: u32) -> TWAPResult {
+    let empty = empty_chunk();
+    stream_twap_from_chunks(
+        &[&empty, &empty, chunk],
+        3_600,
+        [0, 0],
+        WeightingScheme::ConfidenceWeighted,
+        START + SAMPLES as i64 * STEP,
+        &Pubkey::new_unique(),
+        max_saturation_events,
+    )
+    .unwrap()
+}
+
+#[test]
+fn every_overflowing_step_is_a_fallback() {
+    let result = stream(&overflowing_chunk(), 3);
+
+    // Four intervals between samples plus the final segment up to now
+    assert_eq!(result.saturation_fallbacks, SAMPLES as u32);
+    assert_eq!(result.saturation_warnings, 3);
+}
+
+#[test]
+fn warnings_respect_the_configured_limit() {
+    let chunk = overflowing_chunk();
+
+    assert_eq!(stream(&chunk, 0).saturation_warnings, 0);
+    assert_eq!(stream(&chunk, 1).saturation_warnings, 1);
+    assert_eq!(
+        stream(&chunk, u8::MAX as u32).saturation_warnings,
+        SAMPLES as u32
+    );
+    // The limit only mutes events; the fallback count never changes
+    assert_eq!(stream(&chunk, 0).saturation_fallbacks, SAMPLES as u32);
+}
+
+#[test]
+fn ordinary_prices_do_not_saturate() {
+    let mut chunk = empty_chunk();
+    chunk.push(PricePoint {
+        price: 100,
+        conf: 10_000,
+        timestamp: START,
+        volume: 0,
+    });
+
+    let result = stream(&chunk, 3);
+    assert_eq!(result.saturation_fallbacks, 0);
+    assert_eq!(result.saturation_warnings, 0);
+}
+
+#[test]
+fn fallbacks_accumulate_on_state_and_surface_in_the_reading() {
+    let mut oracle = oracle_fixture();
+    oracle.current_price.price = 100;
+    oracle.current_price.timestamp = START;
+    let result = stream(&overflowing_chunk(), 1);
+
+    oracle.record_saturation(result.saturation_fallbacks);
+    oracle.record_saturation(result.saturation_fallbacks);
+
+    assert_eq!(oracle.saturation_count, 2 * SAMPLES as u64);
+    assert_eq!(
+        oracle
+            .read_price_reading(START, 0)
+            .unwrap()
+            .saturation_count,
+        2 * SAMPLES as u64
+    );
+
+    oracle.saturation_count = u64::MAX - 1;
+    oracle.record_saturation(SAMPLES as u32);
+    assert_eq!(oracle.saturation_count, u64::MAX);
+}
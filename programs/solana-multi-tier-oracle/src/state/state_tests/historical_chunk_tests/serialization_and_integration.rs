@@ -1,5 +1,6 @@
-//! Tests that exercise serialization round-trips and the integration hook into
-//! `OracleState::check_snapshot_requirements_from_history`.
+//! Tests that exercise serialization round-trips and the integration hooks into
+//! `OracleState::check_snapshot_requirements_from_history` and its joint
+//! count-and-span successor `check_snapshot_requirements_v2`.
 //!
 //! Intent and risk model:
 //! - These tests validate two orthogonal but related contracts: (1) that the
@@ -79,7 +80,7 @@ fn linked_chunks_preserve_fifo_across_chain() {
         .expect("full chunk should yield latest element");
     assert_price_point_eq(
         &deterministic_price_point(BUFFER_SIZE as i64 - 1),
-        chunk_one_latest,
+        &chunk_one_latest,
     );
 
     // Second chunk picks up subsequent history once the chain rotates.
@@ -129,7 +130,7 @@ fn linked_chunks_preserve_fifo_across_chain() {
         .latest()
         .expect("second chunk must contain recent entries");
     let expected_latest = deterministic_price_point(BUFFER_SIZE as i64 + rollover_len as i64 - 1);
-    assert_price_point_eq(chain_latest, &expected_latest);
+    assert_price_point_eq(&chain_latest, &expected_latest);
 }
 /// Roundtrip through the zero-copy byte image to prove the struct retains
 /// deterministic representations compatible with Anchor account loading.
@@ -293,3 +294,160 @@ fn timestamp_filtering_discards_out_of_window_points() {
         other => panic!("expected sufficient status for 24h window, got {:?}", other),
     }
 }
+
+/// Chunks holding one point per seed, split at `BUFFER_SIZE` like rotated history.
+fn chunks_from_seeds(seeds: impl IntoIterator<Item = i64>) -> Vec<HistoricalChunk> {
+    let oracle_state_pk = Pubkey::new_unique();
+    let mut chunks: Vec<HistoricalChunk> = Vec::new();
+
+    for seed in seeds {
+        if chunks
+            .last()
+            .map_or(true, |chunk| chunk.count as usize == BUFFER_SIZE)
+        {
+            let mut chunk = empty_chunk();
+            chunk.chunk_id = chunks.len() as u16;
+            chunk.oracle_state = oracle_state_pk;
+            chunks.push(chunk);
+        }
+        chunks
+            .last_mut()
+            .unwrap()
+            .push(deterministic_price_point(seed));
+    }
+
+    chunks
+}
+
+/// Both versions at the 72-hour fence-post boundary: 289 contiguous points span
+/// exactly 72 hours and satisfy the full-cadence default count.
+#[test]
+fn v2_accepts_full_seventy_two_hour_history() {
+    let oracle_state: OracleState = minimal_oracle_state();
+    let chunks = build_historical_span(289, 0);
+    let now = deterministic_price_point(0).timestamp;
+
+    let status = oracle_state.check_snapshot_requirements_v2(&chunks, now, 72, 0);
+    match status {
+        SnapshotStatus::Sufficient {
+            snapshot_count,
+            time_span_hours,
+            ..
+        } => {
+            assert_eq!(snapshot_count, 289);
+            assert_eq!(time_span_hours, 72);
+        }
+        other => panic!("expected sufficient snapshot status, found {:?}", other),
+    }
+}
+
+/// One missed update inside an otherwise full 72-hour window. The legacy check
+/// accepts it because the span is intact; v2 reports the missing fence post.
+#[test]
+fn v2_rejects_interior_gap_the_legacy_check_accepts() {
+    let oracle_state: OracleState = minimal_oracle_state();
+    let chunks = chunks_from_seeds((-288..=0).filter(|seed| *seed != -144));
+    let now = deterministic_price_point(0).timestamp;
+
+    assert!(oracle_state
+        .check_snapshot_requirements_from_history(&chunks, now, 72)
+        .is_sufficient());
+    assert_eq!(
+        oracle_state.check_snapshot_requirements_v2(&chunks, now, 72, 0),
+        SnapshotStatus::InsufficientCount {
+            found: 288,
+            required: 289,
+        }
+    );
+}
+
+/// Half-cadence history spanning the full window: 145 points every 30 minutes
+/// meet the legacy 50% floor but not an explicit requirement of 289.
+#[test]
+fn v2_rejects_half_cadence_history() {
+    let oracle_state: OracleState = minimal_oracle_state();
+    let chunks = chunks_from_seeds((-288..=0).step_by(2));
+    let now = deterministic_price_point(0).timestamp;
+
+    assert!(oracle_state
+        .check_snapshot_requirements_from_history(&chunks, now, 72)
+        .is_sufficient());
+    assert_eq!(
+        oracle_state.check_snapshot_requirements_v2(&chunks, now, 72, 289),
+        SnapshotStatus::InsufficientCount {
+            found: 145,
+            required: 289,
+        }
+    );
+    // The same history passes once the caller accepts half cadence
+    assert!(oracle_state
+        .check_snapshot_requirements_v2(&chunks, now, 72, 145)
+        .is_sufficient());
+}
+
+/// 288 contiguous points fall one interval short of 72 hours. Count and span
+/// both fail, but the points are as dense as possible, so the span is reported.
+#[test]
+fn v2_reports_span_for_dense_but_young_history() {
+    let oracle_state: OracleState = minimal_oracle_state();
+    let chunks = build_historical_span(288, 0);
+    let now = deterministic_price_point(0).timestamp;
+
+    assert_eq!(
+        oracle_state.check_snapshot_requirements_v2(&chunks, now, 72, 0),
+        SnapshotStatus::InsufficientTimeSpan {
+            span_hours: 71,
+            required_hours: 72,
+        }
+    );
+}
+
+/// Sparse and short: 100 points every 30 minutes cover under 50 hours. Both
+/// criteria fail and the missing updates are the more useful report.
+#[test]
+fn v2_reports_count_for_sparse_and_young_history() {
+    let oracle_state: OracleState = minimal_oracle_state();
+    let chunks = chunks_from_seeds((-198..=0).step_by(2));
+    let now = deterministic_price_point(0).timestamp;
+
+    assert_eq!(
+        oracle_state.check_snapshot_requirements_v2(&chunks, now, 72, 0),
+        SnapshotStatus::InsufficientCount {
+            found: 100,
+            required: 289,
+        }
+    );
+}
+
+/// Full three-chunk history for 96 hours: 384 points span 95.75 hours, so the
+/// request fails on span whether the count is the default 385 or relaxed to 384.
+#[test]
+fn v2_flags_ninety_six_hour_span_gap_at_any_count() {
+    let oracle_state: OracleState = minimal_oracle_state();
+    let chunks = build_historical_span(BUFFER_SIZE * 3, 0);
+    let now = deterministic_price_point(0).timestamp;
+    let expected = SnapshotStatus::InsufficientTimeSpan {
+        span_hours: 95,
+        required_hours: 96,
+    };
+
+    assert_eq!(
+        oracle_state.check_snapshot_requirements_v2(&chunks, now, 96, 0),
+        expected
+    );
+    assert_eq!(
+        oracle_state.check_snapshot_requirements_v2(&chunks, now, 96, 384),
+        expected
+    );
+}
+
+#[test]
+fn v2_reports_no_snapshots_for_empty_history() {
+    let oracle_state: OracleState = minimal_oracle_state();
+    let chunks = [empty_chunk()];
+
+    assert_eq!(
+        oracle_state.check_snapshot_requirements_v2(&chunks, 1_700_000_000, 72, 0),
+        SnapshotStatus::NoSnapshots
+    );
+}
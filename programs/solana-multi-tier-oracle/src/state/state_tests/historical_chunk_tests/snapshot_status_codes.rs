@@ -0,0 +1,85 @@
+//! Stable code mapping for `SnapshotStatus` return data.
+//!
+//! Discriminants are ABI for CPI consumers, so they are asserted literally rather
+//! than through the named constants: renumbering a constant must fail here.
+
+use crate::state::snapshot_status::{SnapshotStatus, SnapshotStatusCode};
+use crate::utils::constants::MAX_SNAPSHOTS_PER_HOUR;
+use anchor_lang::prelude::*;
+
+fn every_variant() -> [SnapshotStatus; 5] {
+    [
+        SnapshotStatus::Sufficient {
+            snapshot_count: 288,
+            time_span_hours: 72,
+            max_hourly_density: MAX_SNAPSHOTS_PER_HOUR,
+        },
+        SnapshotStatus::InsufficientCount {
+            found: 10,
+            required: 144,
+        },
+        SnapshotStatus::InsufficientTimeSpan {
+            span_hours: 12,
+            required_hours: 24,
+        },
+        SnapshotStatus::ExcessiveClustering {
+            max_per_hour: 9,
+            limit_per_hour: MAX_SNAPSHOTS_PER_HOUR,
+        },
+        SnapshotStatus::NoSnapshots,
+    ]
+}
+
+#[test]
+fn discriminants_are_pinned() {
+    let codes: Vec<u8> = every_variant().iter().map(|s| s.as_code().0).collect();
+    assert_eq!(codes, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn every_variant_round_trips() {
+    for status in every_variant() {
+        let code = status.as_code();
+        assert_eq!(SnapshotStatus::from_code(code), Some(status.clone()));
+        assert_eq!(
+            SnapshotStatus::from_code(code).unwrap().as_code(),
+            code,
+            "code must be a fixed point for {status:?}"
+        );
+    }
+}
+
+#[test]
+fn sufficient_decodes_density_as_upper_bound() {
+    let status = SnapshotStatus::Sufficient {
+        snapshot_count: 100,
+        time_span_hours: 30,
+        max_hourly_density: 2,
+    };
+
+    assert_eq!(status.as_code(), (0, 100, 30));
+    assert_eq!(
+        SnapshotStatus::from_code(status.as_code()),
+        Some(SnapshotStatus::Sufficient {
+            snapshot_count: 100,
+            time_span_hours: 30,
+            max_hourly_density: MAX_SNAPSHOTS_PER_HOUR,
+        })
+    );
+}
+
+#[test]
+fn unknown_discriminant_is_rejected() {
+    for discriminant in [5u8, 42, u8::MAX] {
+        assert_eq!(SnapshotStatus::from_code((discriminant, 1, 2)), None);
+    }
+}
+
+#[test]
+fn return_data_matches_tuple_encoding() {
+    for status in every_variant() {
+        let wrapped = SnapshotStatusCode::from(&status).try_to_vec().unwrap();
+        let tuple = status.as_code().try_to_vec().unwrap();
+        assert_eq!(wrapped, tuple);
+    }
+}
@@ -0,0 +1,82 @@
+//! Span and sampling-density accessors over the retained FIFO window.
+//!
+//! Points built by `deterministic_price_point` are spaced exactly
+//! `MIN_HISTORICAL_INTERVAL` apart, so a contiguous run of seeds is a chunk
+//! written at the ideal cadence and any skipped seed is a missed write.
+
+use super::helpers::{deterministic_price_point, empty_chunk};
+use crate::state::historical_chunk::PricePoint;
+use crate::utils::constants::{BUFFER_SIZE, MIN_HISTORICAL_INTERVAL};
+
+#[test]
+fn empty_chunk_has_no_span_or_coverage() {
+    let chunk = empty_chunk();
+
+    assert_eq!(chunk.time_span(), None);
+    assert_eq!(chunk.coverage_ratio(MIN_HISTORICAL_INTERVAL), 0);
+}
+
+#[test]
+fn single_point_spans_zero_with_full_coverage() {
+    let mut chunk = empty_chunk();
+    chunk.push(deterministic_price_point(7));
+
+    assert_eq!(chunk.time_span(), Some(0));
+    assert_eq!(chunk.coverage_ratio(MIN_HISTORICAL_INTERVAL), 10_000);
+}
+
+#[test]
+fn full_chunk_after_wrap_spans_retained_window() {
+    let mut chunk = empty_chunk();
+    // Overfill so the oldest points are evicted and the window wraps the ring
+    let extra = 5;
+    for seed in 0..(BUFFER_SIZE as i64 + extra) {
+        chunk.push(deterministic_price_point(seed));
+    }
+
+    assert_eq!(chunk.count as usize, BUFFER_SIZE);
+    assert_eq!(
+        chunk.time_span(),
+        Some((BUFFER_SIZE as i64 - 1) * MIN_HISTORICAL_INTERVAL)
+    );
+    assert_eq!(chunk.coverage_ratio(MIN_HISTORICAL_INTERVAL), 10_000);
+}
+
+#[test]
+fn missed_writes_lower_coverage() {
+    let mut chunk = empty_chunk();
+    // Every other interval: 5 points across a span that ideally holds 9
+    for seed in (0..=8).step_by(2) {
+        chunk.push(deterministic_price_point(seed));
+    }
+
+    assert_eq!(chunk.time_span(), Some(8 * MIN_HISTORICAL_INTERVAL));
+    assert_eq!(
+        chunk.coverage_ratio(MIN_HISTORICAL_INTERVAL),
+        5 * 10_000 / 9
+    );
+}
+
+#[test]
+fn denser_than_expected_history_caps_at_full_coverage() {
+    let mut chunk = empty_chunk();
+    for seed in 0..4 {
+        chunk.push(deterministic_price_point(seed));
+    }
+
+    assert_eq!(chunk.coverage_ratio(MIN_HISTORICAL_INTERVAL * 3), 10_000);
+}
+
+#[test]
+fn zeroed_slots_and_bad_interval_are_ignored() {
+    let mut chunk = empty_chunk();
+    chunk.push(PricePoint::default());
+    chunk.push(deterministic_price_point(0));
+    chunk.push(deterministic_price_point(1));
+    chunk.push(PricePoint::default());
+
+    assert_eq!(chunk.time_span(), Some(MIN_HISTORICAL_INTERVAL));
+    assert_eq!(chunk.coverage_ratio(MIN_HISTORICAL_INTERVAL), 10_000);
+    assert_eq!(chunk.coverage_ratio(0), 0);
+    assert_eq!(chunk.coverage_ratio(-MIN_HISTORICAL_INTERVAL), 0);
+}
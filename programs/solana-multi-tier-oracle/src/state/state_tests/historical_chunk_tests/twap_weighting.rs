@@ -0,0 +1,77 @@
+//! TWAP weighting schemes.
+//!
+//! The schemes differ only in whether confidence scales a sample's weight, so
+//! they must agree exactly when every sample has the same confidence and
+//! diverge once a low-confidence sample sits among high-confidence ones.
+
+use super::helpers::empty_chunk;
+use crate::instructions::update_price::{stream_twap_from_chunks, WeightingScheme};
+use crate::state::historical_chunk::{HistoricalChunk, PricePoint};
+use anchor_lang::prelude::Pubkey;
+
+const START: i64 = 1_700_000_000;
+const STEP: i64 = 100;
+
+/// Chunk holding `(price, conf)` samples spaced `STEP` seconds apart from `START`.
+fn chunk_with_samples(samples: &[(i128, u64)]) -> HistoricalChunk {
+    let mut chunk = empty_chunk();
+    for (i, &(price, conf)) in samples.iter().enumerate() {
+        chunk.push(PricePoint {
+            price,
+            conf,
+            timestamp: START + i as i64 * STEP,
+            volume: 0,
+        });
+    }
+    chunk
+}
+
+fn twap(chunk: &HistoricalChunk, scheme: WeightingScheme, samples: usize) -> i128 {
+    let empty = empty_chunk();
+    let now = START + samples as i64 * STEP;
+    stream_twap_from_chunks(
+        &[&empty, &empty, chunk],
+        3_600,
+        [0, 0],
+        scheme,
+        now,
+        &Pubkey::new_unique(),
+        3,
+    )
+    .unwrap()
+    .twap_price
+}
+
+#[test]
+fn schemes_diverge_when_confidence_varies() {
+    // The 200 print carries a tenth of the confidence of its neighbours
+    let samples = [(100, 10_000), (200, 1_000), (100, 10_000)];
+    let chunk = chunk_with_samples(&samples);
+
+    let time_only = twap(&chunk, WeightingScheme::TimeOnly, samples.len());
+    let confidence_weighted = twap(&chunk, WeightingScheme::ConfidenceWeighted, samples.len());
+
+    // Equal durations: (100 + 200 + 100) / 3
+    assert_eq!(time_only, 133);
+    // (100·10_000 + 200·1_000 + 100·10_000) / 21_000
+    assert_eq!(confidence_weighted, 104);
+}
+
+#[test]
+fn schemes_agree_when_confidence_is_constant() {
+    let samples = [(100, 7_500), (200, 7_500), (150, 7_500), (120, 7_500)];
+    let chunk = chunk_with_samples(&samples);
+
+    assert_eq!(
+        twap(&chunk, WeightingScheme::TimeOnly, samples.len()),
+        twap(&chunk, WeightingScheme::ConfidenceWeighted, samples.len())
+    );
+}
+
+#[test]
+fn confidence_weighted_is_the_default() {
+    assert_eq!(
+        WeightingScheme::default(),
+        WeightingScheme::ConfidenceWeighted
+    );
+}
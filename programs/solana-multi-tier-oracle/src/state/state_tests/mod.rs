@@ -3,4 +3,8 @@ pub mod governance_state_tests;
 #[cfg(test)]
 pub mod historical_chunk_tests;
 #[cfg(test)]
+pub mod oracle_state_tests;
+#[cfg(test)]
 pub mod price_feed_tests;
+#[cfg(test)]
+pub mod proposal_tests;
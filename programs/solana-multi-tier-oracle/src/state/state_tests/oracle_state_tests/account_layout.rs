@@ -0,0 +1,92 @@
+//! Pinned ABI layout of the `OracleState` account.
+//!
+//! Deployed oracle accounts are read in place through zero-copy casts, so the
+//! struct's size, alignment and field offsets are an on-chain contract rather
+//! than an implementation detail. A reordered field or a resized `reserved`
+//! array still compiles and still passes every behavioural test, but decodes
+//! existing accounts as garbage.
+//!
+//! # Updating These Values
+//!
+//! A failure here means the schema changed. If the change is intended:
+//! - carve new fields out of `reserved` so the size and every existing offset
+//!   stay put, and only the `reserved` offset moves
+//! - if the size must change, bump `Version`, ship a migration for deployed
+//!   accounts, and update rent figures in deployment tooling first
+//!
+//! Then update the expected values in the same commit, so the review shows the
+//! layout change next to the code that caused it.
+
+use crate::state::legacy_oracle_state::{LegacyOracleState, LegacyPriceFeed};
+use crate::state::oracle_state::{OracleState, Version};
+use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use anchor_lang::Space;
+use std::mem::{align_of, offset_of, size_of};
+
+/// Schema 0.2.0, written by `initialize_oracle` and `migrate_oracle_state`.
+const EXPECTED_SIZE: usize = 3_536;
+
+/// Schema 0.1.0, the size every account deployed before the migration still has.
+const LEGACY_SIZE: usize = 2_736;
+
+#[test]
+fn size_and_alignment_are_pinned() {
+    assert_eq!(
+        size_of::<OracleState>(),
+        EXPECTED_SIZE,
+        "OracleState size changed; deployed accounts need a versioned migration"
+    );
+    assert_eq!(
+        align_of::<OracleState>(),
+        16,
+        "OracleState alignment is set by its i128 prices and must not change"
+    );
+    assert_eq!(
+        OracleState::INIT_SPACE,
+        EXPECTED_SIZE,
+        "allocated account space must match the zero-copy struct exactly"
+    );
+}
+
+#[test]
+fn key_field_offsets_are_pinned() {
+    assert_eq!(offset_of!(OracleState, authority), 0);
+    assert_eq!(offset_of!(OracleState, version), 32);
+    assert_eq!(offset_of!(OracleState, flags), 36);
+    assert_eq!(offset_of!(OracleState, last_update), 40);
+    assert_eq!(offset_of!(OracleState, current_price), 48);
+    assert_eq!(offset_of!(OracleState, price_feeds), 96);
+    assert_eq!(offset_of!(OracleState, historical_chunks), 2_671);
+    assert_eq!(offset_of!(OracleState, emergency_admin), 2_927);
+    assert_eq!(offset_of!(OracleState, update_nonce), 2_992);
+}
+
+#[test]
+fn reserved_space_runs_to_the_end_of_the_account() {
+    // New fields come out of the front of `reserved`; its end must stay fixed
+    assert_eq!(offset_of!(OracleState, reserved), 3_511);
+    assert_eq!(
+        offset_of!(OracleState, reserved) + 25,
+        EXPECTED_SIZE,
+        "nothing may follow `reserved`, or its growth would move live fields"
+    );
+}
+
+#[test]
+fn legacy_layout_stays_decodable() {
+    // Frozen: `migrate_oracle_state` recognises v0.1 accounts by this exact size
+    assert_eq!(LegacyOracleState::SIZE, LEGACY_SIZE);
+    assert_eq!(size_of::<LegacyPriceFeed>(), 112);
+    assert_eq!(align_of::<LegacyOracleState>(), align_of::<OracleState>());
+    assert_eq!(offset_of!(LegacyOracleState, price_feeds), 96);
+    assert_eq!(offset_of!(LegacyOracleState, reserved), 2_223);
+}
+
+#[test]
+fn growth_is_versioned_and_fits_one_resize() {
+    assert_ne!(Version::CURRENT, Version::LEGACY);
+    assert!(
+        EXPECTED_SIZE - LEGACY_SIZE <= MAX_PERMITTED_DATA_INCREASE,
+        "the migration grows the account in a single instruction"
+    );
+}
@@ -0,0 +1,220 @@
+//! Multi-asset batch initialization.
+//!
+//! Account creation needs the runtime, so these tests drive the two pure
+//! halves of `initialize_oracle_batch` directly: whole-batch validation, then
+//! state population for each validated entry on in-memory fixtures.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::instructions::initialize_oracle::{
+    populate_oracle_accounts, GovernanceConfig, OracleAccountSet,
+};
+use crate::instructions::initialize_oracle_batch::{validate_oracle_batch, BatchOracleEntry};
+use crate::state::governance_state::Permissions;
+use crate::state::state_tests::governance_state_tests::helpers::{
+    assert_state_error, deterministic_pubkey, governance_fixture,
+};
+use crate::state::state_tests::historical_chunk_tests::helpers::empty_chunk;
+use crate::utils::constants::{MAX_MULTISIG_MEMBERS, MAX_ORACLE_BATCH_SIZE, MAX_TWAP_WINDOW};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::keccak;
+
+const NOW: i64 = 1_700_000_000;
+
+fn authority() -> Pubkey {
+    deterministic_pubkey(1)
+}
+
+fn shared_governance() -> GovernanceConfig {
+    let mut initial_members = [Pubkey::default(); MAX_MULTISIG_MEMBERS];
+    let mut member_permissions = [Permissions::default(); MAX_MULTISIG_MEMBERS];
+    initial_members[0] = authority();
+    member_permissions[0] = Permissions::ADMIN_ALL;
+    initial_members[1] = deterministic_pubkey(2);
+    member_permissions[1] = Permissions::UPDATE_PRICE;
+
+    GovernanceConfig {
+        member_count: 2,
+        initial_members,
+        member_permissions,
+        multisig_threshold: 1,
+        voting_period: 7_200,
+        execution_delay: 3_600,
+        quorum_threshold: 5_000,
+        proposal_threshold: 1_000_000,
+    }
+}
+
+fn entry(asset_id: &str, twap_window: u32) -> BatchOracleEntry {
+    BatchOracleEntry {
+        asset_id: asset_id.to_string(),
+        asset_seed: keccak::hashv(&[asset_id.trim().to_lowercase().as_bytes()]).0,
+        twap_window,
+        confidence_threshold: 500,
+        manipulation_threshold: 1_000,
+        emergency_admin: deterministic_pubkey(9),
+        enable_circuit_breaker: true,
+        max_twap_window_override: 0,
+    }
+}
+
+#[test]
+fn two_assets_initialize_with_independent_state() {
+    let entries = [entry("SOL/USDC", 3_600), entry("ETH/USDC", 1_800)];
+    let validated = validate_oracle_batch(&entries, &shared_governance(), &authority()).unwrap();
+    assert_eq!(validated.len(), 2);
+
+    for (index, (config, canonical_asset_id)) in validated.iter().enumerate() {
+        let seed = index as u8 * 10 + 100;
+        let account_set = OracleAccountSet {
+            oracle_state: deterministic_pubkey(seed),
+            historical_chunks: [
+                deterministic_pubkey(seed + 1),
+                deterministic_pubkey(seed + 2),
+                deterministic_pubkey(seed + 3),
+            ],
+            oracle_bump: 250,
+            governance_bump: 249,
+            chunk_bumps: [248, 247, 246],
+        };
+
+        let mut oracle = oracle_fixture();
+        let mut governance = governance_fixture(0);
+        let mut chunks = [empty_chunk(), empty_chunk(), empty_chunk()];
+        let [chunk_0, chunk_1, chunk_2] = &mut chunks;
+
+        populate_oracle_accounts(
+            config,
+            authority(),
+            &account_set,
+            NOW,
+            &mut oracle,
+            &mut governance,
+            [chunk_0, chunk_1, chunk_2],
+        );
+
+        assert_eq!(canonical_asset_id, &entries[index].asset_id.to_lowercase());
+        assert_eq!(oracle.asset_seed, entries[index].asset_seed);
+        assert_eq!(oracle.twap_window, entries[index].twap_window);
+        assert_eq!(oracle.authority, authority());
+        assert_eq!(oracle.bump, 250);
+        assert_eq!(oracle.governance_bump, 249);
+        assert_eq!(oracle.historical_chunks[..3], account_set.historical_chunks);
+
+        assert_eq!(governance.oracle_state, account_set.oracle_state);
+        assert_eq!(governance.active_member_count, 2);
+        assert_eq!(governance.multisig_members[0], authority());
+        assert_eq!(governance.member_stakes[1], 1_000_000);
+
+        for (i, chunk) in chunks.iter().enumerate() {
+            assert_eq!(chunk.chunk_id, i as u16);
+            assert_eq!(chunk.oracle_state, account_set.oracle_state);
+            assert_eq!(chunk.creation_timestamp, NOW);
+            assert_eq!(chunk.bump, account_set.chunk_bumps[i]);
+        }
+        assert_eq!(chunks[0].next_chunk, account_set.historical_chunks[1]);
+        assert_eq!(chunks[1].next_chunk, account_set.historical_chunks[2]);
+        assert_eq!(chunks[2].next_chunk, Pubkey::default());
+    }
+}
+
+#[test]
+fn one_invalid_entry_rejects_the_whole_batch() {
+    let entries = [entry("SOL/USDC", 3_600), entry("ETH/USDC", 0)];
+
+    assert_state_error(
+        validate_oracle_batch(&entries, &shared_governance(), &authority()).map(|_| ()),
+        StateError::InvalidTWAPWindow,
+    );
+}
+
+#[test]
+fn window_ceiling_must_admit_the_configured_window() {
+    let mut below = entry("ETH/USDC", 3_600);
+    below.max_twap_window_override = 1_800;
+    assert_state_error(
+        validate_oracle_batch(&[below], &shared_governance(), &authority()).map(|_| ()),
+        StateError::InvalidTWAPWindow,
+    );
+
+    let mut above_global = entry("ETH/USDC", 3_600);
+    above_global.max_twap_window_override = MAX_TWAP_WINDOW + 1;
+    assert_state_error(
+        validate_oracle_batch(&[above_global], &shared_governance(), &authority()).map(|_| ()),
+        StateError::InvalidTWAPWindow,
+    );
+}
+
+#[test]
+fn window_ceiling_is_written_to_the_oracle() {
+    let mut capped = entry("SOL/USDC", 3_600);
+    capped.max_twap_window_override = 7_200;
+    let validated = validate_oracle_batch(&[capped], &shared_governance(), &authority()).unwrap();
+    let account_set = OracleAccountSet {
+        oracle_state: deterministic_pubkey(100),
+        historical_chunks: [
+            deterministic_pubkey(101),
+            deterministic_pubkey(102),
+            deterministic_pubkey(103),
+        ],
+        oracle_bump: 250,
+        governance_bump: 249,
+        chunk_bumps: [248, 247, 246],
+    };
+
+    let mut oracle = oracle_fixture();
+    let mut governance = governance_fixture(0);
+    let [mut chunk_0, mut chunk_1, mut chunk_2] = [empty_chunk(), empty_chunk(), empty_chunk()];
+    populate_oracle_accounts(
+        &validated[0].0,
+        authority(),
+        &account_set,
+        NOW,
+        &mut oracle,
+        &mut governance,
+        [&mut chunk_0, &mut chunk_1, &mut chunk_2],
+    );
+
+    assert_eq!(oracle.twap_window_ceiling(), 7_200);
+    assert_state_error(
+        oracle.check_twap_window(10_800),
+        StateError::InvalidTWAPWindow,
+    );
+}
+
+#[test]
+fn shared_governance_is_validated_for_every_entry() {
+    let entries = [entry("SOL/USDC", 3_600), entry("ETH/USDC", 1_800)];
+
+    assert_state_error(
+        validate_oracle_batch(&entries, &shared_governance(), &deterministic_pubkey(42))
+            .map(|_| ()),
+        StateError::AuthorityNotAdminMember,
+    );
+}
+
+#[test]
+fn duplicate_assets_are_rejected() {
+    let entries = [entry("SOL/USDC", 3_600), entry(" sol/usdc ", 1_800)];
+
+    assert_state_error(
+        validate_oracle_batch(&entries, &shared_governance(), &authority()).map(|_| ()),
+        StateError::DuplicateBatchAsset,
+    );
+}
+
+#[test]
+fn batch_size_is_bounded() {
+    assert_state_error(
+        validate_oracle_batch(&[], &shared_governance(), &authority()).map(|_| ()),
+        StateError::InvalidBatchSize,
+    );
+
+    let oversized: Vec<BatchOracleEntry> = (0..=MAX_ORACLE_BATCH_SIZE)
+        .map(|i| entry(&format!("ASSET{i}/USDC"), 3_600))
+        .collect();
+    assert_state_error(
+        validate_oracle_batch(&oversized, &shared_governance(), &authority()).map(|_| ()),
+        StateError::InvalidBatchSize,
+    );
+}
@@ -0,0 +1,71 @@
+//! Bounds-checked resolution of linked historical chunk keys.
+//!
+//! Out-of-range indices and unlinked default slots both resolve to `None`, and
+//! verification only accepts the exact key linked at the requested slot.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::oracle_state::OracleState;
+use crate::state::state_tests::governance_state_tests::helpers::{
+    assert_state_error, deterministic_pubkey,
+};
+use crate::utils::constants::MAX_HISTORICAL_CHUNKS;
+use anchor_lang::prelude::Pubkey;
+
+fn linked_oracle() -> OracleState {
+    let mut oracle = oracle_fixture();
+    oracle.historical_chunks = [Pubkey::default(); MAX_HISTORICAL_CHUNKS];
+    for index in 0..3 {
+        oracle.historical_chunks[index] = deterministic_pubkey(10 + index as u8);
+    }
+    oracle
+}
+
+#[test]
+fn linked_slots_resolve_to_their_keys() {
+    let oracle = linked_oracle();
+
+    for index in 0..3 {
+        assert_eq!(
+            oracle.historical_chunk_key(index),
+            Some(deterministic_pubkey(10 + index as u8))
+        );
+        oracle
+            .verify_historical_chunk(index, &deterministic_pubkey(10 + index as u8))
+            .unwrap();
+    }
+}
+
+#[test]
+fn out_of_range_index_resolves_to_none() {
+    let oracle = linked_oracle();
+
+    assert_eq!(oracle.historical_chunk_key(MAX_HISTORICAL_CHUNKS), None);
+    assert_eq!(oracle.historical_chunk_key(usize::MAX), None);
+    assert_state_error(
+        oracle.verify_historical_chunk(MAX_HISTORICAL_CHUNKS, &deterministic_pubkey(10)),
+        StateError::UnlinkedHistoricalChunk,
+    );
+}
+
+#[test]
+fn unlinked_default_slot_resolves_to_none() {
+    let oracle = linked_oracle();
+
+    assert_eq!(oracle.historical_chunk_key(3), None);
+    // The default key must not verify against an unlinked slot
+    assert_state_error(
+        oracle.verify_historical_chunk(3, &Pubkey::default()),
+        StateError::UnlinkedHistoricalChunk,
+    );
+}
+
+#[test]
+fn key_linked_at_another_slot_is_rejected() {
+    let oracle = linked_oracle();
+
+    assert_state_error(
+        oracle.verify_historical_chunk(0, &deterministic_pubkey(11)),
+        StateError::UnlinkedHistoricalChunk,
+    );
+}
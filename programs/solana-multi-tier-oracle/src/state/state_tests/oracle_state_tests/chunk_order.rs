@@ -0,0 +1,41 @@
+//! Chronological chunk ordering.
+//!
+//! `update_price::order_chunks` is the reference implementation that the TWAP
+//! stream relies on; `chunk_order` must agree with it for every rotation
+//! position so index-based callers see the same history as the update path.
+
+use super::helpers::oracle_fixture;
+use crate::instructions::update_price::order_chunks;
+use crate::state::oracle_state::OracleState;
+use crate::state::state_tests::historical_chunk_tests::helpers::empty_chunk;
+
+#[test]
+fn chunk_order_matches_order_chunks_for_every_rotation() {
+    let mut chunks = [empty_chunk(), empty_chunk(), empty_chunk()];
+    for (id, chunk) in chunks.iter_mut().enumerate() {
+        chunk.chunk_id = id as u16;
+    }
+
+    // Cover each residue plus values that wrap, since the index is stored as u16.
+    for current_chunk_index in [0u16, 1, 2, 3, 4, 5, u16::MAX - 1, u16::MAX] {
+        let mut oracle = oracle_fixture();
+        oracle.current_chunk_index = current_chunk_index;
+
+        let reference = order_chunks(&chunks[0], &chunks[1], &chunks[2], current_chunk_index)
+            .map(|chunk| chunk.chunk_id as usize);
+
+        assert_eq!(
+            oracle.chunk_order(),
+            reference,
+            "mismatch at current_chunk_index {current_chunk_index}"
+        );
+    }
+}
+
+#[test]
+fn newest_chunk_is_the_current_one() {
+    for current_chunk_index in 0u16..3 {
+        let order = OracleState::chunk_order_for(current_chunk_index);
+        assert_eq!(order[2], current_chunk_index as usize);
+    }
+}
@@ -0,0 +1,151 @@
+//! Pruning of trailing historical chunks.
+//!
+//! Only an empty chunk linked beyond the three-slot rotating ring may be
+//! unlinked, and only while more than the minimum number of links remain.
+//! Every rejection leaves the oracle's links untouched.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::historical_chunk::HistoricalChunk;
+use crate::state::oracle_state::OracleState;
+use crate::state::state_tests::governance_state_tests::helpers::{
+    assert_state_error, deterministic_pubkey,
+};
+use crate::state::state_tests::historical_chunk_tests::helpers::{
+    deterministic_price_point, empty_chunk,
+};
+use crate::utils::constants::{MAX_HISTORICAL_CHUNKS, MIN_HISTORICAL_CHUNKS};
+use anchor_lang::prelude::Pubkey;
+
+const ORACLE_KEY: u8 = 1;
+const TRAILING: usize = MIN_HISTORICAL_CHUNKS;
+
+fn chunk_key(index: usize) -> Pubkey {
+    deterministic_pubkey(10 + index as u8)
+}
+
+/// Oracle linking `linked` slots, starting at zero.
+fn oracle_linking(linked: usize) -> OracleState {
+    let mut oracle = oracle_fixture();
+    oracle.historical_chunks = [Pubkey::default(); MAX_HISTORICAL_CHUNKS];
+    for index in 0..linked {
+        oracle.historical_chunks[index] = chunk_key(index);
+    }
+    oracle
+}
+
+fn chunk_for(oracle_key: Pubkey) -> HistoricalChunk {
+    let mut chunk = empty_chunk();
+    chunk.oracle_state = oracle_key;
+    chunk
+}
+
+#[test]
+fn empty_trailing_chunk_is_unlinked() {
+    let mut oracle = oracle_linking(TRAILING + 1);
+    let chunk = chunk_for(deterministic_pubkey(ORACLE_KEY));
+
+    oracle
+        .unlink_historical_chunk(
+            TRAILING,
+            &chunk_key(TRAILING),
+            &chunk,
+            &deterministic_pubkey(ORACLE_KEY),
+        )
+        .unwrap();
+
+    assert_eq!(oracle.historical_chunk_key(TRAILING), None);
+    for index in 0..MIN_HISTORICAL_CHUNKS {
+        assert_eq!(oracle.historical_chunk_key(index), Some(chunk_key(index)));
+    }
+}
+
+#[test]
+fn ring_chunks_are_never_unlinked() {
+    let mut oracle = oracle_linking(TRAILING + 1);
+    oracle.current_chunk_index = 1;
+    let chunk = chunk_for(deterministic_pubkey(ORACLE_KEY));
+
+    // The active chunk and the idle ones alike
+    for index in 0..MIN_HISTORICAL_CHUNKS {
+        assert_state_error(
+            oracle.unlink_historical_chunk(
+                index,
+                &chunk_key(index),
+                &chunk,
+                &deterministic_pubkey(ORACLE_KEY),
+            ),
+            StateError::ActiveHistoricalChunk,
+        );
+        assert_eq!(oracle.historical_chunk_key(index), Some(chunk_key(index)));
+    }
+}
+
+#[test]
+fn chunk_holding_data_is_kept() {
+    let mut oracle = oracle_linking(TRAILING + 1);
+    let mut chunk = chunk_for(deterministic_pubkey(ORACLE_KEY));
+    chunk.push(deterministic_price_point(0));
+
+    assert_state_error(
+        oracle.unlink_historical_chunk(
+            TRAILING,
+            &chunk_key(TRAILING),
+            &chunk,
+            &deterministic_pubkey(ORACLE_KEY),
+        ),
+        StateError::HistoricalChunkNotEmpty,
+    );
+    assert_eq!(
+        oracle.historical_chunk_key(TRAILING),
+        Some(chunk_key(TRAILING))
+    );
+}
+
+#[test]
+fn minimum_link_count_is_preserved() {
+    // A gap in the ring leaves only the minimum linked in total
+    let mut oracle = oracle_linking(TRAILING + 1);
+    oracle.historical_chunks[2] = Pubkey::default();
+    let chunk = chunk_for(deterministic_pubkey(ORACLE_KEY));
+
+    assert_state_error(
+        oracle.unlink_historical_chunk(
+            TRAILING,
+            &chunk_key(TRAILING),
+            &chunk,
+            &deterministic_pubkey(ORACLE_KEY),
+        ),
+        StateError::TooFewHistoricalChunks,
+    );
+}
+
+#[test]
+fn links_must_agree_in_both_directions() {
+    let mut oracle = oracle_linking(TRAILING + 1);
+
+    // Chunk key not linked at the slot
+    assert_state_error(
+        oracle.unlink_historical_chunk(
+            TRAILING,
+            &chunk_key(TRAILING + 1),
+            &chunk_for(deterministic_pubkey(ORACLE_KEY)),
+            &deterministic_pubkey(ORACLE_KEY),
+        ),
+        StateError::UnlinkedHistoricalChunk,
+    );
+    // Chunk pointing at another oracle
+    assert_state_error(
+        oracle.unlink_historical_chunk(
+            TRAILING,
+            &chunk_key(TRAILING),
+            &chunk_for(deterministic_pubkey(ORACLE_KEY + 1)),
+            &deterministic_pubkey(ORACLE_KEY),
+        ),
+        StateError::ChunkOracleMismatch,
+    );
+    assert_eq!(
+        oracle.historical_chunk_key(TRAILING),
+        Some(chunk_key(TRAILING))
+    );
+}
@@ -0,0 +1,156 @@
+//! Re-derivation of historical chunk links into canonical order.
+//!
+//! A repaired set must chain slot to slot and terminate like a freshly
+//! initialized oracle, and a foreign chunk must abort the repair before any
+//! link is written.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::historical_chunk::HistoricalChunk;
+use crate::state::oracle_state::OracleState;
+use crate::state::state_tests::governance_state_tests::helpers::{
+    assert_state_error, deterministic_pubkey,
+};
+use crate::state::state_tests::historical_chunk_tests::helpers::empty_chunk;
+use crate::utils::constants::{MAX_HISTORICAL_CHUNKS, MIN_HISTORICAL_CHUNKS};
+use anchor_lang::prelude::Pubkey;
+
+const ORACLE_KEY: u8 = 1;
+
+fn chunk_keys(count: usize) -> Vec<Pubkey> {
+    (0..count)
+        .map(|slot| deterministic_pubkey(10 + slot as u8))
+        .collect()
+}
+
+fn chunk_for(oracle_key: Pubkey) -> HistoricalChunk {
+    let mut chunk = empty_chunk();
+    chunk.oracle_state = oracle_key;
+    chunk
+}
+
+/// Oracle and chunks whose links a migration scrambled in every direction.
+fn broken_set(count: usize) -> (OracleState, Vec<Pubkey>, Vec<HistoricalChunk>) {
+    let keys = chunk_keys(count);
+    let mut oracle = oracle_fixture();
+    oracle.historical_chunks = [Pubkey::default(); MAX_HISTORICAL_CHUNKS];
+    oracle.historical_chunks[0] = keys[1];
+    oracle.historical_chunks[1] = keys[0];
+    oracle.historical_chunks[MAX_HISTORICAL_CHUNKS - 1] = deterministic_pubkey(99);
+
+    let mut chunks: Vec<HistoricalChunk> = (0..count)
+        .map(|_| chunk_for(deterministic_pubkey(ORACLE_KEY)))
+        .collect();
+    chunks[0].next_chunk = keys[0];
+    chunks[1].next_chunk = Pubkey::default();
+    chunks[count - 1].next_chunk = keys[1];
+    chunks[count - 1].chunk_id = 7;
+
+    (oracle, keys, chunks)
+}
+
+fn assert_canonical(oracle: &OracleState, keys: &[Pubkey], chunks: &[HistoricalChunk]) {
+    for slot in 0..MAX_HISTORICAL_CHUNKS {
+        assert_eq!(oracle.historical_chunk_key(slot), keys.get(slot).copied());
+    }
+    for (slot, chunk) in chunks.iter().enumerate() {
+        assert_eq!(chunk.chunk_id, slot as u16);
+        assert_eq!(chunk.has_next(), slot + 1 < keys.len());
+        if let Some(next) = keys.get(slot + 1) {
+            assert_eq!(chunk.next_chunk, *next);
+        }
+    }
+}
+
+#[test]
+fn broken_links_are_rewritten_into_canonical_order() {
+    let (mut oracle, keys, mut chunks) = broken_set(MIN_HISTORICAL_CHUNKS);
+    let mut refs: Vec<&mut HistoricalChunk> = chunks.iter_mut().collect();
+
+    let rewritten = oracle
+        .repair_chunk_links(&deterministic_pubkey(ORACLE_KEY), &keys, &mut refs)
+        .unwrap();
+
+    // Slots 0, 1, 2 and the stray trailing slot, plus all three chunks
+    assert_eq!(rewritten, 7);
+    assert_canonical(&oracle, &keys, &chunks);
+    for slot in 0..MIN_HISTORICAL_CHUNKS {
+        oracle.verify_historical_chunk(slot, &keys[slot]).unwrap();
+    }
+}
+
+#[test]
+fn extended_sets_chain_past_the_rotating_ring() {
+    let (mut oracle, keys, mut chunks) = broken_set(MAX_HISTORICAL_CHUNKS);
+    let mut refs: Vec<&mut HistoricalChunk> = chunks.iter_mut().collect();
+
+    oracle
+        .repair_chunk_links(&deterministic_pubkey(ORACLE_KEY), &keys, &mut refs)
+        .unwrap();
+
+    assert_canonical(&oracle, &keys, &chunks);
+}
+
+#[test]
+fn repairing_a_canonical_set_rewrites_nothing() {
+    let (mut oracle, keys, mut chunks) = broken_set(MIN_HISTORICAL_CHUNKS);
+    let oracle_key = deterministic_pubkey(ORACLE_KEY);
+    let mut refs: Vec<&mut HistoricalChunk> = chunks.iter_mut().collect();
+    oracle
+        .repair_chunk_links(&oracle_key, &keys, &mut refs)
+        .unwrap();
+
+    assert_eq!(
+        oracle
+            .repair_chunk_links(&oracle_key, &keys, &mut refs)
+            .unwrap(),
+        0
+    );
+}
+
+#[test]
+fn foreign_chunk_aborts_before_any_link_changes() {
+    let (mut oracle, keys, mut chunks) = broken_set(MIN_HISTORICAL_CHUNKS);
+    chunks[2].oracle_state = deterministic_pubkey(ORACLE_KEY + 1);
+    let links_before = oracle.historical_chunks;
+    let next_before: Vec<Pubkey> = chunks.iter().map(|chunk| chunk.next_chunk).collect();
+
+    let mut refs: Vec<&mut HistoricalChunk> = chunks.iter_mut().collect();
+    assert_state_error(
+        oracle
+            .repair_chunk_links(&deterministic_pubkey(ORACLE_KEY), &keys, &mut refs)
+            .map(|_| ()),
+        StateError::ChunkOracleMismatch,
+    );
+
+    assert_eq!(oracle.historical_chunks, links_before);
+    let next_after: Vec<Pubkey> = chunks.iter().map(|chunk| chunk.next_chunk).collect();
+    assert_eq!(next_after, next_before);
+}
+
+#[test]
+fn duplicate_keys_are_rejected() {
+    let (mut oracle, mut keys, mut chunks) = broken_set(MIN_HISTORICAL_CHUNKS);
+    keys[2] = keys[0];
+    let mut refs: Vec<&mut HistoricalChunk> = chunks.iter_mut().collect();
+
+    assert_state_error(
+        oracle
+            .repair_chunk_links(&deterministic_pubkey(ORACLE_KEY), &keys, &mut refs)
+            .map(|_| ()),
+        StateError::InvalidChunkSet,
+    );
+}
+
+#[test]
+fn fewer_chunks_than_the_ring_are_rejected() {
+    let (mut oracle, keys, mut chunks) = broken_set(MIN_HISTORICAL_CHUNKS);
+    let mut refs: Vec<&mut HistoricalChunk> = chunks.iter_mut().take(2).collect();
+
+    assert_state_error(
+        oracle
+            .repair_chunk_links(&deterministic_pubkey(ORACLE_KEY), &keys[..2], &mut refs)
+            .map(|_| ()),
+        StateError::TooFewHistoricalChunks,
+    );
+}
@@ -0,0 +1,49 @@
+//! Conversion of a published price to a consumer's own exponent.
+//!
+//! `get_price` applies `PriceData::to_scale` when the caller asks for a target
+//! exponent, so these tests pin its rounding and overflow behaviour.
+
+use crate::error::StateError;
+use crate::state::oracle_state::PriceData;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+
+/// `price` quoted at `-6`, so `123_456_789` reads as 123.456789.
+fn micro_price(price: i128) -> PriceData {
+    PriceData {
+        price,
+        expo: -6,
+        ..PriceData::default()
+    }
+}
+
+#[test]
+fn finer_target_scales_up() {
+    assert_eq!(
+        micro_price(123_456_789).to_scale(-8).unwrap(),
+        12_345_678_900
+    );
+}
+
+#[test]
+fn coarser_target_scales_down_toward_zero() {
+    assert_eq!(micro_price(123_456_789).to_scale(-4).unwrap(), 1_234_567);
+    // Truncation never rounds a negative spread away from zero either
+    assert_eq!(micro_price(-123_456_789).to_scale(-4).unwrap(), -1_234_567);
+}
+
+#[test]
+fn same_exponent_is_unchanged() {
+    assert_eq!(micro_price(123_456_789).to_scale(-6).unwrap(), 123_456_789);
+}
+
+#[test]
+fn overflowing_scale_up_is_rejected() {
+    assert_state_error(
+        micro_price(i128::MAX / 10).to_scale(-8).map(|_| ()),
+        StateError::MathOverflow,
+    );
+    assert_state_error(
+        micro_price(1).to_scale(-60).map(|_| ()),
+        StateError::MathOverflow,
+    );
+}
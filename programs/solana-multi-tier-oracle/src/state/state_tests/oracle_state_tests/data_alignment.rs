@@ -0,0 +1,34 @@
+//! Runtime alignment guard for zero-copy `OracleState` access.
+//!
+//! The guard inspects only the buffer address, so small buffers placed at a
+//! chosen offset are enough to exercise both outcomes.
+
+use crate::error::StateError;
+use crate::state::oracle_state::OracleState;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+
+/// Storage aligned for any field type `OracleState` contains.
+#[repr(C, align(16))]
+struct AlignedBuffer([u8; 64]);
+
+#[test]
+fn aligned_account_data_is_accepted() {
+    let buffer = AlignedBuffer([0; 64]);
+    assert!(core::mem::align_of::<OracleState>() <= 16);
+
+    // Starting 8 bytes in puts the state, after its discriminator, on a 16-byte boundary
+    OracleState::check_data_alignment(&buffer.0[8..]).unwrap();
+}
+
+#[test]
+fn misaligned_account_data_is_rejected() {
+    let buffer = AlignedBuffer([0; 64]);
+
+    // Odd offsets can never satisfy an alignment of two or more
+    for offset in [1, 3, 7] {
+        assert_state_error(
+            OracleState::check_data_alignment(&buffer.0[offset..]),
+            StateError::MisalignedAccountData,
+        );
+    }
+}
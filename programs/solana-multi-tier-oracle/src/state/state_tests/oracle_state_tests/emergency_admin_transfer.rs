@@ -0,0 +1,177 @@
+//! Dual-signed emergency admin rotation.
+//!
+//! Rotation needs the outgoing emergency admin and a governance `ADMIN_ALL`
+//! member as two distinct signers; any single party acting alone is rejected
+//! and leaves the stored admin untouched.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::OracleState;
+use crate::state::state_tests::governance_state_tests::helpers::{
+    assert_state_error, governance_fixture,
+};
+use anchor_lang::prelude::Pubkey;
+
+struct Fixture {
+    oracle: OracleState,
+    governance: GovernanceState,
+    emergency_admin: Pubkey,
+    governance_admin: Pubkey,
+}
+
+fn fixture() -> Fixture {
+    let emergency_admin = Pubkey::new_unique();
+    let mut oracle = oracle_fixture();
+    oracle.emergency_admin = emergency_admin;
+
+    let mut governance = governance_fixture(3);
+    governance.member_permissions[0] = Permissions::ADMIN_ALL;
+    governance.member_permissions[1] = Permissions::MODIFY_CONFIG;
+    let governance_admin = governance.multisig_members[0];
+
+    Fixture {
+        oracle,
+        governance,
+        emergency_admin,
+        governance_admin,
+    }
+}
+
+#[test]
+fn both_signers_rotate_the_admin() {
+    let Fixture {
+        mut oracle,
+        governance,
+        emergency_admin,
+        governance_admin,
+    } = fixture();
+    let new_admin = Pubkey::new_unique();
+
+    let previous = oracle
+        .transfer_emergency_admin(&governance, &emergency_admin, &governance_admin, new_admin)
+        .unwrap();
+
+    assert_eq!(previous, emergency_admin);
+    assert_eq!(oracle.emergency_admin, new_admin);
+}
+
+#[test]
+fn emergency_admin_alone_is_rejected() {
+    let Fixture {
+        mut oracle,
+        governance,
+        emergency_admin,
+        ..
+    } = fixture();
+
+    // Countersigned by a key governance does not know
+    assert_state_error(
+        oracle
+            .transfer_emergency_admin(
+                &governance,
+                &emergency_admin,
+                &Pubkey::new_unique(),
+                Pubkey::new_unique(),
+            )
+            .map(|_| ()),
+        StateError::UnauthorizedCaller,
+    );
+    assert_eq!(oracle.emergency_admin, emergency_admin);
+}
+
+#[test]
+fn governance_admin_alone_is_rejected() {
+    let Fixture {
+        mut oracle,
+        governance,
+        emergency_admin,
+        governance_admin,
+    } = fixture();
+
+    // Governance admin claiming both roles, and governance admin with an impostor
+    for current in [governance_admin, Pubkey::new_unique()] {
+        assert_state_error(
+            oracle
+                .transfer_emergency_admin(
+                    &governance,
+                    &current,
+                    &governance_admin,
+                    Pubkey::new_unique(),
+                )
+                .map(|_| ()),
+            StateError::UnauthorizedCaller,
+        );
+    }
+    assert_eq!(oracle.emergency_admin, emergency_admin);
+}
+
+#[test]
+fn one_key_holding_both_roles_cannot_self_approve() {
+    let Fixture {
+        mut oracle,
+        governance,
+        governance_admin,
+        ..
+    } = fixture();
+    oracle.emergency_admin = governance_admin;
+
+    assert_state_error(
+        oracle
+            .transfer_emergency_admin(
+                &governance,
+                &governance_admin,
+                &governance_admin,
+                Pubkey::new_unique(),
+            )
+            .map(|_| ()),
+        StateError::DualSignatureRequired,
+    );
+    assert_eq!(oracle.emergency_admin, governance_admin);
+}
+
+#[test]
+fn approver_without_admin_role_is_rejected() {
+    let Fixture {
+        mut oracle,
+        governance,
+        emergency_admin,
+        ..
+    } = fixture();
+    let config_member = governance.multisig_members[1];
+
+    assert_state_error(
+        oracle
+            .transfer_emergency_admin(
+                &governance,
+                &emergency_admin,
+                &config_member,
+                Pubkey::new_unique(),
+            )
+            .map(|_| ()),
+        StateError::InsufficientPermissions,
+    );
+}
+
+#[test]
+fn default_key_is_never_a_valid_successor() {
+    let Fixture {
+        mut oracle,
+        governance,
+        emergency_admin,
+        governance_admin,
+    } = fixture();
+
+    assert_state_error(
+        oracle
+            .transfer_emergency_admin(
+                &governance,
+                &emergency_admin,
+                &governance_admin,
+                Pubkey::default(),
+            )
+            .map(|_| ()),
+        StateError::InvalidEmergencyAdmin,
+    );
+    assert_eq!(oracle.emergency_admin, emergency_admin);
+}
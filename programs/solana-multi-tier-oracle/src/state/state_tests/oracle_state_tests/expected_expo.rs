@@ -0,0 +1,83 @@
+//! Canonical exponent enforcement.
+//!
+//! Every price the oracle publishes is `price * 10^expo`; if two updates are
+//! scaled differently, consumers see a jump of whole orders of magnitude that
+//! no confidence interval would flag. These tests pin the lock taken when the
+//! first feed is registered and the rejection of later mismatches.
+
+use super::helpers::{decimal_price_with_decimals, oracle_fixture};
+use crate::error::StateError;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+
+#[test]
+fn implied_expo_follows_quote_token_decimals() {
+    // SOL (9) / USDC (6): the price is counted in micro-USDC per whole SOL.
+    assert_eq!(decimal_price_with_decimals(9, 6).implied_expo(), -6);
+    assert_eq!(decimal_price_with_decimals(6, 9).implied_expo(), -9);
+    assert_eq!(decimal_price_with_decimals(6, 6).implied_expo(), -6);
+}
+
+#[test]
+fn first_registration_locks_canonical_expo() {
+    let mut oracle = oracle_fixture();
+    assert_eq!(oracle.expected_expo(), None);
+
+    oracle.lock_expected_expo(decimal_price_with_decimals(9, 6).implied_expo());
+
+    assert_eq!(oracle.expected_expo(), Some(-6));
+    assert_eq!(oracle.current_price.expo, -6);
+}
+
+#[test]
+fn later_registrations_keep_the_first_lock() {
+    let mut oracle = oracle_fixture();
+    oracle.lock_expected_expo(-6);
+
+    oracle.lock_expected_expo(-9);
+
+    assert_eq!(oracle.expected_expo(), Some(-6));
+}
+
+#[test]
+fn updates_cannot_choose_the_expo_before_a_feed_is_registered() {
+    let oracle = oracle_fixture();
+
+    assert_state_error(oracle.check_expected_expo(-6), StateError::ExponentMismatch);
+    assert_eq!(oracle.expected_expo(), None);
+}
+
+#[test]
+fn zero_expo_is_locked_like_any_other_value() {
+    let mut oracle = oracle_fixture();
+    oracle.lock_expected_expo(0);
+
+    oracle
+        .check_expected_expo(0)
+        .expect("equal decimals lock expo 0");
+    assert_eq!(oracle.expected_expo(), Some(0));
+    assert_state_error(oracle.check_expected_expo(3), StateError::ExponentMismatch);
+}
+
+#[test]
+fn matching_expo_is_accepted() {
+    let mut oracle = oracle_fixture();
+    oracle.lock_expected_expo(-9);
+
+    oracle
+        .check_expected_expo(decimal_price_with_decimals(6, 9).implied_expo())
+        .expect("same decimals must be accepted");
+    assert_eq!(oracle.expected_expo(), Some(-9));
+}
+
+#[test]
+fn mismatched_expo_is_rejected_without_rescaling() {
+    let mut oracle = oracle_fixture();
+    oracle.lock_expected_expo(-9);
+
+    assert_state_error(
+        oracle.check_expected_expo(decimal_price_with_decimals(9, 6).implied_expo()),
+        StateError::ExponentMismatch,
+    );
+    // The canonical exponent must survive the rejected update untouched.
+    assert_eq!(oracle.expected_expo(), Some(-9));
+}
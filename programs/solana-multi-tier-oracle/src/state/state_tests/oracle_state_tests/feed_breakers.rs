@@ -0,0 +1,112 @@
+//! Per-feed circuit breakers.
+//!
+//! A feed whose manipulation score crosses the oracle threshold is isolated on
+//! its own; the oracle-wide breaker only engages once too few healthy feeds
+//! remain to keep publishing.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::oracle_state::{OracleState, StateFlags};
+use crate::state::price_feed::{FeedFlags, PriceFeed};
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use crate::utils::constants::MIN_HEALTHY_FEEDS;
+
+const NOW: i64 = 1_700_000_000;
+const THRESHOLD: u16 = 5_000;
+
+fn feed(last_price: i128, manipulation_score: u16) -> PriceFeed {
+    let mut feed = PriceFeed {
+        last_price,
+        last_expo: -6,
+        last_update: NOW,
+        weight: 3_000,
+        manipulation_score,
+        ..PriceFeed::default()
+    };
+    feed.flags.set(FeedFlags::ACTIVE);
+    feed
+}
+
+fn oracle_with_feeds(feeds: &[PriceFeed]) -> OracleState {
+    let mut oracle = oracle_fixture();
+    oracle.current_price.price = 101_000_000;
+    oracle.current_price.timestamp = NOW;
+    oracle.current_price.expo = -6;
+    oracle.manipulation_threshold = THRESHOLD;
+    oracle.flags.set(StateFlags::CIRCUIT_BREAKER_ENABLED);
+    oracle.price_feeds[..feeds.len()].copy_from_slice(feeds);
+    oracle.set_active_feed_count(feeds.len() as u8).unwrap();
+    oracle
+}
+
+#[test]
+fn tripping_one_of_three_feeds_keeps_the_oracle_live() {
+    let mut oracle = oracle_with_feeds(&[
+        feed(100_000_000, 100),
+        feed(500_000_000, 9_000),
+        feed(102_000_000, 200),
+    ]);
+
+    assert!(oracle.enforce_feed_breakers(MIN_HEALTHY_FEEDS).unwrap());
+    assert!(!oracle.flags.is_emergency_mode());
+
+    let tripped: Vec<bool> = oracle
+        .active_feeds()
+        .iter()
+        .map(|feed| feed.flags.is_breaker_tripped())
+        .collect();
+    assert_eq!(tripped, [false, true, false]);
+
+    // The manipulated outlier no longer moves the aggregate or vetoes consensus
+    assert_eq!(oracle.aggregate_tiered(NOW).unwrap().price, 101_000_000);
+    assert!(oracle.feeds_agree(300, NOW));
+    oracle.read_price().unwrap();
+
+    let participation = oracle.feed_participation(NOW);
+    assert_eq!(participation.aggregated, 2);
+    assert_eq!(participation.excluded_manipulated, 1);
+    assert_eq!(participation.sources_mask, 0b101);
+}
+
+#[test]
+fn breaker_resets_once_the_score_recovers() {
+    let mut oracle = oracle_with_feeds(&[feed(100_000_000, 100), feed(100_000_000, 9_000)]);
+    oracle.enforce_feed_breakers(MIN_HEALTHY_FEEDS).unwrap();
+    assert!(oracle.price_feeds[1].flags.is_breaker_tripped());
+
+    oracle.price_feeds[1].manipulation_score = THRESHOLD;
+    oracle.enforce_feed_breakers(MIN_HEALTHY_FEEDS).unwrap();
+    assert!(!oracle.price_feeds[1].flags.is_breaker_tripped());
+}
+
+#[test]
+fn too_few_healthy_feeds_escalates_to_emergency_mode() {
+    let mut oracle = oracle_with_feeds(&[
+        feed(100_000_000, 9_000),
+        feed(100_000_000, 8_000),
+        feed(100_000_000, 100),
+    ]);
+
+    // One healthy feed is enough at the default minimum, but not at two
+    assert!(oracle.enforce_feed_breakers(MIN_HEALTHY_FEEDS).unwrap());
+    assert!(!oracle.enforce_feed_breakers(2).unwrap());
+    assert!(oracle.flags.is_emergency_mode());
+}
+
+#[test]
+fn escalation_without_circuit_breaker_rejects_the_update() {
+    let mut oracle = oracle_with_feeds(&[feed(100_000_000, 9_000)]);
+    oracle.flags.clear(StateFlags::CIRCUIT_BREAKER_ENABLED);
+
+    assert_state_error(
+        oracle.enforce_feed_breakers(MIN_HEALTHY_FEEDS).map(|_| ()),
+        StateError::ManipulationDetected,
+    );
+    assert!(!oracle.flags.is_emergency_mode());
+}
+
+#[test]
+fn breaker_flag_survives_sanitization() {
+    let flags = FeedFlags::from_u8_truncate(FeedFlags::BREAKER_TRIPPED.as_u8());
+    assert!(flags.is_breaker_tripped());
+}
@@ -0,0 +1,180 @@
+//! Cross-feed consistency gate.
+//!
+//! `update_price` only publishes when fresh feeds agree within the spread
+//! stored on the oracle; otherwise it either rejects the update or, with the
+//! circuit breaker armed, enters emergency mode without touching
+//! `current_price`.
+
+use anchor_lang::prelude::Pubkey;
+
+use super::helpers::{decimal_price_with_decimals, oracle_fixture};
+use crate::error::StateError;
+use crate::instructions::register_price_feed::{create_price_feed, PriceFeedConfig};
+use crate::instructions::update_price::pool_reading;
+use crate::state::oracle_state::{OracleState, StateFlags};
+use crate::state::price_feed::{FeedFlags, PriceFeed, SourceType};
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use crate::utils::constants::{DEFAULT_FEED_SPREAD_BPS, MAX_EXTERNAL_STALENESS};
+
+const NOW: i64 = 1_700_000_000;
+const MAX_SPREAD_BPS: u16 = 100; // 1%
+
+fn fresh_feed(last_price: i128, last_expo: i32) -> PriceFeed {
+    let mut feed = PriceFeed {
+        last_price,
+        last_expo,
+        last_update: NOW,
+        ..PriceFeed::default()
+    };
+    feed.flags.set(FeedFlags::ACTIVE);
+    feed
+}
+
+fn oracle_with_feeds(feeds: &[PriceFeed]) -> OracleState {
+    let mut oracle = oracle_fixture();
+    oracle.current_price.expo = -6;
+    oracle.price_feeds[..feeds.len()].copy_from_slice(feeds);
+    oracle.set_active_feed_count(feeds.len() as u8).unwrap();
+    oracle.set_feed_spread(MAX_SPREAD_BPS).unwrap();
+    oracle
+}
+
+#[test]
+fn tight_spread_allows_publication() {
+    // 100.00 and 100.50 at mixed exponents: 50 bps apart
+    let mut oracle =
+        oracle_with_feeds(&[fresh_feed(100_000_000, -6), fresh_feed(10_050_000_000, -8)]);
+
+    assert!(oracle.feeds_agree(MAX_SPREAD_BPS, NOW));
+    assert!(oracle.enforce_feed_consensus(NOW).unwrap());
+    assert!(!oracle.flags.is_emergency_mode());
+}
+
+#[test]
+fn wide_spread_blocks_publication() {
+    // 100 vs 105: 500 bps apart
+    let mut oracle = oracle_with_feeds(&[fresh_feed(100_000_000, -6), fresh_feed(105_000_000, -6)]);
+    oracle.flags.clear(StateFlags::CIRCUIT_BREAKER_ENABLED);
+
+    assert!(!oracle.feeds_agree(MAX_SPREAD_BPS, NOW));
+    assert_state_error(
+        oracle.enforce_feed_consensus(NOW).map(|_| ()),
+        StateError::FeedsDisagree,
+    );
+    assert!(!oracle.flags.is_emergency_mode());
+}
+
+#[test]
+fn wide_spread_trips_armed_breaker() {
+    let mut oracle = oracle_with_feeds(&[fresh_feed(100_000_000, -6), fresh_feed(105_000_000, -6)]);
+    oracle.flags.set(StateFlags::CIRCUIT_BREAKER_ENABLED);
+
+    assert!(!oracle.enforce_feed_consensus(NOW).unwrap());
+    assert!(oracle.flags.is_emergency_mode());
+}
+
+#[test]
+fn stale_outlier_does_not_veto_fresh_consensus() {
+    let mut stale = fresh_feed(200_000_000, -6);
+    stale.last_update = NOW - MAX_EXTERNAL_STALENESS as i64 - 1;
+    let oracle = oracle_with_feeds(&[
+        fresh_feed(100_000_000, -6),
+        fresh_feed(100_100_000, -6),
+        stale,
+    ]);
+
+    assert!(oracle.feeds_agree(MAX_SPREAD_BPS, NOW));
+}
+
+#[test]
+fn single_feed_and_non_positive_prices() {
+    assert!(oracle_with_feeds(&[fresh_feed(100_000_000, -6)]).feeds_agree(0, NOW));
+    assert!(!oracle_with_feeds(&[fresh_feed(0, -6), fresh_feed(1, -6)]).feeds_agree(10_000, NOW));
+}
+
+#[test]
+fn newly_registered_feed_does_not_veto_the_next_update() {
+    let mut oracle = oracle_with_feeds(&[fresh_feed(100_000_000, -6)]);
+    oracle.flags.set(StateFlags::CIRCUIT_BREAKER_ENABLED);
+
+    let registered = create_price_feed(
+        &PriceFeedConfig {
+            source_address: Pubkey::new_unique(),
+            source_type: SourceType::DEX,
+            weight: 1_000,
+            min_liquidity: 0,
+            staleness_threshold: 60,
+            expo: -6,
+            normalize: false,
+            asset_seed: [0; 32],
+        },
+        NOW,
+    );
+    let index = oracle.claim_feed_slot(registered).unwrap();
+
+    // The first feed refreshes in the same slot the second was registered
+    oracle.price_feeds[0].record_price(101_000_000, 10_000);
+    oracle.price_feeds[0].record_good_read(NOW);
+
+    assert!(oracle.enforce_feed_consensus(NOW).unwrap());
+    assert!(!oracle.flags.is_emergency_mode());
+    assert_eq!(oracle.aggregate_tiered(NOW).unwrap().price, 101_000_000);
+
+    let participation = oracle.feed_participation(NOW);
+    assert_eq!(participation.aggregated, 1);
+    assert_eq!(participation.excluded_stale, 1);
+    assert_eq!(participation.sources_mask & (1 << index), 0);
+}
+
+#[test]
+fn a_moving_pool_is_recorded_on_its_own_feed_and_trips_consensus() {
+    // Both feeds last agreed at 100, which is also what the oracle published
+    let mut oracle = oracle_with_feeds(&[fresh_feed(100_000_000, -6), fresh_feed(100_000_000, -6)]);
+    oracle.current_price.price = 100_000_000;
+    oracle.flags.set(StateFlags::CIRCUIT_BREAKER_ENABLED);
+
+    // The second pool moves to 105 (USDC-quoted, so six decimals)
+    let mut moved = decimal_price_with_decimals(9, 6);
+    moved.price = 105_000_000;
+    let reading = pool_reading(&moved, oracle.price_feeds[1].last_expo, NOW).unwrap();
+    oracle.price_feeds[1].record_price(reading.price, 10_000);
+    oracle.price_feeds[1].record_good_read(NOW);
+
+    // The feed holds the pool's price, not the aggregate it was published into
+    assert_eq!(oracle.price_feeds[1].last_price, 105_000_000);
+    assert!(!oracle.feeds_agree(MAX_SPREAD_BPS, NOW));
+    assert!(!oracle.enforce_feed_consensus(NOW).unwrap());
+    assert!(oracle.flags.is_emergency_mode());
+}
+
+#[test]
+fn pool_readings_are_scaled_into_the_feed_expo() {
+    let mut observed = decimal_price_with_decimals(9, 6);
+    observed.price = 105_000_000;
+
+    let reading = pool_reading(&observed, -8, NOW).unwrap();
+    assert_eq!((reading.price, reading.expo), (10_500_000_000, -8));
+    assert_eq!(reading.conf, observed.confidence as u64);
+    assert_eq!(reading.timestamp, NOW);
+}
+
+#[test]
+fn unset_spread_reads_as_the_default() {
+    // 100 vs 104: 400 bps apart, inside the 500 bps default
+    let mut oracle = oracle_with_feeds(&[fresh_feed(100_000_000, -6), fresh_feed(104_000_000, -6)]);
+    oracle.max_feed_spread_bps = 0;
+
+    assert_eq!(oracle.feed_spread_bps(), DEFAULT_FEED_SPREAD_BPS);
+    assert!(oracle.enforce_feed_consensus(NOW).unwrap());
+}
+
+#[test]
+fn spread_bound_must_be_within_one_to_ten_thousand_bps() {
+    let mut oracle = oracle_fixture();
+
+    for bps in [0, 10_001] {
+        assert_state_error(oracle.set_feed_spread(bps), StateError::InvalidFeedSpread);
+    }
+    oracle.set_feed_spread(10_000).unwrap();
+    assert_eq!(oracle.feed_spread_bps(), 10_000);
+}
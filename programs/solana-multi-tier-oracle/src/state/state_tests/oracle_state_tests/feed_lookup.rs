@@ -0,0 +1,79 @@
+//! Resolution of the feed an update targets.
+//!
+//! `update_price` resolves its pool to a feed slot before doing any other work,
+//! so these tests pin which error an operator sees for an empty feed table
+//! versus a pool the oracle simply does not read from, and that a deactivated
+//! feed is never resolved.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::oracle_state::OracleState;
+use crate::state::price_feed::{FeedFlags, PriceFeed};
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use anchor_lang::prelude::*;
+
+fn registered_feed(source: Pubkey) -> PriceFeed {
+    let mut feed = PriceFeed {
+        source_address: source,
+        weight: 1_000,
+        ..PriceFeed::default()
+    };
+    feed.flags.set(FeedFlags::ACTIVE);
+    feed
+}
+
+fn oracle_with_sources(sources: &[Pubkey]) -> OracleState {
+    let mut oracle = oracle_fixture();
+    for (slot, source) in sources.iter().enumerate() {
+        oracle.price_feeds[slot] = registered_feed(*source);
+    }
+    oracle.set_active_feed_count(sources.len() as u8).unwrap();
+    oracle
+}
+
+#[test]
+fn empty_feed_table_reports_no_active_feeds() {
+    let oracle = oracle_with_sources(&[]);
+
+    assert_state_error(
+        oracle.feed_index_for(&Pubkey::new_unique()).map(|_| ()),
+        StateError::NoActiveFeeds,
+    );
+    // Not even an empty slot's default address resolves
+    assert_state_error(
+        oracle.feed_index_for(&Pubkey::default()).map(|_| ()),
+        StateError::NoActiveFeeds,
+    );
+}
+
+#[test]
+fn registered_source_resolves_to_its_slot() {
+    let sources = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let oracle = oracle_with_sources(&sources);
+
+    assert_eq!(oracle.feed_index_for(&sources[0]).unwrap(), 0);
+    assert_eq!(oracle.feed_index_for(&sources[1]).unwrap(), 1);
+}
+
+#[test]
+fn unknown_source_is_still_an_invalid_address() {
+    let oracle = oracle_with_sources(&[Pubkey::new_unique()]);
+
+    assert_state_error(
+        oracle.feed_index_for(&Pubkey::new_unique()).map(|_| ()),
+        StateError::InvalidSourceAddress,
+    );
+}
+
+#[test]
+fn deactivated_feed_does_not_resolve() {
+    let sources = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut oracle = oracle_with_sources(&sources);
+    oracle.price_feeds[0].flags.clear(FeedFlags::ACTIVE);
+
+    assert_state_error(
+        oracle.feed_index_for(&sources[0]).map(|_| ()),
+        StateError::InvalidSourceAddress,
+    );
+    assert_eq!(oracle.feed_index_for(&sources[1]).unwrap(), 1);
+}
@@ -0,0 +1,107 @@
+//! Feed participation counts reported in `PriceUpdated`.
+//!
+//! Each active feed must land in exactly one bucket so monitoring can trust the
+//! counts to add up to the registered source set.
+
+use super::helpers::oracle_fixture;
+use crate::state::oracle_state::{FeedParticipation, OracleState};
+use crate::state::price_feed::{FeedFlags, PriceFeed};
+use crate::utils::constants::MAX_EXTERNAL_STALENESS;
+
+const NOW: i64 = 1_700_000_000;
+const MAX_STALENESS: i64 = MAX_EXTERNAL_STALENESS as i64;
+
+fn active_feed(last_update: i64, manipulation_score: u16) -> PriceFeed {
+    let mut feed = PriceFeed {
+        last_update,
+        manipulation_score,
+        ..PriceFeed::default()
+    };
+    feed.flags.set(FeedFlags::ACTIVE);
+    feed
+}
+
+fn oracle_with_feeds(feeds: &[PriceFeed]) -> OracleState {
+    let mut oracle = oracle_fixture();
+    oracle.manipulation_threshold = 5_000;
+    oracle.price_feeds[..feeds.len()].copy_from_slice(feeds);
+    oracle.set_active_feed_count(feeds.len() as u8).unwrap();
+    oracle
+}
+
+#[test]
+fn counts_one_stale_and_one_manipulated_feed() {
+    let oracle = oracle_with_feeds(&[
+        active_feed(NOW, 100),
+        active_feed(NOW - MAX_STALENESS - 1, 100),
+        active_feed(NOW - 10, 9_000),
+    ]);
+
+    assert_eq!(
+        oracle.feed_participation(NOW),
+        FeedParticipation {
+            aggregated: 1,
+            excluded_stale: 1,
+            excluded_manipulated: 1,
+            sources_mask: 0b001,
+        }
+    );
+}
+
+#[test]
+fn stale_takes_precedence_over_manipulation() {
+    let oracle = oracle_with_feeds(&[active_feed(NOW - MAX_STALENESS - 1, 9_000)]);
+
+    let participation = oracle.feed_participation(NOW);
+    assert_eq!(participation.excluded_stale, 1);
+    assert_eq!(participation.excluded_manipulated, 0);
+}
+
+#[test]
+fn flags_and_inactive_feeds_are_respected() {
+    let mut flagged_stale = active_feed(NOW, 0);
+    flagged_stale.flags.set(FeedFlags::STALE);
+    let mut flagged_manipulated = active_feed(NOW, 0);
+    flagged_manipulated
+        .flags
+        .set(FeedFlags::MANIPULATION_DETECTED);
+    let mut inactive = active_feed(NOW, 0);
+    inactive.flags.clear(FeedFlags::ACTIVE);
+
+    let oracle = oracle_with_feeds(&[flagged_stale, flagged_manipulated, inactive]);
+
+    assert_eq!(
+        oracle.feed_participation(NOW),
+        FeedParticipation {
+            aggregated: 0,
+            excluded_stale: 1,
+            excluded_manipulated: 1,
+            sources_mask: 0,
+        }
+    );
+}
+
+#[test]
+fn boundary_age_and_threshold_still_aggregate() {
+    let oracle = oracle_with_feeds(&[active_feed(NOW - MAX_STALENESS, 5_000)]);
+
+    assert_eq!(oracle.feed_participation(NOW).aggregated, 1);
+}
+
+#[test]
+fn excluding_a_stale_feed_clears_its_source_bit() {
+    let mut oracle = oracle_with_feeds(&[active_feed(NOW, 100), active_feed(NOW, 100)]);
+    oracle.current_price.price = 100;
+    oracle.current_price.timestamp = NOW;
+    assert_eq!(oracle.feed_participation(NOW).sources_mask, 0b11);
+
+    oracle.price_feeds[1].last_update = NOW - MAX_STALENESS - 1;
+    let participation = oracle.feed_participation(NOW);
+    oracle.last_sources_mask = participation.sources_mask;
+
+    assert_eq!(oracle.last_sources_mask, 0b01);
+    assert_eq!(
+        oracle.read_price_reading(NOW, 0).unwrap().sources_mask,
+        0b01
+    );
+}
@@ -0,0 +1,122 @@
+//! Slot assignment for newly registered feeds.
+//!
+//! Registration must only ever fill a slot without an active feed, whatever
+//! `active_feed_count` claims, so a stale count cannot overwrite a committed feed.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::oracle_state::OracleState;
+use crate::state::price_feed::{FeedFlags, PriceFeed};
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use crate::utils::constants::{MAX_PRICE_FEEDS, WEIGHT_PRECISION};
+use anchor_lang::prelude::*;
+
+fn active_feed(seed: u8) -> PriceFeed {
+    let mut feed = PriceFeed {
+        source_address: Pubkey::new_from_array([seed; 32]),
+        weight: 1_000,
+        ..PriceFeed::default()
+    };
+    feed.flags.set(FeedFlags::ACTIVE);
+    feed
+}
+
+fn oracle_with_feeds(count: usize) -> OracleState {
+    let mut oracle = oracle_fixture();
+    for slot in 0..count {
+        oracle.price_feeds[slot] = active_feed(slot as u8 + 1);
+    }
+    oracle.set_active_feed_count(count as u8).unwrap();
+    oracle
+}
+
+#[test]
+fn appends_after_the_active_range() {
+    let mut oracle = oracle_with_feeds(2);
+
+    let index = oracle.claim_feed_slot(active_feed(9)).unwrap();
+
+    assert_eq!(index, 2);
+    assert_eq!(oracle.active_feed_count, 3);
+    assert_eq!(
+        oracle.price_feeds[2].source_address,
+        active_feed(9).source_address
+    );
+}
+
+#[test]
+fn stale_count_does_not_clobber_a_committed_feed() {
+    // Two feeds committed, but the count still says one: the slot a naive
+    // append would pick is already taken
+    let mut oracle = oracle_with_feeds(2);
+    oracle.active_feed_count = 1;
+    let committed = oracle.price_feeds[1];
+
+    let index = oracle.claim_feed_slot(active_feed(9)).unwrap();
+
+    assert_eq!(index, 2, "collision resolved to a distinct slot");
+    assert_eq!(
+        oracle.price_feeds[1].source_address,
+        committed.source_address
+    );
+    assert_eq!(
+        oracle.active_feed_count, 3,
+        "count covers every active slot"
+    );
+}
+
+#[test]
+fn hole_inside_the_active_range_is_reused() {
+    let mut oracle = oracle_with_feeds(3);
+    oracle.price_feeds[1].flags.clear(FeedFlags::ACTIVE);
+
+    let index = oracle.claim_feed_slot(active_feed(9)).unwrap();
+
+    assert_eq!(index, 1);
+    assert_eq!(oracle.active_feed_count, 3);
+}
+
+#[test]
+fn full_oracle_fails_cleanly() {
+    let mut oracle = oracle_with_feeds(MAX_PRICE_FEEDS);
+    let before: Vec<Pubkey> = oracle
+        .price_feeds
+        .iter()
+        .map(|feed| feed.source_address)
+        .collect();
+
+    assert_state_error(
+        oracle.claim_feed_slot(active_feed(99)).map(|_| ()),
+        StateError::TooManyFeeds,
+    );
+    assert!(oracle
+        .price_feeds
+        .iter()
+        .zip(&before)
+        .all(|(feed, key)| feed.source_address == *key));
+    assert_eq!(oracle.active_feed_count as usize, MAX_PRICE_FEEDS);
+}
+
+#[test]
+fn headroom_is_the_weight_left_after_registration() {
+    let mut oracle = oracle_with_feeds(3);
+    assert_eq!(oracle.weight_headroom(), WEIGHT_PRECISION - 3_000);
+
+    oracle.claim_feed_slot(active_feed(9)).unwrap();
+    let total_weight: u32 = oracle
+        .active_feeds()
+        .iter()
+        .map(|feed| feed.weight as u32)
+        .sum();
+
+    assert_eq!(total_weight, 4_000);
+    assert_eq!(oracle.weight_headroom(), WEIGHT_PRECISION - total_weight);
+    assert_eq!(oracle.to_summary().weight_headroom, 6_000);
+}
+
+#[test]
+fn headroom_is_zero_once_fully_allocated() {
+    let oracle = oracle_with_feeds(10);
+
+    assert_eq!(oracle.weight_headroom(), 0);
+}
@@ -0,0 +1,80 @@
+//! Per-source-type iteration over the active feed set.
+//!
+//! The fixture mixes every source type with an inactive slot, a slot past the
+//! active count, and an unknown discriminant, so each filter must pick its feeds
+//! out by type alone and in slot order.
+
+use super::helpers::oracle_fixture;
+use crate::state::oracle_state::OracleState;
+use crate::state::price_feed::{FeedFlags, PriceFeed, SourceType};
+use anchor_lang::prelude::Pubkey;
+
+fn feed(seed: u8, source_type: SourceType) -> PriceFeed {
+    let mut feed = PriceFeed {
+        source_address: Pubkey::new_from_array([seed; 32]),
+        ..PriceFeed::default()
+    };
+    feed.set_source_type(source_type);
+    feed.flags.set(FeedFlags::ACTIVE);
+    feed
+}
+
+fn mixed_oracle() -> OracleState {
+    let mut oracle = oracle_fixture();
+    oracle.price_feeds[0] = feed(1, SourceType::DEX);
+    oracle.price_feeds[1] = feed(2, SourceType::Oracle);
+    oracle.price_feeds[2] = feed(3, SourceType::CEX);
+    oracle.price_feeds[3] = feed(4, SourceType::DEX);
+    oracle.price_feeds[4] = feed(5, SourceType::Aggregator);
+    oracle.price_feeds[5] = feed(6, SourceType::Oracle);
+    oracle.price_feeds[5].flags.clear(FeedFlags::ACTIVE);
+    oracle.price_feeds[6] = feed(7, SourceType::DEX);
+    oracle.price_feeds[6].source_type = u8::MAX; // unknown discriminant
+    oracle.set_active_feed_count(7).unwrap();
+    // Past the active count, so never reported
+    oracle.price_feeds[7] = feed(8, SourceType::CEX);
+    oracle
+}
+
+fn seeds(oracle: &OracleState, source_type: SourceType) -> Vec<u8> {
+    oracle
+        .feeds_of_type(source_type)
+        .map(|feed| feed.source_address.to_bytes()[0])
+        .collect()
+}
+
+#[test]
+fn each_filter_yields_exactly_its_feeds() {
+    let oracle = mixed_oracle();
+
+    assert_eq!(seeds(&oracle, SourceType::DEX), vec![1, 4, 7]);
+    assert_eq!(seeds(&oracle, SourceType::CEX), vec![3]);
+    assert_eq!(seeds(&oracle, SourceType::Oracle), vec![2]);
+    assert_eq!(seeds(&oracle, SourceType::Aggregator), vec![5]);
+}
+
+#[test]
+fn filters_partition_the_active_feeds() {
+    let oracle = mixed_oracle();
+
+    let total: usize = [
+        SourceType::DEX,
+        SourceType::CEX,
+        SourceType::Oracle,
+        SourceType::Aggregator,
+    ]
+    .into_iter()
+    .map(|source_type| oracle.feeds_of_type(source_type).count())
+    .sum();
+
+    // Seven slots in range, one of them flagged inactive
+    assert_eq!(total, 6);
+}
+
+#[test]
+fn empty_oracle_yields_nothing() {
+    let mut oracle = oracle_fixture();
+    oracle.set_active_feed_count(0).unwrap();
+
+    assert_eq!(oracle.feeds_of_type(SourceType::DEX).count(), 0);
+}
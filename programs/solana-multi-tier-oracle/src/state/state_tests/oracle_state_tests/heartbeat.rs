@@ -0,0 +1,78 @@
+//! Keeper heartbeats.
+//!
+//! A heartbeat proves liveness only: it must move `last_update` and the nonce
+//! while leaving the published price and the historical record exactly as they
+//! were, and it must be rate limited.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::historical_chunk::PricePoint;
+use crate::state::oracle_state::StateFlags;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use crate::state::state_tests::historical_chunk_tests::helpers::empty_chunk;
+use crate::utils::constants::MIN_HEARTBEAT_INTERVAL;
+
+const PRICE_AT: i64 = 1_700_000_000;
+
+#[test]
+fn heartbeat_advances_last_update_without_touching_history() {
+    let mut oracle = oracle_fixture();
+    oracle.last_update = PRICE_AT;
+    oracle.current_price.timestamp = PRICE_AT;
+    oracle.current_price.price = 42;
+
+    let mut chunk = empty_chunk();
+    chunk.push(PricePoint {
+        price: 42,
+        conf: 100,
+        timestamp: PRICE_AT,
+        volume: 0,
+    });
+    let count_before = chunk.count;
+
+    let now = PRICE_AT + MIN_HEARTBEAT_INTERVAL;
+    let nonce = oracle
+        .record_heartbeat(now, MIN_HEARTBEAT_INTERVAL)
+        .unwrap();
+
+    assert_eq!(nonce, 1);
+    assert_eq!(oracle.update_nonce, 1);
+    assert_eq!(oracle.last_update, now);
+    assert_eq!(chunk.count, count_before);
+    assert_eq!(
+        oracle.current_price.timestamp, PRICE_AT,
+        "price age is preserved"
+    );
+    assert_eq!(oracle.current_price.price, 42);
+}
+
+#[test]
+fn heartbeat_inside_minimum_interval_is_rejected() {
+    let mut oracle = oracle_fixture();
+    oracle.last_update = PRICE_AT;
+
+    assert_state_error(
+        oracle
+            .record_heartbeat(
+                PRICE_AT + MIN_HEARTBEAT_INTERVAL - 1,
+                MIN_HEARTBEAT_INTERVAL,
+            )
+            .map(|_| ()),
+        StateError::HeartbeatTooFrequent,
+    );
+    assert_eq!(oracle.last_update, PRICE_AT);
+    assert_eq!(oracle.update_nonce, 0);
+}
+
+#[test]
+fn heartbeat_is_blocked_while_updates_are_paused() {
+    let mut oracle = oracle_fixture();
+    oracle.flags.set(StateFlags::MAINTENANCE_MODE);
+
+    assert_state_error(
+        oracle
+            .record_heartbeat(PRICE_AT, MIN_HEARTBEAT_INTERVAL)
+            .map(|_| ()),
+        StateError::MaintenanceActive,
+    );
+}
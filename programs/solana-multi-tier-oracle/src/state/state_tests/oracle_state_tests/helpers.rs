@@ -0,0 +1,32 @@
+use crate::components::raydium_clmm_observer::fetch_raydium_price::{DecimalPrice, PriceMethod};
+use crate::state::oracle_state::OracleState;
+use crate::state::state_tests::historical_chunk_tests::helpers::minimal_oracle_state;
+use anchor_lang::prelude::Pubkey;
+
+/// Oracle fixture for update-path tests.
+///
+/// Delegates to the historical chunk suite's minimal state so the field list of
+/// `OracleState` is spelled out in as few places as possible; schema additions
+/// then only need to touch the shared constructors.
+pub(crate) fn oracle_fixture() -> OracleState {
+    minimal_oracle_state()
+}
+
+/// Builds an observer result for a pool with the given token decimals.
+///
+/// Only the decimals matter for exponent checks; the remaining fields carry
+/// plausible values so the struct remains realistic if a test inspects them.
+pub(crate) fn decimal_price_with_decimals(decimal_0: u8, decimal_1: u8) -> DecimalPrice {
+    DecimalPrice {
+        price: 1u128 << 64,
+        confidence: 9_500,
+        timestamp: 1_700_000_000,
+        source: Pubkey::new_unique(),
+        liquidity_depth: 5_000_000,
+        manipulation_score: 100,
+        twap_deviation_bps: 0,
+        method: PriceMethod::CrossValidated,
+        decimal_0,
+        decimal_1,
+    }
+}
@@ -0,0 +1,115 @@
+//! Keeper-asserted sanity bounds on the published price.
+//!
+//! Bounds are inclusive and independently optional, so a keeper can guard one
+//! side only; leaving both out must never reject an update. They hold the
+//! tiered aggregate, not the single pool read in the update, to the range.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::instructions::update_price::{bounded_aggregate, check_expected_bounds};
+use crate::state::oracle_state::OracleState;
+use crate::state::price_feed::{FeedFlags, PriceFeed, SourceType};
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+
+const PRICE: i128 = 150_000_000;
+const NOW: i64 = 1_700_000_000;
+
+fn feed(source_type: SourceType, last_price: i128) -> PriceFeed {
+    let mut feed = PriceFeed {
+        last_price,
+        last_expo: -6,
+        ..PriceFeed::default()
+    };
+    feed.set_source_type(source_type);
+    feed.flags.set(FeedFlags::ACTIVE);
+    feed.record_good_read(NOW);
+    feed
+}
+
+/// An Oracle-tier feed at 100.00 and a DEX feed at 102.00 blended 50/50.
+fn blended_oracle() -> OracleState {
+    let mut oracle = oracle_fixture();
+    oracle.current_price.expo = -6;
+    oracle.price_feeds[0] = feed(SourceType::Oracle, 100_000_000);
+    oracle.price_feeds[1] = feed(SourceType::DEX, 102_000_000);
+    oracle.set_active_feed_count(2).unwrap();
+    oracle.set_tier_config([5_000, 0, 5_000], 500).unwrap();
+    oracle
+}
+
+#[test]
+fn no_bounds_accept_any_price() {
+    assert!(check_expected_bounds(PRICE, None, None).is_ok());
+    assert!(check_expected_bounds(i128::MIN, None, None).is_ok());
+}
+
+#[test]
+fn price_inside_bounds_is_accepted() {
+    assert!(check_expected_bounds(PRICE, Some(100_000_000), Some(200_000_000)).is_ok());
+}
+
+#[test]
+fn bounds_are_inclusive() {
+    assert!(check_expected_bounds(PRICE, Some(PRICE), Some(PRICE)).is_ok());
+}
+
+#[test]
+fn price_below_min_is_rejected() {
+    assert_state_error(
+        check_expected_bounds(PRICE, Some(PRICE + 1), Some(200_000_000)),
+        StateError::PriceOutsideExpectedBounds,
+    );
+}
+
+#[test]
+fn price_above_max_is_rejected() {
+    assert_state_error(
+        check_expected_bounds(PRICE, Some(100_000_000), Some(PRICE - 1)),
+        StateError::PriceOutsideExpectedBounds,
+    );
+}
+
+#[test]
+fn one_sided_bounds_guard_only_their_side() {
+    assert!(check_expected_bounds(PRICE, Some(PRICE), None).is_ok());
+    assert!(check_expected_bounds(PRICE, None, Some(PRICE)).is_ok());
+    assert_state_error(
+        check_expected_bounds(PRICE, None, Some(PRICE - 1)),
+        StateError::PriceOutsideExpectedBounds,
+    );
+}
+
+#[test]
+fn inverted_bounds_reject_everything() {
+    assert_state_error(
+        check_expected_bounds(PRICE, Some(200_000_000), Some(100_000_000)),
+        StateError::PriceOutsideExpectedBounds,
+    );
+}
+
+#[test]
+fn aggregate_inside_bounds_is_published() {
+    let oracle = blended_oracle();
+    let price = bounded_aggregate(&oracle, NOW, Some(100_500_000), Some(101_500_000)).unwrap();
+
+    assert_eq!(price.price, 101_000_000);
+    assert_eq!(price.conf, oracle.aggregate_tiered(NOW).unwrap().conf);
+}
+
+#[test]
+fn bounds_apply_to_the_aggregate_not_the_pool_reading() {
+    // The DEX pool alone sits inside the range, but the blend it feeds does not
+    let oracle = blended_oracle();
+    assert!(check_expected_bounds(102_000_000, Some(101_500_000), None).is_ok());
+    assert_state_error(
+        bounded_aggregate(&oracle, NOW, Some(101_500_000), None).map(|_| ()),
+        StateError::PriceOutsideExpectedBounds,
+    );
+}
+
+#[test]
+fn outlying_pool_reading_is_accepted_when_the_aggregate_is_in_bounds() {
+    let oracle = blended_oracle();
+    assert!(check_expected_bounds(102_000_000, None, Some(101_500_000)).is_err());
+    assert!(bounded_aggregate(&oracle, NOW, None, Some(101_500_000)).is_ok());
+}
@@ -0,0 +1,62 @@
+//! Unilateral emergency halt.
+//!
+//! Only the stored emergency admin may trip the kill switch; governance
+//! membership, even with full admin rights, grants no such power.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::oracle_state::{OracleState, StateFlags};
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use anchor_lang::prelude::Pubkey;
+
+fn oracle_with_admin() -> (OracleState, Pubkey) {
+    let emergency_admin = Pubkey::new_unique();
+    let mut oracle = oracle_fixture();
+    oracle.emergency_admin = emergency_admin;
+    (oracle, emergency_admin)
+}
+
+#[test]
+fn emergency_admin_halts_oracle() {
+    let (mut oracle, emergency_admin) = oracle_with_admin();
+
+    oracle.activate_kill_switch(&emergency_admin).unwrap();
+
+    assert!(oracle.flags.is_emergency_mode());
+    assert!(oracle.flags.has(StateFlags::CIRCUIT_BREAKER_ENABLED));
+    assert_state_error(
+        oracle.require_operational(),
+        StateError::CircuitBreakerActive,
+    );
+    assert_state_error(
+        oracle.read_price().map(|_| ()),
+        StateError::CircuitBreakerActive,
+    );
+}
+
+#[test]
+fn other_signers_are_rejected_without_side_effects() {
+    let (mut oracle, _) = oracle_with_admin();
+    let flags_before = oracle.flags;
+
+    for caller in [Pubkey::new_unique(), oracle.authority] {
+        assert_state_error(
+            oracle.activate_kill_switch(&caller),
+            StateError::UnauthorizedCaller,
+        );
+    }
+
+    assert_eq!(oracle.flags, flags_before);
+    assert!(oracle.require_operational().is_ok());
+}
+
+#[test]
+fn repeated_activation_is_idempotent() {
+    let (mut oracle, emergency_admin) = oracle_with_admin();
+
+    oracle.activate_kill_switch(&emergency_admin).unwrap();
+    let flags_after_first = oracle.flags;
+    oracle.activate_kill_switch(&emergency_admin).unwrap();
+
+    assert_eq!(oracle.flags, flags_after_first);
+}
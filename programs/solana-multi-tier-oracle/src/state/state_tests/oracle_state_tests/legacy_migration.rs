@@ -0,0 +1,205 @@
+//! In-place upgrade of v0.1 oracle accounts to the current schema.
+//!
+//! `migrate_oracle_state` only checks the account and resizes it; everything
+//! that decides what the migrated oracle looks like lives in
+//! `LegacyOracleState::upgrade_into`, which these tests drive directly.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::legacy_oracle_state::{LegacyOracleState, LegacyPriceFeed};
+use crate::state::oracle_state::{OracleState, StateFlags, UpdaterPolicy, Version};
+use crate::state::price_feed::{rescale_price, FeedFlags};
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use crate::utils::constants::{
+    DEFAULT_MANIPULATION_COOLDOWN, DEFAULT_MANIPULATION_DECAY_PERIOD, MAX_PRICE_FEEDS,
+};
+use anchor_lang::prelude::Pubkey;
+use bytemuck::Zeroable;
+
+const LAST_UPDATE: i64 = 1_700_000_000;
+const CANONICAL_EXPO: i32 = -6;
+
+fn legacy_feed(last_price: i128) -> LegacyPriceFeed {
+    let mut feed = LegacyPriceFeed::zeroed();
+    feed.source_address = Pubkey::new_unique();
+    feed.last_price = last_price;
+    feed.liquidity_depth = 5_000_000;
+    feed.last_conf = 9_000;
+    feed.last_update = LAST_UPDATE;
+    feed.weight = 5_000;
+    feed.manipulation_score = 120;
+    feed.flags.set(FeedFlags::ACTIVE);
+    feed
+}
+
+/// A v0.1 oracle with two feeds that has published 150 at exponent 0.
+fn legacy_oracle() -> LegacyOracleState {
+    let mut legacy = LegacyOracleState::zeroed();
+    legacy.authority = Pubkey::new_unique();
+    legacy.version = Version::LEGACY;
+    legacy.flags.set(StateFlags::CIRCUIT_BREAKER_ENABLED);
+    legacy.last_update = LAST_UPDATE;
+    legacy.current_price.price = 150;
+    legacy.current_price.timestamp = LAST_UPDATE;
+    legacy.price_feeds[0] = legacy_feed(150);
+    legacy.price_feeds[1] = legacy_feed(151);
+    legacy.twap_window = 3_600;
+    legacy.max_chunk_size = 128;
+    legacy.manipulation_threshold = 5_000;
+    legacy.active_feed_count = 2;
+    legacy.bump = 254;
+    legacy.governance_bump = 253;
+    legacy.historical_chunks[0] = Pubkey::new_unique();
+    legacy.emergency_admin = Pubkey::new_unique();
+    legacy.asset_seed = [7; 32];
+    legacy
+}
+
+fn migrate(legacy: &LegacyOracleState) -> OracleState {
+    // Start from a populated account so a field the upgrade forgets to write shows up
+    let mut target = oracle_fixture();
+    target.update_nonce = 99;
+    legacy.upgrade_into(&mut target, CANONICAL_EXPO).unwrap();
+    target
+}
+
+#[test]
+fn every_legacy_field_is_carried_over() {
+    let legacy = legacy_oracle();
+    let oracle = migrate(&legacy);
+
+    assert_eq!(oracle.authority, legacy.authority);
+    assert_eq!(oracle.version, Version::CURRENT);
+    assert_eq!(oracle.flags.as_u32(), legacy.flags.as_u32());
+    assert_eq!(oracle.last_update, LAST_UPDATE);
+    assert_eq!(oracle.current_price.timestamp, LAST_UPDATE);
+    assert_eq!(oracle.twap_window, 3_600);
+    assert_eq!(oracle.max_chunk_size, 128);
+    assert_eq!(oracle.manipulation_threshold, 5_000);
+    assert_eq!(oracle.active_feed_count, 2);
+    assert_eq!((oracle.bump, oracle.governance_bump), (254, 253));
+    assert_eq!(oracle.historical_chunks, legacy.historical_chunks);
+    assert_eq!(oracle.emergency_admin, legacy.emergency_admin);
+    assert_eq!(oracle.asset_seed, [7; 32]);
+}
+
+#[test]
+fn feeds_keep_their_readings_and_count_as_validated() {
+    let legacy = legacy_oracle();
+    let oracle = migrate(&legacy);
+
+    for (feed, old) in oracle.active_feeds().iter().zip(&legacy.price_feeds) {
+        assert_eq!(feed.source_address, old.source_address);
+        assert_eq!(feed.last_price, old.last_price * 1_000_000);
+        assert_eq!(feed.last_expo, CANONICAL_EXPO);
+        assert_eq!(feed.liquidity_depth, old.liquidity_depth);
+        assert_eq!(feed.last_conf, old.last_conf);
+        assert_eq!(feed.weight, old.weight);
+        assert_eq!(feed.manipulation_score, old.manipulation_score);
+        assert!(feed.flags.is_active());
+        assert_eq!(feed.last_good_update, LAST_UPDATE);
+        assert_eq!(feed.reading_age(LAST_UPDATE + 30), 30);
+    }
+}
+
+#[test]
+fn smoothed_price_continues_from_the_last_reading() {
+    let mut feed = migrate(&legacy_oracle()).price_feeds[0];
+    assert_eq!(feed.ewma_price, 150_000_000);
+
+    // Half-weight step from the carried-over price, not from zero
+    feed.record_price(160_000_000, 5_000);
+    assert_eq!(feed.ewma_price, 155_000_000);
+}
+
+#[test]
+fn newer_fields_start_at_their_initialization_defaults() {
+    let oracle = migrate(&legacy_oracle());
+
+    assert_eq!(oracle.manipulation_cooldown, DEFAULT_MANIPULATION_COOLDOWN);
+    assert_eq!(
+        oracle.manipulation_decay_period,
+        DEFAULT_MANIPULATION_DECAY_PERIOD
+    );
+    assert_eq!(oracle.update_nonce, 0);
+    assert_eq!(oracle.last_sources_mask, 0);
+    assert_eq!(oracle.tier_weights_bps, [0; 3]);
+}
+
+#[test]
+fn updates_stay_limited_to_governance_keepers() {
+    let mut target = oracle_fixture();
+    target.updater_policy = UpdaterPolicy::Allowlist.as_u8();
+    target.allowed_updaters[0] = Pubkey::new_unique();
+    legacy_oracle()
+        .upgrade_into(&mut target, CANONICAL_EXPO)
+        .unwrap();
+
+    assert_eq!(target.get_updater_policy(), UpdaterPolicy::GovernanceOnly);
+    assert!(target
+        .allowed_updaters
+        .iter()
+        .all(|key| *key == Pubkey::default()));
+}
+
+#[test]
+fn registered_feeds_lock_the_canonical_expo_and_rescale_the_price() {
+    let oracle = migrate(&legacy_oracle());
+
+    assert_eq!(oracle.expected_expo(), Some(CANONICAL_EXPO));
+    assert_eq!(oracle.current_price.price, 150_000_000);
+}
+
+#[test]
+fn feed_readings_round_trip_through_the_canonical_expo() {
+    let mut oracle = migrate(&legacy_oracle());
+
+    // The migrated reading still prices at 150 in the oracle's exponent
+    let feed = oracle.price_feeds[0];
+    assert_eq!(
+        rescale_price(feed.last_price, feed.last_expo, CANONICAL_EXPO).unwrap(),
+        150_000_000
+    );
+
+    // A later reading with sub-unit precision is stored without truncation
+    let feed = &mut oracle.price_feeds[0];
+    let reading = rescale_price(150_123_456, CANONICAL_EXPO, feed.last_expo).unwrap();
+    feed.record_price(reading, 10_000);
+    assert_eq!(feed.last_price, 150_123_456);
+    assert_eq!(
+        oracle.aggregate_tiered(LAST_UPDATE).unwrap().price,
+        (150_123_456 + 151_000_000) / 2
+    );
+}
+
+#[test]
+fn an_oracle_without_feeds_is_left_for_its_first_registration() {
+    let mut legacy = legacy_oracle();
+    legacy.active_feed_count = 0;
+    legacy.price_feeds = [LegacyPriceFeed::zeroed(); MAX_PRICE_FEEDS];
+    legacy.current_price.price = 0;
+
+    assert_eq!(migrate(&legacy).expected_expo(), None);
+}
+
+#[test]
+fn only_v0_1_accounts_are_migrated() {
+    let mut target = oracle_fixture();
+    let mut legacy = legacy_oracle();
+    legacy.version = Version::CURRENT;
+
+    assert_state_error(
+        legacy.upgrade_into(&mut target, CANONICAL_EXPO),
+        StateError::UnsupportedStateVersion,
+    );
+}
+
+#[test]
+fn out_of_range_expo_is_rejected() {
+    let mut target = oracle_fixture();
+
+    assert_state_error(
+        legacy_oracle().upgrade_into(&mut target, 2),
+        StateError::InvalidFeedExpo,
+    );
+}
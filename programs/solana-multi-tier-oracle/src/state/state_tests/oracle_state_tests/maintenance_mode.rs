@@ -0,0 +1,53 @@
+//! Planned maintenance gating.
+//!
+//! Maintenance must pause writes without looking like an incident: updates are
+//! refused with their own error, reads keep serving the last validated price,
+//! and clearing the flag restores normal operation with no residual state.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::oracle_state::StateFlags;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+
+#[test]
+fn updates_fail_during_maintenance_and_resume_afterward() {
+    let mut oracle = oracle_fixture();
+    oracle
+        .require_operational()
+        .expect("fresh oracle accepts updates");
+
+    oracle.flags.set(StateFlags::MAINTENANCE_MODE);
+    assert_state_error(oracle.require_operational(), StateError::MaintenanceActive);
+
+    oracle.flags.clear(StateFlags::MAINTENANCE_MODE);
+    oracle
+        .require_operational()
+        .expect("updates resume once maintenance ends");
+}
+
+#[test]
+fn reads_still_succeed_during_maintenance() {
+    let mut oracle = oracle_fixture();
+    oracle.current_price.price = 123_456;
+    oracle.current_price.timestamp = 1_700_000_000;
+    oracle.flags.set(StateFlags::MAINTENANCE_MODE);
+
+    let price = oracle.read_price().expect("reads are not paused");
+    assert_eq!(price.price, 123_456);
+}
+
+#[test]
+fn emergency_blocks_reads_and_takes_precedence_over_maintenance() {
+    let mut oracle = oracle_fixture();
+    oracle.flags.set(StateFlags::MAINTENANCE_MODE);
+    oracle.flags.set(StateFlags::EMERGENCY_MODE);
+
+    assert_state_error(
+        oracle.require_operational(),
+        StateError::CircuitBreakerActive,
+    );
+    assert_state_error(
+        oracle.read_price().map(|_| ()),
+        StateError::CircuitBreakerActive,
+    );
+}
@@ -0,0 +1,103 @@
+//! Time-decayed manipulation scoring.
+//!
+//! Alternating a manipulated update with a benign one must not wash out the
+//! manipulation signal. These tests drive a feed through a spike followed by
+//! clean reads at the observation cadence and check the effective score over
+//! the cooldown and decay horizons.
+
+use super::helpers::oracle_fixture;
+use crate::state::oracle_state::OracleState;
+
+const FEED: usize = 0;
+const SPIKE_AT: i64 = 1_700_000_000;
+const COOLDOWN: u32 = 900;
+const DECAY: u32 = 1_800;
+const SPIKE: u16 = 9_000;
+const BENIGN: u16 = 200;
+
+fn decaying_oracle() -> OracleState {
+    let mut oracle = oracle_fixture();
+    oracle.manipulation_cooldown = COOLDOWN;
+    oracle.manipulation_decay_period = DECAY;
+    oracle
+}
+
+#[test]
+fn spike_followed_by_benign_reads_stays_elevated_through_cooldown() {
+    let mut oracle = decaying_oracle();
+    assert_eq!(
+        oracle.blend_manipulation_score(FEED, SPIKE, SPIKE_AT),
+        SPIKE
+    );
+
+    // Clean reads every 15s for the whole cooldown must not lower the score.
+    for offset in (15..=COOLDOWN as i64).step_by(15) {
+        let score = oracle.blend_manipulation_score(FEED, BENIGN, SPIKE_AT + offset);
+        assert_eq!(score, SPIKE, "score dropped {offset}s after spike");
+    }
+}
+
+#[test]
+fn score_decays_linearly_after_cooldown_then_clears() {
+    let mut oracle = decaying_oracle();
+    oracle.blend_manipulation_score(FEED, SPIKE, SPIKE_AT);
+
+    let halfway = SPIKE_AT + COOLDOWN as i64 + DECAY as i64 / 2;
+    assert_eq!(
+        oracle.blend_manipulation_score(FEED, BENIGN, halfway),
+        SPIKE / 2
+    );
+
+    let fully_decayed = SPIKE_AT + COOLDOWN as i64 + DECAY as i64;
+    assert_eq!(
+        oracle.blend_manipulation_score(FEED, BENIGN, fully_decayed),
+        BENIGN,
+        "once the peak is forgotten the raw score applies again"
+    );
+}
+
+#[test]
+fn benign_reads_do_not_reset_the_decay_clock() {
+    let mut oracle = decaying_oracle();
+    oracle.blend_manipulation_score(FEED, SPIKE, SPIKE_AT);
+    oracle.blend_manipulation_score(FEED, BENIGN, SPIKE_AT + 600);
+
+    assert_eq!(oracle.manipulation_peak_at[FEED], SPIKE_AT);
+    assert_eq!(oracle.manipulation_peak_score[FEED], SPIKE);
+}
+
+#[test]
+fn higher_spike_resets_peak_and_clock() {
+    let mut oracle = decaying_oracle();
+    oracle.blend_manipulation_score(FEED, 5_000, SPIKE_AT);
+
+    let later = SPIKE_AT + 1_200;
+    assert_eq!(oracle.blend_manipulation_score(FEED, SPIKE, later), SPIKE);
+    assert_eq!(oracle.manipulation_peak_at[FEED], later);
+    assert_eq!(
+        oracle.decayed_manipulation_peak(FEED, later + COOLDOWN as i64),
+        SPIKE
+    );
+}
+
+#[test]
+fn zero_configuration_preserves_overwrite_semantics() {
+    let mut oracle = oracle_fixture();
+    oracle.blend_manipulation_score(FEED, SPIKE, SPIKE_AT);
+
+    assert_eq!(
+        oracle.blend_manipulation_score(FEED, BENIGN, SPIKE_AT + 1),
+        BENIGN
+    );
+}
+
+#[test]
+fn feeds_decay_independently() {
+    let mut oracle = decaying_oracle();
+    oracle.blend_manipulation_score(FEED, SPIKE, SPIKE_AT);
+
+    assert_eq!(
+        oracle.blend_manipulation_score(FEED + 1, BENIGN, SPIKE_AT + 15),
+        BENIGN
+    );
+}
@@ -0,0 +1,165 @@
+//! Escalation of manipulation scores along the configured severity ladder.
+//!
+//! A score over the threshold only keeps its feed out of publication; the
+//! ladder decides when the oracle as a whole arms its breaker or halts. Flags
+//! must move only in the protective direction and only for scores that are
+//! actually over the threshold.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::oracle_state::{ManipulationSeverity, OracleState, StateFlags};
+use crate::state::price_feed::{FeedFlags, PriceFeed};
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use crate::utils::constants::MIN_HEALTHY_FEEDS;
+
+const THRESHOLD: u16 = 5_000;
+const ARM: u16 = 7_000;
+const EMERGENCY: u16 = 9_000;
+
+fn feed(manipulation_score: u16) -> PriceFeed {
+    let mut feed = PriceFeed {
+        last_price: 100_000_000,
+        last_expo: -6,
+        weight: 3_000,
+        manipulation_score,
+        ..PriceFeed::default()
+    };
+    feed.flags.set(FeedFlags::ACTIVE);
+    feed
+}
+
+fn oracle(ladder: [u16; 2]) -> OracleState {
+    let mut oracle = oracle_fixture();
+    oracle.manipulation_threshold = THRESHOLD;
+    oracle.flags.clear(StateFlags::CIRCUIT_BREAKER_ENABLED);
+    oracle.set_escalation_ladder(ladder).unwrap();
+    oracle
+}
+
+#[test]
+fn below_threshold_score_is_normal() {
+    let mut oracle = oracle([ARM, EMERGENCY]);
+    oracle.price_feeds[0] = feed(THRESHOLD);
+    oracle.set_active_feed_count(1).unwrap();
+    let flags_before = oracle.flags;
+
+    assert_eq!(
+        oracle.escalate_manipulation(THRESHOLD, THRESHOLD),
+        ManipulationSeverity::Normal
+    );
+    assert_eq!(oracle.flags, flags_before);
+    assert!(oracle.enforce_feed_breakers(MIN_HEALTHY_FEEDS).unwrap());
+    assert!(!oracle.price_feeds[0].flags.is_breaker_tripped());
+}
+
+#[test]
+fn above_threshold_score_skips_the_price_and_consults_the_breaker() {
+    let mut oracle = oracle([0, 0]);
+    oracle.price_feeds[0] = feed(THRESHOLD + 1);
+    oracle.price_feeds[1] = feed(0);
+    oracle.set_active_feed_count(2).unwrap();
+    let flags_before = oracle.flags;
+
+    assert_eq!(
+        oracle.escalate_manipulation(THRESHOLD + 1, THRESHOLD),
+        ManipulationSeverity::Suspicious
+    );
+    assert_eq!(
+        oracle.flags, flags_before,
+        "an unset ladder never escalates"
+    );
+
+    // The suspicious feed is isolated; the healthy one keeps the oracle live
+    assert!(oracle.enforce_feed_breakers(MIN_HEALTHY_FEEDS).unwrap());
+    assert!(oracle.price_feeds[0].flags.is_breaker_tripped());
+    assert!(!oracle.price_feeds[1].flags.is_breaker_tripped());
+}
+
+#[test]
+fn ladder_escalates_rung_by_rung() {
+    let mut suspicious = oracle([ARM, EMERGENCY]);
+    assert_eq!(
+        suspicious.escalate_manipulation(ARM - 1, THRESHOLD),
+        ManipulationSeverity::Suspicious
+    );
+    assert!(!suspicious.is_circuit_breaker_enabled());
+
+    let mut armed = oracle([ARM, EMERGENCY]);
+    assert_eq!(
+        armed.escalate_manipulation(ARM, THRESHOLD),
+        ManipulationSeverity::BreakerArmed
+    );
+    assert!(armed.is_circuit_breaker_enabled());
+    assert!(!armed.flags.is_emergency_mode());
+
+    let mut halted = oracle([ARM, EMERGENCY]);
+    assert_eq!(
+        halted.escalate_manipulation(EMERGENCY, THRESHOLD),
+        ManipulationSeverity::Emergency
+    );
+    assert!(halted.is_circuit_breaker_enabled());
+    assert!(halted.flags.is_emergency_mode());
+}
+
+#[test]
+fn emergency_rung_needs_an_enabled_breaker() {
+    let mut disarmed = oracle([0, EMERGENCY]);
+    assert_eq!(
+        disarmed.escalate_manipulation(EMERGENCY, THRESHOLD),
+        ManipulationSeverity::Suspicious
+    );
+    assert!(!disarmed.flags.is_emergency_mode());
+
+    let mut enabled = oracle([0, EMERGENCY]);
+    enabled.flags.set(StateFlags::CIRCUIT_BREAKER_ENABLED);
+    assert_eq!(
+        enabled.escalate_manipulation(EMERGENCY, THRESHOLD),
+        ManipulationSeverity::Emergency
+    );
+    assert!(enabled.flags.is_emergency_mode());
+}
+
+#[test]
+fn rungs_below_the_threshold_wait_for_it() {
+    let mut oracle = oracle([1_000, 2_000]);
+
+    assert_eq!(
+        oracle.escalate_manipulation(3_000, THRESHOLD),
+        ManipulationSeverity::Normal
+    );
+    assert!(!oracle.is_circuit_breaker_enabled());
+}
+
+#[test]
+fn armed_breaker_turns_a_feed_shortage_into_a_halt() {
+    let mut oracle = oracle([ARM, 0]);
+    oracle.price_feeds[0] = feed(ARM);
+    oracle.set_active_feed_count(1).unwrap();
+
+    assert_eq!(
+        oracle.escalate_manipulation(ARM, THRESHOLD),
+        ManipulationSeverity::BreakerArmed
+    );
+    // Without arming this would revert with ManipulationDetected
+    assert!(!oracle.enforce_feed_breakers(MIN_HEALTHY_FEEDS).unwrap());
+    assert!(oracle.flags.is_emergency_mode());
+}
+
+#[test]
+fn ladder_validation() {
+    let mut oracle = oracle([0, 0]);
+
+    assert_state_error(
+        oracle.set_escalation_ladder([EMERGENCY, ARM]),
+        StateError::InvalidEscalationLadder,
+    );
+    assert_state_error(
+        oracle.set_escalation_ladder([10_001, 0]),
+        StateError::InvalidEscalationLadder,
+    );
+    assert_eq!(oracle.escalation_ladder, [0, 0]);
+
+    oracle.set_escalation_ladder([EMERGENCY, 0]).unwrap();
+    oracle.set_escalation_ladder([ARM, ARM]).unwrap();
+    assert_eq!(oracle.escalation_ladder, [ARM, ARM]);
+}
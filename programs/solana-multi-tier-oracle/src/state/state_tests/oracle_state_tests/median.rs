@@ -0,0 +1,98 @@
+//! Deterministic cross-feed median.
+//!
+//! Even counts average the two middle prices and keep the lower of their two
+//! confidence scores, independent of the slot order the feeds were registered in.
+
+use super::helpers::oracle_fixture;
+use crate::state::oracle_state::{median_reading, OracleState};
+use crate::state::price_feed::{FeedFlags, PriceFeed};
+
+const NOW: i64 = 1_700_000_000;
+
+fn fresh_feed(last_price: i128, last_conf: u64) -> PriceFeed {
+    let mut feed = PriceFeed {
+        last_price,
+        last_conf,
+        last_expo: -6,
+        last_update: NOW,
+        ..PriceFeed::default()
+    };
+    feed.flags.set(FeedFlags::ACTIVE);
+    feed
+}
+
+fn oracle_with_feeds(feeds: &[PriceFeed]) -> OracleState {
+    let mut oracle = oracle_fixture();
+    oracle.current_price.expo = -6;
+    oracle.price_feeds[..feeds.len()].copy_from_slice(feeds);
+    oracle.set_active_feed_count(feeds.len() as u8).unwrap();
+    oracle
+}
+
+#[test]
+fn even_count_with_equal_middle_prices_takes_lower_confidence() {
+    let mut readings = [(100, 5), (100, 40), (90, 1), (120, 1)];
+    assert_eq!(median_reading(&mut readings), Some((100, 5)));
+}
+
+#[test]
+fn even_count_with_unequal_middles_averages_price_and_keeps_min_conf() {
+    let mut readings = [(101, 30), (90, 1), (100, 10), (120, 1)];
+    // Mean of 100 and 101 rounds toward the lower middle
+    assert_eq!(median_reading(&mut readings), Some((100, 10)));
+
+    let mut readings = [(104, 7), (100, 12)];
+    assert_eq!(median_reading(&mut readings), Some((102, 7)));
+}
+
+#[test]
+fn slot_order_does_not_change_the_median() {
+    let forward = [
+        fresh_feed(100_000_000, 5),
+        fresh_feed(100_000_000, 40),
+        fresh_feed(90_000_000, 1),
+        fresh_feed(120_000_000, 1),
+    ];
+    let mut reversed = forward;
+    reversed.reverse();
+
+    // Every feed sits in one tier, so the blend is that tier's median unchanged
+
+    let a = oracle_with_feeds(&forward).aggregate_tiered(NOW).unwrap();
+    let b = oracle_with_feeds(&reversed).aggregate_tiered(NOW).unwrap();
+
+    assert_eq!((a.price, a.conf), (100_000_000, 5));
+    assert_eq!((b.price, b.conf), (a.price, a.conf));
+    assert_eq!(a.expo, -6);
+    assert_eq!(a.timestamp, NOW);
+}
+
+#[test]
+fn odd_count_and_empty_inputs() {
+    let mut readings = [(300, 3), (100, 1), (200, 2)];
+    assert_eq!(median_reading(&mut readings), Some((200, 2)));
+    assert_eq!(median_reading(&mut []), None);
+}
+
+#[test]
+fn extreme_middle_prices_do_not_overflow() {
+    // The true mean is -0.5, which rounds toward the lower middle
+    let mut readings = [(i128::MIN, 1), (i128::MAX, 2)];
+    assert_eq!(median_reading(&mut readings), Some((-1, 1)));
+
+    let mut readings = [(i128::MAX, 1), (i128::MAX - 1, 2)];
+    assert_eq!(median_reading(&mut readings), Some((i128::MAX - 1, 1)));
+
+    let mut readings = [(-3, 1), (4, 1)];
+    assert_eq!(median_reading(&mut readings), Some((0, 1)));
+}
+
+#[test]
+fn stale_feeds_are_left_out_of_the_median() {
+    let mut stale = fresh_feed(500_000_000, 1);
+    stale.flags.set(FeedFlags::STALE);
+    let oracle = oracle_with_feeds(&[fresh_feed(100_000_000, 3), stale]);
+
+    let median = oracle.aggregate_tiered(NOW).unwrap();
+    assert_eq!((median.price, median.conf), (100_000_000, 3));
+}
@@ -0,0 +1,44 @@
+//! Stored ring-depth floor for DEX feeds.
+//!
+//! The floor lives on the oracle rather than the update config, so an unset
+//! value must still enforce a floor and the setter must refuse a value no
+//! Raydium ring could meet.
+
+use super::helpers::oracle_fixture;
+use crate::components::raydium_clmm_observer::raydium_constants::OBSERVATION_NUM;
+use crate::error::StateError;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use crate::utils::constants::DEFAULT_MIN_OBSERVATIONS;
+
+#[test]
+fn unset_floor_reads_as_the_default() {
+    let oracle = oracle_fixture();
+
+    assert_eq!(oracle.min_observations, 0);
+    assert_eq!(oracle.observation_floor(), DEFAULT_MIN_OBSERVATIONS);
+}
+
+#[test]
+fn stored_floor_replaces_the_default() {
+    let mut oracle = oracle_fixture();
+
+    oracle.set_min_observations(40).unwrap();
+    assert_eq!(oracle.observation_floor(), 40);
+    oracle.set_min_observations(1).unwrap();
+    assert_eq!(oracle.observation_floor(), 1);
+}
+
+#[test]
+fn floor_must_fit_inside_the_observation_ring() {
+    let mut oracle = oracle_fixture();
+    oracle.set_min_observations(40).unwrap();
+
+    for count in [0, OBSERVATION_NUM as u16 + 1] {
+        assert_state_error(
+            oracle.set_min_observations(count),
+            StateError::InvalidMinObservations,
+        );
+    }
+    oracle.set_min_observations(OBSERVATION_NUM as u16).unwrap();
+    assert_eq!(oracle.observation_floor(), OBSERVATION_NUM as u16);
+}
@@ -0,0 +1,91 @@
+//! Test harness for `OracleState` behaviour beyond feed bookkeeping.
+//!
+//! Feed-level coupling lives in `price_feed_tests::oracle_state_integration`;
+//! this suite covers oracle-wide invariants enforced on the update path:
+//! - `helpers`: shared fixtures layered on top of the minimal oracle state.
+//! - `account_layout`: pinned size, alignment and field offsets of the deployed account.
+//! - `batch_initialization`: multi-asset setup validated as one all-or-nothing batch.
+//! - `chunk_links`: bounds-checked lookup and verification of linked chunk keys.
+//! - `chunk_repair`: re-derivation of scrambled chunk links into canonical order.
+//! - `consumer_scale`: checked conversion of the published price to a caller's exponent.
+//! - `chunk_pruning`: unlinking of empty chunks beyond the rotating ring.
+//! - `chunk_order`: chronological chunk permutation independent of account loads.
+//! - `data_alignment`: error instead of a panic for misaligned state buffers.
+//! - `emergency_admin_transfer`: dual-signed rotation of the emergency admin.
+//! - `expected_expo`: canonical exponent locking and mismatch rejection.
+//! - `feed_breakers`: per-feed isolation ahead of the oracle-wide breaker.
+//! - `feed_consensus`: cross-feed spread gate in front of price publication.
+//! - `feed_lookup`: explicit errors for an empty feed table versus an unknown pool.
+//! - `feed_participation`: per-update counts of aggregated and excluded feeds.
+//! - `feeds_by_type`: per-source-type iteration over a mixed feed set.
+//! - `feed_slots`: registration into free slots without trusting the active count, and the weight left after it.
+//! - `heartbeat`: liveness signalling that leaves price and history untouched.
+//! - `keeper_bounds`: rejection of aggregate prices outside a keeper's asserted range.
+//! - `kill_switch`: unilateral emergency halt by the emergency admin.
+//! - `legacy_migration`: in-place upgrade of v0.1 accounts to the current schema.
+//! - `manipulation_escalation`: severity ladder from a skipped price to an armed breaker or halt.
+//! - `manipulation_decay`: spike memory held through cooldown, then decayed.
+//! - `min_observations`: stored ring-depth floor DEX feeds must reach before they are priced.
+//! - `median`: deterministic cross-feed median and its even-count tie-break.
+//! - `maintenance_mode`: update pausing that leaves reads available.
+//! - `operational_guards`: emergency vs maintenance halt guards for mutating instructions.
+//! - `resume`: threshold-gated recovery from an emergency halt.
+//! - `relaxed_min_elapsed`: stored opt-in for pricing observation spans under `min_seconds`.
+//! - `price_age_histogram`: active feeds bucketed by time since their last refresh.
+//! - `scaled_compare`: value ordering of prices quoted at different exponents.
+//! - `source_thresholds`: per-source scaling that trips DEX feeds before oracle feeds.
+//! - `summary`: compact indexer view decoded back against the full state.
+//! - `tiered_aggregation`: per-tier medians blended where they agree with the most trusted tier.
+//! - `timestamp_guards`: distinct stale, non-monotonic and too-soon errors.
+//! - `twap_window_ceiling`: per-oracle window bound set at initialization, capped by the global maximum.
+//! - `twap_window_ramp`: gradual window changes that settle at the target.
+//! - `unpublished_price`: reads refused until the first update publishes a price.
+//! - `update_cooldown`: minimum gap between published prices.
+//! - `update_nonce`: monotonic update ordering across chunk rotation.
+//! - `updater_policy`: governance-only and allowlisted gating of `update_price`.
+//! - `validation_policy`: stored cross-validation policy that fails closed to `Both`.
+//! - `window_alignment`: strict rejection or rounding of windows off the observation cadence.
+
+pub mod account_layout;
+pub mod batch_initialization;
+pub mod chunk_links;
+pub mod chunk_order;
+pub mod chunk_pruning;
+pub mod chunk_repair;
+pub mod consumer_scale;
+pub mod data_alignment;
+pub mod emergency_admin_transfer;
+pub mod expected_expo;
+pub mod feed_breakers;
+pub mod feed_consensus;
+pub mod feed_lookup;
+pub mod feed_participation;
+pub mod feed_slots;
+pub mod feeds_by_type;
+pub mod heartbeat;
+pub mod helpers;
+pub mod keeper_bounds;
+pub mod kill_switch;
+pub mod legacy_migration;
+pub mod maintenance_mode;
+pub mod manipulation_decay;
+pub mod manipulation_escalation;
+pub mod median;
+pub mod min_observations;
+pub mod operational_guards;
+pub mod price_age_histogram;
+pub mod relaxed_min_elapsed;
+pub mod resume;
+pub mod scaled_compare;
+pub mod source_thresholds;
+pub mod summary;
+pub mod tiered_aggregation;
+pub mod timestamp_guards;
+pub mod twap_window_ceiling;
+pub mod twap_window_ramp;
+pub mod unpublished_price;
+pub mod update_cooldown;
+pub mod update_nonce;
+pub mod updater_policy;
+pub mod validation_policy;
+pub mod window_alignment;
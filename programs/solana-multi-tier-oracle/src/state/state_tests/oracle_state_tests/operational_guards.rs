@@ -0,0 +1,62 @@
+//! Shared halt guards used by every oracle-mutating instruction.
+//!
+//! Configuration instructions call `require_not_emergency` and price-path
+//! instructions call `require_operational`. The two must agree on emergency
+//! handling and differ only in whether planned maintenance blocks the caller.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::oracle_state::StateFlags;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+
+#[test]
+fn clean_oracle_passes_both_guards() {
+    let oracle = oracle_fixture();
+
+    oracle.require_not_emergency().expect("no halt flags set");
+    oracle.require_operational().expect("no halt flags set");
+}
+
+#[test]
+fn maintenance_blocks_price_path_but_not_configuration() {
+    let mut oracle = oracle_fixture();
+    oracle.flags.set(StateFlags::MAINTENANCE_MODE);
+
+    oracle
+        .require_not_emergency()
+        .expect("maintenance is the window for reconfiguration");
+    assert_state_error(oracle.require_operational(), StateError::MaintenanceActive);
+}
+
+#[test]
+fn emergency_blocks_both_guards_with_the_same_error() {
+    for also_in_maintenance in [false, true] {
+        let mut oracle = oracle_fixture();
+        oracle.flags.set(StateFlags::EMERGENCY_MODE);
+        oracle
+            .flags
+            .set_to(StateFlags::MAINTENANCE_MODE, also_in_maintenance);
+
+        assert_state_error(
+            oracle.require_not_emergency(),
+            StateError::CircuitBreakerActive,
+        );
+        assert_state_error(
+            oracle.require_operational(),
+            StateError::CircuitBreakerActive,
+        );
+    }
+}
+
+#[test]
+fn unrelated_flags_do_not_trip_the_guards() {
+    let mut oracle = oracle_fixture();
+    oracle.flags.set(StateFlags::CIRCUIT_BREAKER_ENABLED);
+    oracle.flags.set(StateFlags::UPGRADE_LOCKED);
+    oracle.flags.set(StateFlags::TWAP_ENABLED);
+
+    oracle
+        .require_not_emergency()
+        .expect("only halt flags gate");
+    oracle.require_operational().expect("only halt flags gate");
+}
@@ -0,0 +1,71 @@
+//! Feed staleness histogram surfaced through the read instruction.
+//!
+//! Every active feed lands in exactly one bucket by `now - last_update`, with
+//! bucket edges belonging to the older side.
+
+use super::helpers::oracle_fixture;
+use crate::state::oracle_state::OracleState;
+use crate::state::price_feed::PriceFeed;
+
+const NOW: i64 = 1_700_000_000;
+
+fn oracle_with_ages(ages: &[i64]) -> OracleState {
+    let mut oracle = oracle_fixture();
+    for (slot, age) in ages.iter().enumerate() {
+        oracle.price_feeds[slot] = PriceFeed {
+            last_update: NOW - age,
+            ..PriceFeed::default()
+        };
+    }
+    oracle.set_active_feed_count(ages.len() as u8).unwrap();
+    oracle
+}
+
+#[test]
+fn feeds_spread_across_every_bucket() {
+    let oracle = oracle_with_ages(&[0, 59, 60, 299, 300, 899, 900, 3_599, 3_600, 86_400]);
+
+    assert_eq!(oracle.price_age_histogram(NOW), [2, 2, 2, 2, 2]);
+}
+
+#[test]
+fn subset_going_stale_shows_up_in_older_buckets() {
+    let oracle = oracle_with_ages(&[5, 10, 12, 7_200, 10_000]);
+
+    assert_eq!(oracle.price_age_histogram(NOW), [3, 0, 0, 0, 2]);
+}
+
+#[test]
+fn only_active_feeds_are_counted() {
+    let mut oracle = oracle_with_ages(&[30, 30]);
+    // A stale slot past active_feed_count must not leak into the counts
+    oracle.price_feeds[2].last_update = NOW - 10_000;
+
+    assert_eq!(oracle.price_age_histogram(NOW), [2, 0, 0, 0, 0]);
+}
+
+#[test]
+fn future_and_never_updated_feeds_are_bucketed() {
+    // A clock-skewed feed counts as fresh; one never refreshed is maximally old
+    let oracle = oracle_with_ages(&[-120, NOW]);
+
+    assert_eq!(oracle.price_age_histogram(NOW), [1, 0, 0, 0, 1]);
+}
+
+#[test]
+fn empty_oracle_reports_all_zero() {
+    let oracle = oracle_with_ages(&[]);
+
+    assert_eq!(oracle.price_age_histogram(NOW), [0; 5]);
+}
+
+#[test]
+fn read_instruction_carries_the_histogram() {
+    let mut oracle = oracle_with_ages(&[0, 400]);
+    oracle.current_price.price = 100;
+    oracle.current_price.timestamp = NOW;
+
+    let reading = oracle.read_price_reading(NOW, 0).unwrap();
+
+    assert_eq!(reading.feed_age_histogram, [1, 0, 1, 0, 0]);
+}
@@ -0,0 +1,35 @@
+//! Stored opt-in for pricing short observation spans.
+//!
+//! The relaxation lives in the oracle's flags rather than the update config,
+//! so it must start off, survive a round trip through the raw flag word, and
+//! stay independent of the operational flags around it.
+
+use super::helpers::oracle_fixture;
+use crate::state::oracle_state::StateFlags;
+
+#[test]
+fn new_oracles_enforce_the_minimum_span() {
+    let oracle = oracle_fixture();
+    assert!(!oracle.flags.is_min_elapsed_relaxed());
+}
+
+#[test]
+fn relaxation_survives_the_raw_flag_word() {
+    let mut flags = StateFlags::new();
+    flags.set(StateFlags::RELAXED_MIN_ELAPSED);
+
+    let decoded = StateFlags::from_u32_truncate(flags.as_u32());
+    assert!(decoded.is_min_elapsed_relaxed());
+    assert!(!decoded.is_maintenance_mode());
+}
+
+#[test]
+fn clearing_the_relaxation_leaves_other_flags_set() {
+    let mut flags = StateFlags::new();
+    flags.set(StateFlags::TWAP_ENABLED);
+    flags.set(StateFlags::RELAXED_MIN_ELAPSED);
+
+    flags.set_to(StateFlags::RELAXED_MIN_ELAPSED, false);
+    assert!(!flags.is_min_elapsed_relaxed());
+    assert!(flags.is_twap_enabled());
+}
@@ -0,0 +1,117 @@
+//! Governance recovery from an emergency halt.
+//!
+//! Every halt path leaves `EMERGENCY_MODE` set; `resume_operation` is the only
+//! way back, and it takes the multisig threshold rather than one signer.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::{OracleState, StateFlags};
+use crate::state::price_feed::{FeedFlags, PriceFeed};
+use crate::state::state_tests::governance_state_tests::helpers::{
+    assert_state_error, deterministic_pubkey, governance_fixture,
+};
+use anchor_lang::prelude::Pubkey;
+
+const NOW: i64 = 1_700_000_000;
+
+/// Four members with a 2-of-N threshold where slots 0 to 2 hold `MODIFY_CONFIG`.
+fn governance() -> GovernanceState {
+    let mut state = governance_fixture(4);
+    state.multi_sig_threshold = 2;
+    state.member_permissions[0] = Permissions::ADMIN_ALL;
+    state.member_permissions[1].grant(Permissions::MODIFY_CONFIG);
+    state
+}
+
+fn quorum() -> [Pubkey; 2] {
+    [deterministic_pubkey(100), deterministic_pubkey(101)]
+}
+
+fn fresh_feed(last_price: i128) -> PriceFeed {
+    let mut feed = PriceFeed {
+        last_price,
+        last_expo: -6,
+        last_update: NOW,
+        last_good_update: NOW,
+        ..PriceFeed::default()
+    };
+    feed.flags.set(FeedFlags::ACTIVE);
+    feed
+}
+
+/// Oracle halted by the breaker tripping on two feeds 5% apart.
+fn tripped_by_disagreement() -> OracleState {
+    let mut oracle = oracle_fixture();
+    oracle.current_price.expo = -6;
+    oracle.price_feeds[0] = fresh_feed(100_000_000);
+    oracle.price_feeds[1] = fresh_feed(105_000_000);
+    oracle.set_active_feed_count(2).unwrap();
+    oracle.flags.set(StateFlags::CIRCUIT_BREAKER_ENABLED);
+    oracle.set_feed_spread(100).unwrap();
+
+    assert!(!oracle.enforce_feed_consensus(NOW).unwrap());
+    assert!(oracle.flags.is_emergency_mode());
+    oracle
+}
+
+#[test]
+fn threshold_resumes_a_tripped_oracle() {
+    let mut oracle = tripped_by_disagreement();
+    assert_state_error(
+        oracle.require_operational(),
+        StateError::CircuitBreakerActive,
+    );
+
+    oracle.resume_operation(&governance(), &quorum()).unwrap();
+
+    assert!(!oracle.flags.is_emergency_mode());
+    assert!(!oracle.is_circuit_breaker_enabled());
+    assert!(oracle.require_operational().is_ok());
+    assert!(oracle.require_not_emergency().is_ok());
+}
+
+#[test]
+fn threshold_resumes_after_the_kill_switch() {
+    let emergency_admin = Pubkey::new_unique();
+    let mut oracle = oracle_fixture();
+    oracle.emergency_admin = emergency_admin;
+    oracle.activate_kill_switch(&emergency_admin).unwrap();
+
+    oracle.resume_operation(&governance(), &quorum()).unwrap();
+
+    assert!(!oracle.flags.is_emergency_mode());
+    assert!(!oracle.is_circuit_breaker_enabled());
+}
+
+#[test]
+fn single_signer_cannot_resume() {
+    let mut oracle = tripped_by_disagreement();
+    let flags_before = oracle.flags;
+
+    for signers in [
+        vec![deterministic_pubkey(100)],
+        // Slot 3 is a member without MODIFY_CONFIG
+        vec![deterministic_pubkey(100), deterministic_pubkey(103)],
+        // The emergency admin is not a governance member
+        vec![deterministic_pubkey(100), oracle.emergency_admin],
+    ] {
+        assert_state_error(
+            oracle.resume_operation(&governance(), &signers),
+            StateError::InsufficientSigners,
+        );
+    }
+
+    assert_eq!(oracle.flags, flags_before);
+}
+
+#[test]
+fn resume_leaves_maintenance_in_place() {
+    let mut oracle = tripped_by_disagreement();
+    oracle.flags.set(StateFlags::MAINTENANCE_MODE);
+
+    oracle.resume_operation(&governance(), &quorum()).unwrap();
+
+    assert!(oracle.flags.is_maintenance_mode());
+    assert_state_error(oracle.require_operational(), StateError::MaintenanceActive);
+}
@@ -0,0 +1,81 @@
+//! Exponent-aware ordering of `PriceData`.
+//!
+//! Feeds quoting the same asset at different precisions must order by value,
+//! not by raw mantissa, and must refuse to order when rescaling overflows.
+
+use crate::error::StateError;
+use crate::state::oracle_state::PriceData;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use core::cmp::Ordering;
+
+fn price(price: i128, expo: i32) -> PriceData {
+    PriceData {
+        price,
+        expo,
+        ..PriceData::default()
+    }
+}
+
+#[test]
+fn equal_values_at_different_exponents_compare_equal() {
+    let micro = price(100, -6);
+    let centi_bps = price(1, -4);
+
+    assert_eq!(micro.cmp_scaled(&centi_bps).unwrap(), Ordering::Equal);
+    assert_eq!(centi_bps.cmp_scaled(&micro).unwrap(), Ordering::Equal);
+}
+
+#[test]
+fn ordering_follows_value_not_mantissa() {
+    // 150e-6 is larger than 1e-4 even though its exponent is finer
+    let larger = price(150, -6);
+    let smaller = price(1, -4);
+
+    assert_eq!(larger.cmp_scaled(&smaller).unwrap(), Ordering::Greater);
+    assert_eq!(smaller.cmp_scaled(&larger).unwrap(), Ordering::Less);
+
+    // Negative prices keep their sign through rescaling
+    assert_eq!(
+        price(-2, -4).cmp_scaled(&price(-150, -6)).unwrap(),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn confidence_and_timestamp_do_not_affect_ordering() {
+    let mut a = price(5, -2);
+    let mut b = price(50, -3);
+    a.conf = 1;
+    b.conf = 1_000;
+    a.timestamp = 1;
+    b.timestamp = 2;
+
+    assert_eq!(a.cmp_scaled(&b).unwrap(), Ordering::Equal);
+}
+
+#[test]
+fn heterogeneous_feeds_sort_by_value() {
+    let mut readings = [price(3, -2), price(1_000, -6), price(20_000, -5)];
+    readings.sort_by(|a, b| a.cmp_scaled(b).unwrap());
+
+    let values: Vec<(i128, i32)> = readings.iter().map(|p| (p.price, p.expo)).collect();
+    assert_eq!(values, [(1_000, -6), (3, -2), (20_000, -5)]);
+}
+
+#[test]
+fn overflow_while_rescaling_is_an_error() {
+    // i128::MAX at expo 0 cannot be expressed in units of 10^-2
+    assert_state_error(
+        price(i128::MAX, 0).cmp_scaled(&price(1, -2)).map(|_| ()),
+        StateError::MathOverflow,
+    );
+    assert_state_error(
+        price(1, -2).cmp_scaled(&price(i128::MIN, 0)).map(|_| ()),
+        StateError::MathOverflow,
+    );
+    // An exponent gap wider than i128 can span overflows even for small prices
+    assert_state_error(
+        price(1, 0).cmp_scaled(&price(1, -40)).map(|_| ()),
+        StateError::MathOverflow,
+    );
+}
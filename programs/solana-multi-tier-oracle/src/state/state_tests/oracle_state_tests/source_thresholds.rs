@@ -0,0 +1,143 @@
+//! Per-source scaling of the manipulation threshold.
+//!
+//! The same raw score must trip a DEX feed while an external oracle feed keeps
+//! contributing, and an account that never configured scaling must behave
+//! exactly as before the knob existed.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::oracle_state::{OracleState, StateFlags};
+use crate::state::price_feed::{FeedFlags, PriceFeed, SourceType};
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use crate::utils::constants::{MAX_SOURCE_THRESHOLD_BPS, MIN_HEALTHY_FEEDS};
+
+const NOW: i64 = 1_700_000_000;
+const THRESHOLD: u16 = 5_000;
+const SCORE: u16 = 3_000;
+
+// DEX at half the base threshold, everything else unscaled
+const DEX_STRICT: [u16; 4] = [5_000, 10_000, 10_000, 10_000];
+
+fn feed(source_type: SourceType, manipulation_score: u16) -> PriceFeed {
+    let mut feed = PriceFeed {
+        last_price: 100_000_000,
+        last_expo: -6,
+        last_update: NOW,
+        weight: 3_000,
+        manipulation_score,
+        ..PriceFeed::default()
+    };
+    feed.set_source_type(source_type);
+    feed.flags.set(FeedFlags::ACTIVE);
+    feed
+}
+
+fn oracle_with_feeds(feeds: &[PriceFeed]) -> OracleState {
+    let mut oracle = oracle_fixture();
+    oracle.manipulation_threshold = THRESHOLD;
+    oracle.flags.set(StateFlags::CIRCUIT_BREAKER_ENABLED);
+    oracle.price_feeds[..feeds.len()].copy_from_slice(feeds);
+    oracle.set_active_feed_count(feeds.len() as u8).unwrap();
+    oracle
+}
+
+#[test]
+fn unset_scaling_keeps_the_base_threshold_for_every_source() {
+    let oracle = oracle_with_feeds(&[]);
+
+    for source in [
+        SourceType::DEX,
+        SourceType::CEX,
+        SourceType::Oracle,
+        SourceType::Aggregator,
+    ] {
+        assert_eq!(oracle.manipulation_threshold_for(source.as_u8()), THRESHOLD);
+    }
+}
+
+#[test]
+fn scaling_rounds_down_to_the_stricter_threshold() {
+    let mut oracle = oracle_with_feeds(&[]);
+    oracle.manipulation_threshold = 3;
+    oracle.set_source_thresholds([5_000; 4]).unwrap();
+
+    assert_eq!(
+        oracle.manipulation_threshold_for(SourceType::DEX.as_u8()),
+        1
+    );
+}
+
+#[test]
+fn unknown_source_is_held_to_the_dex_threshold() {
+    let mut oracle = oracle_with_feeds(&[]);
+    oracle.set_source_thresholds(DEX_STRICT).unwrap();
+
+    assert_eq!(oracle.manipulation_threshold_for(u8::MAX), 2_500);
+}
+
+#[test]
+fn identical_scores_fail_resistance_for_dex_only() {
+    let mut dex_oracle = oracle_with_feeds(&[feed(SourceType::DEX, SCORE)]);
+    let mut oracle_feed_oracle = oracle_with_feeds(&[feed(SourceType::Oracle, SCORE)]);
+
+    // Below the base threshold both pass before scaling is configured
+    assert!(dex_oracle.check_manipulation_resistance().is_ok());
+
+    dex_oracle.set_source_thresholds(DEX_STRICT).unwrap();
+    oracle_feed_oracle
+        .set_source_thresholds(DEX_STRICT)
+        .unwrap();
+
+    assert_state_error(
+        dex_oracle.check_manipulation_resistance(),
+        StateError::ManipulationDetected,
+    );
+    assert!(oracle_feed_oracle.check_manipulation_resistance().is_ok());
+}
+
+#[test]
+fn identical_scores_trip_only_the_dex_breaker() {
+    let mut oracle = oracle_with_feeds(&[
+        feed(SourceType::DEX, SCORE),
+        feed(SourceType::Oracle, SCORE),
+    ]);
+    oracle.set_source_thresholds(DEX_STRICT).unwrap();
+
+    assert!(oracle.enforce_feed_breakers(MIN_HEALTHY_FEEDS).unwrap());
+
+    let tripped: Vec<bool> = oracle
+        .active_feeds()
+        .iter()
+        .map(|feed| feed.flags.is_breaker_tripped())
+        .collect();
+    assert_eq!(tripped, [true, false]);
+}
+
+#[test]
+fn identical_scores_exclude_only_the_dex_feed_from_aggregation() {
+    let mut oracle = oracle_with_feeds(&[
+        feed(SourceType::DEX, SCORE),
+        feed(SourceType::Oracle, SCORE),
+    ]);
+    oracle.set_source_thresholds(DEX_STRICT).unwrap();
+
+    let participation = oracle.feed_participation(NOW);
+    assert_eq!(participation.aggregated, 1);
+    assert_eq!(participation.excluded_manipulated, 1);
+    assert_eq!(participation.sources_mask, 0b10);
+}
+
+#[test]
+fn zero_or_loosening_scale_is_rejected() {
+    let mut oracle = oracle_with_feeds(&[]);
+
+    assert_state_error(
+        oracle.set_source_thresholds([0, 10_000, 10_000, 10_000]),
+        StateError::InvalidSourceThreshold,
+    );
+    assert_state_error(
+        oracle.set_source_thresholds([10_000, MAX_SOURCE_THRESHOLD_BPS + 1, 10_000, 10_000]),
+        StateError::InvalidSourceThreshold,
+    );
+    assert_eq!(oracle.source_threshold_bps, [0; 4]);
+}
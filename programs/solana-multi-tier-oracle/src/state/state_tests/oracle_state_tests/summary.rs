@@ -0,0 +1,66 @@
+//! Compact indexer summary of an oracle.
+//!
+//! The summary travels as Borsh return data, so these tests decode the
+//! serialized bytes rather than inspecting the struct, and compare every field
+//! with the full state it was taken from.
+
+use super::helpers::oracle_fixture;
+use crate::state::oracle_state::{OracleState, OracleSummary, StateFlags};
+use crate::state::state_tests::governance_state_tests::helpers::deterministic_pubkey;
+use anchor_lang::prelude::*;
+
+const NOW: i64 = 1_700_000_000;
+
+fn decoded_summary(oracle: &OracleState) -> OracleSummary {
+    let bytes = oracle.to_summary().try_to_vec().unwrap();
+    OracleSummary::try_from_slice(&bytes).unwrap()
+}
+
+fn assert_matches_state(summary: &OracleSummary, oracle: &OracleState) {
+    assert_eq!(summary.authority, oracle.authority);
+    assert_eq!(summary.current_price.price, oracle.current_price.price);
+    assert_eq!(summary.current_price.conf, oracle.current_price.conf);
+    assert_eq!(
+        summary.current_price.timestamp,
+        oracle.current_price.timestamp
+    );
+    assert_eq!(summary.current_price.expo, oracle.current_price.expo);
+    assert_eq!(summary.last_update, oracle.last_update);
+    assert_eq!(summary.active_feed_count, oracle.active_feed_count);
+    assert_eq!(summary.flags, oracle.flags);
+    assert_eq!(summary.weight_headroom, oracle.weight_headroom());
+}
+
+#[test]
+fn decoded_summary_matches_the_full_state() {
+    let mut oracle = oracle_fixture();
+    oracle.authority = deterministic_pubkey(7);
+    oracle.current_price.price = 150_017_226;
+    oracle.current_price.conf = 9_200;
+    oracle.current_price.timestamp = NOW;
+    oracle.current_price.expo = -6;
+    oracle.last_update = NOW;
+    oracle.active_feed_count = 3;
+    oracle.flags.set(StateFlags::CIRCUIT_BREAKER_ENABLED);
+
+    assert_matches_state(&decoded_summary(&oracle), &oracle);
+}
+
+#[test]
+fn halted_or_unpublished_oracle_is_summarized_as_stored() {
+    let mut oracle = oracle_fixture();
+    oracle.flags.set(StateFlags::EMERGENCY_MODE);
+
+    let summary = decoded_summary(&oracle);
+
+    assert_matches_state(&summary, &oracle);
+    assert!(summary.flags.is_emergency_mode());
+    assert!(!summary.current_price.is_initialized());
+}
+
+#[test]
+fn summary_is_a_fraction_of_the_account() {
+    let bytes = oracle_fixture().to_summary().try_to_vec().unwrap();
+
+    assert!(bytes.len() < core::mem::size_of::<OracleState>() / 10);
+}
@@ -0,0 +1,214 @@
+//! Tiered aggregation across Oracle, CEX and DEX feeds.
+//!
+//! Each tier is priced from its own feeds first; the tiers are then blended
+//! only where they agree with the most trusted tier present. Prices are quoted
+//! at `expo = -6`, so `100_000_000` is 100.00.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::instructions::register_price_feed::{create_price_feed, PriceFeedConfig};
+use crate::state::oracle_state::{OracleState, PriceTier};
+use crate::state::price_feed::{FeedFlags, PriceFeed, SourceType};
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use crate::utils::constants::MAX_EXTERNAL_STALENESS;
+use anchor_lang::prelude::Pubkey;
+
+const NOW: i64 = 1_700_000_000;
+const WEIGHTS: [u16; 3] = [5_000, 3_000, 2_000]; // Oracle, CEX, DEX
+const SPREAD_BPS: u16 = 100; // 1%
+
+fn tier_feed(source_type: SourceType, last_price: i128, last_conf: u64) -> PriceFeed {
+    let mut feed = PriceFeed {
+        last_price,
+        last_conf,
+        last_expo: -6,
+        last_update: NOW,
+        ..PriceFeed::default()
+    };
+    feed.set_source_type(source_type);
+    feed.flags.set(FeedFlags::ACTIVE);
+    feed
+}
+
+fn oracle_with_feeds(feeds: &[PriceFeed]) -> OracleState {
+    let mut oracle = oracle_fixture();
+    oracle.current_price.expo = -6;
+    oracle.price_feeds[..feeds.len()].copy_from_slice(feeds);
+    oracle.set_active_feed_count(feeds.len() as u8).unwrap();
+    oracle
+}
+
+fn configured(feeds: &[PriceFeed]) -> OracleState {
+    let mut oracle = oracle_with_feeds(feeds);
+    oracle.set_tier_config(WEIGHTS, SPREAD_BPS).unwrap();
+    oracle
+}
+
+#[test]
+fn agreeing_tiers_blend_by_weight() {
+    let oracle = configured(&[
+        tier_feed(SourceType::DEX, 100_400_000, 3_000),
+        tier_feed(SourceType::Oracle, 100_000_000, 1_000),
+        tier_feed(SourceType::CEX, 100_200_000, 2_000),
+    ]);
+
+    let price = oracle.aggregate_tiered(NOW).unwrap();
+
+    // 0.5 * 100.00 + 0.3 * 100.20 + 0.2 * 100.40
+    assert_eq!(price.price, 100_140_000);
+    assert_eq!(price.conf, 1_700);
+    assert_eq!(price.timestamp, NOW);
+    assert_eq!(price.expo, -6);
+}
+
+#[test]
+fn unset_weights_blend_agreeing_tiers_equally() {
+    let oracle = oracle_with_feeds(&[
+        tier_feed(SourceType::Oracle, 100_000_000, 1_000),
+        tier_feed(SourceType::CEX, 100_300_000, 1_000),
+        tier_feed(SourceType::DEX, 100_600_000, 4_000),
+    ]);
+
+    let price = oracle.aggregate_tiered(NOW).unwrap();
+
+    // Within the default 5% bound, so all three count
+    assert_eq!(price.price, 100_300_000);
+    assert_eq!(price.conf, 2_000);
+}
+
+#[test]
+fn disagreeing_tier_is_left_out_of_the_blend() {
+    let oracle = configured(&[
+        tier_feed(SourceType::Oracle, 100_000_000, 1_000),
+        tier_feed(SourceType::CEX, 100_500_000, 1_000),
+        // A pool pushed 10% off the rest of the market
+        tier_feed(SourceType::DEX, 110_000_000, 1_000),
+    ]);
+
+    let price = oracle.aggregate_tiered(NOW).unwrap();
+
+    // (0.5 * 100.00 + 0.3 * 100.50) / 0.8
+    assert_eq!(price.price, 100_187_500);
+}
+
+#[test]
+fn falls_back_to_the_trusted_tier_when_all_others_disagree() {
+    let oracle = configured(&[
+        tier_feed(SourceType::Oracle, 100_000_000, 1_000),
+        tier_feed(SourceType::CEX, 90_000_000, 5_000),
+        tier_feed(SourceType::DEX, 110_000_000, 5_000),
+    ]);
+
+    let price = oracle.aggregate_tiered(NOW).unwrap();
+
+    assert_eq!(price.price, 100_000_000);
+    assert_eq!(price.conf, 1_000);
+}
+
+#[test]
+fn cex_is_trusted_when_no_oracle_feed_is_fresh() {
+    let mut stale_oracle = tier_feed(SourceType::Oracle, 50_000_000, 1_000);
+    stale_oracle.last_update = NOW - MAX_EXTERNAL_STALENESS as i64 - 1;
+    let oracle = configured(&[
+        stale_oracle,
+        tier_feed(SourceType::CEX, 100_000_000, 1_000),
+        tier_feed(SourceType::DEX, 120_000_000, 1_000),
+    ]);
+
+    let price = oracle.aggregate_tiered(NOW).unwrap();
+
+    assert_eq!(price.price, 100_000_000);
+}
+
+#[test]
+fn zero_weight_tier_checks_but_does_not_move_the_blend() {
+    let mut oracle = oracle_with_feeds(&[
+        tier_feed(SourceType::Oracle, 100_000_000, 1_000),
+        tier_feed(SourceType::CEX, 100_400_000, 1_000),
+        tier_feed(SourceType::DEX, 100_800_000, 1_000),
+    ]);
+    oracle
+        .set_tier_config([0, 5_000, 5_000], SPREAD_BPS)
+        .unwrap();
+    assert_eq!(oracle.aggregate_tiered(NOW).unwrap().price, 100_600_000);
+
+    // Once the weighted tiers stray, only the unweighted reference is left
+    oracle.price_feeds[1].last_price = 102_000_000;
+    oracle.price_feeds[2].last_price = 98_000_000;
+    assert_eq!(oracle.aggregate_tiered(NOW).unwrap().price, 100_000_000);
+}
+
+#[test]
+fn each_tier_is_priced_from_the_median_of_its_feeds() {
+    let oracle = oracle_with_feeds(&[
+        tier_feed(SourceType::DEX, 100_000_000, 1_000),
+        tier_feed(SourceType::DEX, 150_000_000, 1_000),
+        tier_feed(SourceType::Aggregator, 100_200_000, 1_000),
+        tier_feed(SourceType::CEX, 100_100_000, 2_000),
+    ]);
+
+    let tiers = oracle.tier_prices(NOW);
+
+    assert_eq!(tiers[PriceTier::Oracle as usize], None);
+    assert_eq!(tiers[PriceTier::Cex as usize], Some((100_100_000, 2_000)));
+    // The aggregator joins the pools, and the median shrugs off the outlier
+    assert_eq!(tiers[PriceTier::Dex as usize], Some((100_200_000, 1_000)));
+}
+
+#[test]
+fn never_read_tier_does_not_become_the_reference() {
+    let mut oracle = configured(&[tier_feed(SourceType::DEX, 100_000_000, 2_000)]);
+    let placeholder = create_price_feed(
+        &PriceFeedConfig {
+            source_address: Pubkey::new_unique(),
+            source_type: SourceType::Oracle,
+            weight: 1_000,
+            min_liquidity: 0,
+            staleness_threshold: 300,
+            expo: -6,
+            normalize: false,
+            asset_seed: [0; 32],
+        },
+        NOW,
+    );
+    oracle.claim_feed_slot(placeholder).unwrap();
+
+    // The Oracle tier outranks DEX, but its feed has never produced a price
+    assert_eq!(oracle.tier_prices(NOW)[PriceTier::Oracle as usize], None);
+    assert_eq!(oracle.aggregate_tiered(NOW).unwrap().price, 100_000_000);
+}
+
+#[test]
+fn no_fresh_feed_is_an_error() {
+    let mut feed = tier_feed(SourceType::Oracle, 100_000_000, 1_000);
+    feed.flags.set(FeedFlags::STALE);
+    let oracle = oracle_with_feeds(&[feed]);
+
+    assert_state_error(
+        oracle.aggregate_tiered(NOW).map(|_| ()),
+        StateError::NoActiveFeeds,
+    );
+}
+
+#[test]
+fn tier_config_must_be_a_full_weight_split_with_a_bounded_spread() {
+    let mut oracle = oracle_fixture();
+
+    assert_state_error(
+        oracle.set_tier_config([5_000, 3_000, 1_000], SPREAD_BPS),
+        StateError::InvalidTierConfig,
+    );
+    assert_state_error(
+        oracle.set_tier_config(WEIGHTS, 0),
+        StateError::InvalidTierConfig,
+    );
+    assert_state_error(
+        oracle.set_tier_config(WEIGHTS, 10_001),
+        StateError::InvalidTierConfig,
+    );
+    assert_eq!(oracle.tier_weights_bps, [0; 3]);
+
+    oracle.set_tier_config(WEIGHTS, 10_000).unwrap();
+    assert_eq!(oracle.tier_weights_bps, WEIGHTS);
+    assert_eq!(oracle.max_tier_spread_bps, 10_000);
+}
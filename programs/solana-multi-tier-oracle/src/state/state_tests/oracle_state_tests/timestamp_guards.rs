@@ -0,0 +1,78 @@
+//! Distinct errors for the read and update timing guards.
+//!
+//! Staleness, a clock running backwards and rate limiting are different
+//! failures; each guard must report its own `StateError` variant.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::oracle_state::OracleState;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+
+const PUBLISHED_AT: i64 = 1_700_000_000;
+const MAX_AGE: u32 = 60;
+const COOLDOWN: u32 = 30;
+
+fn published_oracle() -> OracleState {
+    let mut oracle = oracle_fixture();
+    oracle.current_price.price = 1_000;
+    oracle.current_price.timestamp = PUBLISHED_AT;
+    oracle.update_cooldown = COOLDOWN;
+    oracle
+}
+
+#[test]
+fn read_past_max_age_is_stale_price() {
+    let oracle = published_oracle();
+
+    assert_state_error(
+        oracle
+            .read_fresh_price(PUBLISHED_AT + MAX_AGE as i64 + 1, MAX_AGE)
+            .map(|_| ()),
+        StateError::StalePrice,
+    );
+    assert_eq!(
+        oracle
+            .read_fresh_price(PUBLISHED_AT + MAX_AGE as i64, MAX_AGE)
+            .unwrap()
+            .price,
+        1_000
+    );
+}
+
+#[test]
+fn zero_max_age_disables_staleness_bound() {
+    let oracle = published_oracle();
+    assert!(oracle.read_fresh_price(PUBLISHED_AT + 86_400, 0).is_ok());
+}
+
+#[test]
+fn read_of_future_price_is_non_monotonic() {
+    let oracle = published_oracle();
+
+    // Reported as a clock problem even though no staleness bound applies
+    assert_state_error(
+        oracle.read_fresh_price(PUBLISHED_AT - 1, 0).map(|_| ()),
+        StateError::NonMonotonicTimestamps,
+    );
+}
+
+#[test]
+fn update_behind_published_price_is_non_monotonic() {
+    let oracle = published_oracle();
+
+    assert_state_error(
+        oracle.check_update_timestamp(PUBLISHED_AT - 1),
+        StateError::NonMonotonicTimestamps,
+    );
+    assert!(oracle.check_update_timestamp(PUBLISHED_AT).is_ok());
+}
+
+#[test]
+fn update_inside_cooldown_is_update_too_soon() {
+    let oracle = published_oracle();
+    let now = PUBLISHED_AT + COOLDOWN as i64 - 1;
+
+    // The clock is monotonic here, so only the cooldown guard fires
+    assert!(oracle.check_update_timestamp(now).is_ok());
+    assert_state_error(oracle.check_update_cooldown(now), StateError::UpdateTooSoon);
+}
@@ -0,0 +1,49 @@
+//! Per-oracle TWAP window ceilings.
+//!
+//! `update_price` and `ramp_twap_window` check every window through
+//! `check_twap_window`, so these tests pin how the override set at
+//! initialization combines with the program-wide `MAX_TWAP_WINDOW`.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use crate::utils::constants::MAX_TWAP_WINDOW;
+
+const DAY: u32 = 86_400;
+
+#[test]
+fn unset_override_defers_to_the_global_cap() {
+    let oracle = oracle_fixture();
+
+    assert_eq!(oracle.twap_window_ceiling(), MAX_TWAP_WINDOW);
+    assert!(oracle.check_twap_window(MAX_TWAP_WINDOW).is_ok());
+    assert_state_error(
+        oracle.check_twap_window(MAX_TWAP_WINDOW + 1),
+        StateError::InvalidTWAPWindow,
+    );
+}
+
+#[test]
+fn lower_ceiling_rejects_a_window_the_global_cap_allows() {
+    let mut oracle = oracle_fixture();
+    oracle.max_twap_window_override = DAY;
+
+    assert!(oracle.check_twap_window(DAY).is_ok());
+    assert!(2 * DAY <= MAX_TWAP_WINDOW);
+    assert_state_error(
+        oracle.check_twap_window(2 * DAY),
+        StateError::InvalidTWAPWindow,
+    );
+}
+
+#[test]
+fn override_cannot_raise_the_global_cap() {
+    let mut oracle = oracle_fixture();
+    oracle.max_twap_window_override = MAX_TWAP_WINDOW * 2;
+
+    assert_eq!(oracle.twap_window_ceiling(), MAX_TWAP_WINDOW);
+    assert_state_error(
+        oracle.check_twap_window(MAX_TWAP_WINDOW + 1),
+        StateError::InvalidTWAPWindow,
+    );
+}
@@ -0,0 +1,97 @@
+//! Gradual TWAP window changes.
+//!
+//! A ramp interpolates the window in force from `twap_window` to the target;
+//! the published price therefore never sees the whole window change at once.
+
+use super::helpers::oracle_fixture;
+use crate::state::oracle_state::OracleState;
+
+const STARTED_AT: i64 = 1_700_000_000;
+const FROM_WINDOW: u32 = 1_800;
+const TARGET_WINDOW: u32 = 7_200;
+const RAMP: u32 = 3_600;
+const CADENCE: u32 = 15;
+
+fn ramping_oracle() -> OracleState {
+    let mut oracle = oracle_fixture();
+    oracle.twap_window = FROM_WINDOW;
+    oracle.start_twap_ramp(TARGET_WINDOW, RAMP, STARTED_AT, CADENCE);
+    oracle
+}
+
+#[test]
+fn effective_window_moves_gradually_towards_target() {
+    let oracle = ramping_oracle();
+
+    assert_eq!(oracle.effective_twap_window(STARTED_AT), FROM_WINDOW);
+
+    let mut previous = FROM_WINDOW;
+    for step in 1..=4 {
+        let window = oracle.effective_twap_window(STARTED_AT + step * RAMP as i64 / 4);
+        assert!(window > previous, "step {step}: {window} <= {previous}");
+        // No single quarter of the ramp moves more than a quarter of the change
+        assert!(window - previous <= (TARGET_WINDOW - FROM_WINDOW) / 4);
+        previous = window;
+    }
+
+    assert_eq!(
+        oracle.effective_twap_window(STARTED_AT + RAMP as i64 / 2),
+        (FROM_WINDOW + TARGET_WINDOW) / 2
+    );
+}
+
+#[test]
+fn ramp_settles_at_target() {
+    let mut oracle = ramping_oracle();
+    let finished = STARTED_AT + RAMP as i64;
+
+    assert!(!oracle.settle_twap_ramp(finished - 1));
+    assert_eq!(oracle.twap_window, FROM_WINDOW);
+
+    assert_eq!(oracle.effective_twap_window(finished), TARGET_WINDOW);
+    assert!(oracle.settle_twap_ramp(finished));
+    assert_eq!(oracle.twap_window, TARGET_WINDOW);
+    assert_eq!(oracle.twap_ramp_started_at, 0);
+
+    // Settled oracles stay put
+    assert_eq!(
+        oracle.effective_twap_window(finished + 10 * RAMP as i64),
+        TARGET_WINDOW
+    );
+    assert!(!oracle.settle_twap_ramp(finished + 1));
+}
+
+#[test]
+fn shrinking_ramp_decreases_monotonically() {
+    let mut oracle = oracle_fixture();
+    oracle.twap_window = TARGET_WINDOW;
+    oracle.start_twap_ramp(FROM_WINDOW, RAMP, STARTED_AT, CADENCE);
+
+    let quarter = oracle.effective_twap_window(STARTED_AT + RAMP as i64 / 4);
+    let half = oracle.effective_twap_window(STARTED_AT + RAMP as i64 / 2);
+    assert!(TARGET_WINDOW > quarter && quarter > half && half > FROM_WINDOW);
+}
+
+#[test]
+fn retargeting_mid_ramp_starts_from_window_in_force() {
+    let mut oracle = ramping_oracle();
+    let midway = STARTED_AT + RAMP as i64 / 3;
+    let in_force = oracle.effective_twap_window(midway);
+
+    let from = oracle.start_twap_ramp(FROM_WINDOW, RAMP, midway, CADENCE);
+
+    assert_eq!(from, in_force - in_force % CADENCE);
+    assert_eq!(from % CADENCE, 0);
+    assert_eq!(oracle.effective_twap_window(midway), from);
+}
+
+#[test]
+fn zero_duration_switches_immediately() {
+    let mut oracle = oracle_fixture();
+    oracle.twap_window = FROM_WINDOW;
+    oracle.start_twap_ramp(TARGET_WINDOW, 0, STARTED_AT, CADENCE);
+
+    assert_eq!(oracle.twap_window, TARGET_WINDOW);
+    assert_eq!(oracle.effective_twap_window(STARTED_AT), TARGET_WINDOW);
+    assert!(!oracle.settle_twap_ramp(STARTED_AT));
+}
@@ -0,0 +1,85 @@
+//! Reads before the first published price.
+//!
+//! A new oracle's `current_price` is zeroed. Every read path must refuse it
+//! with `PriceNotYetAvailable` instead of returning a zero price stamped at the
+//! epoch, and must serve the price normally once one has been published.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::oracle_state::{PriceData, StateFlags};
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+
+const NOW: i64 = 1_700_000_000;
+
+#[test]
+fn default_price_is_not_initialized() {
+    assert!(!PriceData::default().is_initialized());
+
+    let stamped_zero = PriceData {
+        timestamp: NOW,
+        ..PriceData::default()
+    };
+    assert!(!stamped_zero.is_initialized());
+
+    let unstamped = PriceData {
+        price: 1_000,
+        ..PriceData::default()
+    };
+    assert!(!unstamped.is_initialized());
+}
+
+#[test]
+fn reads_before_first_update_are_refused() {
+    let oracle = oracle_fixture();
+
+    assert_state_error(
+        oracle.read_price().map(|_| ()),
+        StateError::PriceNotYetAvailable,
+    );
+    assert_state_error(
+        oracle.read_fresh_price(NOW, 0).map(|_| ()),
+        StateError::PriceNotYetAvailable,
+    );
+    assert_state_error(
+        oracle.read_price_reading(NOW, 60).map(|_| ()),
+        StateError::PriceNotYetAvailable,
+    );
+}
+
+#[test]
+fn reads_after_first_update_return_the_price() {
+    let mut oracle = oracle_fixture();
+    oracle.current_price = PriceData {
+        price: 1_234_567,
+        conf: 50,
+        timestamp: NOW,
+        expo: -6,
+        _padding: [0; 12],
+    };
+
+    assert!(oracle.current_price.is_initialized());
+    let reading = oracle.read_price_reading(NOW + 10, 60).unwrap();
+    assert_eq!(reading.price.price, 1_234_567);
+    assert_eq!(reading.price.timestamp, NOW);
+}
+
+#[test]
+fn negative_published_price_is_initialized() {
+    // Spreads can be negative; only zero marks the placeholder
+    let mut oracle = oracle_fixture();
+    oracle.current_price.price = -25;
+    oracle.current_price.timestamp = NOW;
+
+    assert_eq!(oracle.read_price().unwrap().price, -25);
+}
+
+#[test]
+fn emergency_takes_precedence_over_missing_price() {
+    let mut oracle = oracle_fixture();
+    oracle.flags.set(StateFlags::EMERGENCY_MODE);
+
+    assert_state_error(
+        oracle.read_price().map(|_| ()),
+        StateError::CircuitBreakerActive,
+    );
+}
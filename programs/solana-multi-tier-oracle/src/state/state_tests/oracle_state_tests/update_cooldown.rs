@@ -0,0 +1,80 @@
+//! Per-oracle rate limit on price publication.
+//!
+//! The cooldown runs from the last published price, so heartbeats, which only
+//! touch `last_update`, neither consume nor reset it.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::governance_state::Permissions;
+use crate::state::oracle_state::OracleState;
+use crate::state::state_tests::governance_state_tests::helpers::{
+    assert_state_error, governance_fixture,
+};
+use anchor_lang::prelude::Pubkey;
+
+const PUBLISHED_AT: i64 = 1_700_000_000;
+const COOLDOWN: u32 = 120;
+
+fn oracle_with_cooldown(cooldown: u32) -> OracleState {
+    let mut oracle = oracle_fixture();
+    oracle.update_cooldown = cooldown;
+    oracle.current_price.timestamp = PUBLISHED_AT;
+    oracle.last_update = PUBLISHED_AT;
+    oracle
+}
+
+#[test]
+fn update_inside_cooldown_is_rejected() {
+    let oracle = oracle_with_cooldown(COOLDOWN);
+
+    assert_state_error(
+        oracle.check_update_cooldown(PUBLISHED_AT + COOLDOWN as i64 - 1),
+        StateError::UpdateTooSoon,
+    );
+}
+
+#[test]
+fn update_after_cooldown_succeeds() {
+    let oracle = oracle_with_cooldown(COOLDOWN);
+
+    assert!(oracle
+        .check_update_cooldown(PUBLISHED_AT + COOLDOWN as i64)
+        .is_ok());
+}
+
+#[test]
+fn zero_cooldown_allows_back_to_back_updates() {
+    let oracle = oracle_with_cooldown(0);
+    assert!(oracle.check_update_cooldown(PUBLISHED_AT).is_ok());
+}
+
+#[test]
+fn heartbeat_does_not_reset_the_cooldown() {
+    let mut oracle = oracle_with_cooldown(COOLDOWN);
+    oracle
+        .record_heartbeat(PUBLISHED_AT + 100, 60)
+        .expect("heartbeat accepted");
+
+    assert!(oracle
+        .check_update_cooldown(PUBLISHED_AT + COOLDOWN as i64)
+        .is_ok());
+}
+
+#[test]
+fn only_full_admins_may_force_through_the_cooldown() {
+    let mut governance = governance_fixture(2);
+    governance.member_permissions[0] = Permissions::ADMIN_ALL;
+    governance.member_permissions[1] = Permissions::OPERATOR_ALL;
+
+    assert!(governance
+        .check_admin(&governance.multisig_members[0])
+        .is_ok());
+    assert_state_error(
+        governance.check_admin(&governance.multisig_members[1]),
+        StateError::InsufficientPermissions,
+    );
+    assert_state_error(
+        governance.check_admin(&Pubkey::new_unique()),
+        StateError::UnauthorizedCaller,
+    );
+}
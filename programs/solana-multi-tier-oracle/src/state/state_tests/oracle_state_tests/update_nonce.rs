@@ -0,0 +1,45 @@
+//! Monotonic update nonce.
+//!
+//! Consumers order `PriceUpdated` events by nonce when timestamps collide, so
+//! the counter must only ever move forward, including across chunk rotation.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+
+#[test]
+fn consecutive_updates_produce_strictly_increasing_nonces() {
+    let mut oracle = oracle_fixture();
+    assert_eq!(oracle.update_nonce, 0);
+
+    let first = oracle.advance_update_nonce().unwrap();
+    let second = oracle.advance_update_nonce().unwrap();
+
+    assert_eq!(first, 1);
+    assert!(second > first, "nonce must strictly increase");
+    assert_eq!(oracle.update_nonce, second);
+}
+
+#[test]
+fn nonce_survives_chunk_rotation() {
+    let mut oracle = oracle_fixture();
+    let before = oracle.advance_update_nonce().unwrap();
+
+    // Rotation only moves the active chunk pointer; the nonce is oracle-wide.
+    oracle.current_chunk_index = (oracle.current_chunk_index + 1) % 3;
+    let after = oracle.advance_update_nonce().unwrap();
+
+    assert_eq!(after, before + 1);
+}
+
+#[test]
+fn nonce_overflow_is_rejected_without_wrapping() {
+    let mut oracle = oracle_fixture();
+    oracle.update_nonce = u64::MAX;
+
+    assert_state_error(
+        oracle.advance_update_nonce().map(|_| ()),
+        StateError::MathOverflow,
+    );
+    assert_eq!(oracle.update_nonce, u64::MAX);
+}
@@ -0,0 +1,206 @@
+//! Caller gating of `update_price` under each updater policy.
+//!
+//! Governance keepers holding `UPDATE_PRICE` pass under every policy; listed
+//! keys pass only under `Allowlist`, and no policy admits an arbitrary signer.
+//! The allowlist itself is replaced wholesale and validated before anything is
+//! written.
+
+use super::helpers::oracle_fixture;
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::oracle_state::{OracleState, UpdaterPolicy};
+use crate::state::state_tests::governance_state_tests::helpers::{
+    assert_state_error, governance_fixture,
+};
+use crate::utils::constants::MAX_ALLOWED_UPDATERS;
+use anchor_lang::prelude::Pubkey;
+
+struct Fixture {
+    oracle: OracleState,
+    governance: GovernanceState,
+    keeper: Pubkey,
+    viewer: Pubkey,
+}
+
+/// Member 0 keeps prices; member 1 only holds `VIEW_METRICS`.
+fn fixture() -> Fixture {
+    let mut governance = governance_fixture(2);
+    governance.member_permissions[0] = Permissions::UPDATE_PRICE;
+    governance.member_permissions[1] = Permissions::VIEW_METRICS;
+
+    Fixture {
+        oracle: oracle_fixture(),
+        keeper: governance.multisig_members[0],
+        viewer: governance.multisig_members[1],
+        governance,
+    }
+}
+
+#[test]
+fn governance_only_is_the_default() {
+    let Fixture {
+        oracle,
+        governance,
+        keeper,
+        viewer,
+    } = fixture();
+
+    assert_eq!(oracle.get_updater_policy(), UpdaterPolicy::GovernanceOnly);
+    oracle.check_updater(&governance, &keeper).unwrap();
+    assert_state_error(
+        oracle.check_updater(&governance, &viewer),
+        StateError::InsufficientPermissions,
+    );
+    assert_state_error(
+        oracle.check_updater(&governance, &Pubkey::new_unique()),
+        StateError::UnauthorizedCaller,
+    );
+}
+
+#[test]
+fn governance_only_ignores_the_allowlist() {
+    let Fixture {
+        mut oracle,
+        governance,
+        ..
+    } = fixture();
+    let listed = Pubkey::new_unique();
+
+    oracle
+        .set_updaters(UpdaterPolicy::GovernanceOnly, &[listed])
+        .unwrap();
+
+    assert_state_error(
+        oracle.check_updater(&governance, &listed),
+        StateError::UnauthorizedCaller,
+    );
+}
+
+#[test]
+fn allowlist_accepts_listed_keepers_and_governance() {
+    let Fixture {
+        mut oracle,
+        governance,
+        keeper,
+        viewer,
+    } = fixture();
+    let listed = Pubkey::new_unique();
+
+    oracle
+        .set_updaters(UpdaterPolicy::Allowlist, &[listed, viewer])
+        .unwrap();
+
+    oracle.check_updater(&governance, &listed).unwrap();
+    oracle.check_updater(&governance, &keeper).unwrap();
+    // Listing overrides the member's missing UPDATE_PRICE bit
+    oracle.check_updater(&governance, &viewer).unwrap();
+}
+
+#[test]
+fn allowlist_rejects_unlisted_keys() {
+    let Fixture {
+        mut oracle,
+        governance,
+        ..
+    } = fixture();
+
+    oracle
+        .set_updaters(UpdaterPolicy::Allowlist, &[Pubkey::new_unique()])
+        .unwrap();
+
+    assert_state_error(
+        oracle.check_updater(&governance, &Pubkey::new_unique()),
+        StateError::UnauthorizedCaller,
+    );
+    // An empty slot must not admit the default key
+    assert!(!oracle.is_allowed_updater(&Pubkey::default()));
+}
+
+#[test]
+fn retired_open_byte_reads_as_governance_only() {
+    let Fixture {
+        mut oracle,
+        governance,
+        keeper,
+        viewer,
+    } = fixture();
+
+    // 2 was the discriminant of the retired `Open` policy
+    oracle.updater_policy = 2;
+
+    assert_eq!(oracle.get_updater_policy(), UpdaterPolicy::GovernanceOnly);
+    oracle.check_updater(&governance, &keeper).unwrap();
+    assert_state_error(
+        oracle.check_updater(&governance, &viewer),
+        StateError::InsufficientPermissions,
+    );
+    assert_state_error(
+        oracle.check_updater(&governance, &Pubkey::new_unique()),
+        StateError::UnauthorizedCaller,
+    );
+}
+
+#[test]
+fn unknown_policy_byte_fails_closed() {
+    let Fixture {
+        mut oracle,
+        governance,
+        keeper,
+        ..
+    } = fixture();
+
+    oracle.updater_policy = 0xff;
+
+    assert_eq!(oracle.get_updater_policy(), UpdaterPolicy::GovernanceOnly);
+    oracle.check_updater(&governance, &keeper).unwrap();
+    assert_state_error(
+        oracle.check_updater(&governance, &Pubkey::new_unique()),
+        StateError::UnauthorizedCaller,
+    );
+}
+
+#[test]
+fn replacing_the_list_drops_previous_keys() {
+    let mut oracle = oracle_fixture();
+    let first = Pubkey::new_unique();
+    let second = Pubkey::new_unique();
+
+    oracle
+        .set_updaters(UpdaterPolicy::Allowlist, &[first])
+        .unwrap();
+    oracle
+        .set_updaters(UpdaterPolicy::Allowlist, &[second])
+        .unwrap();
+
+    assert!(!oracle.is_allowed_updater(&first));
+    assert!(oracle.is_allowed_updater(&second));
+}
+
+#[test]
+fn invalid_lists_are_rejected_without_writing() {
+    let mut oracle = oracle_fixture();
+    let kept = Pubkey::new_unique();
+    oracle
+        .set_updaters(UpdaterPolicy::Allowlist, &[kept])
+        .unwrap();
+
+    let duplicate = Pubkey::new_unique();
+    assert_state_error(
+        oracle.set_updaters(UpdaterPolicy::GovernanceOnly, &[duplicate, duplicate]),
+        StateError::DuplicateUpdater,
+    );
+    assert_state_error(
+        oracle.set_updaters(UpdaterPolicy::GovernanceOnly, &[Pubkey::default()]),
+        StateError::InvalidUpdaterKey,
+    );
+    let too_many: Vec<Pubkey> = (0..=MAX_ALLOWED_UPDATERS)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    assert_state_error(
+        oracle.set_updaters(UpdaterPolicy::GovernanceOnly, &too_many),
+        StateError::TooManyUpdaters,
+    );
+
+    assert_eq!(oracle.get_updater_policy(), UpdaterPolicy::Allowlist);
+    assert!(oracle.is_allowed_updater(&kept));
+}
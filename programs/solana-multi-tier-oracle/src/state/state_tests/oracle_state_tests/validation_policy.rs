@@ -0,0 +1,39 @@
+//! Stored cross-validation policy for DEX reads.
+//!
+//! The policy lives on the oracle rather than the update config, so a zeroed
+//! field and any byte this program does not know must both read as the strict
+//! `Both`, and every policy must survive the stored round trip.
+
+use super::helpers::oracle_fixture;
+use crate::components::raydium_clmm_observer::fetch_raydium_price::ValidationPolicy;
+
+#[test]
+fn unset_policy_cross_validates() {
+    let oracle = oracle_fixture();
+
+    assert_eq!(oracle.validation_policy, 0);
+    assert_eq!(oracle.get_validation_policy(), ValidationPolicy::Both);
+}
+
+#[test]
+fn every_policy_survives_the_stored_byte() {
+    let mut oracle = oracle_fixture();
+
+    for policy in [
+        ValidationPolicy::TwapOnly,
+        ValidationPolicy::T2emaOnly,
+        ValidationPolicy::Fallback,
+        ValidationPolicy::Both,
+    ] {
+        oracle.set_validation_policy(policy);
+        assert_eq!(oracle.get_validation_policy(), policy);
+    }
+}
+
+#[test]
+fn unknown_policy_byte_fails_closed() {
+    let mut oracle = oracle_fixture();
+    oracle.validation_policy = 0xff;
+
+    assert_eq!(oracle.get_validation_policy(), ValidationPolicy::Both);
+}
@@ -0,0 +1,41 @@
+//! Alignment of the requested Raydium window to the observation cadence.
+//!
+//! `update_price` passes the keeper's `window_seconds` through
+//! `aligned_window_seconds` before bounds-checking it, so strict mode rejects a
+//! window off the cadence and relaxed mode averages over the largest aligned
+//! window inside it.
+
+use crate::components::raydium_clmm_observer::raydium_constants::OBSERVATION_UPDATE_DURATION;
+use crate::error::StateError;
+use crate::instructions::update_price::aligned_window_seconds;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+
+const HOUR: u32 = 3_600;
+
+#[test]
+fn aligned_window_is_accepted_in_either_mode() {
+    assert_eq!(aligned_window_seconds(HOUR, true).unwrap(), HOUR);
+    assert_eq!(aligned_window_seconds(HOUR, false).unwrap(), HOUR);
+}
+
+#[test]
+fn misaligned_window_is_rejected_when_alignment_is_required() {
+    assert_state_error(
+        aligned_window_seconds(HOUR + 1, true).map(|_| ()),
+        StateError::InvalidTWAPWindow,
+    );
+}
+
+#[test]
+fn misaligned_window_is_rounded_down_when_relaxed() {
+    assert_eq!(aligned_window_seconds(HOUR + 1, false).unwrap(), HOUR);
+    assert_eq!(
+        aligned_window_seconds(HOUR + OBSERVATION_UPDATE_DURATION - 1, false).unwrap(),
+        HOUR
+    );
+    // Never rounds up past the window the keeper asked for
+    assert_eq!(
+        aligned_window_seconds(OBSERVATION_UPDATE_DURATION - 1, false).unwrap(),
+        0
+    );
+}
@@ -39,17 +39,20 @@ pub(crate) fn sample_price_feed() -> PriceFeed {
     PriceFeed {
         source_address: Pubkey::new_unique(),
         last_price: 42,
+        ewma_price: 42,
         volume_24h: 1_000,
         liquidity_depth: 50_000,
+        prev_liquidity_depth: 50_000,
         last_conf: 25,
         last_update: 1_700_000_000, // Realistic unix timestamp (2023-11-14)
+        last_good_update: 1_700_000_000,
         last_expo: -6,
         weight: 5_000,           // 50% weight in aggregation (basis points)
         lp_concentration: 1_000, // 10% LP concentration
         manipulation_score: 500, // 5% manipulation risk
         source_type: SourceType::DEX.as_u8(),
         flags: FeedFlags::new(),
-        _padding: [0; 4],
+        _padding: [0; 12],
     }
 }
 
@@ -0,0 +1,82 @@
+//! Per-feed exponentially smoothed price.
+//!
+//! `last_price` must always carry the raw reading while `ewma_price` lags it by
+//! the configured alpha, and a feed held at one price must settle on it.
+
+use super::core_unit_tests_and_utils::sample_price_feed;
+use crate::state::price_feed::PriceFeed;
+
+const ALPHA_BPS: u16 = 2_000;
+
+fn fresh_feed() -> PriceFeed {
+    PriceFeed {
+        last_price: 0,
+        ewma_price: 0,
+        last_good_update: 0,
+        ..sample_price_feed()
+    }
+}
+
+/// Feed that has already published `price` for a while.
+fn settled_feed(price: i128) -> PriceFeed {
+    PriceFeed {
+        last_price: price,
+        ewma_price: price,
+        ..sample_price_feed()
+    }
+}
+
+#[test]
+fn first_reading_seeds_the_average() {
+    let mut feed = fresh_feed();
+
+    feed.record_price(1_000_000, ALPHA_BPS);
+
+    assert_eq!(feed.last_price, 1_000_000);
+    assert_eq!(feed.ewma_price, 1_000_000);
+}
+
+#[test]
+fn jump_is_smoothed_while_last_price_stays_raw() {
+    let mut feed = settled_feed(1_000_000);
+
+    feed.record_price(2_000_000, ALPHA_BPS);
+
+    assert_eq!(feed.last_price, 2_000_000);
+    // One step of 20% toward the new level
+    assert!(feed.ewma_price.abs_diff(1_200_000) <= 1);
+
+    feed.record_price(1_000_000, ALPHA_BPS);
+
+    assert_eq!(feed.last_price, 1_000_000);
+    assert!(feed.ewma_price > 1_000_000 && feed.ewma_price < 1_200_000);
+}
+
+#[test]
+fn steady_price_converges_from_either_side() {
+    let target = 5_000_000i128;
+    let tolerance = (10_000 / ALPHA_BPS) as u128;
+
+    for start in [1_000_000, 9_000_000] {
+        let mut feed = settled_feed(start);
+        for _ in 0..200 {
+            feed.record_price(target, ALPHA_BPS);
+        }
+
+        assert_eq!(feed.last_price, target);
+        assert!(
+            feed.ewma_price.abs_diff(target) <= tolerance,
+            "from {start}: ewma {} vs {target}",
+            feed.ewma_price
+        );
+    }
+}
+
+#[test]
+fn full_alpha_tracks_raw_price() {
+    let mut feed = settled_feed(1_000_000);
+
+    feed.record_price(-3_000, 10_000);
+
+    assert_eq!(feed.ewma_price, -3_000);
+}
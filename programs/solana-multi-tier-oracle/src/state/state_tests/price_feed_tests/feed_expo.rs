@@ -0,0 +1,82 @@
+//! Native feed exponents and normalization to the oracle's canonical exponent.
+//!
+//! A feed's exponent is fixed at registration, so these tests pin both the
+//! accepted range and the arithmetic that reconciles feeds quoting at different
+//! precisions.
+
+use crate::error::StateError;
+use crate::instructions::register_price_feed::PriceFeedConfig;
+use crate::state::price_feed::{rescale_price, PriceFeed, SourceType};
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use crate::state::state_tests::oracle_state_tests::helpers::oracle_fixture;
+use crate::utils::constants::{MAX_FEED_EXPO, MIN_FEED_EXPO};
+use anchor_lang::prelude::*;
+
+fn config_with_expo(expo: i32) -> PriceFeedConfig {
+    PriceFeedConfig {
+        source_address: Pubkey::new_unique(),
+        source_type: SourceType::DEX,
+        weight: 1_000,
+        min_liquidity: 1_000_000,
+        staleness_threshold: 300,
+        expo,
+        normalize: false,
+        asset_seed: [7; 32],
+    }
+}
+
+#[test]
+fn registration_rejects_out_of_range_expo() {
+    for expo in [MAX_FEED_EXPO + 1, MIN_FEED_EXPO - 1, i32::MIN, i32::MAX] {
+        assert_state_error(
+            config_with_expo(expo).validate_expo(),
+            StateError::InvalidFeedExpo,
+        );
+    }
+}
+
+#[test]
+fn registration_accepts_expo_range_bounds() {
+    for expo in [MIN_FEED_EXPO, -8, -6, MAX_FEED_EXPO] {
+        assert!(
+            config_with_expo(expo).validate_expo().is_ok(),
+            "expo {expo}"
+        );
+    }
+}
+
+#[test]
+fn feed_at_minus_8_normalizes_to_minus_6_oracle() {
+    let mut oracle = oracle_fixture();
+    oracle.current_price.expo = -6;
+
+    // 123.456789 at 1e-8 precision
+    let feed = PriceFeed {
+        last_price: 12_345_678_900,
+        last_expo: -8,
+        ..PriceFeed::default()
+    };
+
+    assert_eq!(oracle.normalized_feed_price(&feed).unwrap(), 123_456_789);
+}
+
+#[test]
+fn coarser_rescale_truncates_toward_zero() {
+    assert_eq!(rescale_price(12_345_678_999, -8, -6).unwrap(), 123_456_789);
+    assert_eq!(
+        rescale_price(-12_345_678_999, -8, -6).unwrap(),
+        -123_456_789
+    );
+    // A divisor beyond i128 range leaves nothing representable
+    assert_eq!(rescale_price(i128::MAX, -40, 0).unwrap(), 0);
+}
+
+#[test]
+fn finer_rescale_is_exact_and_overflow_checked() {
+    assert_eq!(rescale_price(123_456_789, -6, -8).unwrap(), 12_345_678_900);
+    assert_eq!(rescale_price(42, -6, -6).unwrap(), 42);
+    assert_state_error(
+        rescale_price(i128::MAX / 10, 0, -2).map(|_| ()),
+        StateError::MathOverflow,
+    );
+}
@@ -0,0 +1,141 @@
+//! Last-good-price fallback for feeds whose latest read failed.
+//!
+//! Within the staleness grace a failed feed keeps pricing its tier from its last
+//! validated price, blended at `FALLBACK_WEIGHT_BPS` of the tier's weight;
+//! beyond it, or once its tier has a fresh feed, it drops out. Prices are quoted
+//! at `expo = -6`, so `100_000_000` is 100.00.
+
+use bytemuck::bytes_of;
+
+use crate::state::oracle_state::OracleState;
+use crate::state::price_feed::{FeedFlags, PriceFeed, SourceType};
+use crate::state::state_tests::oracle_state_tests::helpers::oracle_fixture;
+use crate::utils::constants::{FALLBACK_WEIGHT_BPS, MAX_EXTERNAL_STALENESS, WEIGHT_PRECISION};
+
+const NOW: i64 = 1_700_000_000;
+const GRACE: i64 = MAX_EXTERNAL_STALENESS as i64;
+const HEALTHY_PRICE: i128 = 100_000_000;
+const FALLBACK_PRICE: i128 = 102_000_000;
+
+fn good_feed(source_type: SourceType, last_price: i128, read_at: i64) -> PriceFeed {
+    let mut feed = PriceFeed {
+        last_price,
+        last_conf: 50,
+        last_expo: -6,
+        ..PriceFeed::default()
+    };
+    feed.set_source_type(source_type);
+    feed.flags.set(FeedFlags::ACTIVE);
+    feed.record_good_read(read_at);
+    feed
+}
+
+/// A DEX feed validated at `good_at` whose read then failed just before `NOW`.
+fn failed_feed(good_at: i64) -> PriceFeed {
+    let mut feed = good_feed(SourceType::DEX, FALLBACK_PRICE, good_at);
+    feed.record_failed_read(NOW - 5);
+    feed
+}
+
+fn oracle_with_feeds(feeds: &[PriceFeed]) -> OracleState {
+    let mut oracle = oracle_fixture();
+    oracle.current_price.expo = -6;
+    oracle.price_feeds[..feeds.len()].copy_from_slice(feeds);
+    oracle.set_active_feed_count(feeds.len() as u8).unwrap();
+    oracle
+}
+
+#[test]
+fn failed_feed_within_grace_prices_its_tier_at_reduced_weight() {
+    let oracle = oracle_with_feeds(&[
+        good_feed(SourceType::Oracle, HEALTHY_PRICE, NOW),
+        failed_feed(NOW - GRACE / 2),
+    ]);
+    let price = oracle.aggregate_tiered(NOW).unwrap().price;
+
+    let full = WEIGHT_PRECISION as i128;
+    let reduced = full * FALLBACK_WEIGHT_BPS as i128 / 10_000;
+    let expected = (HEALTHY_PRICE * full + FALLBACK_PRICE * reduced) / (full + reduced);
+    assert_eq!(price, expected);
+    // Pulled towards the fallback, but less than an equal-weight average would be
+    assert!(price > HEALTHY_PRICE && price < (HEALTHY_PRICE + FALLBACK_PRICE) / 2);
+
+    // Still counted as participating while it is served from the fallback
+    let participation = oracle.feed_participation(NOW);
+    assert_eq!(participation.aggregated, 2);
+    assert_eq!(participation.sources_mask, 0b11);
+}
+
+#[test]
+fn failed_feed_past_grace_is_dropped() {
+    let oracle = oracle_with_feeds(&[
+        good_feed(SourceType::Oracle, HEALTHY_PRICE, NOW),
+        failed_feed(NOW - GRACE - 1),
+    ]);
+    assert_eq!(oracle.aggregate_tiered(NOW).unwrap().price, HEALTHY_PRICE);
+
+    let participation = oracle.feed_participation(NOW);
+    assert_eq!(participation.aggregated, 1);
+    assert_eq!(participation.excluded_stale, 1);
+}
+
+#[test]
+fn fresh_feed_in_the_same_tier_displaces_the_fallback() {
+    let oracle = oracle_with_feeds(&[
+        good_feed(SourceType::DEX, HEALTHY_PRICE, NOW),
+        failed_feed(NOW - 10),
+    ]);
+    assert_eq!(oracle.aggregate_tiered(NOW).unwrap().price, HEALTHY_PRICE);
+
+    let participation = oracle.feed_participation(NOW);
+    assert_eq!(participation.sources_mask, 0b01);
+    assert_eq!(participation.excluded_stale, 1);
+}
+
+#[test]
+fn fallbacks_alone_still_publish() {
+    let oracle = oracle_with_feeds(&[failed_feed(NOW - 10)]);
+
+    assert_eq!(oracle.aggregate_tiered(NOW).unwrap().price, FALLBACK_PRICE);
+}
+
+#[test]
+fn failed_read_keeps_age_running_from_last_good_reading() {
+    // The failed attempt refreshes `last_update` but not the reading's age
+    let feed = failed_feed(NOW - 200);
+    assert_eq!(feed.last_update, NOW - 5);
+    assert_eq!(feed.reading_age(NOW), 200);
+}
+
+#[test]
+fn failed_read_writes_only_the_read_marker() {
+    // A soft-skipped update commits this and nothing else, so every byte of the
+    // reading it falls back to must survive
+    let good = good_feed(SourceType::DEX, FALLBACK_PRICE, NOW - 200);
+    let mut failed = good;
+    failed.record_failed_read(NOW - 5);
+    assert!(failed.flags.is_read_failed());
+
+    failed.last_update = good.last_update;
+    failed.flags = good.flags;
+    assert_eq!(bytes_of(&failed), bytes_of(&good));
+}
+
+#[test]
+fn feed_without_a_good_reading_never_falls_back() {
+    let mut feed = PriceFeed::default();
+    feed.flags.set(FeedFlags::ACTIVE);
+    feed.record_failed_read(NOW);
+
+    assert_eq!(feed.reading_age(NOW), i64::MAX);
+    assert!(oracle_with_feeds(&[feed]).aggregate_tiered(NOW).is_err());
+}
+
+#[test]
+fn good_read_clears_the_fallback() {
+    let mut feed = failed_feed(NOW - 10);
+    feed.record_good_read(NOW);
+
+    assert!(!feed.flags.is_read_failed());
+    assert_eq!(feed.reading_age(NOW), 0);
+}
@@ -23,7 +23,7 @@ fn price_feed_struct_layout_invariants() {
     // client-side assumptions about byte offsets.
     assert_eq!(
         size_of::<PriceFeed>(),
-        112,
+        160,
         "repr(C) layout changed: check account sizing"
     );
 
@@ -0,0 +1,107 @@
+//! Liquidity-change detection between consecutive updates.
+//!
+//! Only a drop beyond the oracle's stored bound is a shock; ordinary churn and
+//! growth in depth must pass through without adding risk.
+
+use super::core_unit_tests_and_utils::sample_price_feed;
+use crate::error::StateError;
+use crate::instructions::update_price::raw_manipulation_score;
+use crate::state::price_feed::PriceFeed;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use crate::state::state_tests::oracle_state_tests::helpers::oracle_fixture;
+use crate::utils::constants::DEFAULT_LIQUIDITY_DROP_BPS;
+
+const MAX_DROP_BPS: u16 = 3_000;
+
+fn feed_with_depth(depth: i128) -> PriceFeed {
+    PriceFeed {
+        liquidity_depth: depth,
+        ..sample_price_feed()
+    }
+}
+
+#[test]
+fn small_drop_is_not_flagged() {
+    let mut feed = feed_with_depth(1_000_000);
+
+    assert_eq!(feed.record_liquidity(950_000, MAX_DROP_BPS), None);
+    assert_eq!(feed.prev_liquidity_depth, 1_000_000);
+    assert_eq!(feed.liquidity_depth, 950_000);
+    assert_eq!(feed.liquidity_drop_bps(), 500);
+}
+
+#[test]
+fn large_drop_reports_shock() {
+    let mut feed = feed_with_depth(1_000_000);
+
+    assert_eq!(feed.record_liquidity(200_000, MAX_DROP_BPS), Some(8_000));
+    assert_eq!(feed.prev_liquidity_depth, 1_000_000);
+}
+
+#[test]
+fn drop_at_threshold_is_not_flagged() {
+    let mut feed = feed_with_depth(1_000_000);
+    assert_eq!(feed.record_liquidity(700_000, MAX_DROP_BPS), None);
+}
+
+#[test]
+fn growth_and_first_reading_are_not_flagged() {
+    let mut feed = feed_with_depth(1_000_000);
+    assert_eq!(feed.record_liquidity(5_000_000, 0), None);
+
+    // A freshly registered feed has no prior depth to compare against
+    let mut fresh = feed_with_depth(0);
+    assert_eq!(fresh.record_liquidity(1_000_000, 0), None);
+}
+
+#[test]
+fn full_drain_and_extreme_depths_stay_in_range() {
+    let mut feed = feed_with_depth(i128::MAX);
+    assert_eq!(
+        feed.record_liquidity(i128::MAX / 2, MAX_DROP_BPS),
+        Some(5_000)
+    );
+    assert_eq!(feed.record_liquidity(0, MAX_DROP_BPS), Some(10_000));
+
+    // A full drain is never above the 10_000 ceiling, so that setting disables the check
+    let mut feed = feed_with_depth(1_000_000);
+    assert_eq!(feed.record_liquidity(0, 10_000), None);
+}
+
+#[test]
+fn shock_adds_risk_to_observed_score() {
+    let mut feed = feed_with_depth(1_000_000);
+    let shock = feed.record_liquidity(200_000, MAX_DROP_BPS);
+
+    assert_eq!(raw_manipulation_score(1_500, shock), 9_500);
+    assert_eq!(raw_manipulation_score(1_500, None), 1_500);
+    assert_eq!(raw_manipulation_score(4_000, shock), 10_000);
+}
+
+#[test]
+fn unset_oracle_bound_reads_as_the_default() {
+    let oracle = oracle_fixture();
+    assert_eq!(oracle.max_liquidity_drop_bps, 0);
+    assert_eq!(oracle.liquidity_drop_bps(), DEFAULT_LIQUIDITY_DROP_BPS);
+
+    // The default still catches a drain the keeper can no longer wave through
+    let mut feed = feed_with_depth(1_000_000);
+    assert_eq!(
+        feed.record_liquidity(200_000, oracle.liquidity_drop_bps()),
+        Some(8_000)
+    );
+}
+
+#[test]
+fn oracle_bound_must_be_within_one_to_ten_thousand_bps() {
+    let mut oracle = oracle_fixture();
+
+    for bps in [0, 10_001] {
+        assert_state_error(
+            oracle.set_liquidity_drop_bound(bps),
+            StateError::InvalidLiquidityDropBound,
+        );
+    }
+    oracle.set_liquidity_drop_bound(MAX_DROP_BPS).unwrap();
+    assert_eq!(oracle.liquidity_drop_bps(), MAX_DROP_BPS);
+}
@@ -1,5 +1,12 @@
 pub mod core_unit_tests_and_utils;
+pub mod ewma_price;
+pub mod feed_expo;
 pub mod fuzz_flags_and_source_type;
+pub mod last_good_fallback;
 pub mod layout_zero_copy;
+pub mod liquidity_shock;
 pub mod oracle_state_integration;
+pub mod quality_score;
 pub mod serialization_defaults;
+pub mod source_account_checks;
+pub mod weight_normalization;
@@ -0,0 +1,81 @@
+//! Composite source quality used to rank feeds for operators.
+
+use super::core_unit_tests_and_utils::sample_price_feed;
+use crate::state::price_feed::{rank_by_quality, FeedFlags, PriceFeed};
+use crate::utils::constants::{
+    MAX_EXTERNAL_STALENESS, QUALITY_CONCENTRATION_WEIGHT, QUALITY_FRESHNESS_WEIGHT,
+    QUALITY_LIQUIDITY_WEIGHT, QUALITY_MANIPULATION_WEIGHT, QUALITY_REFERENCE_LIQUIDITY,
+};
+
+const NOW: i64 = 1_700_000_000;
+
+fn feed(age: i64, liquidity_depth: i128, manipulation_score: u16) -> PriceFeed {
+    let mut flags = FeedFlags::new();
+    flags.set(FeedFlags::ACTIVE);
+    PriceFeed {
+        last_update: NOW - age,
+        liquidity_depth,
+        lp_concentration: 1_000,
+        manipulation_score,
+        flags,
+        ..sample_price_feed()
+    }
+}
+
+#[test]
+fn weights_cover_full_scale() {
+    assert_eq!(
+        QUALITY_FRESHNESS_WEIGHT
+            + QUALITY_LIQUIDITY_WEIGHT
+            + QUALITY_CONCENTRATION_WEIGHT
+            + QUALITY_MANIPULATION_WEIGHT,
+        10_000
+    );
+}
+
+#[test]
+fn fresh_deep_feed_outranks_stale_thin_feed() {
+    let fresh_deep = feed(0, QUALITY_REFERENCE_LIQUIDITY, 0);
+    let stale_thin = feed(
+        MAX_EXTERNAL_STALENESS as i64,
+        QUALITY_REFERENCE_LIQUIDITY / 50,
+        0,
+    );
+
+    let fresh_score = fresh_deep.quality_score(NOW);
+    let stale_score = stale_thin.quality_score(NOW);
+    assert!(fresh_score > stale_score, "{fresh_score} <= {stale_score}");
+    // Only LP concentration holds the fresh, deep, clean feed below full marks
+    let concentration_penalty = 1_000 * QUALITY_CONCENTRATION_WEIGHT / 10_000;
+    assert_eq!(fresh_score as u32, 10_000 - concentration_penalty);
+
+    let ranked = rank_by_quality(&[stale_thin, fresh_deep], NOW);
+    assert_eq!(
+        ranked.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+        vec![1, 0]
+    );
+}
+
+#[test]
+fn manipulation_flagged_feed_scores_near_zero() {
+    let mut flagged = feed(0, QUALITY_REFERENCE_LIQUIDITY, 0);
+    flagged.flags.set(FeedFlags::MANIPULATION_DETECTED);
+    assert_eq!(flagged.quality_score(NOW), 0);
+
+    // A near-maximal score alone sinks a feed that is otherwise ideal
+    let suspicious = feed(0, QUALITY_REFERENCE_LIQUIDITY, 9_500);
+    assert!(suspicious.quality_score(NOW) < 500);
+}
+
+#[test]
+fn ranking_skips_inactive_and_keeps_slot_order_on_ties() {
+    let mut inactive = feed(0, QUALITY_REFERENCE_LIQUIDITY, 0);
+    inactive.flags.clear(FeedFlags::ACTIVE);
+    let twin = feed(10, QUALITY_REFERENCE_LIQUIDITY, 0);
+
+    let ranked = rank_by_quality(&[twin, inactive, twin], NOW);
+    assert_eq!(
+        ranked.iter().map(|(i, _)| *i).collect::<Vec<_>>(),
+        vec![0, 2]
+    );
+}
@@ -0,0 +1,147 @@
+//! Registration-time sanity checks on the feed source account.
+//!
+//! These run regardless of strict mode: a source that holds no lamports, a
+//! pool-backed source too small to contain a pool, or a source type no
+//! observer reads, can never yield a price. A DEX feed must also declare the
+//! exponent its pool prices in.
+
+use crate::components::components_tests::raydium_clmm_tests::helpers::{
+    pool_account_data, set_mint_decimals,
+};
+use crate::components::raydium_clmm_observer::raydium_accounts::MIN_POOL_ACCOUNT_LEN;
+use crate::error::StateError;
+use crate::instructions::register_price_feed::PriceFeedConfig;
+use crate::state::price_feed::SourceType;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use anchor_lang::prelude::*;
+
+fn feed_config(source_address: Pubkey, source_type: SourceType) -> PriceFeedConfig {
+    PriceFeedConfig {
+        source_address,
+        source_type,
+        weight: 1_000,
+        min_liquidity: 1_000_000,
+        staleness_threshold: 300,
+        expo: 0,
+        normalize: false,
+        asset_seed: [7; 32],
+    }
+}
+
+/// Runs `check` against a synthetic account with the given balance and data size.
+fn with_source_account(lamports: u64, data_len: usize, check: impl FnOnce(&AccountInfo)) {
+    let key = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut lamports = lamports;
+    let mut data = vec![0u8; data_len];
+    let account_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    check(&account_info);
+}
+
+/// Runs `check` against a funded pool whose mints carry `(decimal_0, decimal_1)`.
+fn with_pool_decimals(decimal_0: u8, decimal_1: u8, check: impl FnOnce(&AccountInfo)) {
+    let key = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mut lamports = 1_000_000;
+    let mut data = pool_account_data(1_000_000_000, 1 << 64);
+    set_mint_decimals(&mut data, decimal_0, decimal_1);
+    let account_info = AccountInfo::new(
+        &key,
+        false,
+        false,
+        &mut lamports,
+        &mut data,
+        &owner,
+        false,
+        0,
+    );
+    check(&account_info);
+}
+
+#[test]
+fn empty_account_is_rejected() {
+    with_source_account(0, 0, |source| {
+        let config = feed_config(*source.key, SourceType::DEX);
+        assert_state_error(
+            config.validate_source_account(source),
+            StateError::InvalidSourceAddress,
+        );
+    });
+}
+
+#[test]
+fn funded_account_without_pool_data_is_rejected_for_pool_types() {
+    for source_type in [SourceType::DEX, SourceType::Aggregator] {
+        with_source_account(1_000_000, MIN_POOL_ACCOUNT_LEN - 1, |source| {
+            let config = feed_config(*source.key, source_type);
+            assert_state_error(
+                config.validate_source_account(source),
+                StateError::InvalidSourceAddress,
+            );
+        });
+    }
+}
+
+#[test]
+fn plausibly_sized_pool_account_is_accepted() {
+    with_source_account(2_000_000, MIN_POOL_ACCOUNT_LEN, |source| {
+        let config = feed_config(*source.key, SourceType::DEX);
+        config
+            .validate_source_account(source)
+            .expect("pool-sized funded account passes");
+    });
+}
+
+#[test]
+fn off_chain_sources_only_need_to_exist() {
+    for source_type in [SourceType::CEX, SourceType::Oracle] {
+        with_source_account(1_000_000, 0, |source| {
+            let config = feed_config(*source.key, source_type);
+            config
+                .validate_source_account(source)
+                .expect("funded account suffices for pushed sources");
+        });
+    }
+}
+
+#[test]
+fn source_types_without_an_observer_are_rejected() {
+    for source_type in [SourceType::CEX, SourceType::Oracle, SourceType::Aggregator] {
+        assert_state_error(
+            feed_config(Pubkey::new_unique(), source_type).validate_source_type(),
+            StateError::UnsupportedSourceType,
+        );
+    }
+    feed_config(Pubkey::new_unique(), SourceType::DEX)
+        .validate_source_type()
+        .expect("DEX feeds are read by the Raydium observer");
+}
+
+#[test]
+fn dex_expo_must_match_the_pool() {
+    // SOL (9) / USDC (6) prices in micro-USDC, so only -6 is accepted
+    with_pool_decimals(9, 6, |pool| {
+        let mut config = feed_config(*pool.key, SourceType::DEX);
+        config.expo = -6;
+        config
+            .validate_source_expo(pool)
+            .expect("expo matches the quote decimals");
+
+        for mismatched in [0, -2, -9] {
+            config.expo = mismatched;
+            assert_state_error(
+                config.validate_source_expo(pool),
+                StateError::FeedExpoMismatch,
+            );
+        }
+    });
+}
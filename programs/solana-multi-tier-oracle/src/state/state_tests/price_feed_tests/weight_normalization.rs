@@ -0,0 +1,83 @@
+//! Proportional weight rebalancing for normalized registrations.
+//!
+//! When the incoming weight does not fit, existing weights shrink by a common
+//! factor rather than the registration being rejected.
+
+use crate::error::StateError;
+use crate::state::oracle_state::OracleState;
+use crate::state::price_feed::PriceFeed;
+use crate::state::state_tests::governance_state_tests::helpers::assert_state_error;
+use crate::state::state_tests::oracle_state_tests::helpers::oracle_fixture;
+use crate::utils::constants::WEIGHT_PRECISION;
+
+fn oracle_with_weights(weights: &[u16]) -> OracleState {
+    let mut oracle = oracle_fixture();
+    for (slot, &weight) in weights.iter().enumerate() {
+        oracle.price_feeds[slot] = PriceFeed {
+            weight,
+            ..PriceFeed::default()
+        };
+    }
+    oracle.set_active_feed_count(weights.len() as u8).unwrap();
+    oracle
+}
+
+fn active_weights(oracle: &OracleState) -> Vec<u16> {
+    oracle
+        .active_feeds()
+        .iter()
+        .map(|feed| feed.weight)
+        .collect()
+}
+
+fn total_with(oracle: &OracleState, incoming: u16) -> u32 {
+    active_weights(oracle)
+        .iter()
+        .map(|&w| w as u32)
+        .sum::<u32>()
+        + incoming as u32
+}
+
+#[test]
+fn normalization_makes_room_where_plain_registration_fails() {
+    let mut oracle = oracle_with_weights(&[6_000, 3_000]);
+    assert!(total_with(&oracle, 4_000) > WEIGHT_PRECISION);
+
+    assert!(oracle.rebalance_weights_for(4_000).unwrap());
+
+    assert_eq!(active_weights(&oracle), vec![4_000, 2_000]);
+    assert!(total_with(&oracle, 4_000) <= WEIGHT_PRECISION);
+}
+
+#[test]
+fn rebalancing_preserves_relative_ratios() {
+    let mut oracle = oracle_with_weights(&[5_000, 2_500, 1_250, 1_250]);
+
+    oracle.rebalance_weights_for(2_000).unwrap();
+
+    let weights = active_weights(&oracle);
+    assert_eq!(weights, vec![4_000, 2_000, 1_000, 1_000]);
+    assert_eq!(weights[0], 2 * weights[1]);
+    assert_eq!(weights[1], 2 * weights[2]);
+    assert_eq!(total_with(&oracle, 2_000), WEIGHT_PRECISION);
+}
+
+#[test]
+fn fitting_registration_leaves_weights_untouched() {
+    let mut oracle = oracle_with_weights(&[3_000, 3_000]);
+
+    assert!(!oracle.rebalance_weights_for(4_000).unwrap());
+
+    assert_eq!(active_weights(&oracle), vec![3_000, 3_000]);
+}
+
+#[test]
+fn rebalancing_that_would_zero_a_feed_is_rejected_atomically() {
+    let mut oracle = oracle_with_weights(&[9_999, 1]);
+
+    assert_state_error(
+        oracle.rebalance_weights_for(5_000).map(|_| ()),
+        StateError::ExcessiveTotalWeight,
+    );
+    assert_eq!(active_weights(&oracle), vec![9_999, 1]);
+}
@@ -0,0 +1,92 @@
+//! Vote bookkeeping across governance membership changes.
+//!
+//! `remove_member` compacts `multisig_members` by moving the last member into
+//! the vacated slot. Votes are recorded against the electorate each proposal
+//! froze at creation, so these tests remove members mid-vote and check that
+//! nobody gains a second vote, inherits someone else's, or votes after leaving.
+
+use super::helpers::{pending_proposal, APPROVED_AT};
+use crate::error::StateError;
+use crate::state::governance_state::{GovernanceState, Permissions};
+use crate::state::state_tests::governance_state_tests::helpers::{
+    assert_state_error, deterministic_pubkey, governance_fixture,
+};
+
+/// Four equal-stake members with slot 0 as admin and room to remove two.
+fn governance() -> GovernanceState {
+    let mut governance = governance_fixture(4);
+    governance.multi_sig_threshold = 2;
+    governance.member_permissions[0] = Permissions::ADMIN_ALL;
+    governance
+}
+
+#[test]
+fn moved_member_cannot_vote_twice() {
+    let mut governance = governance();
+    let moved = governance.multisig_members[3];
+    let mut proposal = pending_proposal(&governance);
+
+    proposal
+        .record_vote(&governance, &moved, false, APPROVED_AT)
+        .expect("first vote accepted");
+    governance
+        .remove_member(1)
+        .expect("removal keeps threshold");
+    assert_eq!(governance.multisig_members[1], moved);
+
+    assert_state_error(
+        proposal.record_vote(&governance, &moved, false, APPROVED_AT),
+        StateError::AlreadyVoted,
+    );
+    assert_eq!(proposal.no_stake, governance.proposal_threshold);
+}
+
+#[test]
+fn slot_occupant_does_not_inherit_the_previous_vote() {
+    let mut governance = governance();
+    let departing = governance.multisig_members[1];
+    let moved = governance.multisig_members[3];
+    let mut proposal = pending_proposal(&governance);
+
+    proposal
+        .record_vote(&governance, &departing, false, APPROVED_AT)
+        .expect("first vote accepted");
+    governance
+        .remove_member(1)
+        .expect("removal keeps threshold");
+
+    // `moved` now sits in the slot `departing` voted from, but has not voted
+    assert!(!proposal.has_voted(&moved));
+    proposal
+        .record_vote(&governance, &moved, false, APPROVED_AT)
+        .expect("moved member still holds one vote");
+    assert_eq!(proposal.no_stake, 2 * governance.proposal_threshold);
+}
+
+#[test]
+fn removed_members_and_later_joiners_cannot_vote() {
+    let mut governance = governance();
+    let departing = governance.multisig_members[2];
+    let mut proposal = pending_proposal(&governance);
+
+    governance
+        .remove_member(2)
+        .expect("removal keeps threshold");
+    assert_state_error(
+        proposal.record_vote(&governance, &departing, true, APPROVED_AT),
+        StateError::UnauthorizedCaller,
+    );
+
+    // A key that joins after the proposal opened waits for the next one
+    let joiner = deterministic_pubkey(150);
+    governance.multisig_members[3] = joiner;
+    governance.set_active_member_count(4).unwrap();
+    governance.member_stakes[3] = governance.proposal_threshold;
+    assert_state_error(
+        proposal.record_vote(&governance, &joiner, true, APPROVED_AT),
+        StateError::UnauthorizedCaller,
+    );
+
+    assert_eq!(proposal.yes_stake, 0);
+    assert_eq!(proposal.voted_members, 0);
+}
@@ -0,0 +1,49 @@
+use crate::state::governance_state::GovernanceState;
+use crate::state::proposal::{Proposal, ProposalStatus};
+use crate::state::state_tests::governance_state_tests::helpers::deterministic_pubkey;
+use crate::utils::constants::MAX_MULTISIG_MEMBERS;
+use anchor_lang::prelude::Pubkey;
+
+/// Fixed approval timestamp shared by proposal fixtures.
+///
+/// Anchoring every fixture to the same instant keeps window arithmetic in the
+/// tests readable (`APPROVED_AT + veto_period`) and failures reproducible.
+pub(crate) const APPROVED_AT: i64 = 1_700_000_000;
+
+/// Builds a proposal that has just been approved at `APPROVED_AT`.
+///
+/// The governance and proposer keys reuse the governance suite's deterministic
+/// generator so proposal fixtures can be paired with `governance_fixture` when
+/// a test needs both halves of the relationship.
+pub(crate) fn approved_proposal() -> Proposal {
+    Proposal {
+        governance: deterministic_pubkey(210),
+        proposer: deterministic_pubkey(100),
+        proposal_id: 1,
+        created_at: APPROVED_AT - 48 * 60 * 60,
+        approved_at: APPROVED_AT,
+        yes_stake: 0,
+        no_stake: 0,
+        status: ProposalStatus::Approved.as_u8(),
+        bump: 254,
+        voted_members: 0,
+        electorate: [Pubkey::default(); MAX_MULTISIG_MEMBERS],
+        _electorate_padding: [0; 4],
+        electorate_stakes: [0; MAX_MULTISIG_MEMBERS],
+        reserved: [0; 104],
+    }
+}
+
+/// Builds a freshly created proposal that is still collecting votes.
+///
+/// Created exactly one voting period before `APPROVED_AT` so votes cast at
+/// `APPROVED_AT` land on the final second of the voting window, with the
+/// governance's current members as its electorate.
+pub(crate) fn pending_proposal(governance: &GovernanceState) -> Proposal {
+    let mut proposal = approved_proposal();
+    proposal.snapshot_electorate(governance);
+    proposal.created_at = APPROVED_AT - governance.voting_period;
+    proposal.approved_at = 0;
+    proposal.set_status(ProposalStatus::Pending);
+    proposal
+}
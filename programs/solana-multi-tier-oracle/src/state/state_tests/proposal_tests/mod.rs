@@ -0,0 +1,13 @@
+//! Test harness for the governance `Proposal` lifecycle.
+//!
+//! Proposals gate every privileged configuration change, so their state machine
+//! is covered independently of `GovernanceState`:
+//! - `electorate`: votes keyed to the member keys frozen at creation, across removals.
+//! - `helpers`: deterministic proposal fixtures anchored to a fixed approval time.
+//! - `stake_weighted_voting`: quorum-by-stake tallies gating approval and execution, and the total stake cap.
+//! - `veto_window`: veto acceptance inside `veto_period` and rejection outside it.
+
+pub mod electorate;
+pub mod helpers;
+pub mod stake_weighted_voting;
+pub mod veto_window;
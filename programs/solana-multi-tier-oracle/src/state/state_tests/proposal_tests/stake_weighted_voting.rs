@@ -0,0 +1,314 @@
+//! Stake-weighted tallying and quorum enforcement.
+//!
+//! Headcount voting lets a coalition of low-stake members outvote the members
+//! who carry the economic risk of a bad oracle configuration. These tests pin
+//! that approval and execution are decided by the stake each proposal froze at
+//! creation against `quorum_threshold`, that the combined stake stays within
+//! the `u64` tallies, and that the timing windows around execution hold.
+
+use super::helpers::{pending_proposal, APPROVED_AT};
+use bytemuck::bytes_of;
+
+use crate::error::StateError;
+use crate::state::proposal::ProposalStatus;
+use crate::state::state_tests::governance_state_tests::helpers::{
+    assert_state_error, governance_fixture,
+};
+
+/// First second at which an approval at `APPROVED_AT` clears both the
+/// execution delay and the veto window of the default governance fixture.
+fn first_executable_second(execution_delay: i64, veto_period: i64) -> i64 {
+    APPROVED_AT + execution_delay.max(veto_period + 1)
+}
+
+#[test]
+fn quorum_is_measured_in_stake_not_headcount() {
+    // 60% quorum over stakes [700, 100, 100, 100]: the single whale clears
+    // quorum alone while the three small holders together do not.
+    let mut governance = governance_fixture(4);
+    for (idx, stake) in [700, 100, 100, 100].into_iter().enumerate() {
+        governance
+            .set_member_stake(idx, stake)
+            .expect("active slot");
+    }
+
+    assert_eq!(governance.total_active_stake(), 1_000);
+    assert!(governance.is_stake_quorum_met(700));
+    assert!(governance.is_stake_quorum_met(600));
+    assert!(!governance.is_stake_quorum_met(599));
+    assert!(!governance.is_stake_quorum_met(300));
+}
+
+#[test]
+fn zero_total_stake_never_meets_quorum() {
+    let mut governance = governance_fixture(3);
+    for idx in 0..3 {
+        governance.set_member_stake(idx, 0).expect("active slot");
+    }
+
+    assert!(!governance.is_stake_quorum_met(0));
+    assert!(!governance.is_stake_quorum_met(u64::MAX));
+}
+
+#[test]
+fn stake_cannot_be_assigned_to_inactive_slots() {
+    let mut governance = governance_fixture(3);
+
+    assert_state_error(
+        governance.set_member_stake(3, 1_000),
+        StateError::UnauthorizedCaller,
+    );
+    assert_eq!(governance.member_stakes[3], 0);
+}
+
+#[test]
+fn total_stake_is_capped_at_u64_max() {
+    let mut governance = governance_fixture(4);
+    for (idx, stake) in [700, 100, 100, 100].into_iter().enumerate() {
+        governance
+            .set_member_stake(idx, stake)
+            .expect("active slot");
+    }
+
+    assert_state_error(
+        governance.set_member_stake(1, u64::MAX - 899),
+        StateError::ExcessiveTotalStake,
+    );
+    assert_eq!(governance.member_stakes[1], 100);
+
+    // The member's own current stake is replaced, not added to
+    governance
+        .set_member_stake(1, u64::MAX - 900)
+        .expect("total reaches exactly u64::MAX");
+    assert_eq!(governance.total_active_stake(), u64::MAX as u128);
+}
+
+#[test]
+fn full_electorate_tally_fits_in_u64() {
+    let mut governance = governance_fixture(2);
+    governance
+        .set_member_stake(0, u64::MAX / 2)
+        .expect("active slot");
+    governance
+        .set_member_stake(1, u64::MAX - u64::MAX / 2)
+        .expect("total reaches exactly u64::MAX");
+    let mut proposal = pending_proposal(&governance);
+
+    for idx in 0..2 {
+        proposal
+            .record_vote(
+                &governance,
+                &governance.multisig_members[idx],
+                true,
+                APPROVED_AT,
+            )
+            .expect("tally cannot overflow");
+    }
+    assert_eq!(proposal.yes_stake, u64::MAX);
+}
+
+#[test]
+fn proposal_below_quorum_cannot_execute() {
+    let mut governance = governance_fixture(4);
+    for (idx, stake) in [700, 100, 100, 100].into_iter().enumerate() {
+        governance
+            .set_member_stake(idx, stake)
+            .expect("active slot");
+    }
+    let mut proposal = pending_proposal(&governance);
+
+    for idx in 1..4 {
+        proposal
+            .record_vote(
+                &governance,
+                &governance.multisig_members[idx],
+                true,
+                APPROVED_AT,
+            )
+            .expect("vote within window");
+    }
+    proposal
+        .record_vote(
+            &governance,
+            &governance.multisig_members[0],
+            false,
+            APPROVED_AT,
+        )
+        .expect("vote within window");
+
+    assert_eq!(proposal.yes_stake, 300);
+    assert_eq!(proposal.no_stake, 700);
+    assert_eq!(proposal.get_status(), Some(ProposalStatus::Pending));
+
+    let later = first_executable_second(governance.execution_delay, governance.veto_period);
+    assert_state_error(
+        proposal.execute(&governance, later),
+        StateError::ProposalNotApproved,
+    );
+}
+
+#[test]
+fn proposal_above_quorum_executes_after_windows_elapse() {
+    let mut governance = governance_fixture(4);
+    for (idx, stake) in [700, 100, 100, 100].into_iter().enumerate() {
+        governance
+            .set_member_stake(idx, stake)
+            .expect("active slot");
+    }
+    let mut proposal = pending_proposal(&governance);
+
+    proposal
+        .record_vote(
+            &governance,
+            &governance.multisig_members[0],
+            true,
+            APPROVED_AT,
+        )
+        .expect("vote within window");
+    assert_eq!(proposal.get_status(), Some(ProposalStatus::Approved));
+    assert_eq!(proposal.approved_at, APPROVED_AT);
+
+    // Still inside the veto window: execution must wait for guardians.
+    assert_state_error(
+        proposal.execute(&governance, APPROVED_AT + governance.veto_period),
+        StateError::ExecutionDelayNotElapsed,
+    );
+
+    let ready = first_executable_second(governance.execution_delay, governance.veto_period);
+    let approved = proposal;
+    proposal
+        .execute(&governance, ready)
+        .expect("approved proposal past its windows executes");
+    assert_eq!(proposal.get_status(), Some(ProposalStatus::Executed));
+
+    // Proposals carry no payload, so the status is the only thing execution moves
+    proposal.status = approved.status;
+    assert_eq!(bytes_of(&proposal), bytes_of(&approved));
+}
+
+#[test]
+fn stake_changes_after_creation_do_not_move_the_tally() {
+    let mut governance = governance_fixture(4);
+    for (idx, stake) in [700, 100, 100, 100].into_iter().enumerate() {
+        governance
+            .set_member_stake(idx, stake)
+            .expect("active slot");
+    }
+    let mut proposal = pending_proposal(&governance);
+
+    // A member inflating their own stake mid-vote still counts at 100...
+    governance
+        .set_member_stake(1, u64::MAX - 900)
+        .expect("total stays within u64");
+    proposal
+        .record_vote(
+            &governance,
+            &governance.multisig_members[1],
+            true,
+            APPROVED_AT,
+        )
+        .expect("vote within window");
+    assert_eq!(proposal.yes_stake, 100);
+    assert_eq!(proposal.get_status(), Some(ProposalStatus::Pending));
+
+    // ...and zeroing the whale does not strip its frozen weight either
+    governance.set_member_stake(0, 0).expect("active slot");
+    proposal
+        .record_vote(
+            &governance,
+            &governance.multisig_members[0],
+            true,
+            APPROVED_AT,
+        )
+        .expect("vote within window");
+    assert_eq!(proposal.yes_stake, 800);
+    assert_eq!(proposal.get_status(), Some(ProposalStatus::Approved));
+
+    let ready = first_executable_second(governance.execution_delay, governance.veto_period);
+    proposal
+        .execute(&governance, ready)
+        .expect("quorum is judged against the frozen stakes");
+}
+
+#[test]
+fn execution_rechecks_quorum_against_a_raised_threshold() {
+    let mut governance = governance_fixture(4);
+    for (idx, stake) in [700, 100, 100, 100].into_iter().enumerate() {
+        governance
+            .set_member_stake(idx, stake)
+            .expect("active slot");
+    }
+    let mut proposal = pending_proposal(&governance);
+    proposal
+        .record_vote(
+            &governance,
+            &governance.multisig_members[0],
+            true,
+            APPROVED_AT,
+        )
+        .expect("vote within window");
+
+    governance.quorum_threshold = 8_000;
+
+    let ready = first_executable_second(governance.execution_delay, governance.veto_period);
+    assert_state_error(
+        proposal.execute(&governance, ready),
+        StateError::QuorumNotMet,
+    );
+    assert_eq!(proposal.get_status(), Some(ProposalStatus::Approved));
+}
+
+#[test]
+fn members_cannot_vote_twice_or_after_voting_period() {
+    let governance = governance_fixture(4);
+    let mut proposal = pending_proposal(&governance);
+
+    proposal
+        .record_vote(
+            &governance,
+            &governance.multisig_members[1],
+            false,
+            APPROVED_AT,
+        )
+        .expect("first vote accepted");
+    assert_state_error(
+        proposal.record_vote(
+            &governance,
+            &governance.multisig_members[1],
+            true,
+            APPROVED_AT,
+        ),
+        StateError::AlreadyVoted,
+    );
+    assert_state_error(
+        proposal.record_vote(
+            &governance,
+            &governance.multisig_members[2],
+            true,
+            APPROVED_AT + 1,
+        ),
+        StateError::VotingClosed,
+    );
+    assert_eq!(proposal.yes_stake, 0);
+}
+
+#[test]
+fn proposer_must_clear_proposal_threshold() {
+    let mut governance = governance_fixture(3);
+    let member = governance.multisig_members[2];
+
+    assert_eq!(
+        governance
+            .check_proposer(&member)
+            .expect("member at threshold"),
+        2
+    );
+
+    governance
+        .set_member_stake(2, governance.proposal_threshold - 1)
+        .expect("active slot");
+    assert_state_error(
+        governance.check_proposer(&member).map(|_| ()),
+        StateError::InsufficientProposerStake,
+    );
+}
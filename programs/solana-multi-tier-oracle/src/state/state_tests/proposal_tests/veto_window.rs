@@ -0,0 +1,97 @@
+//! Veto window enforcement for approved proposals.
+//!
+//! The veto is only meaningful if it is bounded: too early and it could cancel
+//! proposals that never passed, too late and it becomes a standing override of
+//! governance. These tests pin both edges of the `veto_period` window and the
+//! requirement that only approved proposals are vetoable.
+
+use super::helpers::{approved_proposal, APPROVED_AT};
+use crate::error::StateError;
+use crate::state::governance_state::Permissions;
+use crate::state::proposal::ProposalStatus;
+use crate::state::state_tests::governance_state_tests::helpers::{
+    assert_state_error, governance_fixture,
+};
+
+#[test]
+fn veto_within_window_marks_proposal_vetoed() {
+    let governance = governance_fixture(3);
+    let mut proposal = approved_proposal();
+
+    proposal
+        .veto(APPROVED_AT + 60, governance.veto_period)
+        .expect("veto inside the window must succeed");
+
+    assert_eq!(proposal.get_status(), Some(ProposalStatus::Vetoed));
+}
+
+#[test]
+fn veto_window_is_inclusive_of_deadline() {
+    let governance = governance_fixture(3);
+    let mut proposal = approved_proposal();
+
+    proposal
+        .veto(APPROVED_AT + governance.veto_period, governance.veto_period)
+        .expect("veto exactly at the deadline must succeed");
+
+    assert_eq!(proposal.get_status(), Some(ProposalStatus::Vetoed));
+}
+
+#[test]
+fn veto_after_window_is_rejected_and_leaves_status_untouched() {
+    let governance = governance_fixture(3);
+    let mut proposal = approved_proposal();
+
+    assert_state_error(
+        proposal.veto(
+            APPROVED_AT + governance.veto_period + 1,
+            governance.veto_period,
+        ),
+        StateError::VetoWindowClosed,
+    );
+    assert_eq!(proposal.get_status(), Some(ProposalStatus::Approved));
+}
+
+#[test]
+fn veto_requires_approved_status() {
+    let governance = governance_fixture(3);
+
+    for status in [
+        ProposalStatus::Pending,
+        ProposalStatus::Executed,
+        ProposalStatus::Vetoed,
+    ] {
+        let mut proposal = approved_proposal();
+        proposal.set_status(status);
+
+        assert_state_error(
+            proposal.veto(APPROVED_AT + 1, governance.veto_period),
+            StateError::ProposalNotApproved,
+        );
+        assert_eq!(proposal.get_status(), Some(status));
+    }
+}
+
+#[test]
+fn veto_permission_is_gated_by_member_permissions() {
+    let mut governance = governance_fixture(8);
+    // Slot 7 carries VETO in the deterministic fixture layout; slot 0 does not.
+    let guardian = governance.multisig_members[7];
+    let updater = governance.multisig_members[0];
+
+    governance
+        .check_member_permission(&guardian, Permissions::VETO)
+        .expect("guardian holds the veto bit");
+    assert_state_error(
+        governance.check_member_permission(&updater, Permissions::VETO),
+        StateError::InsufficientPermissions,
+    );
+
+    governance
+        .revoke_member_permission(7, Permissions::VETO)
+        .expect("slot is active");
+    assert_state_error(
+        governance.check_member_permission(&guardian, Permissions::VETO),
+        StateError::InsufficientPermissions,
+    );
+}
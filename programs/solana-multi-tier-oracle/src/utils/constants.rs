@@ -2,15 +2,31 @@
 pub const MAX_PRICE_FEEDS: usize = 16; // power of 2 for cache alignment
 pub const BUFFER_SIZE: usize = 128; // power of 2 for efficiency
 pub const MAX_HISTORICAL_CHUNKS: usize = 8;
+pub const PRICE_POINT_LAYOUT_LEGACY: u8 = 0; // chunks created before points carried a layout tag
+pub const PRICE_POINT_LAYOUT_VERSION: u8 = 1; // PricePoint layout written by this program
+pub const MIN_HISTORICAL_CHUNKS: usize = 3; // rotating ring written by update_price, slots 0..3
+pub const MAX_ALLOWED_UPDATERS: usize = 8; // vetted keepers for UpdaterPolicy::Allowlist
 pub const MAX_LP_CONCENTRATION: u16 = 3_000; // 30%
 pub const MAX_MULTISIG_MEMBERS: usize = 16;
 pub const MAX_TWAP_WINDOW: u32 = 345_600; // 96 hours in seconds
 pub const MAX_CONFIDENCE_THRESHOLD: u16 = 10_000; // 100% in basis points
 pub const MAX_MANIPULATION_THRESHOLD: u16 = 10_000; // 100% in basis points
+pub const SOURCE_TYPE_COUNT: usize = 4; // SourceType discriminants 0..4 (DEX, CEX, Oracle, Aggregator)
+pub const MAX_SOURCE_THRESHOLD_BPS: u16 = 10_000; // per-source scaling only tightens the manipulation threshold
+pub const PRICE_TIER_COUNT: usize = 3; // Oracle, CEX and DEX trust tiers, most trusted first
+pub const DEFAULT_TIER_SPREAD_BPS: u16 = 500; // cross-tier divergence tolerated before governance sets a bound
+pub const DEFAULT_FEED_SPREAD_BPS: u16 = 500; // cross-feed divergence tolerated before governance sets a bound
+pub const DEFAULT_LIQUIDITY_DROP_BPS: u16 = 3_000; // depth drop between reads tolerated before governance sets a bound
+pub const DEFAULT_MIN_OBSERVATIONS: u16 = 10; // filled ring slots a DEX feed needs before governance sets a floor
 pub const MAX_QUORUM_THRESHOLD: u16 = 10_000; // 100% in basis points
 pub const DEFAULT_VETO_PERIOD: i64 = 86400; // 24 hours in seconds
 pub const MAX_ALLOWED_PROGRAMS: usize = 8; // max allowed DEX and aggregator programs (DEX or aggregator cannot dominate more than 50% of total price feeds allowed to ensure decentralization)
 pub const MIN_HISTORICAL_INTERVAL: i64 = 900; // minimum interval between historical snapshots (15 minutes)
+pub const DEFAULT_MANIPULATION_COOLDOWN: u32 = 900; // hold a manipulation peak for one snapshot interval
+pub const DEFAULT_MANIPULATION_DECAY_PERIOD: u32 = 3_600; // then decay it linearly to zero over an hour
+pub const MIN_HEARTBEAT_INTERVAL: i64 = 60; // keepers may signal liveness at most once a minute
+pub const MAX_ORACLE_BATCH_SIZE: usize = 4; // oracles per batch initialization (packet size and CU bound)
+pub const ORACLE_BATCH_ACCOUNTS_PER_ENTRY: usize = 5; // oracle state, governance, three historical chunks
 
 /// Price feed constants
 pub const MAX_FEED_WEIGHT: u16 = 10_000; // Maximum weight per feed in basis points
@@ -18,7 +34,18 @@ pub const WEIGHT_PRECISION: u32 = 10_000; // Total weight precision (basis point
 pub const MIN_CLMM_LIQUIDITY: u64 = 100_000; // Minimum liquidity for CLMM sources
 pub const MIN_AMM_LIQUIDITY: u64 = 50_000; // Minimum liquidity for AMM sources
 pub const MAX_EXTERNAL_STALENESS: u32 = 300; // Maximum staleness for external oracles (5 minutes)
+pub const MAX_CLOCK_SKEW: u32 = 60; // Widest future-dating a keeper may tolerate on external source timestamps
+pub const MIN_HEALTHY_FEEDS: u8 = 1; // Untripped feeds required before the oracle-wide breaker engages
+pub const FEED_AGE_BUCKET_BOUNDS: [i64; 4] = [60, 300, 900, 3_600]; // Exclusive upper ages (seconds) of the feed age histogram buckets; the last bucket is open-ended
+pub const FALLBACK_WEIGHT_BPS: u16 = 5_000; // Share of its weight a feed keeps while serving its last good price
 pub const ESTIMATED_CU_PER_FEED: u32 = 2_000; // Estimated compute units per feed processing
+pub const MIN_FEED_EXPO: i32 = -18; // Finest supported native feed precision (matches max token decimals)
+pub const MAX_FEED_EXPO: i32 = 0; // Feeds quote at least whole units; positive exponents lose precision
+pub const QUALITY_FRESHNESS_WEIGHT: u32 = 3_000; // Share of the quality score earned by recent updates
+pub const QUALITY_LIQUIDITY_WEIGHT: u32 = 3_000; // Share earned by depth, saturating at the reference depth
+pub const QUALITY_CONCENTRATION_WEIGHT: u32 = 2_000; // Share earned by dispersed LP ownership
+pub const QUALITY_MANIPULATION_WEIGHT: u32 = 2_000; // Share earned by a low manipulation score
+pub const QUALITY_REFERENCE_LIQUIDITY: i128 = 100 * MIN_CLMM_LIQUIDITY as i128; // Depth earning the full liquidity share
 
 /// Snapshot tracking constants for redemption quality control
 /// (leverages existing HistoricalChunk infrastructure)
@@ -35,3 +62,4 @@ pub const SECONDS_PER_96H: i64 = 345600; // 96 hours maximum supported window
 pub const ORACLE_STATE_SEED: &[u8] = b"oracle_state";
 pub const HISTORICAL_CHUNK_SEED: &[u8] = b"historical_chunk";
 pub const GOVERNANCE_SEED: &[u8] = b"governance";
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
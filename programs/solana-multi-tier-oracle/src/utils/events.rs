@@ -1,3 +1,6 @@
+use crate::components::raydium_clmm_observer::fetch_raydium_price::ValidationPolicy;
+use crate::state::governance_state::GovernanceParams;
+use crate::state::oracle_state::{ManipulationSeverity, UpdaterPolicy, Version};
 use crate::state::price_feed::SourceType;
 use anchor_lang::prelude::*;
 
@@ -12,6 +15,7 @@ pub struct OracleInitialized {
     pub manipulation_threshold: u16,
     pub governance_members: u8,
     pub multisig_threshold: u8,
+    pub governance_digest: [u8; 32],
 }
 
 #[event]
@@ -22,6 +26,7 @@ pub struct PriceFeedRegistered {
     pub weight: u16,
     pub feed_index: u32,
     pub total_weight: u32,
+    pub weight_headroom: u32, // WEIGHT_PRECISION - total_weight: weight left for further feeds
     pub timestamp: i64,
 }
 
@@ -32,9 +37,16 @@ pub struct PriceUpdated {
     pub confidence: u64,
     pub timestamp: i64,
     pub twap_window: u32,
-    pub raydium_pools_used: u8,
+    pub raydium_pools_used: u8, // Retained for existing consumers; prefer feeds_aggregated
     pub observed_manipulation_score: u32,
     pub raydium_network_mainnet: u8, // Network flag for operational visibility
+    pub update_nonce: u64,           // Strict ordering independent of timestamps
+    pub feeds_aggregated: u8,
+    pub feeds_excluded_stale: u8,
+    pub feeds_excluded_manipulated: u8,
+    pub twap_deviation_bps: i32, // Signed: positive when the window averaged above spot
+    pub twap_short: Option<i128>, // Fast divergence TWAP, when requested
+    pub twap_long: Option<i128>, // Slow divergence TWAP, when requested
 }
 
 #[event]
@@ -61,3 +73,308 @@ pub struct SaturationWarning {
     pub timestamp: i64,
     pub data_points_processed: u32,
 }
+
+#[event]
+pub struct ProposalVetoed {
+    pub governance: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub vetoed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub governance: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalVoteCast {
+    pub governance: Pubkey,
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub approve: bool,
+    pub stake: u64,
+    pub yes_stake: u64,
+    pub no_stake: u64,
+    pub approved: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub governance: Pubkey,
+    pub proposal: Pubkey,
+    pub proposal_id: u64,
+    pub executed_by: Pubkey,
+    pub yes_stake: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MemberStakeUpdated {
+    pub governance: Pubkey,
+    pub member: Pubkey,
+    pub previous_stake: u64,
+    pub new_stake: u64,
+    pub updated_by: Pubkey,
+    pub governance_digest: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GovernanceParamsUpdated {
+    pub governance: Pubkey,
+    pub previous: GovernanceParams,
+    pub updated: GovernanceParams,
+    pub updated_by: Pubkey,
+    pub governance_digest: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MaintenanceModeChanged {
+    pub oracle: Pubkey,
+    pub enabled: bool,
+    pub changed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RelaxedMinElapsedChanged {
+    pub oracle: Pubkey,
+    pub enabled: bool,
+    pub changed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SpotFallbackChanged {
+    pub oracle: Pubkey,
+    pub enabled: bool,
+    pub changed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OracleStateMigrated {
+    pub oracle: Pubkey,
+    pub from_version: Version,
+    pub to_version: Version,
+    pub account_size: u32, // New data length, discriminator included
+    pub canonical_expo: i32,
+    pub migrated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MemberRemoved {
+    pub governance: Pubkey,
+    pub member: Pubkey,
+    pub removed_by: Pubkey,
+    pub remaining_members: u8,
+    pub governance_digest: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ManipulationDecayUpdated {
+    pub oracle: Pubkey,
+    pub cooldown: u32,
+    pub decay_period: u32,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SourceThresholdsUpdated {
+    pub oracle: Pubkey,
+    pub previous_thresholds_bps: [u16; 4],
+    pub thresholds_bps: [u16; 4], // Indexed by SourceType discriminant
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmergencyAdminTransferred {
+    pub oracle: Pubkey,
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+    pub approved_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct Heartbeat {
+    pub oracle: Pubkey,
+    pub keeper: Pubkey,
+    pub update_nonce: u64,
+    pub price_timestamp: i64, // Age of the published price, unchanged by the heartbeat
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UpdateSkipped {
+    pub oracle: Pubkey,
+    pub reason_code: u16, // Stable `SkipReason` code
+    pub skipped_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiquidityShock {
+    pub oracle: Pubkey,
+    pub feed: Pubkey,
+    pub previous_depth: i128,
+    pub current_depth: i128,
+    pub drop_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmergencyKillSwitchActivated {
+    pub oracle: Pubkey,
+    pub activated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OracleResumed {
+    pub oracle: Pubkey,
+    pub resumed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WeightsRebalanced {
+    pub oracle: Pubkey,
+    pub weights: Vec<u16>, // Rescaled weights of the existing feeds, in slot order
+    pub incoming_weight: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UpdateCooldownUpdated {
+    pub oracle: Pubkey,
+    pub cooldown: u32,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct UpdatersChanged {
+    pub oracle: Pubkey,
+    pub previous_policy: UpdaterPolicy,
+    pub policy: UpdaterPolicy,
+    pub updater_count: u8,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HistoricalChunkClosed {
+    pub oracle: Pubkey,
+    pub chunk: Pubkey,
+    pub chunk_index: u8,
+    pub recipient: Pubkey,
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ChunkLinksRepaired {
+    pub oracle: Pubkey,
+    pub chunk_count: u8,
+    pub links_rewritten: u8, // Zero when the links were already canonical
+    pub repaired_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TwapWindowRampStarted {
+    pub oracle: Pubkey,
+    pub from_window: u32,
+    pub target_window: u32,
+    pub ramp_duration: u32,
+    pub started_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeedBreakerTripped {
+    pub oracle: Pubkey,
+    pub feed: Pubkey,
+    pub manipulation_score: u16,
+    pub manipulation_threshold: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ManipulationEscalated {
+    pub oracle: Pubkey,
+    pub feed: Pubkey,
+    pub manipulation_score: u16,
+    pub manipulation_threshold: u16,
+    pub severity: ManipulationSeverity,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EscalationLadderUpdated {
+    pub oracle: Pubkey,
+    pub previous_ladder: [u16; 2],
+    pub ladder: [u16; 2], // [arm_breaker, emergency] scores; zero disables a rung
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeedSpreadUpdated {
+    pub oracle: Pubkey,
+    pub previous_max_spread_bps: u16,
+    pub max_spread_bps: u16,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LiquidityDropBoundUpdated {
+    pub oracle: Pubkey,
+    pub previous_max_drop_bps: u16,
+    pub max_drop_bps: u16,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ValidationPolicyUpdated {
+    pub oracle: Pubkey,
+    pub previous_policy: ValidationPolicy,
+    pub policy: ValidationPolicy,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MinObservationsUpdated {
+    pub oracle: Pubkey,
+    pub previous_min_observations: u16,
+    pub min_observations: u16,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TierConfigUpdated {
+    pub oracle: Pubkey,
+    pub previous_weights_bps: [u16; 3],
+    pub weights_bps: [u16; 3], // Oracle, CEX, DEX blend weights summing to 10000
+    pub previous_max_spread_bps: u16,
+    pub max_spread_bps: u16,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
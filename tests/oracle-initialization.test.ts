@@ -74,6 +74,7 @@ function buildConfig(
     manipulationThreshold: overrides.manipulationThreshold || 1000,
     emergencyAdmin,
     enableCircuitBreaker: overrides.enableCircuitBreaker ?? true,
+    maxTwapWindowOverride: overrides.maxTwapWindowOverride ?? 0,
     governanceConfig: {
       memberCount: overrides.memberCount || 3,
       initialMembers: [
@@ -195,6 +196,7 @@ describe("Oracle Initialization Integration Tests", () => {
         manipulationThreshold: 1000, // 10%
         emergencyAdmin: emergencyAdmin.publicKey,
         enableCircuitBreaker: true,
+        maxTwapWindowOverride: 0,
         governanceConfig: {
           memberCount: 5,
           initialMembers: [
@@ -613,6 +615,7 @@ describe("Oracle Initialization Integration Tests", () => {
         manipulationThreshold: 1000,
         emergencyAdmin: emergencyAdmin.publicKey,
         enableCircuitBreaker: true,
+        maxTwapWindowOverride: 0,
         governanceConfig: {
           memberCount: 3,
           initialMembers: [
@@ -670,6 +673,7 @@ describe("Oracle Initialization Integration Tests", () => {
         manipulationThreshold: 1000,
         emergencyAdmin: emergencyAdmin.publicKey,
         enableCircuitBreaker: true,
+        maxTwapWindowOverride: 0,
         governanceConfig: {
           memberCount: 3,
           initialMembers: [
@@ -730,6 +734,7 @@ describe("Oracle Initialization Integration Tests", () => {
         manipulationThreshold: 1000,
         emergencyAdmin: emergencyAdmin.publicKey,
         enableCircuitBreaker: true,
+        maxTwapWindowOverride: 0,
         governanceConfig: {
           memberCount: 3,
           initialMembers: [
@@ -795,6 +800,7 @@ describe("Oracle Initialization Integration Tests", () => {
         manipulationThreshold: 2000,
         emergencyAdmin: emergencyAdmin.publicKey,
         enableCircuitBreaker: false,
+        maxTwapWindowOverride: 0,
         governanceConfig: {
           memberCount: 3,
           initialMembers: [
@@ -900,6 +906,7 @@ describe("Oracle Initialization Integration Tests", () => {
         manipulationThreshold: 1000,
         emergencyAdmin: emergencyAdmin.publicKey,
         enableCircuitBreaker: true,
+        maxTwapWindowOverride: 0,
         governanceConfig: {
           memberCount: 3,
           initialMembers: [
@@ -958,6 +965,7 @@ describe("Oracle Initialization Integration Tests", () => {
         manipulationThreshold: 1000,
         emergencyAdmin: emergencyAdmin.publicKey,
         enableCircuitBreaker: true,
+        maxTwapWindowOverride: 0,
         governanceConfig: {
           memberCount: 3,
           initialMembers: [
@@ -1018,6 +1026,7 @@ describe("Oracle Initialization Integration Tests", () => {
         manipulationThreshold: 1000,
         emergencyAdmin: emergencyAdmin.publicKey,
         enableCircuitBreaker: true,
+        maxTwapWindowOverride: 0,
         governanceConfig: {
           memberCount: 5,
           initialMembers: [
@@ -1080,6 +1089,7 @@ describe("Oracle Initialization Integration Tests", () => {
         manipulationThreshold: 1000,
         emergencyAdmin: emergencyAdmin.publicKey,
         enableCircuitBreaker: true,
+        maxTwapWindowOverride: 0,
         governanceConfig: {
           memberCount: 5,
           initialMembers: [
@@ -1179,7 +1189,7 @@ describe("Oracle Initialization Integration Tests", () => {
       );
 
       expect(oracleAccount.version.major).to.equal(0);
-      expect(oracleAccount.version.minor).to.equal(1);
+      expect(oracleAccount.version.minor).to.equal(2);
       expect(oracleAccount.version.patch).to.equal(0);
     });
 
@@ -1334,6 +1344,7 @@ describe("Oracle Initialization Integration Tests", () => {
         manipulationThreshold: 1000,
         emergencyAdmin: emergencyAdmin.publicKey,
         enableCircuitBreaker: true,
+        maxTwapWindowOverride: 0,
         governanceConfig: {
           memberCount: 3,
           initialMembers: [
@@ -1393,6 +1404,7 @@ describe("Oracle Initialization Integration Tests", () => {
         manipulationThreshold: 1000,
         emergencyAdmin: emergencyAdmin.publicKey,
         enableCircuitBreaker: true,
+        maxTwapWindowOverride: 0,
         governanceConfig: {
           memberCount: 3,
           initialMembers: [
@@ -1456,6 +1468,7 @@ describe("Oracle Initialization Integration Tests", () => {
         manipulationThreshold: 1000,
         emergencyAdmin: emergencyAdmin.publicKey,
         enableCircuitBreaker: true,
+        maxTwapWindowOverride: 0,
         governanceConfig: {
           memberCount: 3,
           initialMembers: [
@@ -1513,6 +1526,7 @@ describe("Oracle Initialization Integration Tests", () => {
         manipulationThreshold: 1000,
         emergencyAdmin: emergencyAdmin.publicKey,
         enableCircuitBreaker: true,
+        maxTwapWindowOverride: 0,
         governanceConfig: {
           memberCount: 3,
           initialMembers: [
@@ -1574,6 +1588,7 @@ describe("Oracle Initialization Integration Tests", () => {
         manipulationThreshold: 1000,
         emergencyAdmin: emergencyAdmin.publicKey,
         enableCircuitBreaker: true,
+        maxTwapWindowOverride: 0,
         governanceConfig: {
           memberCount: 3,
           initialMembers: [
@@ -1634,6 +1649,7 @@ describe("Oracle Initialization Integration Tests", () => {
         manipulationThreshold: 1000,
         emergencyAdmin: emergencyAdmin.publicKey,
         enableCircuitBreaker: true,
+        maxTwapWindowOverride: 0,
         governanceConfig: {
           memberCount: 3,
           initialMembers: [
@@ -1692,6 +1708,7 @@ describe("Oracle Initialization Integration Tests", () => {
         manipulationThreshold: 1000,
         emergencyAdmin: emergencyAdmin.publicKey,
         enableCircuitBreaker: true,
+        maxTwapWindowOverride: 0,
         governanceConfig: {
           memberCount: 3,
           initialMembers: [
@@ -12,6 +12,7 @@ const MIN_CLMM_LIQUIDITY = new BN("100000"); // 100,000 base units
 const MIN_AMM_LIQUIDITY = new BN("50000"); // 50,000 base units
 const MAX_PRICE_FEEDS = 16;
 const MAX_FEED_WEIGHT = 10_000; // 100% in basis points
+const MIN_POOL_ACCOUNT_LEN = 273; // discriminator + mapped Raydium pool prefix
 
 // Source type constants
 const SourceType = {
@@ -43,12 +44,14 @@ async function createSystemAccount(
   pubkey: PublicKey,
   payer: Keypair,
   privateKey?: Keypair,
-  lamports = 1_000_000
+  space = MIN_POOL_ACCOUNT_LEN
 ) {
+  // Sources must hold lamports and, for pool-backed types, pool-sized data
+  const lamports = await connection.getMinimumBalanceForRentExemption(space);
   const ix = anchor.web3.SystemProgram.createAccount({
     fromPubkey: payer.publicKey,
     newAccountPubkey: pubkey,
-    space: 0,
+    space,
     lamports,
     programId: anchor.web3.SystemProgram.programId,
   });
@@ -98,6 +101,7 @@ async function registerFeed({
   weight,
   minLiquidity,
   stalenessThreshold,
+  expo = 0,
   assetSeed,
   oracleAccounts,
   authority = null,
@@ -109,6 +113,7 @@ async function registerFeed({
   weight: number;
   minLiquidity: BN;
   stalenessThreshold: number;
+  expo?: number;
   assetSeed: Uint8Array;
   oracleAccounts: any;
   authority?: Keypair | null;
@@ -128,6 +133,8 @@ async function registerFeed({
     weight,
     minLiquidity,
     stalenessThreshold,
+    expo,
+    normalize: false,
     assetSeed: Array.from(assetSeed),
   };
 
@@ -271,6 +278,7 @@ describe("Price Feed Registration Integration Tests", () => {
       manipulationThreshold: 1000,
       emergencyAdmin: emergencyAdmin.publicKey,
       enableCircuitBreaker: false, // Disable circuit breaker for testing
+      maxTwapWindowOverride: 0,
       governanceConfig: {
         memberCount: 5,
         initialMembers: [
@@ -320,6 +328,7 @@ describe("Price Feed Registration Integration Tests", () => {
         weight: 500,
         minLiquidity: MIN_CLMM_LIQUIDITY,
         stalenessThreshold: 300,
+        expo: 0,
         assetSeed,
         oracleAccounts,
         authority,
@@ -359,6 +368,7 @@ describe("Price Feed Registration Integration Tests", () => {
         weight: 600,
         minLiquidity: MIN_AMM_LIQUIDITY,
         stalenessThreshold: 300,
+        expo: 0,
         assetSeed,
         oracleAccounts,
         authority,
@@ -374,6 +384,7 @@ describe("Price Feed Registration Integration Tests", () => {
         weight: 700,
         minLiquidity: new BN(0), // CEX doesn't need liquidity check
         stalenessThreshold: 180,
+        expo: 0,
         assetSeed,
         oracleAccounts,
         authority,
@@ -463,6 +474,7 @@ describe("Price Feed Registration Integration Tests", () => {
         manipulationThreshold: 1000,
         emergencyAdmin: emergencyAdmin.publicKey,
         enableCircuitBreaker: false,
+        maxTwapWindowOverride: 0,
         governanceConfig: createMinimalGovernanceConfig(
           authority.publicKey,
           governanceMembers
@@ -500,6 +512,8 @@ describe("Price Feed Registration Integration Tests", () => {
         weight: 2500,
         minLiquidity: MIN_CLMM_LIQUIDITY,
         stalenessThreshold: 300,
+        expo: 0,
+        normalize: false,
         assetSeed: Array.from(testAssetSeed),
       };
 
@@ -521,6 +535,8 @@ describe("Price Feed Registration Integration Tests", () => {
         weight: 3000,
         minLiquidity: MIN_AMM_LIQUIDITY,
         stalenessThreshold: 300,
+        expo: 0,
+        normalize: false,
         assetSeed: Array.from(testAssetSeed),
       };
 
@@ -557,6 +573,8 @@ describe("Price Feed Registration Integration Tests", () => {
         weight: 15000, // 150% - exceeds MAX_FEED_WEIGHT (10,000)
         minLiquidity: MIN_CLMM_LIQUIDITY,
         stalenessThreshold: 300,
+        expo: 0,
+        normalize: false,
         assetSeed: Array.from(testAssetSeed),
       };
 
@@ -593,6 +611,8 @@ describe("Price Feed Registration Integration Tests", () => {
         weight: 0, // Invalid zero weight
         minLiquidity: MIN_CLMM_LIQUIDITY,
         stalenessThreshold: 300,
+        expo: 0,
+        normalize: false,
         assetSeed: Array.from(testAssetSeed),
       };
 
@@ -614,6 +634,45 @@ describe("Price Feed Registration Integration Tests", () => {
       }
     });
 
+    it("Should reject DEX sources without pool-sized data", async () => {
+      const emptySource = Keypair.generate();
+      await createSystemAccount(
+        provider.connection,
+        emptySource.publicKey,
+        authority,
+        emptySource,
+        0 // Funded but holds no data
+      );
+
+      const feedConfig = {
+        sourceAddress: emptySource.publicKey,
+        sourceType: { dex: {} },
+        weight: 1000,
+        minLiquidity: MIN_CLMM_LIQUIDITY,
+        stalenessThreshold: 300,
+        expo: 0,
+        normalize: false,
+        assetSeed: Array.from(testAssetSeed),
+      };
+
+      try {
+        await program.methods
+          .registerPriceFeed(feedConfig)
+          .accounts({
+            oracleState: testOracleAccounts.oracle,
+            governanceState: testOracleAccounts.governance,
+            feedSource: feedConfig.sourceAddress,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+
+        assert.fail("Expected transaction to fail with an empty source");
+      } catch (error: any) {
+        expectAnchorError(error, "InvalidSourceAddress");
+      }
+    });
+
     it("Should reject total weight exceeding 100%", async () => {
       // Add feeds up to near the limit first
       const feed1Source = Keypair.generate();
@@ -631,6 +690,8 @@ describe("Price Feed Registration Integration Tests", () => {
           weight: 6000, // 60%
           minLiquidity: MIN_CLMM_LIQUIDITY,
           stalenessThreshold: 300,
+          expo: 0,
+          normalize: false,
           assetSeed: Array.from(testAssetSeed),
         })
         .accounts({
@@ -657,6 +718,8 @@ describe("Price Feed Registration Integration Tests", () => {
         weight: 5000, // 50% - would make total 110% with existing 60%
         minLiquidity: MIN_AMM_LIQUIDITY,
         stalenessThreshold: 300,
+        expo: 0,
+        normalize: false,
         assetSeed: Array.from(testAssetSeed),
       };
 
@@ -694,6 +757,8 @@ describe("Price Feed Registration Integration Tests", () => {
         weight: 1000,
         minLiquidity: MIN_CLMM_LIQUIDITY,
         stalenessThreshold: 300,
+        expo: 0,
+        normalize: false,
         assetSeed: Array.from(wrongSeed), // Wrong seed - doesn't match oracle PDA
       };
 
@@ -736,6 +801,8 @@ describe("Price Feed Registration Integration Tests", () => {
         weight: 300, // Small weight
         minLiquidity: new BN("50000"), // Below MIN_CLMM_LIQUIDITY (100,000)
         stalenessThreshold: 300,
+        expo: 0,
+        normalize: false,
         assetSeed: Array.from(testAssetSeed),
       };
 
@@ -781,6 +848,8 @@ describe("Price Feed Registration Integration Tests", () => {
         weight: 2500,
         minLiquidity: MIN_CLMM_LIQUIDITY,
         stalenessThreshold: 300,
+        expo: 0,
+        normalize: false,
         assetSeed: Array.from(assetSeed),
       };
 
@@ -821,6 +890,8 @@ describe("Price Feed Registration Integration Tests", () => {
         weight: 200, // 2% weight
         minLiquidity: new BN(0),
         stalenessThreshold: 600,
+        expo: 0,
+        normalize: false,
         assetSeed: Array.from(assetSeed),
       };
 
@@ -912,6 +983,7 @@ describe("Price Feed Registration Integration Tests", () => {
           manipulationThreshold: 1000,
           emergencyAdmin: emergencyAdmin.publicKey,
           enableCircuitBreaker: false,
+          maxTwapWindowOverride: 0,
           governanceConfig: createMinimalGovernanceConfig(
             authority.publicKey,
             governanceMembers
@@ -948,6 +1020,8 @@ describe("Price Feed Registration Integration Tests", () => {
             weight: feedWeight,
             minLiquidity: MIN_CLMM_LIQUIDITY,
             stalenessThreshold: 300,
+            expo: 0,
+            normalize: false,
             assetSeed: Array.from(maxFeedsAssetSeed),
           })
           .accounts({
@@ -990,6 +1064,8 @@ describe("Price Feed Registration Integration Tests", () => {
             weight: 500,
             minLiquidity: MIN_CLMM_LIQUIDITY,
             stalenessThreshold: 300,
+            expo: 0,
+            normalize: false,
             assetSeed: Array.from(maxFeedsAssetSeed),
           })
           .accounts({
@@ -1024,6 +1100,8 @@ describe("Price Feed Registration Integration Tests", () => {
         weight: 500, // 5% - small weight to avoid exceeding total
         minLiquidity: new BN(0),
         stalenessThreshold: 240,
+        expo: 0,
+        normalize: false,
         assetSeed: Array.from(assetSeed),
       };
 
@@ -1103,6 +1181,7 @@ describe("Price Feed Registration Integration Tests", () => {
           manipulationThreshold: 1000,
           emergencyAdmin: emergencyAdmin.publicKey,
           enableCircuitBreaker: true, // ENABLE circuit breaker
+          maxTwapWindowOverride: 0,
           governanceConfig: createMinimalGovernanceConfig(
             authority.publicKey,
             governanceMembers
@@ -1137,6 +1216,8 @@ describe("Price Feed Registration Integration Tests", () => {
             weight: 1000,
             minLiquidity: MIN_CLMM_LIQUIDITY,
             stalenessThreshold: 300,
+            expo: 0,
+            normalize: false,
             assetSeed: Array.from(cbAssetSeed),
           })
           .accounts({
@@ -1211,6 +1292,7 @@ describe("Price Feed Registration Integration Tests", () => {
           manipulationThreshold: 1000,
           emergencyAdmin: emergencyAdmin.publicKey,
           enableCircuitBreaker: false,
+          maxTwapWindowOverride: 0,
           governanceConfig: createMinimalGovernanceConfig(
             authority.publicKey,
             governanceMembers
@@ -1273,6 +1355,8 @@ describe("Price Feed Registration Integration Tests", () => {
         weight: 1000,
         minLiquidity: MIN_CLMM_LIQUIDITY,
         stalenessThreshold: 300,
+        expo: 0,
+        normalize: false,
         assetSeed: Array.from(assetSeed),
       };
 
@@ -1312,6 +1396,8 @@ describe("Price Feed Registration Integration Tests", () => {
         weight: 300,
         minLiquidity: new BN(0),
         stalenessThreshold: 600,
+        expo: 0,
+        normalize: false,
         assetSeed: Array.from(assetSeed),
       };
 
@@ -167,6 +167,7 @@ describe("Price Update Integration Tests (mainnet clone)", () => {
       manipulationThreshold: 1000,
       emergencyAdmin: emergencyAdmin.publicKey,
       enableCircuitBreaker: false,
+      maxTwapWindowOverride: 0,
       governanceConfig: {
         memberCount: 5,
         initialMembers: [
@@ -209,6 +210,8 @@ describe("Price Update Integration Tests (mainnet clone)", () => {
       weight: 5000,
       minLiquidity: new BN("100000000000"),
       stalenessThreshold: 31_536_000, // ~1 year to bypass staleness on a snapshot
+      expo: -6, // USDC quote; a coarser feed expo would round the published blend
+      normalize: false,
       assetSeed: Array.from(assetSeed),
     };
 
@@ -233,6 +236,22 @@ describe("Price Update Integration Tests (mainnet clone)", () => {
         minLiquidity: new BN("100000000000"),
         maxTickDeviation: 1000,
         alphaBasisPoints: 500,
+        impactTradeSize: new BN(0),
+        confidenceMethod: { variance: {} },
+        weightingScheme: { confidenceWeighted: {} },
+        softSkip: false,
+        strictCoverage: false,
+        maxClockSkew: 0,
+        historyDeviationBps: 0,
+        requireWindowAlignment: true,
+        force: false,
+        maxSaturationEvents: 3,
+        dryRun: false,
+        expectedMin: null,
+        expectedMax: null,
+        windowShort: 0,
+        windowLong: 0,
+        feedEwmaAlphaBps: 2_000,
         assetSeed: Array.from(assetSeed),
         useMainnet: true, // <<< using mainnet CLMM program id
       };
@@ -273,10 +292,14 @@ describe("Price Update Integration Tests (mainnet clone)", () => {
       //expect(oracleAccount.currentPrice.conf.toNumber()).to.be.greaterThan(0);
       expect(oracleAccount.lastUpdate.toNumber()).to.be.greaterThan(0);
 
+      // Feeds store prices in their native exponent; rescale before comparing
       const feed = oracleAccount.priceFeeds[0];
-      expect(feed.lastPrice.toString()).to.equal(
-        oracleAccount.currentPrice.price.toString()
-      );
+      const shift = oracleAccount.currentPrice.expo - feed.lastExpo;
+      const expectedFeedPrice =
+        shift >= 0
+          ? oracleAccount.currentPrice.price.mul(new BN(10).pow(new BN(shift)))
+          : oracleAccount.currentPrice.price.div(new BN(10).pow(new BN(-shift)));
+      expect(feed.lastPrice.toString()).to.equal(expectedFeedPrice.toString());
       //expect(feed.lastConf.eq(oracleAccount.currentPrice.conf)).to.eq(true);
       expect(feed.lastUpdate.eq(oracleAccount.lastUpdate)).to.eq(true);
     });
@@ -296,6 +319,22 @@ describe("Price Update Integration Tests (mainnet clone)", () => {
         minLiquidity: new BN("100000000000"),
         maxTickDeviation: 1000,
         alphaBasisPoints: 500,
+        impactTradeSize: new BN(0),
+        confidenceMethod: { variance: {} },
+        weightingScheme: { confidenceWeighted: {} },
+        softSkip: false,
+        strictCoverage: false,
+        maxClockSkew: 0,
+        historyDeviationBps: 0,
+        requireWindowAlignment: true,
+        force: false,
+        maxSaturationEvents: 3,
+        dryRun: false,
+        expectedMin: null,
+        expectedMax: null,
+        windowShort: 0,
+        windowLong: 0,
+        feedEwmaAlphaBps: 2_000,
         assetSeed: Array.from(assetSeed),
         useMainnet: true,
       };
@@ -333,6 +372,22 @@ describe("Price Update Integration Tests (mainnet clone)", () => {
         minLiquidity: new BN("100000000000"),
         maxTickDeviation: 1000,
         alphaBasisPoints: alpha,
+        impactTradeSize: new BN(0),
+        confidenceMethod: { variance: {} },
+        weightingScheme: { confidenceWeighted: {} },
+        softSkip: false,
+        strictCoverage: false,
+        maxClockSkew: 0,
+        historyDeviationBps: 0,
+        requireWindowAlignment: true,
+        force: false,
+        maxSaturationEvents: 3,
+        dryRun: false,
+        expectedMin: null,
+        expectedMax: null,
+        windowShort: 0,
+        windowLong: 0,
+        feedEwmaAlphaBps: 2_000,
         assetSeed: Array.from(assetSeed),
         useMainnet: true,
       }));
@@ -373,6 +428,22 @@ describe("Price Update Integration Tests (mainnet clone)", () => {
         minLiquidity: new BN("100000000000"),
         maxTickDeviation: 1000,
         alphaBasisPoints: 500,
+        impactTradeSize: new BN(0),
+        confidenceMethod: { variance: {} },
+        weightingScheme: { confidenceWeighted: {} },
+        softSkip: false,
+        strictCoverage: false,
+        maxClockSkew: 0,
+        historyDeviationBps: 0,
+        requireWindowAlignment: true,
+        force: false,
+        maxSaturationEvents: 3,
+        dryRun: false,
+        expectedMin: null,
+        expectedMax: null,
+        windowShort: 0,
+        windowLong: 0,
+        feedEwmaAlphaBps: 2_000,
         assetSeed: Array.from(assetSeed),
         useMainnet: true,
       };
@@ -411,6 +482,22 @@ describe("Price Update Integration Tests (mainnet clone)", () => {
         minLiquidity: new BN("100000000000"),
         maxTickDeviation: 1000,
         alphaBasisPoints: 500,
+        impactTradeSize: new BN(0),
+        confidenceMethod: { variance: {} },
+        weightingScheme: { confidenceWeighted: {} },
+        softSkip: false,
+        strictCoverage: false,
+        maxClockSkew: 0,
+        historyDeviationBps: 0,
+        requireWindowAlignment: true,
+        force: false,
+        maxSaturationEvents: 3,
+        dryRun: false,
+        expectedMin: null,
+        expectedMax: null,
+        windowShort: 0,
+        windowLong: 0,
+        feedEwmaAlphaBps: 2_000,
         assetSeed: Array.from(assetSeed),
         useMainnet: true,
       };
@@ -446,6 +533,22 @@ describe("Price Update Integration Tests (mainnet clone)", () => {
         minLiquidity: new BN("100000000000"),
         maxTickDeviation: 1000,
         alphaBasisPoints: 500,
+        impactTradeSize: new BN(0),
+        confidenceMethod: { variance: {} },
+        weightingScheme: { confidenceWeighted: {} },
+        softSkip: false,
+        strictCoverage: false,
+        maxClockSkew: 0,
+        historyDeviationBps: 0,
+        requireWindowAlignment: true,
+        force: false,
+        maxSaturationEvents: 3,
+        dryRun: false,
+        expectedMin: null,
+        expectedMax: null,
+        windowShort: 0,
+        windowLong: 0,
+        feedEwmaAlphaBps: 2_000,
         assetSeed: Array.from(assetSeed),
         useMainnet: true,
       };
@@ -470,7 +573,7 @@ describe("Price Update Integration Tests (mainnet clone)", () => {
         const s = err.toString();
         // Accept any of the typical validations the program may hit first
         expect(
-          /PoolMismatch|InvalidProgramOwner|ConstraintOwner|AccountOwnedByWrongProgram/.test(
+          /BadPda|PoolMismatch|InvalidProgramOwner|ConstraintOwner|AccountOwnedByWrongProgram/.test(
             s
           ),
           s
@@ -526,6 +629,22 @@ describe("Price Update Integration Tests (mainnet clone)", () => {
         minLiquidity: new BN("100000000000"),
         maxTickDeviation: 1000,
         alphaBasisPoints: 500,
+        impactTradeSize: new BN(0),
+        confidenceMethod: { variance: {} },
+        weightingScheme: { confidenceWeighted: {} },
+        softSkip: false,
+        strictCoverage: false,
+        maxClockSkew: 0,
+        historyDeviationBps: 0,
+        requireWindowAlignment: true,
+        force: false,
+        maxSaturationEvents: 3,
+        dryRun: false,
+        expectedMin: null,
+        expectedMax: null,
+        windowShort: 0,
+        windowLong: 0,
+        feedEwmaAlphaBps: 2_000,
         assetSeed: Array.from(assetSeed),
         useMainnet: true,
       };
@@ -554,6 +673,317 @@ describe("Price Update Integration Tests (mainnet clone)", () => {
     });
   });
 
+  describe("Dry Run Tests", () => {
+    const dryRunConfig = (dryRun: boolean) => ({
+      windowSeconds: 3600,
+      minSeconds: 60,
+      minLiquidity: new BN("100000000000"),
+      maxTickDeviation: 1000,
+      alphaBasisPoints: 500,
+      impactTradeSize: new BN(0),
+      confidenceMethod: { variance: {} },
+      weightingScheme: { confidenceWeighted: {} },
+      softSkip: false,
+      strictCoverage: false,
+      maxClockSkew: 0,
+      historyDeviationBps: 0,
+      requireWindowAlignment: true,
+      force: false,
+      maxSaturationEvents: 3,
+      dryRun,
+      expectedMin: null,
+      expectedMax: null,
+      windowShort: 0,
+      windowLong: 0,
+      feedEwmaAlphaBps: 2_000,
+      assetSeed: Array.from(assetSeed),
+      useMainnet: true,
+    });
+
+    const updateIx = (dryRun: boolean) =>
+      program.methods
+        .updatePrice(dryRunConfig(dryRun))
+        .accounts({
+          oracleState: oracleAccounts.oracle,
+          governanceState: oracleAccounts.governance,
+          historicalChunk0: oracleAccounts.historicalChunk0,
+          historicalChunk1: oracleAccounts.historicalChunk1,
+          historicalChunk2: oracleAccounts.historicalChunk2,
+          raydiumPool,
+          raydiumObservation,
+          authority: authority.publicKey,
+        })
+        .instruction();
+
+    const accountBytes = async (key: PublicKey) =>
+      (await provider.connection.getAccountInfo(key))!.data;
+
+    it("leaves oracle and history untouched", async () => {
+      await sleep(1200);
+      const watched = [
+        oracleAccounts.oracle,
+        oracleAccounts.historicalChunk0,
+        oracleAccounts.historicalChunk1,
+        oracleAccounts.historicalChunk2,
+      ];
+      const before = await Promise.all(watched.map(accountBytes));
+
+      await program.methods
+        .updatePrice(dryRunConfig(true))
+        .accounts({
+          oracleState: oracleAccounts.oracle,
+          governanceState: oracleAccounts.governance,
+          historicalChunk0: oracleAccounts.historicalChunk0,
+          historicalChunk1: oracleAccounts.historicalChunk1,
+          historicalChunk2: oracleAccounts.historicalChunk2,
+          raydiumPool,
+          raydiumObservation,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc({ commitment: "confirmed" });
+
+      const after = await Promise.all(watched.map(accountBytes));
+      watched.forEach((_, i) => {
+        expect(Buffer.compare(before[i], after[i])).to.equal(
+          0,
+          `account ${i} changed during a dry run`
+        );
+      });
+    });
+
+    it("returns the price a real update then publishes", async () => {
+      await sleep(1200);
+
+      // Same transaction, same clock: the dry run's state restore means the
+      // real update starts from exactly the state the dry run saw
+      const tx = new anchor.web3.Transaction()
+        .add(await updateIx(true))
+        .add(await updateIx(false));
+      const signature = await (provider as anchor.AnchorProvider).sendAndConfirm(
+        tx,
+        [authority],
+        { commitment: "confirmed" }
+      );
+
+      const confirmed = await provider.connection.getTransaction(signature, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const returned = (confirmed?.meta?.logMessages ?? [])
+        .filter((line) => line.startsWith(`Program return: ${program.programId}`))
+        .map((line) => line.split(" ").pop()!);
+
+      expect(returned).to.have.length(2);
+      expect(returned[0]).to.equal(returned[1]);
+
+      // Option<PriceData>: a Some tag followed by the i128 price
+      const payload = Buffer.from(returned[0], "base64");
+      expect(payload[0]).to.equal(1);
+      const oracleAccount = await (program.account as any).oracleState.fetch(
+        oracleAccounts.oracle
+      );
+      const price = new BN(payload.subarray(1, 17), "le").fromTwos(128);
+      expect(price.toString()).to.equal(
+        oracleAccount.currentPrice.price.toString()
+      );
+    });
+  });
+
+  describe("Halt Guard Tests", () => {
+    const setMaintenance = (enabled: boolean) =>
+      program.methods
+        .setMaintenance(enabled)
+        .accounts({
+          oracleState: oracleAccounts.oracle,
+          governanceState: oracleAccounts.governance,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+    const guardedConfig = {
+      windowSeconds: 3600,
+      minSeconds: 60,
+      minLiquidity: new BN("100000000000"),
+      maxTickDeviation: 1000,
+      alphaBasisPoints: 500,
+      impactTradeSize: new BN(0),
+      confidenceMethod: { variance: {} },
+      weightingScheme: { confidenceWeighted: {} },
+      softSkip: false,
+      strictCoverage: false,
+      maxClockSkew: 0,
+      historyDeviationBps: 0,
+      requireWindowAlignment: true,
+      force: false,
+      maxSaturationEvents: 3,
+      dryRun: false,
+      expectedMin: null,
+      expectedMax: null,
+      windowShort: 0,
+      windowLong: 0,
+      feedEwmaAlphaBps: 2_000,
+      assetSeed: Array.from(assetSeed),
+      useMainnet: true,
+    };
+
+    before(async () => {
+      await setMaintenance(true);
+    });
+
+    after(async () => {
+      await setMaintenance(false);
+    });
+
+    it("rejects price updates with MaintenanceActive", async () => {
+      try {
+        await program.methods
+          .updatePrice(guardedConfig)
+          .accounts({
+            oracleState: oracleAccounts.oracle,
+            governanceState: oracleAccounts.governance,
+            historicalChunk0: oracleAccounts.historicalChunk0,
+            historicalChunk1: oracleAccounts.historicalChunk1,
+            historicalChunk2: oracleAccounts.historicalChunk2,
+            raydiumPool,
+            raydiumObservation,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+        assert.fail("Expected MaintenanceActive");
+      } catch (err: any) {
+        expect(err.toString()).to.include("MaintenanceActive");
+      }
+    });
+
+    it("rejects heartbeats with MaintenanceActive", async () => {
+      try {
+        await program.methods
+          .heartbeat()
+          .accounts({
+            oracleState: oracleAccounts.oracle,
+            governanceState: oracleAccounts.governance,
+            keeper: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+        assert.fail("Expected MaintenanceActive");
+      } catch (err: any) {
+        expect(err.toString()).to.include("MaintenanceActive");
+      }
+    });
+
+    it("still accepts configuration changes", async () => {
+      const before = await (program.account as any).oracleState.fetch(
+        oracleAccounts.oracle
+      );
+
+      await program.methods
+        .setUpdateCooldown(before.updateCooldown)
+        .accounts({
+          oracleState: oracleAccounts.oracle,
+          governanceState: oracleAccounts.governance,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+    });
+  });
+
+  describe("Updater Policy Tests", () => {
+    const keeper = Keypair.generate();
+
+    const manageUpdaters = (policy: object, updaters: PublicKey[], signer = authority) =>
+      program.methods
+        .manageUpdaters(policy as any, updaters)
+        .accounts({
+          oracleState: oracleAccounts.oracle,
+          governanceState: oracleAccounts.governance,
+          authority: signer.publicKey,
+        })
+        .signers([signer])
+        .rpc();
+
+    const updateAs = (signer: Keypair) =>
+      program.methods
+        .updatePrice({
+          windowSeconds: 3600,
+          minSeconds: 60,
+          minLiquidity: new BN("100000000000"),
+          maxTickDeviation: 1000,
+          alphaBasisPoints: 500,
+          impactTradeSize: new BN(0),
+          confidenceMethod: { variance: {} },
+          weightingScheme: { confidenceWeighted: {} },
+          softSkip: false,
+          strictCoverage: false,
+          maxClockSkew: 0,
+          historyDeviationBps: 0,
+          requireWindowAlignment: true,
+          force: false,
+          maxSaturationEvents: 3,
+          dryRun: true,
+          expectedMin: null,
+          expectedMax: null,
+          windowShort: 0,
+          windowLong: 0,
+          feedEwmaAlphaBps: 2_000,
+          assetSeed: Array.from(assetSeed),
+          useMainnet: true,
+        })
+        .accounts({
+          oracleState: oracleAccounts.oracle,
+          governanceState: oracleAccounts.governance,
+          historicalChunk0: oracleAccounts.historicalChunk0,
+          historicalChunk1: oracleAccounts.historicalChunk1,
+          historicalChunk2: oracleAccounts.historicalChunk2,
+          raydiumPool,
+          raydiumObservation,
+          authority: signer.publicKey,
+        })
+        .signers([signer])
+        .rpc();
+
+    after(async () => {
+      await manageUpdaters({ governanceOnly: {} }, []);
+    });
+
+    it("rejects policy changes from non-members", async () => {
+      try {
+        await manageUpdaters({ allowlist: {} }, [keeper.publicKey], keeper);
+        assert.fail("Expected UnauthorizedCaller");
+      } catch (err: any) {
+        expect(err.toString()).to.include("UnauthorizedCaller");
+      }
+    });
+
+    it("stores the allowlist and policy", async () => {
+      await manageUpdaters({ allowlist: {} }, [keeper.publicKey]);
+
+      const state = await program.account.oracleState.fetch(
+        oracleAccounts.oracle
+      );
+      expect(state.updaterPolicy).to.equal(1);
+      expect(state.allowedUpdaters[0].toBase58()).to.equal(
+        keeper.publicKey.toBase58()
+      );
+    });
+
+    it("rejects unlisted keepers under the allowlist", async () => {
+      const stranger = Keypair.generate();
+      await provider.connection.requestAirdrop(stranger.publicKey, 1_000_000_000);
+
+      try {
+        await updateAs(stranger);
+        assert.fail("Expected UnauthorizedCaller");
+      } catch (err: any) {
+        expect(err.toString()).to.include("UnauthorizedCaller");
+      }
+    });
+  });
+
   describe("Performance Tests", () => {
     it("consumes reasonable compute", async () => {
       const updateConfig = {
@@ -562,6 +992,22 @@ describe("Price Update Integration Tests (mainnet clone)", () => {
         minLiquidity: new BN("100000000000"),
         maxTickDeviation: 1000,
         alphaBasisPoints: 500,
+        impactTradeSize: new BN(0),
+        confidenceMethod: { variance: {} },
+        weightingScheme: { confidenceWeighted: {} },
+        softSkip: false,
+        strictCoverage: false,
+        maxClockSkew: 0,
+        historyDeviationBps: 0,
+        requireWindowAlignment: true,
+        force: false,
+        maxSaturationEvents: 3,
+        dryRun: false,
+        expectedMin: null,
+        expectedMax: null,
+        windowShort: 0,
+        windowLong: 0,
+        feedEwmaAlphaBps: 2_000,
         assetSeed: Array.from(assetSeed),
         useMainnet: true,
       };
@@ -734,6 +1180,22 @@ describe("Price Update Integration Tests (mainnet clone)", () => {
         minLiquidity: new BN("100000000000"),
         maxTickDeviation: 1000,
         alphaBasisPoints: 500,
+        impactTradeSize: new BN(0),
+        confidenceMethod: { variance: {} },
+        weightingScheme: { confidenceWeighted: {} },
+        softSkip: false,
+        strictCoverage: false,
+        maxClockSkew: 0,
+        historyDeviationBps: 0,
+        requireWindowAlignment: true,
+        force: false,
+        maxSaturationEvents: 3,
+        dryRun: false,
+        expectedMin: null,
+        expectedMax: null,
+        windowShort: 0,
+        windowLong: 0,
+        feedEwmaAlphaBps: 2_000,
         assetSeed: Array.from(assetSeed),
         useMainnet: true,
       };